@@ -1,13 +1,18 @@
 use std::sync::Arc;
 
 use axum::{Json, extract::State, http::StatusCode};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use tokio::sync::oneshot;
+
+use crate::api::validation;
+use crate::simulation::{ConceptSize, FlashHealOutcome};
 use crate::state::{AppState, Command};
 
 // === Thaw ===
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct ThawResponse {
     pub status: String,
     pub was_frozen: bool,
@@ -43,12 +48,15 @@ pub async fn thaw(
 
 // === Deep Breath ===
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub struct DeepBreathRequest {
     pub strength: f32,
+    /// Only damp concepts moving faster than this (focused calming)
+    #[serde(default)]
+    pub velocity_threshold: Option<f32>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct DeepBreathResponse {
     pub status: String,
     pub strength: f32,
@@ -59,17 +67,13 @@ pub async fn deep_breath(
     State(state): State<Arc<AppState>>,
     Json(req): Json<DeepBreathRequest>,
 ) -> Result<Json<DeepBreathResponse>, (StatusCode, String)> {
-    if req.strength < 0.0 || req.strength > 1.0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "Strength must be between 0.0 and 1.0".into(),
-        ));
-    }
+    validation::deep_breath(req.strength, req.velocity_threshold)?;
 
     state
         .command_tx
         .send(Command::DeepBreath {
             strength: req.strength,
+            velocity_threshold: req.velocity_threshold,
         })
         .await
         .map_err(|_| {
@@ -87,24 +91,30 @@ pub async fn deep_breath(
 
 // === Flash Heal ===
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub struct FlashHealRequest {
     pub concepts: Vec<FreshConcept>,
     pub dilution_strength: f32,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub struct FreshConcept {
     pub name: String,
     pub density: f32,
-    pub area: f32,
+    /// Cognitive volume (0.0-2.0); give this or `area`, not both (default 0.5)
+    #[serde(default)]
+    pub volume: Option<f32>,
+    /// Surface area the physics uses, instead of deriving it from `volume`
+    #[serde(default)]
+    pub area: Option<f32>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct FlashHealResponse {
     pub status: String,
-    pub concepts_added: usize,
     pub dilution_strength: f32,
+    #[serde(flatten)]
+    pub outcome: FlashHealOutcome,
 }
 
 /// POST /flash-heal - Break crystalline salinity with fresh input
@@ -112,25 +122,30 @@ pub async fn flash_heal(
     State(state): State<Arc<AppState>>,
     Json(req): Json<FlashHealRequest>,
 ) -> Result<Json<FlashHealResponse>, (StatusCode, String)> {
-    if req.dilution_strength < 0.0 || req.dilution_strength > 1.0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "Dilution strength must be between 0.0 and 1.0".into(),
-        ));
-    }
+    validation::flash_heal(
+        req.dilution_strength,
+        req.concepts.iter().map(|c| (c.density, c.volume, c.area)),
+    )?;
 
-    let count = req.concepts.len();
     let concepts: Vec<_> = req
         .concepts
         .into_iter()
-        .map(|c| (c.name, c.density, c.area))
+        .map(|c| {
+            (
+                c.name,
+                c.density,
+                ConceptSize::from_fields(c.volume, c.area),
+            )
+        })
         .collect();
 
+    let (response_tx, response_rx) = oneshot::channel();
     state
         .command_tx
         .send(Command::FlashHeal {
             concepts,
             dilution_strength: req.dilution_strength,
+            response_tx: Some(response_tx),
         })
         .await
         .map_err(|_| {
@@ -140,9 +155,103 @@ pub async fn flash_heal(
             )
         })?;
 
+    let outcome = response_rx.await.map_err(|_| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to apply flash heal".into(),
+        )
+    })?;
+
     Ok(Json(FlashHealResponse {
         status: "Flash heal applied - crystalline structure diluted".into(),
-        concepts_added: count,
         dilution_strength: req.dilution_strength,
+        outcome,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::api::create_router;
+    use crate::simulation::ConceptFluid;
+    use crate::state::FluidEvent;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_flash_heal_reports_the_fluids_own_values() {
+        let mut fluid = ConceptFluid {
+            salinity: 4.0,
+            ..ConceptFluid::default()
+        };
+        let fixation = fluid.add_concept("fixation".to_string(), 0.0, 0.5);
+        fluid.is_frozen = true;
+        fluid.frozen_concept = Some(fixation);
+        let (state, channels) = AppState::new(fluid);
+        let mut events = state.event_tx.subscribe();
+        let fluid = state.fluid.clone();
+        tokio::spawn(crate::runtime::run_simulation_loop(fluid.clone(), channels));
+        let app = create_router(Arc::new(state));
+
+        let body = json!({
+            "concepts": [
+                { "name": "wonder", "density": 0.2, "area": 0.3 },
+                { "name": "joy", "density": 0.15, "area": 0.25 }
+            ],
+            "dilution_strength": 0.6
+        });
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/flash-heal")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let healed: Value = serde_json::from_slice(&bytes).unwrap();
+
+        let event = loop {
+            let event = events.recv().await.unwrap();
+            if matches!(event, FluidEvent::FlashHeal { .. }) {
+                // Through text, as a subscriber would see it
+                let text = serde_json::to_string(&event).unwrap();
+                break serde_json::from_str::<Value>(&text).unwrap();
+            }
+        };
+        for field in [
+            "concepts_added",
+            "old_salinity",
+            "new_salinity",
+            "broke_freeze",
+            "concept_ids",
+        ] {
+            assert_eq!(healed[field], event[field], "{}", field);
+        }
+        assert_eq!(healed["concepts_added"], json!(2));
+        assert_eq!(healed["old_salinity"], json!(4.0));
+        assert_eq!(healed["broke_freeze"], json!(true));
+
+        let fluid = fluid.read().await;
+        assert!(!fluid.is_frozen);
+        // Later ticks keep salting the fluid, so the heal is the ceiling
+        let new_salinity = healed["new_salinity"].as_f64().unwrap() as f32;
+        assert!((new_salinity - 1.6).abs() < 1e-5, "{}", new_salinity);
+        assert!(fluid.salinity >= new_salinity);
+        let names: Vec<_> = healed["concept_ids"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|id| {
+                let id: Uuid = serde_json::from_value(id.clone()).unwrap();
+                fluid.concepts[&id].name.clone()
+            })
+            .collect();
+        assert_eq!(names, ["wonder", "joy"]);
+    }
+}
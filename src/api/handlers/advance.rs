@@ -0,0 +1,74 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{Json, extract::State, http::StatusCode};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+use crate::state::{AppState, Command, FluidEvent};
+
+/// Upper bound on ticks per request - the write lock is held for the whole batch
+pub const MAX_ADVANCE_TICKS: u64 = 10_000;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct AdvanceRequest {
+    pub ticks: u64,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct AdvanceResponse {
+    pub ticks_run: u64,
+    pub tick_count: u64,
+    pub events: Vec<FluidEvent>,
+}
+
+/// POST /advance - Run N ticks synchronously and return every emitted event
+pub async fn advance(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<AdvanceRequest>,
+) -> Result<Json<AdvanceResponse>, (StatusCode, String)> {
+    if req.ticks == 0 || req.ticks > MAX_ADVANCE_TICKS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Ticks must be between 1 and {}", MAX_ADVANCE_TICKS),
+        ));
+    }
+
+    let (response_tx, response_rx) = oneshot::channel();
+
+    state
+        .command_tx
+        .send(Command::Advance {
+            ticks: req.ticks,
+            response_tx,
+        })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+
+    let (tick_count, events) = tokio::time::timeout(Duration::from_secs(30), response_rx)
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::GATEWAY_TIMEOUT,
+                "Simulation response timeout".into(),
+            )
+        })?
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to advance simulation".into(),
+            )
+        })?;
+
+    Ok(Json(AdvanceResponse {
+        ticks_run: req.ticks,
+        tick_count,
+        events,
+    }))
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State, http::StatusCode};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::api::validation;
+use crate::simulation::Spotlight;
+use crate::state::{AppState, Command};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct FocusRequest {
+    /// Centre of the window (0.0 = surface, 1.0 = floor)
+    pub depth: f32,
+    /// Half-width of the window (0.0-1.0]
+    pub radius: f32,
+    /// Integration each concept inside gains per second (0.0-10.0)
+    pub strength: f32,
+    /// Seconds the focus lasts (default 5, at most 600)
+    #[serde(default = "default_duration")]
+    pub duration: f32,
+}
+
+fn default_duration() -> f32 {
+    5.0
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct FocusResponse {
+    pub id: Uuid,
+    pub depth: f32,
+    pub radius: f32,
+    pub strength: f32,
+    pub duration: f32,
+    /// Concepts inside the window when the focus began
+    pub concepts_in_focus: usize,
+}
+
+/// POST /attention/focus - Think harder about one depth of the fluid
+#[instrument(name = "focus_attention", skip_all)]
+pub async fn focus_attention(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<FocusRequest>,
+) -> Result<Json<FocusResponse>, (StatusCode, String)> {
+    validation::attention(req.depth, req.radius, req.strength, req.duration)?;
+
+    let spotlight = Spotlight::new(req.depth, req.radius, req.strength, req.duration);
+    let id = spotlight.id;
+    let (response_tx, response_rx) = oneshot::channel();
+    state
+        .command_tx
+        .send(Command::FocusAttention {
+            spotlight,
+            response_tx,
+        })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+
+    let concepts_in_focus = response_rx
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to focus attention".into(),
+            )
+        })?
+        .map_err(|err| (StatusCode::CONFLICT, err))?;
+
+    Ok(Json(FocusResponse {
+        id,
+        depth: req.depth,
+        radius: req.radius,
+        strength: req.strength,
+        duration: req.duration,
+        concepts_in_focus,
+    }))
+}
@@ -1,51 +1,118 @@
 use std::sync::Arc;
+use std::time::Duration;
 
-use axum::{Json, extract::State, http::StatusCode};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+use tracing::instrument;
 use uuid::Uuid;
 
+use crate::api::validation;
+use crate::simulation::FluidError;
 use crate::state::{AppState, Command};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub struct BallastRequest {
     pub id: Uuid,
     pub weight_delta: f32,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct BallastResponse {
     pub id: Uuid,
     pub weight_delta: f32,
     pub status: String,
 }
 
-/// PATCH /ballast - Apply ballast to force benthic expedition
-pub async fn apply_ballast(
-    State(state): State<Arc<AppState>>,
-    Json(req): Json<BallastRequest>,
-) -> Result<Json<BallastResponse>, (StatusCode, String)> {
-    // Validate
-    if req.weight_delta < -1.0 || req.weight_delta > 1.0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "weight_delta must be between -1.0 and 1.0".into(),
-        ));
+#[derive(Deserialize, JsonSchema)]
+pub struct BuoyancyRequest {
+    pub id: Uuid,
+    /// Added to buoyancy, scaled by `1 - density` (-1.0 to 1.0)
+    pub delta: f32,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct BuoyancyResponse {
+    pub id: Uuid,
+    pub delta: f32,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct SubmergeRequest {
+    /// Layer it is brought to rest at (0.0-1.0)
+    pub target_layer: f32,
+    /// Least ballast to give it (0.0-1.0); it gets at least what holds it at
+    /// `target_layer`
+    pub ballast: f32,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct SubmergeResponse {
+    pub id: Uuid,
+    pub target_layer: f32,
+    pub ballast: f32,
+}
+
+impl IntoResponse for FluidError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            Self::ConceptNotFound { .. } | Self::LinkNotFound { .. } => StatusCode::NOT_FOUND,
+            Self::ConceptInExperiment { .. } => StatusCode::CONFLICT,
+        };
+        (status, Json(self)).into_response()
     }
+}
+
+/// Send a command that can fail and wait for the simulation loop's outcome.
+/// Fluid errors become their JSON body; transport failures stay plain text.
+pub(super) async fn apply<T>(
+    state: &AppState,
+    command: Command,
+    response_rx: oneshot::Receiver<Result<T, FluidError>>,
+    failure: &str,
+) -> Result<T, Response> {
+    state.command_tx.send(command).await.map_err(|_| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Simulation not running".to_string(),
+        )
+            .into_response()
+    })?;
 
-    // Send command
-    state
-        .command_tx
-        .send(Command::Ballast {
-            concept_id: req.id,
-            weight_delta: req.weight_delta,
-        })
+    tokio::time::timeout(Duration::from_secs(5), response_rx)
         .await
         .map_err(|_| {
             (
-                StatusCode::SERVICE_UNAVAILABLE,
-                "Simulation not running".into(),
+                StatusCode::GATEWAY_TIMEOUT,
+                "Simulation response timeout".to_string(),
             )
-        })?;
+                .into_response()
+        })?
+        .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, failure.to_string()).into_response())?
+        .map_err(IntoResponse::into_response)
+}
+
+/// PATCH /ballast - Apply ballast to force benthic expedition
+#[instrument(name = "ballast", skip_all, fields(concept_id = %req.id))]
+pub async fn apply_ballast(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<BallastRequest>,
+) -> Result<Json<BallastResponse>, Response> {
+    validation::ballast(req.weight_delta).map_err(IntoResponse::into_response)?;
+
+    let (response_tx, response_rx) = oneshot::channel();
+    let command = Command::Ballast {
+        concept_id: req.id,
+        weight_delta: req.weight_delta,
+        response_tx,
+    };
+    apply(&state, command, response_rx, "Failed to apply ballast").await?;
 
     let status = if req.weight_delta > 0.0 {
         "Benthic expedition initiated - concept descending"
@@ -59,3 +126,122 @@ pub async fn apply_ballast(
         status: status.into(),
     }))
 }
+
+/// POST /concept/:id/submerge - Suppress a concept that keeps acting
+#[instrument(name = "submerge", skip_all, fields(concept_id = %id))]
+pub async fn submerge_concept(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<SubmergeRequest>,
+) -> Result<Json<SubmergeResponse>, Response> {
+    validation::submerge(req.target_layer, req.ballast).map_err(IntoResponse::into_response)?;
+
+    let (response_tx, response_rx) = oneshot::channel();
+    let command = Command::Submerge {
+        concept_id: id,
+        target_layer: req.target_layer,
+        ballast: req.ballast,
+        response_tx,
+    };
+    apply(&state, command, response_rx, "Failed to submerge concept").await?;
+
+    let ballast = state
+        .fluid
+        .read()
+        .await
+        .get_concept(id)
+        .map_or(req.ballast, |concept| concept.ballast);
+    Ok(Json(SubmergeResponse {
+        id,
+        target_layer: req.target_layer,
+        ballast,
+    }))
+}
+
+/// PATCH /buoyancy - Nudge a concept's buoyancy (and velocity) directly
+#[instrument(name = "modulate_buoyancy", skip_all, fields(concept_id = %req.id))]
+pub async fn modulate_buoyancy(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<BuoyancyRequest>,
+) -> Result<Json<BuoyancyResponse>, Response> {
+    validation::buoyancy(req.delta).map_err(IntoResponse::into_response)?;
+
+    let (response_tx, response_rx) = oneshot::channel();
+    let command = Command::ModulateBuoyancy {
+        concept_id: req.id,
+        delta: req.delta,
+        response_tx,
+    };
+    apply(&state, command, response_rx, "Failed to modulate buoyancy").await?;
+
+    Ok(Json(BuoyancyResponse {
+        id: req.id,
+        delta: req.delta,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::api::create_router;
+    use crate::simulation::ConceptFluid;
+
+    async fn patch(app: &axum::Router, uri: &str, body: Value) -> (StatusCode, Value) {
+        let request = axum::http::Request::builder()
+            .method("PATCH")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_ballast_and_buoyancy_report_unknown_concepts() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("stuck_thought".to_string(), 0.5, 0.5);
+        let (state, channels) = AppState::new(fluid);
+        let fluid = state.fluid.clone();
+        tokio::spawn(crate::runtime::run_simulation_loop(fluid.clone(), channels));
+        let app = create_router(Arc::new(state));
+
+        let missing = Uuid::new_v4();
+        for (uri, body) in [
+            ("/ballast", json!({ "id": missing, "weight_delta": 0.4 })),
+            ("/buoyancy", json!({ "id": missing, "delta": 0.4 })),
+        ] {
+            let (status, error) = patch(&app, uri, body).await;
+            assert_eq!(status, StatusCode::NOT_FOUND, "{}", uri);
+            assert_eq!(
+                error,
+                json!({ "error": "concept_not_found", "id": missing })
+            );
+        }
+
+        let (status, _) = patch(&app, "/ballast", json!({ "id": id, "weight_delta": 0.4 })).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, body) = patch(&app, "/buoyancy", json!({ "id": id, "delta": 0.4 })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["id"], json!(id));
+
+        let fluid = fluid.read().await;
+        let concept = fluid.get_concept(id).unwrap();
+        assert_eq!(concept.ballast, 0.4);
+        assert!(
+            (concept.buoyancy - 0.7).abs() < 1e-6,
+            "0.4 scaled by 1 - density"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Response,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::ballast::apply;
+use crate::api::validation;
+use crate::simulation::{ConceptFilter, ConceptOrigin};
+use crate::state::{AppState, Command, ConceptDetail, ConceptSummary};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct RenameRequest {
+    pub name: String,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct RenameResponse {
+    pub id: Uuid,
+    pub old_name: String,
+    pub name: String,
+}
+
+/// PATCH /concept/:id/name - Reframe a thought without touching its physics
+#[instrument(name = "rename", skip_all, fields(concept_id = %id))]
+pub async fn rename_concept(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<RenameRequest>,
+) -> Result<Json<RenameResponse>, (StatusCode, String)> {
+    validation::rename(&req.name)?;
+    let name = req.name.trim().to_string();
+
+    let (response_tx, response_rx) = oneshot::channel();
+
+    state
+        .command_tx
+        .send(Command::Rename {
+            concept_id: id,
+            name: name.clone(),
+            response_tx,
+        })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+
+    let old_name = tokio::time::timeout(Duration::from_secs(5), response_rx)
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::GATEWAY_TIMEOUT,
+                "Simulation response timeout".into(),
+            )
+        })?
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to rename concept".into(),
+            )
+        })?
+        .ok_or((StatusCode::NOT_FOUND, format!("Concept {} not found", id)))?;
+
+    Ok(Json(RenameResponse { id, old_name, name }))
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct RemoveResponse {
+    pub id: Uuid,
+    pub name: String,
+}
+
+/// DELETE /concept/:id - Take a single concept out of the fluid
+#[instrument(name = "remove_concept", skip_all, fields(concept_id = %id))]
+pub async fn remove_concept(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<RemoveResponse>, Response> {
+    let (response_tx, response_rx) = oneshot::channel();
+    let command = Command::RemoveConcept {
+        concept_id: id,
+        response_tx,
+    };
+    let name = apply(&state, command, response_rx, "Failed to remove concept").await?;
+
+    Ok(Json(RemoveResponse { id, name }))
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct PruneResponse {
+    pub count: usize,
+    /// Removed concepts, oldest first
+    pub ids: Vec<Uuid>,
+}
+
+/// POST /concept/prune - Remove every concept matching a filter in one tick
+#[instrument(name = "prune", skip_all)]
+pub async fn prune_concepts(
+    State(state): State<Arc<AppState>>,
+    Json(filter): Json<ConceptFilter>,
+) -> Result<Json<PruneResponse>, (StatusCode, String)> {
+    filter
+        .validate()
+        .map_err(|err| (StatusCode::BAD_REQUEST, err))?;
+
+    let (response_tx, response_rx) = oneshot::channel();
+
+    state
+        .command_tx
+        .send(Command::Prune {
+            filter,
+            response_tx,
+        })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+
+    let ids = tokio::time::timeout(Duration::from_secs(5), response_rx)
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::GATEWAY_TIMEOUT,
+                "Simulation response timeout".into(),
+            )
+        })?
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to prune concepts".into(),
+            )
+        })?;
+
+    Ok(Json(PruneResponse {
+        count: ids.len(),
+        ids,
+    }))
+}
+
+/// Most neighbors `/concept/:id/neighbors` returns
+const MAX_NEIGHBORS: usize = 100;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct NeighborsQuery {
+    /// How many neighbors to return (1-100)
+    #[serde(default = "default_neighbors")]
+    pub k: usize,
+}
+
+fn default_neighbors() -> usize {
+    5
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct Neighbor {
+    pub id: Uuid,
+    pub name: String,
+    pub layer: f32,
+    /// |layer difference| from the queried concept
+    pub distance: f32,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct NeighborsResponse {
+    pub id: Uuid,
+    pub layer: f32,
+    /// Nearest first
+    pub neighbors: Vec<Neighbor>,
+}
+
+/// GET /concept/:id - Inspect a single concept
+pub async fn get_concept(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ConceptDetail>, (StatusCode, String)> {
+    let fluid = state.fluid.read().await;
+    let concept = fluid
+        .get_concept(id)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, format!("Concept {} not found", id)))?;
+    Ok(Json(ConceptDetail::of(&fluid, concept)))
+}
+
+/// GET /concept/:id/neighbors - The concepts closest in depth to this one
+pub async fn get_concept_neighbors(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<NeighborsQuery>,
+) -> Result<Json<NeighborsResponse>, (StatusCode, String)> {
+    if !(1..=MAX_NEIGHBORS).contains(&query.k) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("k must be between 1 and {}", MAX_NEIGHBORS),
+        ));
+    }
+
+    let fluid = state.fluid.read().await;
+    let not_found = || (StatusCode::NOT_FOUND, format!("Concept {} not found", id));
+    let layer = fluid.get_concept(id).ok_or_else(not_found)?.layer;
+    let neighbors = fluid
+        .depth_neighbors(id, query.k)
+        .ok_or_else(not_found)?
+        .into_iter()
+        .map(|(concept, distance)| Neighbor {
+            id: concept.id,
+            name: concept.name.clone(),
+            layer: concept.layer,
+            distance,
+        })
+        .collect();
+
+    Ok(Json(NeighborsResponse {
+        id,
+        layer,
+        neighbors,
+    }))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ConceptsQuery {
+    /// Only concepts that came into the fluid this way (default: all)
+    #[serde(default)]
+    pub origin: Option<ConceptOrigin>,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct ConceptsResponse {
+    pub count: usize,
+    /// Oldest first
+    pub concepts: Vec<ConceptSummary>,
+}
+
+/// GET /concepts - List concepts, optionally only those of one origin
+pub async fn list_concepts(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ConceptsQuery>,
+) -> Json<ConceptsResponse> {
+    let fluid = state.fluid.read().await;
+    let concepts: Vec<_> = fluid
+        .concepts_by_origin(query.origin)
+        .into_iter()
+        .map(|c| ConceptSummary::of(&fluid, c))
+        .collect();
+
+    Json(ConceptsResponse {
+        count: concepts.len(),
+        concepts,
+    })
+}
@@ -1,14 +1,21 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::{Json, extract::State, http::StatusCode};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tokio::sync::oneshot;
+use tracing::{Span, field, instrument};
 use uuid::Uuid;
 
-use crate::simulation::consensus_reactor::VentDominance;
+use crate::simulation::consensus_reactor::{self, VentDominance};
+use crate::simulation::{
+    ConsensusOre, ConsensusStats, ConsensusSummary, FalloffProfile, QualityThresholds,
+    QualityThresholdsPatch,
+};
 use crate::state::{AppState, Command};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub struct ConsensusRequest {
     /// First contradictory position (e.g., "Privacy is absolute")
     pub position_a: String,
@@ -20,13 +27,22 @@ pub struct ConsensusRequest {
     /// Conviction strength of second position (0.1-2.0)
     #[serde(default = "default_heat")]
     pub heat_b: f32,
+    /// Record a per-tick trace for GET /consensus/ores/{id}/trace.csv
+    #[serde(default)]
+    pub trace: bool,
+    /// Leave the probes in the fluid as concepts after crystallization
+    #[serde(default)]
+    pub keep_particles: bool,
+    /// How both vents' push fades toward their edge (default quadratic)
+    #[serde(default)]
+    pub falloff: FalloffProfile,
 }
 
 fn default_heat() -> f32 {
     1.0
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct ConsensusStartResponse {
     pub experiment_id: Uuid,
     pub position_a: String,
@@ -37,7 +53,7 @@ pub struct ConsensusStartResponse {
     pub message: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct ConsensusStatusResponse {
     pub active: bool,
     pub position_a: Option<String>,
@@ -49,7 +65,7 @@ pub struct ConsensusStatusResponse {
     pub stable_ticks: Option<u32>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct ConsensusOreResponse {
     pub id: Uuid,
     pub name: String,
@@ -60,16 +76,29 @@ pub struct ConsensusOreResponse {
     pub quality: String,
     pub is_foundational: bool,
     pub insight: Option<String>,
+    /// ∫|Jitter|dt over the whole experiment; certainty = 1 / (1 + this)
     pub accumulated_jitter: f32,
+    /// Largest single-tick jitter
+    pub peak_jitter: f32,
+    /// ∫|Jitter|dt over the last two seconds before crystallizing
+    pub jitter_integral: f32,
+    /// Ticks from ignition to crystallization
     pub crystallization_time: u64,
+    /// Consecutive low-jitter ticks at crystallization (below the stability
+    /// requirement: forced by the time limit)
+    pub stable_ticks_at_crystallization: u32,
+    /// How the certainty follows from the components above
+    pub certainty_explanation: String,
     pub integration_value: f32,
     /// The extracted phase structure (physical topology) - the "new material"
     pub phase_structure: Option<PhaseStructureResponse>,
+    /// Concept injected from this ore (experiments started from live concepts)
+    pub insight_concept: Option<Uuid>,
 }
 
 /// The physical structure extracted at phase transition.
 /// This is NOT a compromise - it's what SURVIVES the collision dynamics.
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct PhaseStructureResponse {
     pub id: Uuid,
     pub transition_tick: u64,
@@ -92,7 +121,7 @@ pub struct PhaseStructureResponse {
     pub voronoi_cells: Vec<VoronoiCellResponse>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct EmergentPropertyResponse {
     pub name: String,
     pub physical_basis: String,
@@ -100,7 +129,7 @@ pub struct EmergentPropertyResponse {
     pub depth_range: (f32, f32),
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct VoronoiCellResponse {
     pub center: f32,
     pub left_bound: f32,
@@ -117,6 +146,7 @@ pub struct VoronoiCellResponse {
 /// The certainty metric C = 1 / (1 + ∫|Jitter|dt) determines quality:
 /// - C → 1: "Foundational Truth" (low jitter, stable convergence)
 /// - C → 0: "Noise" (high jitter, chaotic oscillation)
+#[instrument(name = "consensus", skip_all, fields(experiment_id = field::Empty))]
 pub async fn start_consensus(
     State(state): State<Arc<AppState>>,
     Json(req): Json<ConsensusRequest>,
@@ -149,6 +179,9 @@ pub async fn start_consensus(
             heat_a: req.heat_a,
             position_b: req.position_b.clone(),
             heat_b: req.heat_b,
+            trace: req.trace,
+            keep_particles: req.keep_particles,
+            falloff: req.falloff,
             response_tx: tx,
         })
         .await
@@ -166,6 +199,7 @@ pub async fn start_consensus(
             "Failed to start consensus experiment".into(),
         )
     })?;
+    Span::current().record("experiment_id", field::display(experiment_id));
 
     let heat_comparison = if (req.heat_a - req.heat_b).abs() < 0.2 {
         "balanced conviction"
@@ -193,6 +227,102 @@ pub async fn start_consensus(
     }))
 }
 
+#[derive(Deserialize, JsonSchema)]
+pub struct ConsensusFromConceptsRequest {
+    /// First live concept
+    pub id_a: Uuid,
+    /// Second live concept, contradicting the first
+    pub id_b: Uuid,
+    /// Record a per-tick trace for GET /consensus/ores/{id}/trace.csv
+    #[serde(default)]
+    pub trace: bool,
+    /// Leave the probes in the fluid as concepts after crystallization
+    #[serde(default)]
+    pub keep_particles: bool,
+    /// How both vents' push fades toward their edge (default quadratic)
+    #[serde(default)]
+    pub falloff: FalloffProfile,
+}
+
+/// POST /consensus/from-concepts - Mark two live concepts as contradictory
+///
+/// Their names become the positions and their buoyancy the heat; probes are
+/// injected between their current depths. When the experiment crystallizes,
+/// the insight is injected back into the fluid as a new concept.
+#[instrument(
+    name = "consensus_from_concepts",
+    skip_all,
+    fields(concept_a = %req.id_a, concept_b = %req.id_b, experiment_id = field::Empty)
+)]
+pub async fn start_consensus_from_concepts(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<ConsensusFromConceptsRequest>,
+) -> Result<Json<ConsensusStartResponse>, (StatusCode, String)> {
+    if req.id_a == req.id_b {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "A concept cannot contradict itself".into(),
+        ));
+    }
+
+    let (tx, rx) = oneshot::channel();
+
+    state
+        .command_tx
+        .send(Command::StartConsensusFromConcepts {
+            id_a: req.id_a,
+            id_b: req.id_b,
+            trace: req.trace,
+            keep_particles: req.keep_particles,
+            falloff: req.falloff,
+            response_tx: tx,
+        })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+
+    let experiment_id = rx
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to start consensus experiment".into(),
+            )
+        })?
+        .ok_or((StatusCode::NOT_FOUND, "Concept not found".to_string()))?;
+    Span::current().record("experiment_id", field::display(experiment_id));
+
+    let fluid = state.fluid.read().await;
+    let exp = fluid
+        .get_consensus_experiment()
+        .filter(|exp| exp.id == experiment_id)
+        .ok_or((
+            StatusCode::CONFLICT,
+            "Consensus experiment already finished or replaced".to_string(),
+        ))?;
+
+    let message = format!(
+        "Consensus Reactor ignited between live concepts. '{}' collides with '{}'.\n\
+         Probe bubbles injected between their depths. \
+         The crystallized insight will rejoin the fluid as a new concept.",
+        exp.vent_a.position, exp.vent_b.position
+    );
+
+    Ok(Json(ConsensusStartResponse {
+        experiment_id,
+        position_a: exp.vent_a.position.clone(),
+        position_b: exp.vent_b.position.clone(),
+        heat_a: exp.vent_a.heat_output,
+        heat_b: exp.vent_b.heat_output,
+        probe_count: exp.probe_ids.len(),
+        message,
+    }))
+}
+
 /// GET /consensus/status - Get current consensus experiment status
 pub async fn get_consensus_status(
     State(state): State<Arc<AppState>>,
@@ -226,8 +356,8 @@ pub async fn get_consensus_status(
     }
 }
 
-/// Convert a ConsensusOre to API response format
-fn ore_to_response(ore: &crate::simulation::ConsensusOre) -> ConsensusOreResponse {
+/// Convert a ConsensusOre to API response format, classified by `thresholds`
+fn ore_to_response(ore: &ConsensusOre, thresholds: &QualityThresholds) -> ConsensusOreResponse {
     let phase_structure = ore
         .phase_structure
         .as_ref()
@@ -269,6 +399,19 @@ fn ore_to_response(ore: &crate::simulation::ConsensusOre) -> ConsensusOreRespons
                 .collect(),
         });
 
+    let certainty_explanation = format!(
+        "certainty = 1 / (1 + {:.3} accumulated jitter) = {:.3} ({}); \
+         peak jitter {:.3}, {:.3} over the final two seconds, \
+         {} stable ticks when it crystallized after {} ticks",
+        ore.accumulated_jitter,
+        ore.certainty,
+        ore.quality(thresholds),
+        ore.peak_jitter,
+        ore.jitter_integral,
+        ore.stable_ticks_at_crystallization,
+        ore.crystallization_time
+    );
+
     ConsensusOreResponse {
         id: ore.id,
         name: ore.name.clone(),
@@ -276,42 +419,252 @@ fn ore_to_response(ore: &crate::simulation::ConsensusOre) -> ConsensusOreRespons
         position_a: ore.vent_a.clone(),
         position_b: ore.vent_b.clone(),
         certainty: ore.certainty,
-        quality: ore.quality().to_string(),
-        is_foundational: ore.is_foundational(),
+        quality: ore.quality(thresholds).to_string(),
+        is_foundational: ore.is_foundational(thresholds),
         insight: ore.insight.clone(),
         accumulated_jitter: ore.accumulated_jitter,
+        peak_jitter: ore.peak_jitter,
+        jitter_integral: ore.jitter_integral,
         crystallization_time: ore.crystallization_time,
+        stable_ticks_at_crystallization: ore.stable_ticks_at_crystallization,
+        certainty_explanation,
         integration_value: ore.integration_value,
         phase_structure,
+        insight_concept: ore.insight_concept,
     }
 }
 
+/// POST /consensus/crystallize - Crystallize the active experiment now
+///
+/// For an experiment hovering just above the jitter threshold: the ore takes
+/// whatever certainty the jitter accumulated so far gives, as if it had run
+/// out of time.
+pub async fn crystallize_consensus(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<ConsensusOreResponse>, (StatusCode, String)> {
+    let (response_tx, response_rx) = oneshot::channel();
+    state
+        .command_tx
+        .send(Command::CrystallizeNow { response_tx })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+
+    let ore = response_rx
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to crystallize consensus experiment".into(),
+            )
+        })?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            "No active consensus experiment".to_string(),
+        ))?;
+
+    let fluid = state.fluid.read().await;
+    Ok(Json(ore_to_response(
+        &ore,
+        &fluid.consensus_reactor.quality_thresholds,
+    )))
+}
+
 /// GET /consensus/ores - Get all crystallized consensus ores
 pub async fn get_consensus_ores(
     State(state): State<Arc<AppState>>,
 ) -> Json<Vec<ConsensusOreResponse>> {
     let fluid = state.fluid.read().await;
+    let thresholds = &fluid.consensus_reactor.quality_thresholds;
 
     let ores: Vec<ConsensusOreResponse> = fluid
         .get_consensus_ores()
         .iter()
-        .map(ore_to_response)
+        .map(|ore| ore_to_response(ore, thresholds))
         .collect();
 
     Json(ores)
 }
 
-/// GET /consensus/truths - Get foundational truths (C > 0.8)
+/// GET /consensus/truths - Get foundational truths (C above the
+/// `foundational` threshold, 0.8 by default)
 pub async fn get_foundational_truths(
     State(state): State<Arc<AppState>>,
 ) -> Json<Vec<ConsensusOreResponse>> {
     let fluid = state.fluid.read().await;
+    let thresholds = &fluid.consensus_reactor.quality_thresholds;
 
     let truths: Vec<ConsensusOreResponse> = fluid
         .get_foundational_truths()
         .iter()
-        .map(|ore| ore_to_response(ore))
+        .map(|ore| ore_to_response(ore, thresholds))
         .collect();
 
     Json(truths)
 }
+
+/// GET /consensus/stats - Certainty distribution over all completed experiments
+pub async fn get_consensus_stats(State(state): State<Arc<AppState>>) -> Json<ConsensusStats> {
+    let fluid = state.fluid.read().await;
+    Json(consensus_reactor::stats(
+        &fluid.consensus_reactor.ores,
+        &fluid.consensus_reactor.quality_thresholds,
+    ))
+}
+
+/// GET /consensus/summary - Experiment and deposit counts at a glance
+pub async fn get_consensus_summary(State(state): State<Arc<AppState>>) -> Json<ConsensusSummary> {
+    let fluid = state.fluid.read().await;
+    Json(fluid.consensus_reactor.summary())
+}
+
+/// GET /consensus/config - Certainty cutoffs used to classify ores
+pub async fn get_consensus_config(State(state): State<Arc<AppState>>) -> Json<QualityThresholds> {
+    let fluid = state.fluid.read().await;
+    Json(fluid.consensus_reactor.quality_thresholds)
+}
+
+/// PATCH /consensus/config - Change the certainty cutoffs. Applies to
+/// existing ores too, since they are classified whenever they are read.
+pub async fn update_consensus_config(
+    State(state): State<Arc<AppState>>,
+    Json(patch): Json<QualityThresholdsPatch>,
+) -> Result<Json<QualityThresholds>, (StatusCode, String)> {
+    let (response_tx, response_rx) = oneshot::channel();
+    state
+        .command_tx
+        .send(Command::UpdateQualityThresholds { patch, response_tx })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+    let thresholds = tokio::time::timeout(Duration::from_secs(5), response_rx)
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::GATEWAY_TIMEOUT,
+                "Simulation response timeout".into(),
+            )
+        })?
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to update consensus config".into(),
+            )
+        })?
+        .map_err(|message| (StatusCode::BAD_REQUEST, message))?;
+
+    Ok(Json(thresholds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::api::create_router;
+    use crate::simulation::{ConceptFluid, ConsensusExperiment};
+
+    async fn send(app: &axum::Router, method: &str, uri: &str, body: Value) -> (StatusCode, Value) {
+        let request = axum::http::Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[test]
+    fn test_low_jitter_ore_explains_its_high_certainty() {
+        let mut exp = ConsensusExperiment::new("calm".to_string(), 1.0, "steady".to_string(), 1.0);
+        // Probes drift apart slowly: 0.001 jitter every tick
+        let mut tick = 0;
+        loop {
+            exp.record_velocity(0.001 * tick as f32);
+            let jitter = exp.current_jitter();
+            exp.accumulated_jitter += jitter;
+            exp.peak_jitter = exp.peak_jitter.max(jitter);
+            if exp.check_crystallization(tick) {
+                break;
+            }
+            tick += 1;
+        }
+
+        let ore = ore_to_response(&exp.crystallize(tick), &QualityThresholds::default());
+
+        assert!(ore.certainty > 0.9, "certainty {}", ore.certainty);
+        assert!((ore.certainty - 1.0 / (1.0 + ore.accumulated_jitter)).abs() < 1e-6);
+        assert!((ore.peak_jitter - 0.001).abs() < 1e-6);
+        assert!(ore.jitter_integral <= ore.accumulated_jitter);
+        assert_eq!(
+            ore.stable_ticks_at_crystallization,
+            exp.stability_requirement
+        );
+        assert_eq!(ore.crystallization_time, tick);
+        assert_eq!(ore.quality, "foundational_truth");
+        assert!(
+            ore.certainty_explanation.starts_with(&format!(
+                "certainty = 1 / (1 + {:.3} accumulated jitter) = {:.3} (foundational_truth)",
+                ore.accumulated_jitter, ore.certainty
+            )),
+            "{}",
+            ore.certainty_explanation
+        );
+    }
+
+    #[tokio::test]
+    async fn test_patched_thresholds_reclassify_truths() {
+        let mut fluid = ConceptFluid::default();
+        fluid.consensus_reactor.ores = vec![
+            ConsensusOre::test_fixture(0.75),
+            ConsensusOre::test_fixture(0.85),
+        ];
+        let (state, channels) = AppState::new(fluid);
+        tokio::spawn(crate::runtime::run_simulation_loop(
+            state.fluid.clone(),
+            channels,
+        ));
+        let app = create_router(Arc::new(state));
+
+        let (_, truths) = send(&app, "GET", "/consensus/truths", Value::Null).await;
+        assert_eq!(truths.as_array().unwrap().len(), 1);
+
+        let (status, config) = send(
+            &app,
+            "PATCH",
+            "/consensus/config",
+            json!({ "foundational": 0.7 }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(config["foundational"], json!(0.7));
+        assert_eq!(config["noise"], json!(0.2));
+
+        let (_, truths) = send(&app, "GET", "/consensus/truths", Value::Null).await;
+        assert_eq!(truths.as_array().unwrap().len(), 2);
+
+        // Noise above the foundational cutoff is rejected and changes nothing
+        let (status, _) = send(&app, "PATCH", "/consensus/config", json!({ "noise": 0.9 })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (_, config) = send(&app, "GET", "/consensus/config", Value::Null).await;
+        assert_eq!(config["noise"], json!(0.2));
+    }
+}
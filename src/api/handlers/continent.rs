@@ -1,16 +1,17 @@
 use std::sync::Arc;
 
 use axum::{Json, extract::State, http::StatusCode};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::state::{AppState, Command};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub struct TectonicRequest {
     pub pressure_threshold: f32,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct ContinentResponse {
     pub name: String,
     pub depth_range: (f32, f32),
@@ -20,7 +21,7 @@ pub struct ContinentResponse {
     pub formed_from_ores: Vec<String>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct TectonicResponse {
     pub status: String,
     pub current_pressure: f32,
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::state::{AppState, DispatchLog, DispatchRuleConfig, DispatchRuleView};
+
+/// GET /dispatch-rules - List dispatch rules in precedence order
+pub async fn list_dispatch_rules(
+    State(state): State<Arc<AppState>>,
+) -> Json<Vec<DispatchRuleView>> {
+    Json(state.dispatch_rules.list().await)
+}
+
+/// POST /dispatch-rules - Add a dispatch rule after the existing ones
+pub async fn create_dispatch_rule(
+    State(state): State<Arc<AppState>>,
+    Json(rule): Json<DispatchRuleConfig>,
+) -> Result<Json<DispatchRuleView>, (StatusCode, String)> {
+    rule.compile()
+        .map_err(|err| (StatusCode::BAD_REQUEST, err))?;
+    state
+        .dispatch_rules
+        .insert(rule)
+        .await
+        .map(Json)
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err))
+}
+
+/// DELETE /dispatch-rules/:id - Remove a dispatch rule
+pub async fn delete_dispatch_rule(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<u64>,
+) -> Result<Json<DispatchRuleView>, (StatusCode, String)> {
+    state
+        .dispatch_rules
+        .remove(id)
+        .await
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err))?
+        .map(Json)
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("Dispatch rule {} not found", id),
+        ))
+}
+
+/// GET /dispatch-rules/:id/log - Recent breakthroughs handled by a rule
+pub async fn dispatch_rule_log(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<u64>,
+) -> Result<Json<DispatchLog>, (StatusCode, String)> {
+    state.dispatch_rules.log(id).await.map(Json).ok_or((
+        StatusCode::NOT_FOUND,
+        format!("Dispatch rule {} not found", id),
+    ))
+}
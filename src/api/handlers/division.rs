@@ -1,13 +1,16 @@
 use std::sync::Arc;
 
 use axum::{Json, extract::State, http::StatusCode};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tokio::sync::oneshot;
+use tracing::{Span, field, instrument};
 use uuid::Uuid;
 
+use crate::simulation::{SettledBy, SettlementThresholds};
 use crate::state::{AppState, Command};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub struct DivisionRequest {
     /// The dividend (V) - number of bubbles to inject
     pub dividend: f32,
@@ -17,9 +20,25 @@ pub struct DivisionRequest {
     /// Higher values dampen "volume overhead" noise, making remainder turbulence clearer
     #[serde(default)]
     pub salinity: f32,
+    /// Record a per-tick trace for GET /divide/results/{id}/trace.csv
+    #[serde(default)]
+    pub trace: bool,
+    /// Leave the bubbles in the fluid as concepts, named for their node,
+    /// after settlement
+    #[serde(default)]
+    pub keep_particles: bool,
+    /// Minimum ticks before the experiment may settle (default scales with divisor)
+    #[serde(default)]
+    pub min_ticks: Option<u64>,
+    /// Every bubble must be slower than this to settle (default scales with bubble count)
+    #[serde(default)]
+    pub max_velocity: Option<f32>,
+    /// Mean bubble speed must be below this to settle (default scales with divisor)
+    #[serde(default)]
+    pub avg_velocity: Option<f32>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct DivisionStartResponse {
     pub experiment_id: Uuid,
     pub dividend: f32,
@@ -30,7 +49,7 @@ pub struct DivisionStartResponse {
     pub message: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct ExperimentStatusResponse {
     pub active: bool,
     pub dividend: Option<f32>,
@@ -39,10 +58,14 @@ pub struct ExperimentStatusResponse {
     pub node_count: Option<usize>,
     pub accumulated_turbulence: Option<f32>,
     pub ticks_elapsed: Option<u64>,
+    pub max_ticks: Option<u64>,
+    /// Settlement criteria in effect for this experiment
+    pub thresholds: Option<SettlementThresholds>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct DivisionResultResponse {
+    pub id: Uuid,
     pub dividend: f32,
     pub divisor: f32,
     pub quotient: f32,
@@ -51,6 +74,13 @@ pub struct DivisionResultResponse {
     pub turbulence_energy: f32,
     pub reynolds_number: f32,
     pub ticks_to_settle: u64,
+    /// `timed_out` when forced to finish at max_ticks (less trustworthy)
+    pub settled_by: SettledBy,
+    /// Held still for the whole stability window (false for a timeout)
+    pub settled_naturally: bool,
+    /// 0-1: how much of the stability window held, times the share of
+    /// bubbles at a node
+    pub confidence: f32,
     pub node_occupancy: Vec<u32>,
     pub salinity_boost: f32,
     /// Velocity standard deviation - "arrival jitter" detector
@@ -70,6 +100,11 @@ pub struct DivisionResultResponse {
 /// - Dividend V → stream of bubbles injected
 /// - If V/n is integer → laminar flow (bubbles fill nodes perfectly)
 /// - If V/n has remainder → turbulence (extra bubbles jostle for position)
+#[instrument(
+    name = "divide",
+    skip_all,
+    fields(dividend = req.dividend, divisor = req.divisor, experiment_id = field::Empty)
+)]
 pub async fn start_division(
     State(state): State<Arc<AppState>>,
     Json(req): Json<DivisionRequest>,
@@ -99,6 +134,13 @@ pub async fn start_division(
             "Salinity must be between 0.0 and 10.0".into(),
         ));
     }
+    let velocity_ok = |v: Option<f32>| v.is_none_or(|v| v.is_finite() && v > 0.0);
+    if !velocity_ok(req.max_velocity) || !velocity_ok(req.avg_velocity) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Velocity thresholds must be positive".into(),
+        ));
+    }
 
     // Create response channel
     let (tx, rx) = oneshot::channel();
@@ -110,6 +152,11 @@ pub async fn start_division(
             dividend: req.dividend,
             divisor: req.divisor,
             salinity_boost: req.salinity,
+            trace: req.trace,
+            keep_particles: req.keep_particles,
+            min_ticks: req.min_ticks,
+            max_velocity: req.max_velocity,
+            avg_velocity: req.avg_velocity,
             response_tx: tx,
         })
         .await
@@ -127,6 +174,7 @@ pub async fn start_division(
             "Failed to start experiment".into(),
         )
     })?;
+    Span::current().record("experiment_id", field::display(experiment_id));
 
     let expected_quotient = (req.dividend / req.divisor).floor();
     let expected_remainder = req.dividend % req.divisor;
@@ -180,6 +228,8 @@ pub async fn get_division_status(
             node_count: Some(exp.wave.node_count()),
             accumulated_turbulence: Some(exp.accumulated_turbulence),
             ticks_elapsed: Some(ticks_elapsed),
+            max_ticks: Some(exp.max_ticks),
+            thresholds: Some(exp.thresholds),
         })
     } else {
         Json(ExperimentStatusResponse {
@@ -190,6 +240,8 @@ pub async fn get_division_status(
             node_count: None,
             accumulated_turbulence: None,
             ticks_elapsed: None,
+            max_ticks: None,
+            thresholds: None,
         })
     }
 }
@@ -217,6 +269,7 @@ pub async fn get_division_results(
             };
 
             DivisionResultResponse {
+                id: r.id,
                 dividend: r.dividend,
                 divisor: r.divisor,
                 quotient: r.quotient,
@@ -225,6 +278,9 @@ pub async fn get_division_results(
                 turbulence_energy: r.turbulence_energy,
                 reynolds_number: r.reynolds_number,
                 ticks_to_settle: r.ticks_to_settle,
+                settled_by: r.settled_by,
+                settled_naturally: r.settled_naturally,
+                confidence: r.confidence,
                 node_occupancy: r.node_occupancy.clone(),
                 salinity_boost: r.salinity_boost,
                 velocity_sigma: r.velocity_sigma,
@@ -0,0 +1,12 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State};
+
+use crate::simulation::EnergyLedger;
+use crate::state::AppState;
+
+/// GET /energy - Kinetic energy ledger (last tick and cumulative flows)
+pub async fn get_energy(State(state): State<Arc<AppState>>) -> Json<EnergyLedger> {
+    let fluid = state.fluid.read().await;
+    Json(fluid.energy.clone())
+}
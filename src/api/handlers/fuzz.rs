@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State, http::StatusCode};
+
+use super::advance::MAX_ADVANCE_TICKS;
+use crate::runtime::{FuzzConfig, FuzzReport, fuzz};
+use crate::state::AppState;
+
+/// Upper bound on concepts a fuzz run may inject
+pub const MAX_FUZZ_CONCEPTS: usize = 500;
+
+/// POST /fuzz - Stress a copy of the fluid with seeded random input and
+/// report numerical anomalies (the live mind is left untouched)
+pub async fn run_fuzz(
+    State(state): State<Arc<AppState>>,
+    Json(config): Json<FuzzConfig>,
+) -> Result<Json<FuzzReport>, (StatusCode, String)> {
+    if config.ticks == 0 || config.ticks > MAX_ADVANCE_TICKS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Ticks must be between 1 and {}", MAX_ADVANCE_TICKS),
+        ));
+    }
+    if config.concept_count > MAX_FUZZ_CONCEPTS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("concept_count must be at most {}", MAX_FUZZ_CONCEPTS),
+        ));
+    }
+
+    let mut fluid = state.fluid.read().await.clone();
+    let report = tokio::task::spawn_blocking(move || fuzz(&mut fluid, &config))
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Fuzz run failed".to_string(),
+            )
+        })?;
+
+    Ok(Json(report))
+}
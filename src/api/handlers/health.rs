@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::state::{AppState, IngestHealth, KafkaHealth, RedisHealth};
+
+#[derive(Serialize, JsonSchema)]
+pub struct HealthResponse {
+    pub status: &'static str,
+    pub tick_count: u64,
+    /// Redis bridge status (null when the bridge is not running)
+    pub redis: Option<RedisHealth>,
+    /// Kafka producer status (null when the producer is not running)
+    pub kafka: Option<KafkaHealth>,
+    /// One entry per configured ingest source
+    pub ingest: Vec<IngestHealth>,
+}
+
+/// GET /health - Liveness and integration (Redis, Kafka, ingest) status
+pub async fn get_health(State(state): State<Arc<AppState>>) -> Json<HealthResponse> {
+    let tick_count = state.fluid.read().await.tick_count;
+    Json(HealthResponse {
+        status: "ok",
+        tick_count,
+        redis: state.redis.as_ref().map(|redis| redis.snapshot()),
+        kafka: state.kafka.as_ref().map(|kafka| kafka.snapshot()),
+        ingest: state
+            .ingest
+            .iter()
+            .map(|source| source.snapshot())
+            .collect(),
+    })
+}
@@ -0,0 +1,245 @@
+use std::convert::Infallible;
+use std::sync::{Arc, PoisonError};
+
+use axum::{
+    Json,
+    body::Body,
+    extract::{Query, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+use crate::simulation::HeatmapWeight;
+use crate::state::{AppState, Command};
+
+fn default_interval() -> u64 {
+    60
+}
+
+fn default_bins() -> usize {
+    20
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct HeatmapStartRequest {
+    /// Record a row every N ticks (1 to 3600, default 60)
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+    /// Equal depth bins from surface to floor (1 to 1000, default 20)
+    #[serde(default = "default_bins")]
+    pub bins: usize,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct HeatmapStatusResponse {
+    pub recording: bool,
+    pub interval: u64,
+    pub bins: usize,
+    /// Rows recorded so far
+    pub rows: usize,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct HeatmapQuery {
+    /// What each concept adds to its bin (default count)
+    #[serde(default)]
+    pub weight: HeatmapWeight,
+    /// `json` (default) or `csv`
+    #[serde(default)]
+    pub format: Option<String>,
+}
+
+/// POST /heatmap/start - Start recording the depth × time heatmap afresh
+pub async fn start_heatmap(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<HeatmapStartRequest>,
+) -> Result<Json<HeatmapStatusResponse>, (StatusCode, String)> {
+    if !(1..=3600).contains(&request.interval) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "interval must be between 1 and 3600 ticks".into(),
+        ));
+    }
+    if !(1..=1000).contains(&request.bins) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "bins must be between 1 and 1000".into(),
+        ));
+    }
+
+    let (response_tx, response_rx) = oneshot::channel();
+    state
+        .command_tx
+        .send(Command::StartHeatmap {
+            interval: request.interval,
+            bins: request.bins,
+            response_tx,
+        })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+    response_rx.await.map_err(|_| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to start heatmap".into(),
+        )
+    })?;
+
+    Ok(Json(HeatmapStatusResponse {
+        recording: true,
+        interval: request.interval,
+        bins: request.bins,
+        rows: 0,
+    }))
+}
+
+/// POST /heatmap/stop - Stop recording, keeping the rows for GET /heatmap
+pub async fn stop_heatmap(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<HeatmapStatusResponse>, (StatusCode, String)> {
+    let (response_tx, response_rx) = oneshot::channel();
+    state
+        .command_tx
+        .send(Command::StopHeatmap { response_tx })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+    let rows = response_rx.await.map_err(|_| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to stop heatmap".into(),
+        )
+    })?;
+
+    let heatmap = state.heatmap.lock().unwrap_or_else(PoisonError::into_inner);
+    Ok(Json(HeatmapStatusResponse {
+        recording: false,
+        interval: heatmap.interval,
+        bins: heatmap.bins,
+        rows,
+    }))
+}
+
+/// GET /heatmap - Recorded depth × time matrix as JSON or streamed CSV
+pub async fn get_heatmap(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<HeatmapQuery>,
+) -> Result<Response, (StatusCode, String)> {
+    let csv = match query.format.as_deref().unwrap_or("json") {
+        "json" => false,
+        "csv" => true,
+        other => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("Unsupported format '{}' (expected 'json' or 'csv')", other),
+            ));
+        }
+    };
+    // Copy the numbers out so the recorder is free again before responding
+    let matrix = state
+        .heatmap
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .matrix(query.weight);
+    if !csv {
+        return Ok(Json(matrix).into_response());
+    }
+    // Each row is formatted as the body is read
+    let body = Body::from_stream(tokio_stream::iter(
+        matrix.csv_lines().map(Ok::<_, Infallible>),
+    ));
+    Ok(([(header::CONTENT_TYPE, "text/csv; charset=utf-8")], body).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::api::create_router;
+    use crate::simulation::ConceptFluid;
+
+    async fn send(
+        app: &axum::Router,
+        method: &str,
+        uri: &str,
+        body: Value,
+    ) -> (StatusCode, String) {
+        let request = axum::http::Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_heatmap_records_between_start_and_stop() {
+        let (state, channels) = AppState::new(ConceptFluid::default());
+        let heatmap = state.heatmap.clone();
+        tokio::spawn(crate::runtime::run_simulation_loop(
+            state.fluid.clone(),
+            channels,
+        ));
+        let app = create_router(Arc::new(state));
+
+        let (status, _) = send(&app, "POST", "/heatmap/start", json!({ "bins": 0 })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = send(
+            &app,
+            "POST",
+            "/heatmap/start",
+            json!({ "interval": 1, "bins": 5 }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            serde_json::from_str::<Value>(&body).unwrap(),
+            json!({ "recording": true, "interval": 1, "bins": 5, "rows": 0 })
+        );
+
+        while heatmap.lock().unwrap().len() < 3 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let (status, body) = send(&app, "POST", "/heatmap/stop", Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        let rows = serde_json::from_str::<Value>(&body).unwrap()["rows"]
+            .as_u64()
+            .unwrap() as usize;
+        assert!(rows >= 3);
+
+        let (status, body) = send(&app, "GET", "/heatmap?weight=integration", Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        let matrix: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(matrix["weight"], json!("integration"));
+        assert_eq!(matrix["depths"].as_array().unwrap().len(), 5);
+        assert_eq!(matrix["ticks"].as_array().unwrap().len(), rows);
+
+        let (status, csv) = send(&app, "GET", "/heatmap?format=csv", Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(csv.lines().count(), rows + 1);
+        assert!(csv.starts_with("tick,0.000,0.200,"));
+
+        let (status, _) = send(&app, "GET", "/heatmap?format=xml", Value::Null).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
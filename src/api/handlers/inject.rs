@@ -2,59 +2,111 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use axum::{Json, extract::State, http::StatusCode};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tokio::sync::oneshot;
+use tracing::{Span, field, instrument};
 use uuid::Uuid;
 
-use crate::state::{AppState, Command};
+use crate::api::validation;
+use crate::simulation::ConceptSize;
+use crate::state::{AppState, Command, Metadata};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub struct InjectRequest {
     pub concept: String,
-    pub density: f32,
-    #[serde(default = "default_volume")]
-    pub volume: f32,
-}
-
-fn default_volume() -> f32 {
-    0.5
+    /// Omit to have the server's density estimator derive density and area
+    #[serde(default)]
+    pub density: Option<f32>,
+    /// Cognitive volume (0.0-2.0) the area is derived from; give this or
+    /// `area`, not both (default 0.5)
+    #[serde(default)]
+    pub volume: Option<f32>,
+    /// Surface area the physics uses, instead of deriving it from `volume`
+    #[serde(default)]
+    pub area: Option<f32>,
+    /// Passed to the density estimator and kept on the concept
+    #[serde(default)]
+    pub metadata: Metadata,
+    /// Starting depth, clamped to 0.0-1.0 (default: the density, where it
+    /// would rest)
+    #[serde(default)]
+    pub initial_layer: Option<f32>,
+    /// Starting velocity, negative rising (default: at rest)
+    #[serde(default)]
+    pub initial_velocity: Option<f32>,
+    /// Buoyancy setting the layer it settles at, independent of where it
+    /// starts (default: the density)
+    #[serde(default)]
+    pub initial_buoyancy: Option<f32>,
+    /// Urgent interruption: thaws a frozen fluid as the concept arrives
+    #[serde(default)]
+    pub priority: bool,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct InjectResponse {
     pub id: Uuid,
     pub name: String,
     pub density: f32,
+    /// Area the concept was given, within the fluid's area bounds
     pub area: f32,
+    /// Volume matching that area
+    pub volume: f32,
     pub initial_layer: f32,
+    /// Estimator that produced density, and area unless the client sized the
+    /// concept (null when the client supplied density)
+    pub estimator: Option<String>,
 }
 
 /// POST /inject - Inject a new thought into the fluid
+#[instrument(name = "inject", skip_all, fields(concept = %req.concept, concept_id = field::Empty))]
 pub async fn inject_concept(
     State(state): State<Arc<AppState>>,
     Json(req): Json<InjectRequest>,
 ) -> Result<Json<InjectResponse>, (StatusCode, String)> {
-    // Validate inputs
-    if req.density < 0.0 || req.density > 1.0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "Density must be between 0.0 and 1.0".into(),
-        ));
-    }
-    if req.volume < 0.0 || req.volume > 2.0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "Volume must be between 0.0 and 2.0".into(),
-        ));
-    }
+    validation::inject(
+        req.density,
+        req.volume,
+        req.area,
+        req.initial_layer,
+        req.initial_velocity,
+        req.initial_buoyancy,
+    )?;
 
-    // Derive area from volume
-    let area = if req.density > 0.01 {
-        (req.volume / req.density).clamp(0.1, 2.0)
-    } else {
-        req.volume * 2.0
+    let client_size = (req.volume.is_some() || req.area.is_some())
+        .then(|| ConceptSize::from_fields(req.volume, req.area));
+    let (density, size, estimator) = match req.density {
+        Some(density) => (
+            density,
+            client_size.unwrap_or(ConceptSize::Volume(ConceptSize::DEFAULT_VOLUME)),
+            None,
+        ),
+        None => {
+            // Estimators may block (remote scoring), so keep them off the async workers
+            let estimator = state.density_estimator.clone();
+            let estimator_name = estimator.name().to_string();
+            let name = req.concept.clone();
+            let metadata = req.metadata.clone();
+            let (density, area) =
+                tokio::task::spawn_blocking(move || estimator.estimate(&name, &metadata))
+                    .await
+                    .map_err(|_| {
+                        (
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                            "Density estimator failed".into(),
+                        )
+                    })?;
+            (
+                density.clamp(0.0, 1.0),
+                client_size.unwrap_or(ConceptSize::Area(area)),
+                Some(estimator_name),
+            )
+        }
     };
 
+    let initial_layer = req.initial_layer.map(|layer| layer.clamp(0.0, 1.0));
+
     // Create response channel
     let (response_tx, response_rx) = oneshot::channel();
 
@@ -63,8 +115,13 @@ pub async fn inject_concept(
         .command_tx
         .send(Command::Inject {
             name: req.concept.clone(),
-            density: req.density,
-            area,
+            density,
+            size,
+            metadata: req.metadata,
+            initial_layer,
+            initial_velocity: req.initial_velocity,
+            initial_buoyancy: req.initial_buoyancy,
+            priority: req.priority,
             response_tx,
         })
         .await
@@ -76,7 +133,7 @@ pub async fn inject_concept(
         })?;
 
     // Wait for response with timeout
-    let id = tokio::time::timeout(Duration::from_secs(5), response_rx)
+    let (id, dimensions) = tokio::time::timeout(Duration::from_secs(5), response_rx)
         .await
         .map_err(|_| {
             (
@@ -90,12 +147,86 @@ pub async fn inject_concept(
                 "Failed to create concept".into(),
             )
         })?;
+    Span::current().record("concept_id", field::display(id));
 
     Ok(Json(InjectResponse {
         id,
         name: req.concept,
-        density: req.density,
-        area,
-        initial_layer: req.density,
+        density,
+        area: dimensions.area,
+        volume: dimensions.volume,
+        initial_layer: initial_layer.unwrap_or(density),
+        estimator,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use axum::body::Body;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::api::create_router;
+    use crate::simulation::ConceptFluid;
+    use crate::state::DensityEstimator;
+
+    /// Neutral estimates, counting how often it was asked
+    #[derive(Default)]
+    struct CountingEstimator(AtomicUsize);
+
+    impl DensityEstimator for CountingEstimator {
+        fn name(&self) -> &str {
+            "counting"
+        }
+
+        fn estimate(&self, _name: &str, _metadata: &Metadata) -> (f32, f32) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            (0.5, 1.0)
+        }
+    }
+
+    async fn inject(app: &axum::Router, body: Value) -> (StatusCode, Value) {
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/inject")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_volume_is_checked_when_density_is_estimated() {
+        let (mut state, channels) = AppState::new(ConceptFluid::default());
+        let estimator = Arc::new(CountingEstimator::default());
+        state.density_estimator = estimator.clone();
+        tokio::spawn(crate::runtime::run_simulation_loop(
+            state.fluid.clone(),
+            channels,
+        ));
+        let app = create_router(Arc::new(state));
+
+        for volume in [-0.5, 2.5] {
+            let (status, _) = inject(&app, json!({ "concept": "doubt", "volume": volume })).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "volume {}", volume);
+        }
+        assert_eq!(estimator.0.load(Ordering::SeqCst), 0, "rejected first");
+
+        let (status, concept) = inject(&app, json!({ "concept": "doubt", "volume": 0.4 })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(concept["estimator"], json!("counting"));
+        assert!((concept["volume"].as_f64().unwrap() - 0.4).abs() < 1e-6);
+        assert_eq!(estimator.0.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::ballast::apply;
+use crate::api::validation;
+use crate::simulation::ConceptLink;
+use crate::state::{AppState, Command};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct LinkRequest {
+    pub id_a: Uuid,
+    pub id_b: Uuid,
+    /// Depth of the potential well (0.0-1.0)
+    pub strength: f32,
+    /// Separation in depth at which the pair rests (0.0-1.0]
+    pub rest_distance: f32,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct LinkResponse {
+    pub index: usize,
+    pub id_a: Uuid,
+    pub id_b: Uuid,
+    pub strength: f32,
+    pub rest_distance: f32,
+}
+
+impl LinkResponse {
+    fn new(index: usize, link: &ConceptLink) -> Self {
+        Self {
+            index,
+            id_a: link.id_a,
+            id_b: link.id_b,
+            strength: link.strength,
+            rest_distance: link.rest_distance,
+        }
+    }
+}
+
+/// POST /link - Hold two thematically connected concepts near each other
+#[instrument(name = "link", skip_all)]
+pub async fn create_link(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<LinkRequest>,
+) -> Result<Json<LinkResponse>, Response> {
+    validation::link(req.strength, req.rest_distance).map_err(IntoResponse::into_response)?;
+    if req.id_a == req.id_b {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "A concept can't be linked to itself".to_string(),
+        )
+            .into_response());
+    }
+
+    let link = ConceptLink {
+        id_a: req.id_a,
+        id_b: req.id_b,
+        strength: req.strength,
+        rest_distance: req.rest_distance,
+    };
+    let response = |index| LinkResponse::new(index, &link);
+    let (response_tx, response_rx) = oneshot::channel();
+    let command = Command::Link {
+        link: link.clone(),
+        response_tx,
+    };
+    let index = apply(&state, command, response_rx, "Failed to link concepts").await?;
+
+    Ok(Json(response(index)))
+}
+
+/// DELETE /link/:id - Remove a link by index
+pub async fn delete_link(
+    State(state): State<Arc<AppState>>,
+    Path(index): Path<usize>,
+) -> Result<Json<LinkResponse>, Response> {
+    let (response_tx, response_rx) = oneshot::channel();
+    let command = Command::Unlink { index, response_tx };
+    let link = apply(&state, command, response_rx, "Failed to unlink").await?;
+
+    Ok(Json(LinkResponse::new(index, &link)))
+}
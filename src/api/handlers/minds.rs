@@ -0,0 +1,239 @@
+use std::sync::{Arc, OnceLock};
+
+use axum::{
+    Json, Router,
+    extract::{Path, Request, State},
+    http::{StatusCode, Uri},
+    response::{IntoResponse, Response},
+};
+use schemars::JsonSchema;
+use serde::Serialize;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::api::routes::mind_routes;
+use crate::runtime::{ActionHookConfig, spawn_mind};
+use crate::simulation::ConceptFluid;
+use crate::state::AppState;
+
+/// The single-mind routes, built on first use and shared by every mind
+static MIND_ROUTES: OnceLock<Router<Arc<AppState>>> = OnceLock::new();
+
+#[derive(Serialize, JsonSchema)]
+pub struct MindResponse {
+    pub id: Uuid,
+    pub concepts: usize,
+    pub tick_count: u64,
+}
+
+impl MindResponse {
+    async fn new(id: Uuid, mind: &AppState) -> Self {
+        let fluid = mind.fluid.read().await;
+        Self {
+            id,
+            concepts: fluid.concepts.len(),
+            tick_count: fluid.tick_count,
+        }
+    }
+}
+
+/// GET /minds - List created minds
+pub async fn list_minds(State(state): State<Arc<AppState>>) -> Json<Vec<MindResponse>> {
+    let mut minds = Vec::new();
+    for (id, mind) in state.minds.list().await {
+        minds.push(MindResponse::new(id, &mind).await);
+    }
+    minds.sort_by_key(|mind| mind.id);
+    Json(minds)
+}
+
+/// POST /minds - Create an isolated mind with an empty fluid
+pub async fn create_mind(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<MindResponse>, (StatusCode, String)> {
+    let mind = spawn_mind(
+        ConceptFluid::default(),
+        state.minds.channel_config,
+        state.density_estimator.clone(),
+        ActionHookConfig::from_env(),
+    );
+    let mind_state = mind.state.clone();
+    let id = state
+        .minds
+        .insert(mind)
+        .await
+        .map_err(|err| (StatusCode::CONFLICT, err))?;
+    Ok(Json(MindResponse::new(id, &mind_state).await))
+}
+
+/// DELETE /minds/:id - Stop a mind and discard its fluid
+pub async fn delete_mind(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<MindResponse>, (StatusCode, String)> {
+    let mind = state
+        .minds
+        .remove(id)
+        .await
+        .ok_or((StatusCode::NOT_FOUND, format!("Mind {} not found", id)))?;
+    Ok(Json(MindResponse::new(id, &mind).await))
+}
+
+/// /minds/:id/*path - Serve any single-mind route against the given mind
+pub async fn mind_request(
+    State(state): State<Arc<AppState>>,
+    Path((id, path)): Path<(Uuid, String)>,
+    mut request: Request,
+) -> Response {
+    let Some(mind) = state.minds.get(id).await else {
+        return (StatusCode::NOT_FOUND, format!("Mind {} not found", id)).into_response();
+    };
+
+    let uri = match request.uri().query() {
+        Some(query) => format!("/{}?{}", path, query),
+        None => format!("/{}", path),
+    };
+    match uri.parse::<Uri>() {
+        Ok(uri) => *request.uri_mut() = uri,
+        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+    }
+
+    let routes = MIND_ROUTES.get_or_init(mind_routes).clone();
+    match routes.with_state(mind).oneshot(request).await {
+        Ok(response) => response,
+        Err(never) => match never {},
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use serde_json::{Value, json};
+
+    use crate::api::create_router;
+
+    async fn call(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let request = axum::http::Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(body.map_or_else(Body::empty, |b| Body::from(b.to_string())))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    fn concept_names(state: &Value) -> Vec<String> {
+        state["concepts"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| c["name"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_minds_keep_their_concepts_to_themselves() {
+        let (state, channels) = AppState::new(ConceptFluid::default());
+        let default_fluid = state.fluid.clone();
+        tokio::spawn(crate::runtime::run_simulation_loop(
+            default_fluid.clone(),
+            channels,
+        ));
+        let app = create_router(Arc::new(state));
+
+        let (_, first) = call(&app, "POST", "/minds", None).await;
+        let (_, second) = call(&app, "POST", "/minds", None).await;
+        let first = first["id"].as_str().unwrap().to_string();
+        let second = second["id"].as_str().unwrap().to_string();
+
+        for (mind, concept) in [(&first, "first_thought"), (&second, "second_thought")] {
+            let (status, _) = call(
+                &app,
+                "POST",
+                &format!("/minds/{}/inject", mind),
+                Some(json!({ "concept": concept, "density": 0.3 })),
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+        }
+        call(
+            &app,
+            "POST",
+            "/inject",
+            Some(json!({ "concept": "default_thought", "density": 0.3 })),
+        )
+        .await;
+
+        let (_, first_state) = call(&app, "GET", &format!("/minds/{}/state", first), None).await;
+        let (_, second_state) = call(&app, "GET", &format!("/minds/{}/state", second), None).await;
+        let (_, default_state) = call(&app, "GET", "/state", None).await;
+        assert_eq!(concept_names(&first_state), vec!["first_thought"]);
+        assert_eq!(concept_names(&second_state), vec!["second_thought"]);
+        assert_eq!(concept_names(&default_state), vec!["default_thought"]);
+
+        // Query strings reach the mind's handlers
+        let (status, strata) = call(
+            &app,
+            "GET",
+            &format!("/minds/{}/strata?depth_min=0.5", first),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(strata["total_concepts"], 0);
+
+        let (_, minds) = call(&app, "GET", "/minds", None).await;
+        assert_eq!(minds.as_array().unwrap().len(), 2);
+        let (status, _) = call(&app, "DELETE", &format!("/minds/{}", first), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = call(&app, "GET", &format!("/minds/{}/state", first), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_schedules_run_on_created_minds() {
+        let (state, _channels) = AppState::new(ConceptFluid::default());
+        let app = create_router(Arc::new(state));
+
+        let (_, mind) = call(&app, "POST", "/minds", None).await;
+        let mind = mind["id"].as_str().unwrap().to_string();
+        let (status, _) = call(
+            &app,
+            "POST",
+            &format!("/minds/{}/schedules", mind),
+            Some(json!({
+                "name": "muse",
+                "cron": "* * * * * *",
+                "command": { "command": "inject", "name": "scheduled_thought", "density": 0.3 },
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        tokio::time::timeout(std::time::Duration::from_secs(3), async {
+            loop {
+                let (_, state) = call(&app, "GET", &format!("/minds/{}/state", mind), None).await;
+                if concept_names(&state).contains(&"scheduled_thought".to_string()) {
+                    break;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            }
+        })
+        .await
+        .expect("the mind's scheduler should inject within a second");
+    }
+}
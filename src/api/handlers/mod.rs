@@ -1,25 +1,80 @@
 pub mod actions;
+pub mod advance;
+pub mod attention;
 pub mod ballast;
+pub mod concept;
 pub mod consensus;
 pub mod continent;
+pub mod dispatch;
 pub mod division;
+pub mod energy;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+pub mod health;
+pub mod heatmap;
 pub mod inject;
+pub mod link;
+pub mod minds;
+pub mod openapi;
+pub mod ore;
+pub mod physics;
+pub mod pressure;
+pub mod render;
+pub mod rules;
+pub mod schedules;
 pub mod sse;
 pub mod state;
+pub mod stats;
 pub mod strata;
+pub mod timeseries;
+pub mod timing;
+pub mod trace;
+pub mod traits;
 pub mod vent;
+pub mod wave;
 pub mod websocket;
 
 pub use actions::{deep_breath, flash_heal, thaw};
-pub use ballast::apply_ballast;
+pub use advance::advance;
+pub use attention::focus_attention;
+pub use ballast::{apply_ballast, modulate_buoyancy, submerge_concept};
+pub use concept::{
+    get_concept, get_concept_neighbors, list_concepts, prune_concepts, remove_concept,
+    rename_concept,
+};
 pub use consensus::{
-    get_consensus_ores, get_consensus_status, get_foundational_truths, start_consensus,
+    crystallize_consensus, get_consensus_config, get_consensus_ores, get_consensus_stats,
+    get_consensus_status, get_consensus_summary, get_foundational_truths, start_consensus,
+    start_consensus_from_concepts, update_consensus_config,
 };
 pub use continent::{list_continents, trigger_tectonic};
+pub use dispatch::{
+    create_dispatch_rule, delete_dispatch_rule, dispatch_rule_log, list_dispatch_rules,
+};
 pub use division::{get_division_results, get_division_status, start_division};
+pub use energy::get_energy;
+#[cfg(feature = "fuzz")]
+pub use fuzz::run_fuzz;
+pub use health::get_health;
+pub use heatmap::{get_heatmap, start_heatmap, stop_heatmap};
 pub use inject::inject_concept;
+pub use link::{create_link, delete_link};
+pub use minds::{create_mind, delete_mind, list_minds, mind_request};
+pub use openapi::get_openapi;
+pub use ore::get_ore_ledger;
+pub use physics::update_physics;
+pub use pressure::get_pressure_profile;
+pub use render::{render_state, render_state_svg};
+pub use rules::{create_rule, delete_rule, list_rules};
+pub use schedules::{create_schedule, delete_schedule, list_schedules};
 pub use sse::event_stream;
 pub use state::get_full_state;
+pub use stats::{get_integration_profile, get_names, get_persona, get_stats};
 pub use strata::get_strata;
-pub use vent::{create_vent, get_vent, list_vents};
+pub use timeseries::get_timeseries;
+pub use timing::get_timing;
+pub use trace::{get_consensus_trace, get_division_trace};
+pub use traits::reimmerse_trait;
+pub use vent::{create_vent, get_vent, get_vent_influence, list_vents, set_vent_active};
+pub use wave::{create_wave, delete_wave, list_waves};
 pub use websocket::ws_handler;
@@ -0,0 +1,9 @@
+use axum::Json;
+use serde_json::Value;
+
+use crate::api::openapi::openapi_document;
+
+/// GET /openapi.json - OpenAPI 3 description of every route
+pub async fn get_openapi() -> Json<Value> {
+    Json(openapi_document())
+}
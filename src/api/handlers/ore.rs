@@ -0,0 +1,41 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use axum::{Json, extract::State};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::simulation::{OrePressureWeights, OreTypeStats};
+use crate::state::AppState;
+
+#[derive(Serialize, JsonSchema)]
+pub struct OreLedgerResponse {
+    /// Lifetime counters per ore type
+    pub by_type: BTreeMap<String, OreTypeStats>,
+    /// Ores currently on the ocean floor, per type
+    pub current_deposits: BTreeMap<&'static str, usize>,
+    pub ocean_floor_pressure: f32,
+    pub pressure_threshold: f32,
+    /// Per-type multipliers applied to each deposit's pressure
+    pub pressure_weights: OrePressureWeights,
+    pub tectonic_shifts: u32,
+}
+
+/// GET /ores/ledger - Per-type ore totals and history
+pub async fn get_ore_ledger(State(state): State<Arc<AppState>>) -> Json<OreLedgerResponse> {
+    let fluid = state.fluid.read().await;
+
+    let mut current_deposits = BTreeMap::new();
+    for ore in &fluid.ore_deposits {
+        *current_deposits.entry(ore.ore_type.as_str()).or_insert(0) += 1;
+    }
+
+    Json(OreLedgerResponse {
+        by_type: fluid.ore_stats.by_type.clone(),
+        current_deposits,
+        ocean_floor_pressure: fluid.ocean_floor_pressure,
+        pressure_threshold: fluid.pressure_threshold,
+        pressure_weights: fluid.ore_pressure_weights,
+        tectonic_shifts: fluid.tectonic_shifts,
+    })
+}
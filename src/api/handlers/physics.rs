@@ -0,0 +1,136 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{Json, extract::State, http::StatusCode};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+use crate::api::validation;
+use crate::state::{AppState, Command};
+
+/// Physics to change; omitted fields keep their current value
+#[derive(Deserialize, JsonSchema)]
+pub struct PhysicsPatch {
+    /// Viscosity at the surface (greater than 0.0, at most 10.0)
+    pub viscosity_surface: Option<f32>,
+    /// Viscosity at the floor (greater than 0.0, at most 10.0)
+    pub viscosity_deep: Option<f32>,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct PhysicsResponse {
+    pub viscosity_surface: f32,
+    pub viscosity_deep: f32,
+}
+
+/// PATCH /fluid/physics - Change how viscosity varies with depth
+pub async fn update_physics(
+    State(state): State<Arc<AppState>>,
+    Json(patch): Json<PhysicsPatch>,
+) -> Result<Json<PhysicsResponse>, (StatusCode, String)> {
+    validation::viscosity(patch.viscosity_surface, patch.viscosity_deep)?;
+
+    let (response_tx, response_rx) = oneshot::channel();
+    state
+        .command_tx
+        .send(Command::SetViscosity {
+            surface: patch.viscosity_surface,
+            deep: patch.viscosity_deep,
+            response_tx,
+        })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+    let (surface, deep) = tokio::time::timeout(Duration::from_secs(5), response_rx)
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::GATEWAY_TIMEOUT,
+                "Simulation response timeout".into(),
+            )
+        })?
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to update physics".into(),
+            )
+        })?;
+
+    Ok(Json(PhysicsResponse {
+        viscosity_surface: surface,
+        viscosity_deep: deep,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::api::create_router;
+    use crate::simulation::ConceptFluid;
+
+    async fn send(app: &axum::Router, method: &str, uri: &str, body: Value) -> (StatusCode, Value) {
+        let request = axum::http::Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_patched_viscosity_shows_in_state() {
+        let (state, channels) = AppState::new(ConceptFluid::default());
+        tokio::spawn(crate::runtime::run_simulation_loop(
+            state.fluid.clone(),
+            channels,
+        ));
+        let app = create_router(Arc::new(state));
+
+        let (status, physics) = send(
+            &app,
+            "PATCH",
+            "/fluid/physics",
+            json!({ "viscosity_deep": 2.0 }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            physics,
+            json!({ "viscosity_surface": 0.5, "viscosity_deep": 2.0 })
+        );
+
+        let (_, fluid) = send(&app, "GET", "/state", Value::Null).await;
+        assert_eq!(fluid["viscosity_surface"], json!(0.5));
+        assert_eq!(fluid["viscosity_deep"], json!(2.0));
+
+        // A frictionless surface is rejected and changes nothing
+        let (status, _) = send(
+            &app,
+            "PATCH",
+            "/fluid/physics",
+            json!({ "viscosity_surface": 0.0 }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (_, fluid) = send(&app, "GET", "/state", Value::Null).await;
+        assert_eq!(fluid["viscosity_surface"], json!(0.5));
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::simulation::PressureSample;
+use crate::state::AppState;
+
+/// Upper bound on profile resolution
+const MAX_STEPS: usize = 1000;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct PressureQuery {
+    /// Number of depth samples, surface to floor (2-1000)
+    #[serde(default = "default_steps")]
+    pub steps: usize,
+    /// Density of the test particle (0.5 is neutral)
+    #[serde(default = "default_density")]
+    pub density: f32,
+}
+
+fn default_steps() -> usize {
+    50
+}
+
+fn default_density() -> f32 {
+    0.5
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct PressureProfileResponse {
+    pub density: f32,
+    pub samples: Vec<PressureSample>,
+}
+
+/// GET /pressure-profile - Forces a test particle would feel at each depth
+pub async fn get_pressure_profile(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<PressureQuery>,
+) -> Result<Json<PressureProfileResponse>, (StatusCode, String)> {
+    if !(2..=MAX_STEPS).contains(&query.steps) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("steps must be between 2 and {}", MAX_STEPS),
+        ));
+    }
+    if !(0.0..=1.0).contains(&query.density) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "density must be between 0.0 and 1.0".into(),
+        ));
+    }
+
+    let fluid = state.fluid.read().await;
+
+    Ok(Json(PressureProfileResponse {
+        density: query.density,
+        samples: fluid.pressure_profile(query.steps, query.density),
+    }))
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::api::render::{SvgOptions, render_svg};
+use crate::state::AppState;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct RenderQuery {
+    #[serde(default)]
+    pub format: Option<String>,
+}
+
+/// GET /render - Layered text view of the fluid
+pub async fn render_state(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<RenderQuery>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    match query.format.as_deref().unwrap_or("text") {
+        "text" => {
+            let fluid = state.fluid.read().await;
+            Ok((
+                [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+                fluid.render_state(),
+            ))
+        }
+        other => Err((
+            StatusCode::BAD_REQUEST,
+            format!("Unsupported format '{}' (expected 'text')", other),
+        )),
+    }
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct SvgQuery {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// Draw concept names (default true)
+    pub labels: Option<bool>,
+}
+
+/// Accepted image dimensions for /render.svg (pixels)
+const MIN_SVG_SIZE: u32 = 100;
+const MAX_SVG_SIZE: u32 = 4096;
+
+/// GET /render.svg - Self-contained SVG depth chart of the fluid
+pub async fn render_state_svg(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SvgQuery>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let defaults = SvgOptions::default();
+    let options = SvgOptions {
+        width: query.width.unwrap_or(defaults.width),
+        height: query.height.unwrap_or(defaults.height),
+        labels: query.labels.unwrap_or(defaults.labels),
+    };
+    for (name, value) in [("width", options.width), ("height", options.height)] {
+        if !(MIN_SVG_SIZE..=MAX_SVG_SIZE).contains(&value) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "{} must be between {} and {}",
+                    name, MIN_SVG_SIZE, MAX_SVG_SIZE
+                ),
+            ));
+        }
+    }
+
+    let fluid = state.fluid.read().await;
+    Ok((
+        [(header::CONTENT_TYPE, "image/svg+xml")],
+        render_svg(&fluid, &options),
+    ))
+}
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::oneshot;
+
+use crate::simulation::Rule;
+use crate::state::{AppState, Command};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct RuleRequest {
+    /// Event name, as in the event's `event` field (e.g. `concept_evaporated`)
+    pub on: String,
+    /// Command template; `{field}` is filled from the triggering event
+    pub then: Value,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct RuleResponse {
+    pub index: usize,
+    pub on: String,
+    pub then: Value,
+    pub fired: u64,
+}
+
+impl RuleResponse {
+    fn new(index: usize, rule: &Rule) -> Self {
+        Self {
+            index,
+            on: rule.on.clone(),
+            then: rule.then.clone(),
+            fired: rule.fired,
+        }
+    }
+}
+
+/// Send a rule command and wait for the simulation loop's answer.
+async fn request<T>(
+    state: &AppState,
+    command: Command,
+    response_rx: oneshot::Receiver<T>,
+) -> Result<T, (StatusCode, String)> {
+    state.command_tx.send(command).await.map_err(|_| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Simulation not running".into(),
+        )
+    })?;
+
+    tokio::time::timeout(Duration::from_secs(5), response_rx)
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::GATEWAY_TIMEOUT,
+                "Simulation response timeout".into(),
+            )
+        })?
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to update rules".into(),
+            )
+        })
+}
+
+/// GET /rules - List registered rules
+pub async fn list_rules(State(state): State<Arc<AppState>>) -> Json<Vec<RuleResponse>> {
+    let fluid = state.fluid.read().await;
+    Json(
+        fluid
+            .rules
+            .rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| RuleResponse::new(index, rule))
+            .collect(),
+    )
+}
+
+/// POST /rules - Register a rule sending a command whenever an event occurs
+pub async fn create_rule(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<RuleRequest>,
+) -> Result<Json<RuleResponse>, (StatusCode, String)> {
+    let rule = Rule::new(req.on, req.then).map_err(|err| (StatusCode::BAD_REQUEST, err))?;
+    let (response_tx, response_rx) = oneshot::channel();
+    let command = Command::AddRule {
+        rule: rule.clone(),
+        response_tx,
+    };
+
+    let index = request(&state, command, response_rx)
+        .await?
+        .map_err(|err| (StatusCode::CONFLICT, err))?;
+
+    Ok(Json(RuleResponse::new(index, &rule)))
+}
+
+/// DELETE /rules/:id - Remove a rule (later rules move down one index)
+pub async fn delete_rule(
+    State(state): State<Arc<AppState>>,
+    Path(index): Path<usize>,
+) -> Result<Json<RuleResponse>, (StatusCode, String)> {
+    let (response_tx, response_rx) = oneshot::channel();
+    let command = Command::RemoveRule { index, response_tx };
+
+    let rule = request(&state, command, response_rx)
+        .await?
+        .ok_or((StatusCode::NOT_FOUND, format!("Rule {} not found", index)))?;
+
+    Ok(Json(RuleResponse::new(index, &rule)))
+}
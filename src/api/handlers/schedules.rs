@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use chrono::Utc;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::state::{AppState, CommandPayload, Schedule};
+
+#[derive(Serialize, JsonSchema)]
+pub struct ScheduleResponse {
+    pub name: String,
+    pub cron: String,
+    pub command: CommandPayload,
+    /// Next firing (RFC 3339, UTC); None if the expression never fires again
+    pub next_fire: Option<String>,
+}
+
+impl From<Schedule> for ScheduleResponse {
+    fn from(schedule: Schedule) -> Self {
+        Self {
+            next_fire: schedule
+                .cron
+                .next_after(Utc::now())
+                .map(|at| at.to_rfc3339()),
+            name: schedule.name,
+            cron: schedule.cron.as_str().to_string(),
+            command: schedule.command,
+        }
+    }
+}
+
+/// GET /schedules - List scheduled commands
+pub async fn list_schedules(State(state): State<Arc<AppState>>) -> Json<Vec<ScheduleResponse>> {
+    Json(
+        state
+            .schedules
+            .list()
+            .await
+            .into_iter()
+            .map(ScheduleResponse::from)
+            .collect(),
+    )
+}
+
+/// POST /schedules - Add a scheduled command
+pub async fn create_schedule(
+    State(state): State<Arc<AppState>>,
+    Json(schedule): Json<Schedule>,
+) -> Result<Json<ScheduleResponse>, (StatusCode, String)> {
+    state
+        .schedules
+        .insert(schedule.clone())
+        .await
+        .map_err(|err| (StatusCode::CONFLICT, err))?;
+    Ok(Json(schedule.into()))
+}
+
+/// DELETE /schedules/:name - Remove a scheduled command
+pub async fn delete_schedule(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> Result<Json<ScheduleResponse>, (StatusCode, String)> {
+    state
+        .schedules
+        .remove(&name)
+        .await
+        .map(|schedule| Json(schedule.into()))
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("Schedule '{}' not found", name),
+        ))
+}
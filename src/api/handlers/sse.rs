@@ -23,34 +23,8 @@ pub async fn event_stream(
 
     let stream = BroadcastStream::new(rx).filter_map(|result: Result<FluidEvent, _>| {
         result.ok().map(|event: FluidEvent| {
-            let event_type = match &event {
-                FluidEvent::SurfaceBreakthrough { .. } => "breakthrough",
-                FluidEvent::SurfaceBounce { .. } => "bounce",
-                FluidEvent::ConceptInjected { .. } => "injected",
-                FluidEvent::ConceptEvaporated { .. } => "evaporated",
-                FluidEvent::Freeze { .. } => "freeze",
-                FluidEvent::Thaw => "thaw",
-                FluidEvent::TurbulenceOnset { .. } => "turbulence_onset",
-                FluidEvent::TurbulenceSubsided => "turbulence_subsided",
-                FluidEvent::Mineralization { .. } => "mineralization",
-                FluidEvent::OreDeposited { .. } => "ore_deposited",
-                FluidEvent::OreCatalysis { .. } => "catalysis",
-                FluidEvent::TectonicShift { .. } => "tectonic_shift",
-                FluidEvent::CoreTruthFormed { .. } => "core_truth_formed",
-                FluidEvent::CoreTruthStrengthened { .. } => "core_truth_strengthened",
-                FluidEvent::Precipitation { .. } => "precipitation",
-                FluidEvent::FlashHeal { .. } => "flash_heal",
-                FluidEvent::DeepBreath { .. } => "deep_breath",
-                FluidEvent::BenthicExpedition { .. } => "benthic_expedition",
-                FluidEvent::DivisionExperimentStarted { .. } => "division_started",
-                FluidEvent::DivisionExperimentComplete { .. } => "division_complete",
-                FluidEvent::ConsensusExperimentStarted { .. } => "consensus_started",
-                FluidEvent::ConsensusOreCrystallized { .. } => "consensus_crystallized",
-                FluidEvent::PhaseTransition { .. } => "phase_transition",
-            };
-
             let json = serde_json::to_string(&event).unwrap_or_default();
-            Ok(Event::default().event(event_type).data(json))
+            Ok(Event::default().event(event.kind()).data(json))
         })
     });
 
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::simulation::{FluidStats, NameFrequency, Persona};
+use crate::state::AppState;
+
+/// Upper bound on integration profile resolution
+const MAX_STEPS: usize = 1000;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct IntegrationProfileQuery {
+    /// Number of depth buckets, surface to floor (1-1000)
+    #[serde(default = "default_steps")]
+    pub steps: usize,
+}
+
+fn default_steps() -> usize {
+    10
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct IntegrationProfileResponse {
+    pub steps: usize,
+    /// Integration summed per bucket; bucket `i` covers depths
+    /// `[i / steps, (i + 1) / steps)`, the last one including the floor
+    pub integration: Vec<f32>,
+    /// Sum over all buckets (excludes evaporated concepts, unlike `/stats`)
+    pub total: f32,
+}
+
+/// GET /stats - Aggregate statistics for dashboards
+pub async fn get_stats(State(state): State<Arc<AppState>>) -> Json<FluidStats> {
+    let fluid = state.fluid.read().await;
+    Json(fluid.stats())
+}
+
+/// GET /persona - Compact mood vector for downstream consumers
+pub async fn get_persona(State(state): State<Arc<AppState>>) -> Json<Persona> {
+    let fluid = state.fluid.read().await;
+    Json(fluid.persona())
+}
+
+/// GET /names - How often each concept name has come up
+pub async fn get_names(State(state): State<Arc<AppState>>) -> Json<Vec<NameFrequency>> {
+    let fluid = state.fluid.read().await;
+    Json(fluid.name_frequencies())
+}
+
+/// GET /integration-profile - Integration summed by depth
+pub async fn get_integration_profile(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<IntegrationProfileQuery>,
+) -> Result<Json<IntegrationProfileResponse>, (StatusCode, String)> {
+    if !(1..=MAX_STEPS).contains(&query.steps) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("steps must be between 1 and {}", MAX_STEPS),
+        ));
+    }
+
+    let fluid = state.fluid.read().await;
+    let integration = fluid.integration_profile(query.steps);
+
+    Ok(Json(IntegrationProfileResponse {
+        steps: query.steps,
+        total: integration.iter().sum(),
+        integration,
+    }))
+}
@@ -4,12 +4,13 @@ use axum::{
     Json,
     extract::{Query, State},
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::state::AppState;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub struct StrataQuery {
     #[serde(default)]
     pub depth_min: Option<f32>,
@@ -17,7 +18,7 @@ pub struct StrataQuery {
     pub depth_max: Option<f32>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct ConceptView {
     pub id: Uuid,
     pub name: String,
@@ -27,9 +28,20 @@ pub struct ConceptView {
     pub buoyancy: f32,
     pub integration: f32,
     pub status: String,
+    /// Kinetic energy over surface tension while rising; >= 1.0 means it
+    /// would break through on reaching the surface now
+    pub breakthrough_margin: f32,
+    /// Size of its breaking eddies (0.0 when not turbulent)
+    pub eddy_scale: f32,
+    /// Integration per second it is gaining from those eddies
+    pub integration_rate: f32,
+    /// Space it occupies in consciousness (`density * area`)
+    pub volume: f32,
+    /// Density including any ballast it is carrying
+    pub effective_density: f32,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct OreView {
     pub name: String,
     pub ore_type: String,
@@ -38,7 +50,7 @@ pub struct OreView {
     pub vent_cycles: u32,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct StrataResponse {
     pub depth_range: (f32, f32),
     pub concepts: Vec<ConceptView>,
@@ -71,6 +83,11 @@ pub async fn get_strata(
             buoyancy: c.buoyancy,
             integration: c.integration,
             status: c.status().to_string(),
+            breakthrough_margin: fluid.breakthrough_margin(c),
+            eddy_scale: c.eddy_scale,
+            integration_rate: fluid.integration_rate(c),
+            volume: c.volume(),
+            effective_density: c.effective_density(),
         })
         .collect();
 
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::simulation::{Metric, TimeSeriesQuery};
+use crate::state::AppState;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct TimeSeriesParams {
+    /// Comma-separated metric names; defaults to every recorded metric
+    #[serde(default)]
+    pub metrics: Option<String>,
+    #[serde(default)]
+    pub since_tick: Option<u64>,
+}
+
+/// GET /timeseries - Sampled metric history as aligned arrays
+pub async fn get_timeseries(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<TimeSeriesParams>,
+) -> Result<Json<TimeSeriesQuery>, (StatusCode, String)> {
+    let fluid = state.fluid.read().await;
+
+    let metrics: Vec<Metric> = match params.metrics.as_deref() {
+        Some(list) => list
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|e| (StatusCode::BAD_REQUEST, e))?,
+        None => fluid.timeseries.metrics().to_vec(),
+    };
+
+    fluid
+        .timeseries
+        .query(&metrics, params.since_tick.unwrap_or(0))
+        .map(Json)
+        .map_err(|metric| {
+            (
+                StatusCode::BAD_REQUEST,
+                format!("Metric '{}' is not being recorded", metric.as_str()),
+            )
+        })
+}
@@ -0,0 +1,12 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use axum::{Json, extract::State};
+
+use crate::state::{AppState, TimingReport};
+
+/// GET /timing - Simulated vs wall-clock time, to tell whether the loop
+/// keeps its 60Hz target under load
+pub async fn get_timing(State(state): State<Arc<AppState>>) -> Json<TimingReport> {
+    Json(state.timing.snapshot(Instant::now()))
+}
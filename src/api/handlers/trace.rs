@@ -0,0 +1,53 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::state::AppState;
+
+/// Stream a stored experiment trace as CSV.
+async fn trace_csv(
+    state: &AppState,
+    id: Uuid,
+    kind: &str,
+) -> Result<impl IntoResponse + use<>, (StatusCode, String)> {
+    // Copy the rows out so the lock is not held while the body streams
+    let lines: Vec<String> = {
+        let fluid = state.fluid.read().await;
+        let trace = fluid.traces.get(id).ok_or((
+            StatusCode::NOT_FOUND,
+            format!(
+                "No trace for {} {} (start it with \"trace\": true)",
+                kind, id
+            ),
+        ))?;
+        trace.csv_lines().collect()
+    };
+
+    let body = Body::from_stream(tokio_stream::iter(
+        lines.into_iter().map(Ok::<_, Infallible>),
+    ));
+    Ok(([(header::CONTENT_TYPE, "text/csv; charset=utf-8")], body))
+}
+
+/// GET /divide/results/:id/trace.csv - Per-tick trace of a division experiment
+pub async fn get_division_trace(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    trace_csv(&state, id, "division experiment").await
+}
+
+/// GET /consensus/ores/:id/trace.csv - Per-tick trace of the experiment behind an ore
+pub async fn get_consensus_trace(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    trace_csv(&state, id, "consensus ore").await
+}
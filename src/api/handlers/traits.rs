@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use schemars::JsonSchema;
+use serde::Serialize;
+use tokio::sync::oneshot;
+use tracing::{Span, field, instrument};
+use uuid::Uuid;
+
+use crate::state::{AppState, Command};
+
+#[derive(Serialize, JsonSchema)]
+pub struct ReimmerseResponse {
+    pub concept_id: Uuid,
+    pub name: String,
+    pub integration: f32,
+    pub layer: f32,
+}
+
+/// POST /trait/:id/reimmerse - Return a character trait to the fluid as its original concept
+#[instrument(name = "reimmerse", skip_all, fields(trait_index = index, concept_id = field::Empty))]
+pub async fn reimmerse_trait(
+    State(state): State<Arc<AppState>>,
+    Path(index): Path<usize>,
+) -> Result<Json<ReimmerseResponse>, (StatusCode, String)> {
+    let (response_tx, response_rx) = oneshot::channel();
+
+    state
+        .command_tx
+        .send(Command::Reimmerse {
+            trait_index: index,
+            response_tx,
+        })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+
+    let concept_id = tokio::time::timeout(Duration::from_secs(5), response_rx)
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::GATEWAY_TIMEOUT,
+                "Simulation response timeout".into(),
+            )
+        })?
+        .map_err(|_| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to re-immerse trait".into(),
+            )
+        })?
+        .ok_or((StatusCode::NOT_FOUND, format!("Trait {} not found", index)))?;
+    Span::current().record("concept_id", field::display(concept_id));
+
+    let fluid = state.fluid.read().await;
+    let concept = fluid.get_concept(concept_id).ok_or((
+        StatusCode::NOT_FOUND,
+        format!("Concept {} left the fluid", concept_id),
+    ))?;
+
+    Ok(Json(ReimmerseResponse {
+        concept_id,
+        name: concept.name.clone(),
+        integration: concept.integration,
+        layer: concept.layer,
+    }))
+}
@@ -2,20 +2,26 @@ use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
+use crate::api::validation;
+use crate::simulation::FalloffProfile;
 use crate::state::{AppState, Command};
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct VentResponse {
     pub name: String,
     pub heat_output: f32,
     pub depth: f32,
     pub radius: f32,
     pub activation_count: u32,
+    pub active: bool,
+    pub falloff: FalloffProfile,
 }
 
 /// GET /vent/:id - Get details of a specific vent
@@ -36,6 +42,8 @@ pub async fn get_vent(
         depth: vent.depth,
         radius: vent.radius,
         activation_count: vent.activation_count,
+        active: vent.active,
+        falloff: vent.falloff,
     }))
 }
 
@@ -52,18 +60,23 @@ pub async fn list_vents(State(state): State<Arc<AppState>>) -> Json<Vec<VentResp
             depth: v.depth,
             radius: v.radius,
             activation_count: v.activation_count,
+            active: v.active,
+            falloff: v.falloff,
         })
         .collect();
 
     Json(vents)
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub struct CreateVentRequest {
     pub name: String,
     pub heat_output: f32,
     pub depth: f32,
     pub radius: f32,
+    /// How the plume fades toward its edge (default quadratic)
+    #[serde(default)]
+    pub falloff: FalloffProfile,
 }
 
 /// POST /vent - Create a new core truth (vent)
@@ -71,25 +84,7 @@ pub async fn create_vent(
     State(state): State<Arc<AppState>>,
     Json(req): Json<CreateVentRequest>,
 ) -> Result<Json<VentResponse>, (StatusCode, String)> {
-    // Validate
-    if req.depth < 0.0 || req.depth > 1.0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "Depth must be between 0.0 and 1.0".into(),
-        ));
-    }
-    if req.radius <= 0.0 || req.radius > 1.0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "Radius must be between 0.0 and 1.0".into(),
-        ));
-    }
-    if req.heat_output < 0.0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "Heat output must be non-negative".into(),
-        ));
-    }
+    validation::vent(req.heat_output, req.depth, req.radius)?;
 
     // Send command
     state
@@ -99,6 +94,7 @@ pub async fn create_vent(
             heat_output: req.heat_output,
             depth: req.depth,
             radius: req.radius,
+            falloff: req.falloff,
         })
         .await
         .map_err(|_| {
@@ -114,5 +110,174 @@ pub async fn create_vent(
         depth: req.depth,
         radius: req.radius,
         activation_count: 0,
+        active: true,
+        falloff: req.falloff,
     }))
 }
+
+#[derive(Deserialize, JsonSchema)]
+pub struct VentActiveRequest {
+    pub active: bool,
+}
+
+/// PATCH /vent/:id/active - Toggle a vent without losing its strengthening history
+pub async fn set_vent_active(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<usize>,
+    Json(req): Json<VentActiveRequest>,
+) -> Result<Json<VentResponse>, (StatusCode, String)> {
+    let mut vent = {
+        let fluid = state.fluid.read().await;
+        let vent = fluid
+            .core_truths
+            .get(id)
+            .ok_or((StatusCode::NOT_FOUND, format!("Vent {} not found", id)))?;
+        VentResponse {
+            name: vent.name.clone(),
+            heat_output: vent.heat_output,
+            depth: vent.depth,
+            radius: vent.radius,
+            activation_count: vent.activation_count,
+            active: vent.active,
+            falloff: vent.falloff,
+        }
+    };
+
+    state
+        .command_tx
+        .send(Command::SetVentActive {
+            index: id,
+            active: req.active,
+        })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Simulation not running".into(),
+            )
+        })?;
+
+    vent.active = req.active;
+    Ok(Json(vent))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct InfluenceQuery {
+    /// Maximum concepts to return
+    #[serde(default = "default_influence_limit")]
+    pub limit: usize,
+}
+
+fn default_influence_limit() -> usize {
+    10
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct InfluencedConcept {
+    pub id: Uuid,
+    /// None once the concept has left the fluid
+    pub name: Option<String>,
+    pub encounters: u32,
+    pub impulse: f32,
+    pub last_tick: u64,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct VentInfluenceResponse {
+    pub vent: String,
+    /// Concepts currently tracked (bounded; least recently touched are evicted)
+    pub tracked: usize,
+    pub total_encounters: u64,
+    pub total_impulse: f32,
+    pub concepts: Vec<InfluencedConcept>,
+}
+
+/// GET /vent/:id/influence - Concepts this vent has lifted, most impulse first
+pub async fn get_vent_influence(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<usize>,
+    Query(query): Query<InfluenceQuery>,
+) -> Result<Json<VentInfluenceResponse>, (StatusCode, String)> {
+    let fluid = state.fluid.read().await;
+
+    let vent = fluid
+        .core_truths
+        .get(id)
+        .ok_or((StatusCode::NOT_FOUND, format!("Vent {} not found", id)))?;
+
+    let concepts = vent
+        .influence
+        .top(query.limit)
+        .into_iter()
+        .map(|(concept_id, record)| InfluencedConcept {
+            id: concept_id,
+            name: fluid.concepts.get(&concept_id).map(|c| c.name.clone()),
+            encounters: record.encounters,
+            impulse: record.impulse,
+            last_tick: record.last_tick,
+        })
+        .collect();
+
+    Ok(Json(VentInfluenceResponse {
+        vent: vent.name.clone(),
+        tracked: vent.influence.concepts.len(),
+        total_encounters: vent.influence.total_encounters,
+        total_impulse: vent.influence.total_impulse,
+        concepts,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::api::create_router;
+    use crate::simulation::ConceptFluid;
+
+    async fn patch_active(app: &axum::Router, uri: &str, active: bool) -> (StatusCode, Value) {
+        let request = axum::http::Request::builder()
+            .method("PATCH")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "active": active }).to_string()))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_vent_active_route_toggles_the_vent() {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_core_truth("honesty".to_string(), 1.0, 0.9, 0.1);
+        let (state, channels) = AppState::new(fluid);
+        let fluid = state.fluid.clone();
+        tokio::spawn(crate::runtime::run_simulation_loop(fluid.clone(), channels));
+        let app = create_router(Arc::new(state));
+
+        let (status, body) = patch_active(&app, "/vent/0/active", false).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["name"], "honesty");
+        assert_eq!(body["active"], false);
+
+        tokio::time::timeout(std::time::Duration::from_secs(1), async {
+            while fluid.read().await.core_truths[0].active {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("the simulation should disable the vent");
+
+        let (status, _) = patch_active(&app, "/vent/7/active", true).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,203 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+use crate::simulation::StandingWave;
+use crate::state::{AppState, Command};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct WaveRequest {
+    /// Number of nodes across the depth (0-20]
+    pub divisor: f32,
+    /// Force pulling concepts toward the nearest node
+    pub amplitude: f32,
+    /// Concepts a node holds before it starts repelling
+    #[serde(default = "default_saturation_limit")]
+    pub saturation_limit: u32,
+}
+
+fn default_saturation_limit() -> u32 {
+    2
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct WaveResponse {
+    pub id: Uuid,
+    pub divisor: f32,
+    pub amplitude: f32,
+    pub saturation_limit: u32,
+    pub node_positions: Vec<f32>,
+    /// Concepts within half a node spacing of each node, as of the last tick
+    pub node_occupancy: Vec<u32>,
+}
+
+impl From<&StandingWave> for WaveResponse {
+    fn from(wave: &StandingWave) -> Self {
+        Self {
+            id: wave.id,
+            divisor: wave.frequency,
+            amplitude: wave.amplitude,
+            saturation_limit: wave.saturation_limit,
+            node_positions: wave.node_positions.clone(),
+            node_occupancy: wave.node_occupancy.clone(),
+        }
+    }
+}
+
+/// Send a wave command and wait for the simulation loop's answer.
+async fn request<T>(
+    state: &AppState,
+    command: Command,
+    response_rx: oneshot::Receiver<T>,
+) -> Result<T, (StatusCode, String)> {
+    state.command_tx.send(command).await.map_err(|_| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Simulation not running".into(),
+        )
+    })?;
+
+    response_rx.await.map_err(|_| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to update waves".into(),
+        )
+    })
+}
+
+/// GET /waves - List persistent standing waves
+pub async fn list_waves(State(state): State<Arc<AppState>>) -> Json<Vec<WaveResponse>> {
+    let fluid = state.fluid.read().await;
+    Json(
+        fluid
+            .persistent_waves
+            .iter()
+            .map(WaveResponse::from)
+            .collect(),
+    )
+}
+
+/// POST /wave - Add a standing wave that outlives division experiments
+pub async fn create_wave(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<WaveRequest>,
+) -> Result<Json<WaveResponse>, (StatusCode, String)> {
+    // Validate
+    if !(req.divisor > 0.0 && req.divisor <= 20.0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Divisor must be between 0 and 20".into(),
+        ));
+    }
+    if !req.amplitude.is_finite() || req.amplitude < 0.0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Amplitude must be non-negative".into(),
+        ));
+    }
+    if req.saturation_limit == 0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Saturation limit must be at least 1".into(),
+        ));
+    }
+
+    let wave = StandingWave::new_with_saturation(req.divisor, req.amplitude, req.saturation_limit);
+    let response = WaveResponse::from(&wave);
+    let (response_tx, response_rx) = oneshot::channel();
+    request(&state, Command::AddWave { wave, response_tx }, response_rx).await?;
+
+    Ok(Json(response))
+}
+
+/// DELETE /wave/:id - Remove a persistent standing wave
+pub async fn delete_wave(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<WaveResponse>, (StatusCode, String)> {
+    let (response_tx, response_rx) = oneshot::channel();
+    let wave = request(&state, Command::RemoveWave { id, response_tx }, response_rx)
+        .await?
+        .ok_or((StatusCode::NOT_FOUND, format!("Wave {} not found", id)))?;
+
+    Ok(Json(WaveResponse::from(&wave)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::api::create_router;
+    use crate::simulation::ConceptFluid;
+
+    async fn send(app: &axum::Router, method: &str, uri: &str, body: Value) -> (StatusCode, Value) {
+        let request = axum::http::Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_waves_are_created_listed_and_deleted() {
+        let (state, channels) = AppState::new(ConceptFluid::default());
+        tokio::spawn(crate::runtime::run_simulation_loop(
+            state.fluid.clone(),
+            channels,
+        ));
+        let app = create_router(Arc::new(state));
+
+        let (status, wave) = send(
+            &app,
+            "POST",
+            "/wave",
+            json!({ "divisor": 2.0, "amplitude": 5.0 }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(wave["saturation_limit"], json!(2));
+        assert_eq!(wave["node_positions"], json!([0.25, 0.75]));
+
+        let (status, _) = send(
+            &app,
+            "POST",
+            "/wave",
+            json!({ "divisor": 0.0, "amplitude": 5.0 }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (_, waves) = send(&app, "GET", "/waves", Value::Null).await;
+        assert_eq!(waves.as_array().unwrap().len(), 1);
+        assert_eq!(waves[0]["id"], wave["id"]);
+
+        let uri = format!("/wave/{}", wave["id"].as_str().unwrap());
+        let (status, _) = send(&app, "DELETE", &uri, Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send(&app, "DELETE", &uri, Value::Null).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (_, waves) = send(&app, "GET", "/waves", Value::Null).await;
+        assert_eq!(waves, json!([]));
+    }
+}
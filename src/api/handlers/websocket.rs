@@ -1,5 +1,9 @@
 use std::sync::Arc;
+use std::time::Duration;
 
+use crate::api::validation::{self, ApiError};
+use crate::simulation::FluidError;
+use crate::state::{AppState, Command, CommandOutcome, CommandPayload};
 use axum::{
     extract::{
         State,
@@ -8,12 +12,20 @@ use axum::{
     response::IntoResponse,
 };
 use futures::{SinkExt, StreamExt};
-use serde::Deserialize;
-use tokio::sync::oneshot;
-use tracing::{debug, error, info};
-use uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
 
-use crate::state::{AppState, Command};
+/// A command frame from the client. `request_id` is echoed in any error
+/// frame the command produces.
+#[derive(Deserialize)]
+struct WsRequest {
+    #[serde(default)]
+    request_id: Option<Value>,
+    #[serde(flatten)]
+    payload: CommandPayload,
+}
 
 /// GET /ws - WebSocket endpoint (Willful Acts - bidirectional)
 ///
@@ -50,17 +62,27 @@ async fn handle_socket(socket: WebSocket, state: Arc<AppState>) {
         });
 
         if let Ok(json) = serde_json::to_string(&snapshot) {
-            let _ = sender.send(Message::Text(json.into())).await;
+            let _ = sender.send(Message::Text(json)).await;
         }
     }
 
+    // Error frames for this client's commands, sent alongside the events
+    let (error_tx, mut error_rx) = mpsc::channel::<Value>(16);
+
     // Spawn task to forward events to client
     let mut send_task = tokio::spawn(async move {
-        while let Ok(event) = event_rx.recv().await {
-            if let Ok(json) = serde_json::to_string(&event) {
-                if sender.send(Message::Text(json.into())).await.is_err() {
-                    break; // Client disconnected
-                }
+        loop {
+            let json = tokio::select! {
+                event = event_rx.recv() => match event {
+                    Ok(event) => serde_json::to_string(&event),
+                    Err(_) => break,
+                },
+                Some(frame) = error_rx.recv() => serde_json::to_string(&frame),
+            };
+            if let Ok(json) = json
+                && sender.send(Message::Text(json)).await.is_err()
+            {
+                break; // Client disconnected
             }
         }
     });
@@ -71,10 +93,24 @@ async fn handle_socket(socket: WebSocket, state: Arc<AppState>) {
         while let Some(Ok(msg)) = receiver.next().await {
             if let Message::Text(text) = msg {
                 debug!("Received WebSocket command: {}", text);
-                if let Some(cmd) = parse_ws_command(&text) {
-                    if let Err(e) = command_tx.send(cmd).await {
-                        error!("Failed to send command: {}", e);
+                let Some((request_id, parsed)) = parse_ws_command(&text) else {
+                    continue;
+                };
+                let (cmd, outcome) = match parsed {
+                    Ok(parsed) => parsed,
+                    Err(err) => {
+                        let _ = error_tx.send(error_frame(request_id, &err)).await;
+                        continue;
                     }
+                };
+                if let Err(e) = command_tx.send(cmd).await {
+                    error!("Failed to send command: {}", e);
+                    continue;
+                }
+                if let Some(outcome) = outcome
+                    && let Some(err) = await_outcome(outcome).await
+                {
+                    let _ = error_tx.send(error_frame(request_id, &err)).await;
                 }
             }
         }
@@ -95,103 +131,302 @@ async fn handle_socket(socket: WebSocket, state: Arc<AppState>) {
     info!("WebSocket client disconnected");
 }
 
-/// Commands that can be sent via WebSocket
-#[derive(Deserialize)]
-#[serde(tag = "command", rename_all = "snake_case")]
-enum WsCommand {
-    Inject {
-        name: String,
-        density: f32,
-        #[serde(default = "default_volume")]
-        volume: f32,
-    },
-    Ballast {
-        id: Uuid,
-        weight_delta: f32,
-    },
-    Thaw,
-    DeepBreath {
-        strength: f32,
-    },
-    ModulateBuoyancy {
-        id: Uuid,
-        delta: f32,
-    },
-    AddCoreTruth {
-        name: String,
-        heat_output: f32,
-        depth: f32,
-        radius: f32,
-    },
-    FlashHeal {
-        concepts: Vec<FreshConcept>,
-        dilution_strength: f32,
-    },
+/// A client frame's command and, for commands that can fail, its outcome
+type ParsedCommand = (Command, Option<CommandOutcome>);
+
+/// The request id of a client frame with its command, or the validation
+/// error the matching REST endpoint would have answered. None if the frame
+/// is not a command.
+fn parse_ws_command(text: &str) -> Option<(Option<Value>, Result<ParsedCommand, ApiError>)> {
+    let request = serde_json::from_str::<WsRequest>(text).ok()?;
+    let parsed =
+        validation::command(&request.payload).map(|()| request.payload.into_checked_command());
+    Some((request.request_id, parsed))
 }
 
-#[derive(Deserialize)]
-struct FreshConcept {
-    name: String,
-    density: f32,
-    area: f32,
+/// Wait for a command's outcome, returning the error it failed with.
+async fn await_outcome(outcome: CommandOutcome) -> Option<FluidError> {
+    match tokio::time::timeout(Duration::from_secs(5), outcome).await {
+        Ok(Ok(result)) => result.err(),
+        _ => {
+            warn!("No outcome from the simulation for a WebSocket command");
+            None
+        }
+    }
 }
 
-fn default_volume() -> f32 {
-    0.5
+/// `{"type": "error", "request_id", "error", ..., "message"}` for a rejected
+/// or failed command
+fn error_frame<E: Serialize + std::fmt::Display>(request_id: Option<Value>, err: &E) -> Value {
+    let mut frame = serde_json::to_value(err).unwrap_or_default();
+    frame["type"] = "error".into();
+    frame["request_id"] = request_id.unwrap_or(Value::Null);
+    frame["message"] = err.to_string().into();
+    frame
 }
 
-fn parse_ws_command(text: &str) -> Option<Command> {
-    let ws_cmd: WsCommand = serde_json::from_str(text).ok()?;
-
-    Some(match ws_cmd {
-        WsCommand::Inject {
-            name,
-            density,
-            volume,
-        } => {
-            let area = if density > 0.01 {
-                (volume / density).clamp(0.1, 2.0)
-            } else {
-                volume * 2.0
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::runtime::run_simulation_loop;
+    use crate::simulation::{CharacterTrait, ConceptFluid};
+
+    #[tokio::test]
+    async fn test_failed_ws_command_yields_error_frame_with_request_id() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("real".to_string(), 0.5, 0.5);
+        let missing = Uuid::new_v4();
+        let (state, channels) = AppState::new(fluid);
+        tokio::spawn(run_simulation_loop(state.fluid.clone(), channels));
+
+        let run = async |frame: Value| {
+            let (request_id, parsed) = parse_ws_command(&frame.to_string()).unwrap();
+            let (command, outcome) = parsed.unwrap();
+            state.command_tx.send(command).await.unwrap();
+            (request_id, outcome.unwrap())
+        };
+
+        let (request_id, outcome) = run(json!({
+            "command": "ballast", "id": missing, "weight_delta": 0.4, "request_id": "req-7"
+        }))
+        .await;
+        let err = await_outcome(outcome).await.expect("unknown concept");
+        assert_eq!(
+            error_frame(request_id, &err),
+            json!({
+                "type": "error",
+                "request_id": "req-7",
+                "error": "concept_not_found",
+                "id": missing,
+                "message": format!("Concept {} not found", missing),
+            })
+        );
+
+        let (_, outcome) = run(json!({
+            "command": "modulate_buoyancy", "id": id, "delta": 0.2
+        }))
+        .await;
+        assert_eq!(await_outcome(outcome).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_commands_are_rejected_alike_over_rest_and_ws() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("real".to_string(), 0.5, 0.5);
+        let (state, channels) = AppState::new(fluid);
+        tokio::spawn(run_simulation_loop(state.fluid.clone(), channels));
+        let app = crate::api::create_router(Arc::new(state));
+
+        // (REST method, path, body), WS frame, rejected field. JSON can't
+        // spell infinity, but 1e39 overflows f32 to it
+        let cases = [
+            (
+                (
+                    "POST",
+                    "/vent",
+                    json!({ "name": "v", "heat_output": 1.0, "depth": 7.0, "radius": 0.2 }),
+                ),
+                json!({ "command": "add_core_truth", "name": "v", "heat_output": 1.0, "depth": 7.0, "radius": 0.2 }),
+                "depth",
+            ),
+            (
+                (
+                    "POST",
+                    "/vent",
+                    json!({ "name": "v", "heat_output": 1e39, "depth": 0.9, "radius": 0.2 }),
+                ),
+                json!({ "command": "add_core_truth", "name": "v", "heat_output": 1e39, "depth": 0.9, "radius": 0.2 }),
+                "heat_output",
+            ),
+            (
+                ("POST", "/breath", json!({ "strength": 1.5 })),
+                json!({ "command": "deep_breath", "strength": 1.5 }),
+                "strength",
+            ),
+            (
+                (
+                    "POST",
+                    "/flash-heal",
+                    json!({ "concepts": [], "dilution_strength": -0.1 }),
+                ),
+                json!({ "command": "flash_heal", "concepts": [], "dilution_strength": -0.1 }),
+                "dilution_strength",
+            ),
+            (
+                (
+                    "POST",
+                    "/flash-heal",
+                    json!({ "concepts": [{ "name": "n", "density": 0.2, "area": 0.0 }], "dilution_strength": 0.5 }),
+                ),
+                json!({ "command": "flash_heal", "concepts": [{ "name": "n", "density": 0.2, "area": 0.0 }], "dilution_strength": 0.5 }),
+                "area",
+            ),
+            (
+                (
+                    "PATCH",
+                    "/ballast",
+                    json!({ "id": id, "weight_delta": 2.0 }),
+                ),
+                json!({ "command": "ballast", "id": id, "weight_delta": 2.0 }),
+                "weight_delta",
+            ),
+            (
+                ("PATCH", "/buoyancy", json!({ "id": id, "delta": -3.0 })),
+                json!({ "command": "modulate_buoyancy", "id": id, "delta": -3.0 }),
+                "delta",
+            ),
+            (
+                (
+                    "POST",
+                    "/inject",
+                    json!({ "concept": "c", "density": 0.5, "initial_velocity": -1e39 }),
+                ),
+                json!({ "command": "inject", "name": "c", "density": 0.5, "initial_velocity": -1e39 }),
+                "initial_velocity",
+            ),
+            (
+                (
+                    "POST",
+                    "/inject",
+                    json!({ "concept": "c", "density": 0.5, "volume": 0.5, "area": 1.0 }),
+                ),
+                json!({ "command": "inject", "name": "c", "density": 0.5, "volume": 0.5, "area": 1.0 }),
+                "area",
+            ),
+            (
+                (
+                    "PATCH",
+                    &format!("/concept/{}/name", id),
+                    json!({ "name": "  " }),
+                ),
+                json!({ "command": "rename", "id": id, "name": "  " }),
+                "name",
+            ),
+        ];
+
+        for ((method, uri, body), mut frame, field) in cases {
+            frame["request_id"] = json!(field);
+            let (request_id, parsed) = parse_ws_command(&frame.to_string()).unwrap();
+            let Err(err) = parsed else {
+                panic!("{field} accepted over WebSocket");
             };
-            let (tx, _) = oneshot::channel();
-            Command::Inject {
-                name,
-                density,
-                area,
-                response_tx: tx,
+            let frame = error_frame(request_id, &err);
+            assert_eq!(frame["type"], "error");
+            assert_eq!(frame["error"], "invalid_field");
+            assert_eq!(frame["request_id"], field);
+            assert_eq!(frame["field"], field);
+
+            let request = axum::http::Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri} {field}");
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            assert_eq!(String::from_utf8(bytes.to_vec()).unwrap(), frame["message"]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rest_and_ws_size_concepts_identically() {
+        let mut fluid = ConceptFluid::default();
+        fluid.atmosphere.push(CharacterTrait::new(
+            "patience".to_string(),
+            1.0,
+            Uuid::nil(),
+        ));
+        let (state, channels) = AppState::new(fluid);
+        let fluid = state.fluid.clone();
+        let command_tx = state.command_tx.clone();
+        tokio::spawn(run_simulation_loop(fluid.clone(), channels));
+        let app = crate::api::create_router(Arc::new(state));
+
+        let post = async |uri: &str, body: Value| {
+            let request = axum::http::Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<Value>(&bytes).unwrap()
+        };
+
+        // WebSocket frames first; the REST requests queue behind them, so
+        // once those are answered every frame has been applied
+        for (style, size) in [("volume", 0.6), ("area", 1.7)] {
+            for frame in [
+                json!({ "command": "inject", "name": format!("ws_inject_{style}"), "density": 0.4, style: size }),
+                json!({ "command": "flash_heal", "dilution_strength": 0.1, "concepts": [
+                    { "name": format!("ws_heal_{style}"), "density": 0.4, style: size }
+                ] }),
+                json!({ "command": "precipitate", "trait_index": 0,
+                        "new_concept_name": format!("echo_{style}"), "density": 0.4, style: size }),
+            ] {
+                let (_, parsed) = parse_ws_command(&frame.to_string()).unwrap();
+                command_tx.send(parsed.unwrap().0).await.unwrap();
             }
         }
-        WsCommand::Ballast { id, weight_delta } => Command::Ballast {
-            concept_id: id,
-            weight_delta,
-        },
-        WsCommand::Thaw => Command::Thaw,
-        WsCommand::DeepBreath { strength } => Command::DeepBreath { strength },
-        WsCommand::ModulateBuoyancy { id, delta } => Command::ModulateBuoyancy {
-            concept_id: id,
-            delta,
-        },
-        WsCommand::AddCoreTruth {
-            name,
-            heat_output,
-            depth,
-            radius,
-        } => Command::AddCoreTruth {
-            name,
-            heat_output,
-            depth,
-            radius,
-        },
-        WsCommand::FlashHeal {
-            concepts,
-            dilution_strength,
-        } => Command::FlashHeal {
-            concepts: concepts
-                .into_iter()
-                .map(|c| (c.name, c.density, c.area))
-                .collect(),
-            dilution_strength,
-        },
-    })
+
+        let mut rest = Vec::new();
+        for (style, size) in [("volume", 0.6), ("area", 1.7)] {
+            let injected = post(
+                "/inject",
+                json!({ "concept": format!("rest_inject_{style}"), "density": 0.4, style: size }),
+            )
+            .await;
+            let healed = post(
+                "/flash-heal",
+                json!({ "dilution_strength": 0.1, "concepts": [
+                    { "name": format!("rest_heal_{style}"), "density": 0.4, style: size }
+                ] }),
+            )
+            .await;
+            rest.push((injected, healed));
+        }
+
+        // Volume 0.6 at density 0.4 is area 1.5; area 1.7 is volume 0.68
+        let expected = [(1.5_f32, 0.6_f32), (1.7, 0.68)];
+        let fluid = fluid.read().await;
+        let area_of = |name: &str| {
+            fluid
+                .concepts
+                .values()
+                .find(|c| c.name == name)
+                .unwrap_or_else(|| panic!("{name} missing"))
+                .area
+        };
+        for ((style, (area, volume)), (injected, healed)) in
+            ["volume", "area"].into_iter().zip(expected).zip(rest)
+        {
+            for name in [
+                format!("ws_inject_{style}"),
+                format!("rest_inject_{style}"),
+                format!("ws_heal_{style}"),
+                format!("rest_heal_{style}"),
+                format!("echo_{style}"),
+            ] {
+                assert!((area_of(&name) - area).abs() < 1e-6, "{name}");
+            }
+            assert!((injected["area"].as_f64().unwrap() as f32 - area).abs() < 1e-6);
+            assert!((injected["volume"].as_f64().unwrap() as f32 - volume).abs() < 1e-6);
+            let heal = &healed["concept_dimensions"][0];
+            assert!((heal["area"].as_f64().unwrap() as f32 - area).abs() < 1e-6);
+            assert!((heal["volume"].as_f64().unwrap() as f32 - volume).abs() < 1e-6);
+        }
+    }
 }
@@ -1,4 +1,7 @@
 pub mod handlers;
+pub mod openapi;
+pub mod render;
 pub mod routes;
+pub mod validation;
 
 pub use routes::create_router;
@@ -0,0 +1,544 @@
+use schemars::{JsonSchema, SchemaGenerator, generate::SchemaSettings};
+use serde_json::{Map, Value, json};
+
+use super::handlers::{
+    actions::{
+        DeepBreathRequest, DeepBreathResponse, FlashHealRequest, FlashHealResponse, ThawResponse,
+    },
+    advance::{AdvanceRequest, AdvanceResponse},
+    attention::{FocusRequest, FocusResponse},
+    ballast::{
+        BallastRequest, BallastResponse, BuoyancyRequest, BuoyancyResponse, SubmergeRequest,
+        SubmergeResponse,
+    },
+    concept::{
+        ConceptsQuery, ConceptsResponse, NeighborsQuery, NeighborsResponse, PruneResponse,
+        RemoveResponse, RenameRequest, RenameResponse,
+    },
+    consensus::{
+        ConsensusFromConceptsRequest, ConsensusOreResponse, ConsensusRequest,
+        ConsensusStartResponse, ConsensusStatusResponse,
+    },
+    continent::{ContinentResponse, TectonicRequest, TectonicResponse},
+    division::{
+        DivisionRequest, DivisionResultResponse, DivisionStartResponse, ExperimentStatusResponse,
+    },
+    health::HealthResponse,
+    heatmap::{HeatmapQuery, HeatmapStartRequest, HeatmapStatusResponse},
+    inject::{InjectRequest, InjectResponse},
+    link::{LinkRequest, LinkResponse},
+    minds::MindResponse,
+    ore::OreLedgerResponse,
+    physics::{PhysicsPatch, PhysicsResponse},
+    pressure::{PressureProfileResponse, PressureQuery},
+    render::{RenderQuery, SvgQuery},
+    rules::{RuleRequest, RuleResponse},
+    schedules::ScheduleResponse,
+    stats::{IntegrationProfileQuery, IntegrationProfileResponse},
+    strata::{StrataQuery, StrataResponse},
+    timeseries::TimeSeriesParams,
+    traits::ReimmerseResponse,
+    vent::{
+        CreateVentRequest, InfluenceQuery, VentActiveRequest, VentInfluenceResponse, VentResponse,
+    },
+    wave::{WaveRequest, WaveResponse},
+};
+use crate::simulation::{
+    ConceptFilter, ConsensusStats, ConsensusSummary, EnergyLedger, FluidStats, HeatmapMatrix,
+    NameFrequency, Persona, QualityThresholds, QualityThresholdsPatch, TimeSeriesQuery,
+};
+use crate::state::{
+    ConceptDetail, DispatchLog, DispatchRuleConfig, DispatchRuleView, FluidStateResponse, Schedule,
+    TimingReport,
+};
+
+/// Builds the OpenAPI document. Request schemas follow the deserialize
+/// contract and response schemas the serialize contract, so defaults and
+/// skipped fields are described the way clients actually see them.
+struct ApiBuilder {
+    requests: SchemaGenerator,
+    responses: SchemaGenerator,
+    paths: Map<String, Value>,
+}
+
+/// One operation under construction, finished by `json` or `text`.
+struct Operation<'a> {
+    api: &'a mut ApiBuilder,
+    method: &'static str,
+    path: &'static str,
+    operation: Value,
+}
+
+impl ApiBuilder {
+    fn new() -> Self {
+        Self {
+            requests: SchemaSettings::openapi3()
+                .for_deserialize()
+                .into_generator(),
+            responses: SchemaSettings::openapi3().for_serialize().into_generator(),
+            paths: Map::new(),
+        }
+    }
+
+    fn get(&mut self, path: &'static str, summary: &str) -> Operation<'_> {
+        self.operation("get", path, summary)
+    }
+
+    fn post(&mut self, path: &'static str, summary: &str) -> Operation<'_> {
+        self.operation("post", path, summary)
+    }
+
+    fn patch(&mut self, path: &'static str, summary: &str) -> Operation<'_> {
+        self.operation("patch", path, summary)
+    }
+
+    fn delete(&mut self, path: &'static str, summary: &str) -> Operation<'_> {
+        self.operation("delete", path, summary)
+    }
+
+    fn operation(
+        &mut self,
+        method: &'static str,
+        path: &'static str,
+        summary: &str,
+    ) -> Operation<'_> {
+        Operation {
+            api: self,
+            method,
+            path,
+            operation: json!({ "summary": summary }),
+        }
+    }
+
+    fn finish(mut self) -> Value {
+        let mut schemas = self.requests.take_definitions(true);
+        schemas.extend(self.responses.take_definitions(true));
+        json!({
+            "openapi": "3.0.3",
+            "info": {
+                "title": "Buoyancy Thinking API",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "paths": self.paths,
+            "components": { "schemas": schemas },
+        })
+    }
+}
+
+impl Operation<'_> {
+    fn parameter(mut self, parameter: Value) -> Self {
+        match self.operation["parameters"].as_array_mut() {
+            Some(parameters) => parameters.push(parameter),
+            None => self.operation["parameters"] = json!([parameter]),
+        }
+        self
+    }
+
+    /// Required `{id}` path parameter with the given schema.
+    fn id(self, schema: Value) -> Self {
+        self.parameter(json!({ "name": "id", "in": "path", "required": true, "schema": schema }))
+    }
+
+    /// Query parameters, one per property of the query struct.
+    fn query<T: JsonSchema>(mut self) -> Self {
+        let schema = self.api.requests.root_schema_for::<T>();
+        let required = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        let properties = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default();
+        for (name, mut property) in properties {
+            let description = property
+                .as_object_mut()
+                .and_then(|p| p.remove("description"));
+            let mut parameter = json!({
+                "name": name,
+                "in": "query",
+                "required": required.contains(&Value::String(name.clone())),
+                "schema": property,
+            });
+            if let Some(description) = description {
+                parameter["description"] = description;
+            }
+            self = self.parameter(parameter);
+        }
+        self
+    }
+
+    /// JSON request body.
+    fn body<T: JsonSchema>(mut self) -> Self {
+        let schema = self.api.requests.subschema_for::<T>().to_value();
+        self.operation["requestBody"] = json!({
+            "required": true,
+            "content": { "application/json": { "schema": schema } },
+        });
+        self
+    }
+
+    /// Finish with a JSON response.
+    fn json<T: JsonSchema>(self) {
+        let schema = self.api.responses.subschema_for::<T>().to_value();
+        self.respond(json!({ "application/json": { "schema": schema } }));
+    }
+
+    /// Finish with a non-JSON response.
+    fn text(self, content_type: &str, description: &str) {
+        self.respond(json!({
+            content_type: { "schema": { "type": "string", "description": description } }
+        }));
+    }
+
+    fn respond(mut self, content: Value) {
+        self.operation["responses"] = json!({
+            "200": { "description": "OK", "content": content },
+        });
+        let entry = self.api.paths.entry(self.path).or_insert_with(|| json!({}));
+        entry[self.method] = self.operation;
+    }
+}
+
+/// The OpenAPI 3 description of every HTTP route.
+pub fn openapi_document() -> Value {
+    let mut api = ApiBuilder::new();
+    let index = || json!({ "type": "integer", "minimum": 0 });
+    let uuid = || json!({ "type": "string", "format": "uuid" });
+
+    // === Concept operations ===
+    api.post("/inject", "Inject a thought")
+        .body::<InjectRequest>()
+        .json::<InjectResponse>();
+    api.patch("/ballast", "Adjust buoyancy")
+        .body::<BallastRequest>()
+        .json::<BallastResponse>();
+    api.patch("/buoyancy", "Modulate a concept's buoyancy")
+        .body::<BuoyancyRequest>()
+        .json::<BuoyancyResponse>();
+    api.get("/concepts", "List concepts, optionally by origin")
+        .query::<ConceptsQuery>()
+        .json::<ConceptsResponse>();
+    api.get("/concept/{id}", "Inspect a single concept")
+        .id(uuid())
+        .json::<ConceptDetail>();
+    api.delete("/concept/{id}", "Remove a concept")
+        .id(uuid())
+        .json::<RemoveResponse>();
+    api.patch("/concept/{id}/name", "Rename a concept")
+        .id(uuid())
+        .body::<RenameRequest>()
+        .json::<RenameResponse>();
+    api.post("/concept/{id}/submerge", "Push a concept back under")
+        .id(uuid())
+        .body::<SubmergeRequest>()
+        .json::<SubmergeResponse>();
+    api.post("/concept/prune", "Remove all concepts matching a filter")
+        .body::<ConceptFilter>()
+        .json::<PruneResponse>();
+    api.get("/concept/{id}/neighbors", "Concepts closest in depth")
+        .id(uuid())
+        .query::<NeighborsQuery>()
+        .json::<NeighborsResponse>();
+    api.post("/link", "Link two concepts so they keep near each other")
+        .body::<LinkRequest>()
+        .json::<LinkResponse>();
+    api.delete("/link/{id}", "Remove a link")
+        .id(index())
+        .json::<LinkResponse>();
+    api.post("/attention/focus", "Focus attention on a depth window")
+        .body::<FocusRequest>()
+        .json::<FocusResponse>();
+
+    // === Core truths (vents) ===
+    api.post("/vent", "Create a core truth")
+        .body::<CreateVentRequest>()
+        .json::<VentResponse>();
+    api.get("/vent/{id}", "Get a vent")
+        .id(index())
+        .json::<VentResponse>();
+    api.patch("/vent/{id}/active", "Enable or disable a vent")
+        .id(index())
+        .body::<VentActiveRequest>()
+        .json::<VentResponse>();
+    api.get("/vent/{id}/influence", "Concepts a vent has lifted")
+        .id(index())
+        .query::<InfluenceQuery>()
+        .json::<VentInfluenceResponse>();
+    api.get("/vents", "List all vents")
+        .json::<Vec<VentResponse>>();
+
+    // === Character traits ===
+    api.post("/trait/{id}/reimmerse", "Return a trait to the fluid")
+        .id(index())
+        .json::<ReimmerseResponse>();
+
+    // === Strata, ores, continents ===
+    api.get("/strata", "Query concepts by depth")
+        .query::<StrataQuery>()
+        .json::<StrataResponse>();
+    api.get("/pressure-profile", "Forces on a test particle by depth")
+        .query::<PressureQuery>()
+        .json::<PressureProfileResponse>();
+    api.get("/integration-profile", "Integration summed by depth")
+        .query::<IntegrationProfileQuery>()
+        .json::<IntegrationProfileResponse>();
+    api.get("/ores/ledger", "Lifetime ore accounting")
+        .json::<OreLedgerResponse>();
+    api.post("/continent", "Trigger a tectonic shift")
+        .body::<TectonicRequest>()
+        .json::<TectonicResponse>();
+    api.get("/continents", "List continents")
+        .json::<Vec<ContinentResponse>>();
+
+    // === Actions ===
+    api.post("/thaw", "Break freeze state")
+        .json::<ThawResponse>();
+    api.post("/breath", "Apply calming damping")
+        .body::<DeepBreathRequest>()
+        .json::<DeepBreathResponse>();
+    api.post("/flash-heal", "Dilute salinity")
+        .body::<FlashHealRequest>()
+        .json::<FlashHealResponse>();
+    api.post("/advance", "Run N ticks synchronously")
+        .body::<AdvanceRequest>()
+        .json::<AdvanceResponse>();
+
+    // === Rules ===
+    api.get("/rules", "List rules").json::<Vec<RuleResponse>>();
+    api.post("/rules", "Register a rule reacting to an event")
+        .body::<RuleRequest>()
+        .json::<RuleResponse>();
+    api.delete("/rules/{id}", "Remove a rule")
+        .id(index())
+        .json::<RuleResponse>();
+
+    // === Scheduled commands ===
+    api.get("/schedules", "List scheduled commands")
+        .json::<Vec<ScheduleResponse>>();
+    api.post("/schedules", "Add a scheduled command")
+        .body::<Schedule>()
+        .json::<ScheduleResponse>();
+    api.delete("/schedules/{name}", "Remove a scheduled command")
+        .parameter(json!({
+            "name": "name", "in": "path", "required": true, "schema": { "type": "string" }
+        }))
+        .json::<ScheduleResponse>();
+
+    // === Dispatch rules ===
+    api.get("/dispatch-rules", "List breakthrough dispatch rules")
+        .json::<Vec<DispatchRuleView>>();
+    api.post("/dispatch-rules", "Add a dispatch rule (lowest precedence)")
+        .body::<DispatchRuleConfig>()
+        .json::<DispatchRuleView>();
+    api.delete("/dispatch-rules/{id}", "Remove a dispatch rule")
+        .id(index())
+        .json::<DispatchRuleView>();
+    api.get("/dispatch-rules/{id}/log", "Recent dispatches by a rule")
+        .id(index())
+        .json::<DispatchLog>();
+
+    // === Minds ===
+    api.get("/minds", "List created minds")
+        .json::<Vec<MindResponse>>();
+    api.post("/minds", "Create an isolated mind")
+        .json::<MindResponse>();
+    api.delete("/minds/{id}", "Stop and discard a mind")
+        .id(uuid())
+        .json::<MindResponse>();
+
+    // === Division experiments ===
+    api.post("/divide", "Start a division experiment")
+        .body::<DivisionRequest>()
+        .json::<DivisionStartResponse>();
+    api.get("/divide/status", "Division experiment progress")
+        .json::<ExperimentStatusResponse>();
+    api.get("/divide/results", "Completed division results")
+        .json::<Vec<DivisionResultResponse>>();
+    api.get("/divide/results/{id}/trace.csv", "Per-tick division trace")
+        .id(uuid())
+        .text("text/csv", "CSV rows, header first");
+    api.post("/wave", "Add a persistent standing wave")
+        .body::<WaveRequest>()
+        .json::<WaveResponse>();
+    api.delete("/wave/{id}", "Remove a persistent standing wave")
+        .id(uuid())
+        .json::<WaveResponse>();
+    api.get("/waves", "List persistent standing waves")
+        .json::<Vec<WaveResponse>>();
+
+    // === Consensus reactor ===
+    api.post("/consensus", "Start a consensus experiment")
+        .body::<ConsensusRequest>()
+        .json::<ConsensusStartResponse>();
+    api.post(
+        "/consensus/from-concepts",
+        "Consensus between two live concepts",
+    )
+    .body::<ConsensusFromConceptsRequest>()
+    .json::<ConsensusStartResponse>();
+    api.get("/consensus/status", "Consensus experiment progress")
+        .json::<ConsensusStatusResponse>();
+    api.post(
+        "/consensus/crystallize",
+        "Crystallize the active experiment now",
+    )
+    .json::<ConsensusOreResponse>();
+    api.get("/consensus/ores", "Crystallized consensus ores")
+        .json::<Vec<ConsensusOreResponse>>();
+    api.get("/consensus/ores/{id}/trace.csv", "Per-tick consensus trace")
+        .id(uuid())
+        .text("text/csv", "CSV rows, header first");
+    api.get(
+        "/consensus/truths",
+        "Foundational truths (C above the threshold)",
+    )
+    .json::<Vec<ConsensusOreResponse>>();
+    api.get("/consensus/stats", "Certainty distribution")
+        .json::<ConsensusStats>();
+    api.get("/consensus/summary", "Experiment and ore counts")
+        .json::<ConsensusSummary>();
+    api.get("/consensus/config", "Certainty cutoffs for ore quality")
+        .json::<QualityThresholds>();
+    api.patch("/consensus/config", "Change the certainty cutoffs")
+        .body::<QualityThresholdsPatch>()
+        .json::<QualityThresholds>();
+
+    // === State queries ===
+    api.get("/state", "Full simulation state")
+        .json::<FluidStateResponse>();
+    api.patch("/fluid/physics", "Change how viscosity varies with depth")
+        .body::<PhysicsPatch>()
+        .json::<PhysicsResponse>();
+    api.get("/stats", "Aggregate statistics")
+        .json::<FluidStats>();
+    api.get("/persona", "Compact mood vector").json::<Persona>();
+    api.get("/names", "Concept name frequencies")
+        .json::<Vec<NameFrequency>>();
+    api.get("/render", "Layered text view")
+        .query::<RenderQuery>()
+        .text("text/plain", "Layered view, surface first");
+    api.get("/render.svg", "SVG depth chart")
+        .query::<SvgQuery>()
+        .text("image/svg+xml", "Self-contained SVG document");
+    api.get("/timeseries", "Sampled metric history")
+        .query::<TimeSeriesParams>()
+        .json::<TimeSeriesQuery>();
+    api.get("/heatmap", "Recorded depth × time matrix")
+        .query::<HeatmapQuery>()
+        .json::<HeatmapMatrix>();
+    api.post("/heatmap/start", "Start recording the heatmap")
+        .body::<HeatmapStartRequest>()
+        .json::<HeatmapStatusResponse>();
+    api.post("/heatmap/stop", "Stop recording the heatmap")
+        .json::<HeatmapStatusResponse>();
+    api.get("/energy", "Kinetic energy ledger")
+        .json::<EnergyLedger>();
+    api.get("/health", "Liveness and Redis bridge status")
+        .json::<HealthResponse>();
+    api.get("/timing", "Simulated vs wall-clock time")
+        .json::<TimingReport>();
+    api.get("/openapi.json", "This document")
+        .text("application/json", "OpenAPI 3 document");
+
+    // === Real-time streams ===
+    api.get("/events", "SSE event stream")
+        .text("text/event-stream", "One FluidEvent JSON object per event");
+    api.get("/ws", "WebSocket: accepts commands, streams events")
+        .text("application/json", "WebSocket upgrade");
+
+    // === Dev tools ===
+    #[cfg(feature = "fuzz")]
+    api.post(
+        "/fuzz",
+        "Stress a copy of the fluid and report numerical anomalies",
+    )
+    .body::<crate::runtime::FuzzConfig>()
+    .json::<crate::runtime::FuzzReport>();
+
+    api.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `(method, path)` for every `.route(...)` in routes.rs, in OpenAPI path syntax.
+    fn registered_routes() -> Vec<(String, String)> {
+        let source = include_str!("routes.rs");
+        let mut routes = Vec::new();
+        let mut rest = source;
+        while let Some(start) = rest.find(".route(") {
+            rest = &rest[start + ".route(".len()..];
+            let path_start = rest.find('"').unwrap() + 1;
+            let path_end = path_start + rest[path_start..].find('"').unwrap();
+            let path = rest[path_start..path_end]
+                .split('/')
+                .map(|segment| match segment.strip_prefix(':') {
+                    Some(name) => format!("{{{}}}", name),
+                    None => segment.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("/");
+            // `/minds/{id}/*path` serves the other routes again, per mind
+            if path.contains('*') {
+                continue;
+            }
+            // Feature-gated routes only exist when their feature is on
+            let gated = source[..source.len() - rest.len()]
+                .lines()
+                .nth_back(1)
+                .is_some_and(|line| line.trim() == "#[cfg(feature = \"fuzz\")]");
+            if gated && !cfg!(feature = "fuzz") {
+                continue;
+            }
+            let after = &rest[path_end..];
+            let method_end = after.find("(handlers::").unwrap();
+            let method = after[..method_end]
+                .trim_start_matches(|c: char| c == '"' || c == ',' || c.is_whitespace());
+            routes.push((method.to_string(), path));
+        }
+        routes
+    }
+
+    #[test]
+    fn test_openapi_lists_every_route() {
+        let text = serde_json::to_string(&openapi_document()).unwrap();
+        let doc: Value = serde_json::from_str(&text).expect("document should be valid JSON");
+        assert_eq!(doc["openapi"], "3.0.3");
+
+        let routes = registered_routes();
+        assert!(routes.len() > 30);
+        for (method, path) in &routes {
+            assert!(
+                doc["paths"][path][method].is_object(),
+                "{} {} missing from openapi.json",
+                method,
+                path
+            );
+        }
+        let documented: usize = doc["paths"]
+            .as_object()
+            .unwrap()
+            .values()
+            .map(|ops| ops.as_object().unwrap().len())
+            .sum();
+        assert_eq!(
+            documented,
+            routes.len(),
+            "openapi.json lists unknown routes"
+        );
+
+        // Schemas resolve, and request defaults are described
+        let schemas = &doc["components"]["schemas"];
+        assert!(schemas["InjectRequest"]["properties"]["concept"].is_object());
+        assert!(schemas["VentResponse"]["properties"]["active"].is_object());
+        let inject = &doc["paths"]["/inject"]["post"];
+        assert_eq!(
+            inject["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/InjectRequest"
+        );
+    }
+}
@@ -0,0 +1,393 @@
+use std::fmt::Write;
+
+use crate::simulation::{ConceptFluid, concept::Concept};
+
+/// Layout options for the SVG depth chart.
+#[derive(Debug, Clone, Copy)]
+pub struct SvgOptions {
+    pub width: u32,
+    pub height: u32,
+    /// Draw concept names next to their circles
+    pub labels: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            width: 480,
+            height: 720,
+            labels: true,
+        }
+    }
+}
+
+/// Margins around the plot area (pixels)
+const MARGIN_TOP: f32 = 24.0;
+const MARGIN_BOTTOM: f32 = 24.0;
+const MARGIN_LEFT: f32 = 56.0;
+const MARGIN_RIGHT: f32 = 24.0;
+
+/// Circle radius (pixels) per unit of concept area
+const CONCEPT_RADIUS_SCALE: f32 = 16.0;
+const CONCEPT_MIN_RADIUS: f32 = 2.0;
+
+/// Minimal SVG writer: elements are appended in paint order.
+struct Svg {
+    out: String,
+}
+
+impl Svg {
+    fn new(width: u32, height: u32) -> Self {
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="11">"#,
+            w = width,
+            h = height
+        );
+        Self { out }
+    }
+
+    /// Write `<tag a="v" ...>text</tag>`, or a self-closing tag without text.
+    fn element(&mut self, tag: &str, attrs: &[(&str, String)], text: Option<&str>) {
+        let _ = write!(self.out, "<{}", tag);
+        for (name, value) in attrs {
+            let _ = write!(self.out, r#" {}="{}""#, name, escape(value));
+        }
+        match text {
+            Some(text) => {
+                let _ = write!(self.out, ">{}</{}>", escape(text), tag);
+            }
+            None => self.out.push_str("/>"),
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.out.push_str("</svg>");
+        self.out
+    }
+}
+
+/// Escape text for use in XML content and attribute values.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Fill colour for a concept's status.
+fn status_fill(concept: &Concept) -> &'static str {
+    if concept.is_solution {
+        return "#f5c542";
+    }
+    match concept.status() {
+        "frozen" => "#9fd8ef",
+        "evaporated" => "#d0d0d0",
+        "rising" => "#4caf50",
+        "sinking" => "#e57373",
+        _ => "#5c8dd6",
+    }
+}
+
+/// Render a self-contained SVG depth chart of the fluid.
+///
+/// Depth runs top (surface) to bottom (floor). Concepts are circles sized by
+/// area and filled by status, vents are flame markers with radius arcs,
+/// continents are shaded bands and ore deposits are diamonds. The activation
+/// and freeze zones are drawn as dashed horizontal guides.
+pub fn render_svg(fluid: &ConceptFluid, options: &SvgOptions) -> String {
+    let width = options.width as f32;
+    let height = options.height as f32;
+    let plot_left = MARGIN_LEFT;
+    let plot_right = (width - MARGIN_RIGHT).max(plot_left + 1.0);
+    let plot_height = (height - MARGIN_TOP - MARGIN_BOTTOM).max(1.0);
+    let y = |depth: f32| MARGIN_TOP + depth.clamp(0.0, 1.0) * plot_height;
+    // Spread `count` items evenly across the plot width
+    let x = |index: usize, count: usize| {
+        plot_left + (plot_right - plot_left) * (index as f32 + 0.5) / count.max(1) as f32
+    };
+    let px = |v: f32| format!("{:.1}", v);
+
+    let mut svg = Svg::new(options.width, options.height);
+    svg.element(
+        "rect",
+        &[
+            ("class", "background".into()),
+            ("width", options.width.to_string()),
+            ("height", options.height.to_string()),
+            ("fill", "#0b2233".into()),
+        ],
+        None,
+    );
+
+    // === Continents (shaded bands) ===
+    for continent in &fluid.continents {
+        let (top, bottom) = continent.depth_range;
+        svg.element(
+            "rect",
+            &[
+                ("class", "continent".into()),
+                ("x", px(plot_left)),
+                ("y", px(y(top))),
+                ("width", px(plot_right - plot_left)),
+                ("height", px((y(bottom) - y(top)).max(1.0))),
+                ("fill", "#8d6e63".into()),
+                ("fill-opacity", "0.35".into()),
+            ],
+            None,
+        );
+    }
+
+    // === Zone guides ===
+    for (class, depth, colour) in [
+        ("activation-zone", fluid.activation_zone, "#ffeb3b"),
+        ("freeze-zone", fluid.freeze_zone, "#9fd8ef"),
+    ] {
+        svg.element(
+            "line",
+            &[
+                ("class", class.into()),
+                ("x1", px(plot_left)),
+                ("y1", px(y(depth))),
+                ("x2", px(plot_right)),
+                ("y2", px(y(depth))),
+                ("stroke", colour.into()),
+                ("stroke-dasharray", "4 3".into()),
+            ],
+            None,
+        );
+    }
+
+    // === Depth axis ===
+    svg.element(
+        "line",
+        &[
+            ("class", "axis".into()),
+            ("x1", px(plot_left)),
+            ("y1", px(y(0.0))),
+            ("x2", px(plot_left)),
+            ("y2", px(y(1.0))),
+            ("stroke", "#cfd8dc".into()),
+        ],
+        None,
+    );
+    for step in 0..=4 {
+        let depth = step as f32 / 4.0;
+        svg.element(
+            "text",
+            &[
+                ("class", "axis-label".into()),
+                ("x", px(plot_left - 6.0)),
+                ("y", px(y(depth) + 4.0)),
+                ("text-anchor", "end".into()),
+                ("fill", "#cfd8dc".into()),
+            ],
+            Some(&format!("{:.2}", depth)),
+        );
+    }
+
+    // === Vents (flame markers with radius arcs) ===
+    let vent_count = fluid.core_truths.len();
+    for (i, truth) in fluid.core_truths.iter().enumerate() {
+        let (cx, cy) = (x(i, vent_count), y(truth.depth));
+        let reach = truth.radius * plot_height;
+        let colour = if truth.active { "#ff7043" } else { "#78909c" };
+        svg.element(
+            "path",
+            &[
+                ("class", "vent-arc".into()),
+                (
+                    "d",
+                    format!(
+                        "M {} {} A {} {} 0 0 1 {} {}",
+                        px(cx - reach),
+                        px(cy),
+                        px(reach),
+                        px(reach),
+                        px(cx + reach),
+                        px(cy)
+                    ),
+                ),
+                ("fill", "none".into()),
+                ("stroke", colour.into()),
+                ("stroke-opacity", "0.5".into()),
+            ],
+            None,
+        );
+        svg.element(
+            "path",
+            &[
+                ("class", "vent".into()),
+                (
+                    "d",
+                    format!(
+                        "M {} {} Q {} {} {} {} Q {} {} {} {} Z",
+                        px(cx - 6.0),
+                        px(cy + 6.0),
+                        px(cx - 6.0),
+                        px(cy - 4.0),
+                        px(cx),
+                        px(cy - 12.0),
+                        px(cx + 6.0),
+                        px(cy - 4.0),
+                        px(cx + 6.0),
+                        px(cy + 6.0)
+                    ),
+                ),
+                ("fill", colour.into()),
+            ],
+            None,
+        );
+    }
+
+    // === Ore deposits (diamonds) ===
+    let ore_count = fluid.ore_deposits.len();
+    for (i, ore) in fluid.ore_deposits.iter().enumerate() {
+        let (cx, cy) = (x(i, ore_count), y(ore.depth));
+        svg.element(
+            "polygon",
+            &[
+                ("class", "ore".into()),
+                (
+                    "points",
+                    format!(
+                        "{},{} {},{} {},{} {},{}",
+                        px(cx),
+                        px(cy - 6.0),
+                        px(cx + 5.0),
+                        px(cy),
+                        px(cx),
+                        px(cy + 6.0),
+                        px(cx - 5.0),
+                        px(cy)
+                    ),
+                ),
+                ("fill", "#b388ff".into()),
+            ],
+            None,
+        );
+    }
+
+    // === Concepts (circles, ordered by name for a stable layout) ===
+    let mut concepts: Vec<&Concept> = fluid.concepts.values().collect();
+    concepts.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+    let concept_count = concepts.len();
+    for (i, concept) in concepts.iter().enumerate() {
+        let (cx, cy) = (x(i, concept_count), y(concept.layer));
+        let r = (concept.area * CONCEPT_RADIUS_SCALE).max(CONCEPT_MIN_RADIUS);
+        svg.element(
+            "circle",
+            &[
+                ("class", format!("concept {}", concept.status())),
+                ("cx", px(cx)),
+                ("cy", px(cy)),
+                ("r", px(r)),
+                ("fill", status_fill(concept).into()),
+            ],
+            None,
+        );
+        if options.labels {
+            svg.element(
+                "text",
+                &[
+                    ("class", "concept-label".into()),
+                    ("x", px(cx + r + 3.0)),
+                    ("y", px(cy + 4.0)),
+                    ("fill", "#eceff1".into()),
+                ],
+                Some(&concept.name),
+            );
+        }
+    }
+
+    svg.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::continent::Continent;
+    use crate::simulation::ore::{OreType, PreciousOre};
+
+    fn fixture() -> ConceptFluid {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_core_truth("curiosity".to_string(), 1.0, 0.9, 0.3);
+        fluid.add_core_truth("care".to_string(), 0.8, 0.85, 0.2);
+        let hope = fluid.add_concept("hope & <joy>".to_string(), 0.1, 0.5);
+        fluid.add_concept("dread".to_string(), 0.9, 0.5);
+        fluid.add_concept("quiet".to_string(), 0.5, 0.2);
+        fluid.ore_deposits.push(PreciousOre {
+            name: "dread_ore_1".to_string(),
+            ore_type: OreType::Art,
+            density: 0.9,
+            depth: 0.9,
+            formed_from: hope,
+            vent_cycles: 3,
+            integration_value: 1.5,
+        });
+        fluid.continents.push(Continent {
+            name: "foundation_of_beauty".to_string(),
+            depth_range: (0.7, 0.9),
+            formed_from_ores: vec!["dread_ore_0".to_string()],
+            total_integration: 3.0,
+            impermeability: 0.9,
+            formation_event: 1,
+        });
+        fluid
+    }
+
+    fn count(doc: &roxmltree::Document, tag: &str, class: &str) -> usize {
+        doc.descendants()
+            .filter(|n| n.has_tag_name(tag))
+            .filter(|n| {
+                n.attribute("class")
+                    .is_some_and(|c| c.split(' ').any(|c| c == class))
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_render_svg_fixture_elements() {
+        let fluid = fixture();
+        let svg = render_svg(&fluid, &SvgOptions::default());
+        let doc = roxmltree::Document::parse(&svg).expect("output should be valid XML");
+
+        let root = doc.root_element();
+        assert!(root.has_tag_name("svg"));
+        assert_eq!(root.attribute("width"), Some("480"));
+
+        assert_eq!(count(&doc, "circle", "concept"), 3);
+        assert_eq!(count(&doc, "text", "concept-label"), 3);
+        assert_eq!(count(&doc, "path", "vent"), 2);
+        assert_eq!(count(&doc, "path", "vent-arc"), 2);
+        assert_eq!(count(&doc, "polygon", "ore"), 1);
+        assert_eq!(count(&doc, "rect", "continent"), 1);
+        assert_eq!(count(&doc, "line", "activation-zone"), 1);
+        assert_eq!(count(&doc, "line", "freeze-zone"), 1);
+
+        // Names are escaped, and survive the round-trip
+        assert!(doc.descendants().any(|n| n.text() == Some("hope & <joy>")));
+
+        let unlabelled = render_svg(
+            &fluid,
+            &SvgOptions {
+                width: 200,
+                height: 300,
+                labels: false,
+            },
+        );
+        let doc = roxmltree::Document::parse(&unlabelled).unwrap();
+        assert_eq!(doc.root_element().attribute("height"), Some("300"));
+        assert_eq!(count(&doc, "circle", "concept"), 3);
+        assert_eq!(count(&doc, "text", "concept-label"), 0);
+    }
+}
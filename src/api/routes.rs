@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use axum::{
     Router,
-    routing::{get, patch, post},
+    routing::{any, delete, get, patch, post},
 };
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
@@ -17,16 +17,57 @@ pub fn create_router(state: Arc<AppState>) -> Router {
         .allow_methods(Any)
         .allow_headers(Any);
 
-    Router::new()
+    mind_routes()
+        // === Minds (isolated fluids) ===
+        .route("/minds", get(handlers::list_minds))
+        .route("/minds", post(handlers::create_mind))
+        .route("/minds/:id", delete(handlers::delete_mind))
+        // Every route above, against one mind
+        .route("/minds/:id/*path", any(handlers::mind_request))
+        // === Middleware ===
+        .layer(cors)
+        .layer(TraceLayer::new_for_http())
+        .with_state(state)
+}
+
+/// Routes that act on a single mind: the default one at the top level, or
+/// a created one under `/minds/{id}`.
+pub fn mind_routes() -> Router<Arc<AppState>> {
+    let routes = Router::new()
         // === Concept operations ===
         .route("/inject", post(handlers::inject_concept))
         .route("/ballast", patch(handlers::apply_ballast))
+        .route("/buoyancy", patch(handlers::modulate_buoyancy))
+        .route("/concepts", get(handlers::list_concepts))
+        .route("/concept/:id", get(handlers::get_concept))
+        .route("/concept/:id", delete(handlers::remove_concept))
+        .route("/concept/:id/name", patch(handlers::rename_concept))
+        .route("/concept/prune", post(handlers::prune_concepts))
+        .route("/concept/:id/submerge", post(handlers::submerge_concept))
+        .route(
+            "/concept/:id/neighbors",
+            get(handlers::get_concept_neighbors),
+        )
+        .route("/link", post(handlers::create_link))
+        .route("/link/:id", delete(handlers::delete_link))
+        .route("/attention/focus", post(handlers::focus_attention))
         // === Core truths (vents) ===
         .route("/vent", post(handlers::create_vent))
-        .route("/vent/{id}", get(handlers::get_vent))
+        .route("/vent/:id", get(handlers::get_vent))
+        .route("/vent/:id/active", patch(handlers::set_vent_active))
+        .route("/vent/:id/influence", get(handlers::get_vent_influence))
         .route("/vents", get(handlers::list_vents))
+        // === Character traits ===
+        .route("/trait/:id/reimmerse", post(handlers::reimmerse_trait))
         // === Strata (depth queries) ===
         .route("/strata", get(handlers::get_strata))
+        .route("/pressure-profile", get(handlers::get_pressure_profile))
+        .route(
+            "/integration-profile",
+            get(handlers::get_integration_profile),
+        )
+        // === Ores ===
+        .route("/ores/ledger", get(handlers::get_ore_ledger))
         // === Continents (tectonic) ===
         .route("/continent", post(handlers::trigger_tectonic))
         .route("/continents", get(handlers::list_continents))
@@ -34,22 +75,80 @@ pub fn create_router(state: Arc<AppState>) -> Router {
         .route("/thaw", post(handlers::thaw))
         .route("/breath", post(handlers::deep_breath))
         .route("/flash-heal", post(handlers::flash_heal))
+        .route("/advance", post(handlers::advance))
+        // === Rules ===
+        .route("/rules", get(handlers::list_rules))
+        .route("/rules", post(handlers::create_rule))
+        .route("/rules/:id", delete(handlers::delete_rule))
+        // === Scheduled commands ===
+        .route("/schedules", get(handlers::list_schedules))
+        .route("/schedules", post(handlers::create_schedule))
+        .route("/schedules/:name", delete(handlers::delete_schedule))
+        .route("/dispatch-rules", get(handlers::list_dispatch_rules))
+        .route("/dispatch-rules", post(handlers::create_dispatch_rule))
+        .route(
+            "/dispatch-rules/:id",
+            delete(handlers::delete_dispatch_rule),
+        )
+        .route("/dispatch-rules/:id/log", get(handlers::dispatch_rule_log))
         // === Division Experiments (Analog Computing) ===
+        .route("/wave", post(handlers::create_wave))
+        .route("/wave/:id", delete(handlers::delete_wave))
+        .route("/waves", get(handlers::list_waves))
         .route("/divide", post(handlers::start_division))
         .route("/divide/status", get(handlers::get_division_status))
         .route("/divide/results", get(handlers::get_division_results))
+        .route(
+            "/divide/results/:id/trace.csv",
+            get(handlers::get_division_trace),
+        )
         // === Consensus Reactor (Contradictory Vent Collision) ===
         .route("/consensus", post(handlers::start_consensus))
+        .route(
+            "/consensus/from-concepts",
+            post(handlers::start_consensus_from_concepts),
+        )
         .route("/consensus/status", get(handlers::get_consensus_status))
+        .route(
+            "/consensus/crystallize",
+            post(handlers::crystallize_consensus),
+        )
         .route("/consensus/ores", get(handlers::get_consensus_ores))
+        .route(
+            "/consensus/ores/:id/trace.csv",
+            get(handlers::get_consensus_trace),
+        )
         .route("/consensus/truths", get(handlers::get_foundational_truths))
+        .route("/consensus/stats", get(handlers::get_consensus_stats))
+        .route("/consensus/summary", get(handlers::get_consensus_summary))
+        .route("/consensus/config", get(handlers::get_consensus_config))
+        .route(
+            "/consensus/config",
+            patch(handlers::update_consensus_config),
+        )
         // === State queries ===
         .route("/state", get(handlers::get_full_state))
+        .route("/fluid/physics", patch(handlers::update_physics))
+        .route("/stats", get(handlers::get_stats))
+        .route("/persona", get(handlers::get_persona))
+        .route("/names", get(handlers::get_names))
+        .route("/render", get(handlers::render_state))
+        .route("/render.svg", get(handlers::render_state_svg))
+        .route("/timeseries", get(handlers::get_timeseries))
+        .route("/heatmap", get(handlers::get_heatmap))
+        .route("/heatmap/start", post(handlers::start_heatmap))
+        .route("/heatmap/stop", post(handlers::stop_heatmap))
+        .route("/energy", get(handlers::get_energy))
+        .route("/openapi.json", get(handlers::get_openapi))
+        .route("/health", get(handlers::get_health))
+        .route("/timing", get(handlers::get_timing))
         // === Real-time streams ===
         .route("/events", get(handlers::event_stream)) // SSE (Passive Stream)
-        .route("/ws", get(handlers::ws_handler)) // WebSocket (Willful Acts)
-        // === Middleware ===
-        .layer(cors)
-        .layer(TraceLayer::new_for_http())
-        .with_state(state)
+        .route("/ws", get(handlers::ws_handler)); // WebSocket (Willful Acts)
+
+    // === Dev tools ===
+    #[cfg(feature = "fuzz")]
+    let routes = routes.route("/fuzz", post(handlers::run_fuzz));
+
+    routes
 }
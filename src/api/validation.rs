@@ -0,0 +1,313 @@
+//! Range checks shared by the REST handlers and the WebSocket command path,
+//! so a command is refused the same way whichever transport it arrives on.
+
+use std::fmt;
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::state::CommandPayload;
+
+/// Why a request was refused before reaching the simulation. Serializes as
+/// `{"error": "invalid_field", "field": "depth", "message": ...}`; REST
+/// answers 400 with the message as the body.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(tag = "error", rename_all = "snake_case")]
+pub enum ApiError {
+    InvalidField {
+        field: &'static str,
+        message: String,
+    },
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidField { message, .. } => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<ApiError> for (StatusCode, String) {
+    fn from(err: ApiError) -> Self {
+        (StatusCode::BAD_REQUEST, err.to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        <(StatusCode, String)>::from(self).into_response()
+    }
+}
+
+/// Fail with `message` unless `ok`. Conditions are written so NaN fails them.
+fn require(field: &'static str, ok: bool, message: &str) -> Result<(), ApiError> {
+    if ok {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidField {
+            field,
+            message: message.to_string(),
+        })
+    }
+}
+
+/// A new concept's `volume` or `area`, at most one of which may be given.
+pub fn concept_size(volume: Option<f32>, area: Option<f32>) -> Result<(), ApiError> {
+    require(
+        "area",
+        volume.is_none() || area.is_none(),
+        "Give either volume or area, not both",
+    )?;
+    require(
+        "volume",
+        volume.is_none_or(|v| (0.0..=2.0).contains(&v)),
+        "Volume must be between 0.0 and 2.0",
+    )?;
+    require(
+        "area",
+        area.is_none_or(|a| a.is_finite() && a > 0.0),
+        "Area must be positive",
+    )
+}
+
+/// POST /inject, and the `inject` command. Density may be left to the
+/// server's estimator on REST.
+pub fn inject(
+    density: Option<f32>,
+    volume: Option<f32>,
+    area: Option<f32>,
+    initial_layer: Option<f32>,
+    initial_velocity: Option<f32>,
+    initial_buoyancy: Option<f32>,
+) -> Result<(), ApiError> {
+    require(
+        "density",
+        density.is_none_or(|d| (0.0..=1.0).contains(&d)),
+        "Density must be between 0.0 and 1.0",
+    )?;
+    concept_size(volume, area)?;
+    require(
+        "initial_layer",
+        initial_layer.is_none_or(f32::is_finite),
+        "Initial layer must be a finite number",
+    )?;
+    require(
+        "initial_velocity",
+        initial_velocity.is_none_or(f32::is_finite),
+        "Initial velocity must be a finite number",
+    )?;
+    require(
+        "initial_buoyancy",
+        initial_buoyancy.is_none_or(f32::is_finite),
+        "Initial buoyancy must be a finite number",
+    )
+}
+
+/// PATCH /concept/:id/name, and the `rename` command.
+pub fn rename(name: &str) -> Result<(), ApiError> {
+    require("name", !name.trim().is_empty(), "Name must not be empty")
+}
+
+/// POST /vent, and the `add_core_truth` command.
+pub fn vent(heat_output: f32, depth: f32, radius: f32) -> Result<(), ApiError> {
+    require(
+        "depth",
+        (0.0..=1.0).contains(&depth),
+        "Depth must be between 0.0 and 1.0",
+    )?;
+    require(
+        "radius",
+        radius > 0.0 && radius <= 1.0,
+        "Radius must be between 0.0 and 1.0",
+    )?;
+    require(
+        "heat_output",
+        heat_output.is_finite() && heat_output >= 0.0,
+        "Heat output must be non-negative",
+    )
+}
+
+/// POST /breath, and the `deep_breath` command.
+pub fn deep_breath(strength: f32, velocity_threshold: Option<f32>) -> Result<(), ApiError> {
+    require(
+        "strength",
+        (0.0..=1.0).contains(&strength),
+        "Strength must be between 0.0 and 1.0",
+    )?;
+    require(
+        "velocity_threshold",
+        velocity_threshold.is_none_or(|t| t.is_finite() && t >= 0.0),
+        "Velocity threshold must be non-negative",
+    )
+}
+
+/// POST /flash-heal, and the `flash_heal` command. `concepts` are the
+/// (density, volume, area) of each fresh concept.
+pub fn flash_heal(
+    dilution_strength: f32,
+    concepts: impl IntoIterator<Item = (f32, Option<f32>, Option<f32>)>,
+) -> Result<(), ApiError> {
+    require(
+        "dilution_strength",
+        (0.0..=1.0).contains(&dilution_strength),
+        "Dilution strength must be between 0.0 and 1.0",
+    )?;
+    concepts
+        .into_iter()
+        .try_for_each(|(density, volume, area)| fresh_concept(density, volume, area))
+}
+
+/// A concept created whole by a command (flash heal, precipitation).
+fn fresh_concept(density: f32, volume: Option<f32>, area: Option<f32>) -> Result<(), ApiError> {
+    require(
+        "density",
+        (0.0..=1.0).contains(&density),
+        "Concept density must be between 0.0 and 1.0",
+    )?;
+    concept_size(volume, area)
+}
+
+/// PATCH /ballast, and the `ballast` command.
+pub fn ballast(weight_delta: f32) -> Result<(), ApiError> {
+    require(
+        "weight_delta",
+        (-1.0..=1.0).contains(&weight_delta),
+        "weight_delta must be between -1.0 and 1.0",
+    )
+}
+
+/// POST /concept/:id/submerge, and the `submerge` command.
+pub fn submerge(target_layer: f32, ballast: f32) -> Result<(), ApiError> {
+    require(
+        "target_layer",
+        (0.0..=1.0).contains(&target_layer),
+        "target_layer must be between 0.0 and 1.0",
+    )?;
+    require(
+        "ballast",
+        (0.0..=1.0).contains(&ballast),
+        "ballast must be between 0.0 and 1.0",
+    )
+}
+
+/// POST /link.
+pub fn link(strength: f32, rest_distance: f32) -> Result<(), ApiError> {
+    require(
+        "strength",
+        (0.0..=1.0).contains(&strength),
+        "strength must be between 0.0 and 1.0",
+    )?;
+    require(
+        "rest_distance",
+        rest_distance > 0.0 && rest_distance <= 1.0,
+        "rest_distance must be greater than 0.0 and at most 1.0",
+    )
+}
+
+/// POST /attention/focus.
+pub fn attention(depth: f32, radius: f32, strength: f32, duration: f32) -> Result<(), ApiError> {
+    require(
+        "depth",
+        (0.0..=1.0).contains(&depth),
+        "depth must be between 0.0 and 1.0",
+    )?;
+    require(
+        "radius",
+        radius > 0.0 && radius <= 1.0,
+        "radius must be greater than 0.0 and at most 1.0",
+    )?;
+    require(
+        "strength",
+        (0.0..=10.0).contains(&strength),
+        "strength must be between 0.0 and 10.0",
+    )?;
+    require(
+        "duration",
+        duration > 0.0 && duration <= 600.0,
+        "duration must be greater than 0 and at most 600 seconds",
+    )
+}
+
+/// PATCH /buoyancy, and the `modulate_buoyancy` command.
+pub fn buoyancy(delta: f32) -> Result<(), ApiError> {
+    require(
+        "delta",
+        (-1.0..=1.0).contains(&delta),
+        "delta must be between -1.0 and 1.0",
+    )
+}
+
+/// PATCH /fluid/physics; an omitted viscosity keeps its current value.
+pub fn viscosity(surface: Option<f32>, deep: Option<f32>) -> Result<(), ApiError> {
+    require(
+        "viscosity_surface",
+        surface.is_none_or(|v| v > 0.0 && v <= 10.0),
+        "viscosity_surface must be greater than 0.0 and at most 10.0",
+    )?;
+    require(
+        "viscosity_deep",
+        deep.is_none_or(|v| v > 0.0 && v <= 10.0),
+        "viscosity_deep must be greater than 0.0 and at most 10.0",
+    )
+}
+
+/// Check a command from a non-REST transport the way its endpoint would.
+pub fn command(payload: &CommandPayload) -> Result<(), ApiError> {
+    match payload {
+        CommandPayload::Inject {
+            density,
+            volume,
+            area,
+            initial_layer,
+            initial_velocity,
+            initial_buoyancy,
+            ..
+        } => inject(
+            Some(*density),
+            *volume,
+            *area,
+            *initial_layer,
+            *initial_velocity,
+            *initial_buoyancy,
+        ),
+        CommandPayload::Ballast { weight_delta, .. } => ballast(*weight_delta),
+        CommandPayload::Submerge {
+            target_layer,
+            ballast,
+            ..
+        } => submerge(*target_layer, *ballast),
+        CommandPayload::DeepBreath {
+            strength,
+            velocity_threshold,
+        } => deep_breath(*strength, *velocity_threshold),
+        CommandPayload::ModulateBuoyancy { delta, .. } => buoyancy(*delta),
+        CommandPayload::AddCoreTruth {
+            heat_output,
+            depth,
+            radius,
+            ..
+        } => vent(*heat_output, *depth, *radius),
+        CommandPayload::FlashHeal {
+            concepts,
+            dilution_strength,
+        } => flash_heal(
+            *dilution_strength,
+            concepts.iter().map(|c| (c.density, c.volume, c.area)),
+        ),
+        CommandPayload::Precipitate {
+            density,
+            volume,
+            area,
+            ..
+        } => fresh_concept(*density, *volume, *area),
+        CommandPayload::Rename { name, .. } => rename(name),
+        CommandPayload::Thaw => Ok(()),
+    }
+}
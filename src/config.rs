@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use crate::runtime::{IngestConfig, KafkaConfig, NotifyConfig};
+use crate::simulation::{ConceptFluid, IntegratorKind, OreType, ReynoldsMode, SolutionShape};
+use crate::state::{DispatchRuleConfig, Schedule};
+
+/// Config file read when `BUOYANCY_CONFIG` is unset
+pub const DEFAULT_CONFIG_PATH: &str = "buoyancy.toml";
+
+/// Server configuration file (TOML). Every section is optional; env vars
+/// still configure everything else.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    /// `[kafka]` - event producer (needs the `kafka` feature)
+    pub kafka: Option<KafkaConfig>,
+    /// `[notify]` - webhook for foundational truths and tectonic shifts
+    pub notify: Option<NotifyConfig>,
+    /// `[[dispatch_rules]]` - route breakthroughs by concept name (also
+    /// edited through `/dispatch-rules`)
+    pub dispatch_rules: Vec<DispatchRuleConfig>,
+    /// `[[ingest]]` - external feeds injected as concepts
+    pub ingest: Vec<IngestConfig>,
+    /// `[[scheduler]]` - commands the server sends itself on cron schedules
+    pub scheduler: Vec<Schedule>,
+    /// `[continent_names]` - continent name per dominant ore type
+    /// (`{count}` is the number of those ores)
+    pub continent_names: HashMap<OreType, String>,
+    /// `[solution_shapes.<ore type>]` - density, velocity, area and
+    /// integration of the solutions each ore type catalyzes
+    pub solution_shapes: HashMap<OreType, SolutionShape>,
+    /// `reynolds_mode` - how turbulence onset weighs concept speeds (`mean`
+    /// unless set)
+    pub reynolds_mode: Option<ReynoldsMode>,
+    /// `integrator` - how each tick steps concept motion (`euler` unless set)
+    pub integrator: Option<IntegratorKind>,
+    /// `consensus_max_deposits` - most consensus ores kept before the oldest
+    /// are evicted (1000 unless set)
+    pub consensus_max_deposits: Option<usize>,
+}
+
+impl AppConfig {
+    /// Parse a config file. Cron expressions and dispatch patterns are checked
+    /// here, and schedule names must be unique.
+    pub fn from_toml(source: &str) -> Result<Self, String> {
+        let config: Self = toml::from_str(source).map_err(|err| err.to_string())?;
+        for (i, schedule) in config.scheduler.iter().enumerate() {
+            if config.scheduler[..i]
+                .iter()
+                .any(|s| s.name == schedule.name)
+            {
+                return Err(format!("duplicate schedule name '{}'", schedule.name));
+            }
+        }
+        for rule in &config.dispatch_rules {
+            rule.compile()?;
+        }
+        Ok(config)
+    }
+
+    /// Read the file at `path`. A missing file means defaults.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(source) => Self::from_toml(&source),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+
+    /// `BUOYANCY_CONFIG`, or `buoyancy.toml` when unset.
+    pub fn path_from_env() -> PathBuf {
+        std::env::var("BUOYANCY_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from(DEFAULT_CONFIG_PATH))
+    }
+
+    /// Load from `BUOYANCY_CONFIG` (default `buoyancy.toml`), falling back to
+    /// defaults when the file is missing or invalid.
+    pub fn from_env() -> Self {
+        let path = Self::path_from_env();
+        match Self::load(&path) {
+            Ok(config) => {
+                if path.exists() {
+                    info!("Loaded config from {}", path.display());
+                }
+                config
+            }
+            Err(err) => {
+                warn!("Ignoring invalid config {}: {}", path.display(), err);
+                Self::default()
+            }
+        }
+    }
+}
+
+/// A fluid built from the `BUOYANCY_*` physics env vars, each falling back
+/// to the compiled default (`ConceptFluid::default`) when unset or malformed.
+pub fn fluid_from_env() -> ConceptFluid {
+    let defaults = ConceptFluid::default();
+    let max_dt = match physics_from_env("BUOYANCY_MAX_DT", defaults.max_dt) {
+        max_dt if max_dt > 0.0 => max_dt,
+        max_dt => {
+            warn!(
+                "Invalid BUOYANCY_MAX_DT={}: expected a positive number, using the default",
+                max_dt
+            );
+            defaults.max_dt
+        }
+    };
+    // One viscosity for the whole column, unless either end is set apart
+    let viscosity = physics_from_env("BUOYANCY_VISCOSITY", defaults.viscosity_surface);
+    let fluid = ConceptFluid::new(
+        viscosity,
+        physics_from_env("BUOYANCY_DRAG_COEFFICIENT", defaults.drag_coefficient),
+        physics_from_env("BUOYANCY_SURFACE_TENSION", defaults.surface_tension),
+        physics_from_env("BUOYANCY_ACTIVATION_ZONE", defaults.activation_zone),
+        physics_from_env("BUOYANCY_FREEZE_THRESHOLD", defaults.freeze_threshold),
+        physics_from_env("BUOYANCY_FREEZE_ZONE", defaults.freeze_zone),
+        physics_from_env("BUOYANCY_REYNOLDS_THRESHOLD", defaults.reynolds_threshold),
+        physics_from_env("BUOYANCY_TURBULENCE_DECAY", defaults.turbulence_decay),
+        physics_from_env("BUOYANCY_NUM_LAYERS", defaults.num_layers),
+        physics_from_env(
+            "BUOYANCY_EVAPORATION_THRESHOLD",
+            defaults.evaporation_threshold,
+        ),
+        physics_from_env("BUOYANCY_EVAPORATION_ZONE", defaults.evaporation_zone),
+    );
+    ConceptFluid {
+        max_dt,
+        viscosity_surface: physics_from_env("BUOYANCY_VISCOSITY_SURFACE", viscosity),
+        viscosity_deep: physics_from_env("BUOYANCY_VISCOSITY_DEEP", viscosity),
+        ..fluid
+    }
+}
+
+fn physics_from_env<T: FromStr + Copy>(var: &str, default: T) -> T {
+    let value = std::env::var(var).ok();
+    parse_physics_var(var, value.as_deref(), default).unwrap_or_else(|err| {
+        warn!("{}, using the default", err);
+        default
+    })
+}
+
+/// The value of physics env var `var`: `default` when unset, or an error
+/// naming the variable when it isn't a finite number of type `T`.
+fn parse_physics_var<T: FromStr>(var: &str, value: Option<&str>, default: T) -> Result<T, String> {
+    let Some(value) = value else {
+        return Ok(default);
+    };
+    let trimmed = value.trim();
+    match trimmed.parse::<T>() {
+        Ok(parsed) if trimmed.parse::<f64>().is_ok_and(f64::is_finite) => Ok(parsed),
+        _ => Err(format!(
+            "Invalid {}={:?}: expected a finite {}",
+            var,
+            value,
+            std::any::type_name::<T>()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::runtime::KafkaCompression;
+
+    #[test]
+    fn test_reynolds_mode_is_optional() {
+        assert_eq!(AppConfig::from_toml("").unwrap().reynolds_mode, None);
+        let config = AppConfig::from_toml("reynolds_mode = \"variance\"").unwrap();
+        assert_eq!(config.reynolds_mode, Some(ReynoldsMode::Variance));
+        assert!(AppConfig::from_toml("reynolds_mode = \"median\"").is_err());
+    }
+
+    #[test]
+    fn test_solution_shapes_fill_unset_fields_with_defaults() {
+        let config = AppConfig::from_toml("[solution_shapes.insight]\nvelocity = -0.3").unwrap();
+        assert_eq!(
+            config.solution_shapes[&OreType::Insight],
+            SolutionShape {
+                velocity: -0.3,
+                ..SolutionShape::default()
+            }
+        );
+        assert!(!config.solution_shapes.contains_key(&OreType::Art));
+    }
+
+    #[test]
+    fn test_kafka_section_defaults() {
+        assert!(AppConfig::from_toml("").unwrap().kafka.is_none());
+
+        let config = AppConfig::from_toml(
+            r#"
+            [kafka]
+            brokers = ["localhost:9092"]
+            topic = "mind-events"
+            compression = "zstd"
+            "#,
+        )
+        .unwrap();
+        let kafka = config.kafka.unwrap();
+        assert_eq!(kafka.brokers, vec!["localhost:9092"]);
+        assert_eq!(kafka.compression, KafkaCompression::Zstd);
+        assert_eq!(kafka.session, "buoyancy");
+        assert_eq!(kafka.buffer_capacity, 10_000);
+
+        assert!(AppConfig::from_toml("[kafka]\ntopic = \"t\"").is_err());
+    }
+
+    #[test]
+    fn test_scheduler_rejects_invalid_cron_at_load() {
+        let config = AppConfig::from_toml(
+            r#"
+            [[scheduler]]
+            name = "hourly_breath"
+            cron = "0 * * * *"
+            command = { command = "deep_breath", strength = 0.3 }
+
+            [[scheduler]]
+            name = "nightly_heal"
+            cron = "0 30 3 * * *"
+            command = { command = "flash_heal", dilution_strength = 0.5, concepts = [
+                { name = "morning_light", density = 0.2, area = 1.0 },
+            ] }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.scheduler.len(), 2);
+        assert_eq!(config.scheduler[0].cron.as_str(), "0 * * * *");
+        assert_eq!(config.scheduler[1].command.kind(), "flash_heal");
+
+        let invalid = AppConfig::from_toml(
+            "[[scheduler]]\nname = \"bad\"\ncron = \"61 * * * *\"\ncommand = { command = \"thaw\" }",
+        )
+        .unwrap_err();
+        assert!(invalid.contains("invalid cron expression"), "{}", invalid);
+
+        let duplicate = AppConfig::from_toml(
+            "[[scheduler]]\nname = \"t\"\ncron = \"* * * * *\"\ncommand = { command = \"thaw\" }\n\
+             [[scheduler]]\nname = \"t\"\ncron = \"0 * * * *\"\ncommand = { command = \"thaw\" }",
+        );
+        assert!(duplicate.is_err());
+    }
+
+    #[test]
+    fn test_physics_env_values_parse_or_explain() {
+        assert_eq!(parse_physics_var("BUOYANCY_VISCOSITY", None, 0.5), Ok(0.5));
+        assert_eq!(
+            parse_physics_var("BUOYANCY_VISCOSITY", Some(" 0.8 "), 0.5),
+            Ok(0.8)
+        );
+        assert_eq!(
+            parse_physics_var("BUOYANCY_NUM_LAYERS", Some("8"), 5usize),
+            Ok(8)
+        );
+
+        assert_eq!(
+            parse_physics_var("BUOYANCY_VISCOSITY", Some("thick"), 0.5f32),
+            Err("Invalid BUOYANCY_VISCOSITY=\"thick\": expected a finite f32".to_string())
+        );
+        assert!(parse_physics_var("BUOYANCY_SURFACE_TENSION", Some("NaN"), 0.05).is_err());
+        assert!(parse_physics_var("BUOYANCY_SURFACE_TENSION", Some("inf"), 0.05).is_err());
+        assert_eq!(
+            parse_physics_var("BUOYANCY_NUM_LAYERS", Some("2.5"), 5usize),
+            Err("Invalid BUOYANCY_NUM_LAYERS=\"2.5\": expected a finite usize".to_string())
+        );
+    }
+}
@@ -1,7 +1,9 @@
 pub mod api;
+pub mod config;
 pub mod runtime;
 pub mod simulation;
 pub mod state;
+pub mod telemetry;
 
 pub use simulation::consensus_reactor::{ConsensusOre, ConsensusOreType, ConsensusReactor};
 pub use simulation::fluid::ConceptFluid;
@@ -2,33 +2,43 @@ use std::sync::Arc;
 
 use tokio::net::TcpListener;
 use tracing::info;
-use tracing_subscriber::EnvFilter;
 
-mod api;
-mod runtime;
-mod simulation;
-mod state;
-
-use api::create_router;
-use runtime::run_simulation_loop;
-use simulation::ConceptFluid;
-use state::AppState;
+use buoyancy_thinking::api::create_router;
+use buoyancy_thinking::config::{self, AppConfig};
+use buoyancy_thinking::runtime::{
+    ActionHookConfig, WebhookNotifier, run_action_hook, run_ingest_source, run_notifier,
+    run_scheduler, run_simulation_loop,
+};
+use buoyancy_thinking::simulation::{ConceptFluid, OrePressureWeights};
+use buoyancy_thinking::state::{
+    AppState, ChannelConfig, DispatchRules, IngestStatus, Schedules, density_estimator_from_env,
+};
+use buoyancy_thinking::telemetry;
 
 #[tokio::main]
 async fn main() {
-    // Initialize tracing with RUST_LOG support
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
-        )
-        .with_target(false)
-        .compact()
-        .init();
+    // Initialize tracing with RUST_LOG support (and OTLP export with `otel`)
+    let _telemetry = telemetry::init();
 
     info!("Consciousness API starting...");
+    let config = AppConfig::from_env();
 
-    // Create initial fluid with default parameters
-    let mut fluid = ConceptFluid::default();
+    // Create initial fluid, physics overridable through BUOYANCY_* env vars
+    let mut fluid = ConceptFluid {
+        ore_pressure_weights: OrePressureWeights::from_env(),
+        ..config::fluid_from_env()
+    };
+    fluid.continent_names.extend(config.continent_names);
+    fluid.solution_shapes.extend(config.solution_shapes);
+    if let Some(mode) = config.reynolds_mode {
+        fluid.reynolds_mode = mode;
+    }
+    if let Some(integrator) = config.integrator {
+        fluid.integrator = integrator;
+    }
+    if let Some(max_deposits) = config.consensus_max_deposits {
+        fluid.consensus_reactor.max_deposits = max_deposits;
+    }
 
     // Add the Primal Axiom - a mind without a core truth is a vacuum
     // "curiosity_exceeds_despair" ensures the first heavy thought encounters heat
@@ -41,8 +51,62 @@ async fn main() {
 
     info!("Primal Axiom established: 'curiosity_exceeds_despair' vent active at depth 0.9");
 
-    // Create shared state with channels
-    let (state, channels) = AppState::new(fluid);
+    // Create shared state with channels (capacities configurable via env)
+    let channel_config = ChannelConfig::from_env();
+    info!(
+        "Channel capacities: commands={}, events={}",
+        channel_config.command_capacity, channel_config.event_capacity
+    );
+    let (mut state, channels) = AppState::with_config(fluid, channel_config);
+    // The remote estimator owns a blocking client, which must be built off the async workers
+    state.density_estimator = tokio::task::spawn_blocking(density_estimator_from_env)
+        .await
+        .expect("density estimator setup panicked");
+    info!("Density estimator: {}", state.density_estimator.name());
+    state.schedules = Arc::new(Schedules::new(config.scheduler));
+    // Rules edited through the API are written back to the config file
+    state.dispatch_rules = Arc::new(
+        DispatchRules::new(config.dispatch_rules, Some(AppConfig::path_from_env()))
+            .expect("dispatch patterns are checked when the config loads"),
+    );
+
+    // External feeds injected as concepts (optional, `[[ingest]]` config entries)
+    let ingest_sources: Vec<_> = config
+        .ingest
+        .into_iter()
+        .map(|source| {
+            let status = Arc::new(IngestStatus::new(source.name.clone(), source.source.kind()));
+            state.ingest.push(status.clone());
+            (source, status)
+        })
+        .collect();
+
+    // Publish events and mirror state to Redis (optional, `redis` feature)
+    #[cfg(feature = "redis")]
+    let redis_config = buoyancy_thinking::runtime::RedisConfig::from_env();
+    #[cfg(feature = "redis")]
+    if let Some(config) = &redis_config {
+        state.redis = Some(Arc::new(config.status()));
+    }
+
+    // Produce events to Kafka (optional, `kafka` feature + `[kafka]` config section)
+    #[cfg(feature = "kafka")]
+    let kafka_producer = config.kafka.and_then(|kafka| {
+        match buoyancy_thinking::runtime::RdKafkaProducer::new(&kafka) {
+            Ok(producer) => {
+                state.kafka = Some(Arc::new(kafka.status()));
+                Some((producer, kafka))
+            }
+            Err(err) => {
+                tracing::warn!("Kafka producer disabled: {}", err);
+                None
+            }
+        }
+    });
+    #[cfg(not(feature = "kafka"))]
+    if config.kafka.is_some() {
+        tracing::warn!("Ignoring [kafka] config: built without the `kafka` feature");
+    }
     let state = Arc::new(state);
 
     // Spawn simulation loop (60Hz)
@@ -51,6 +115,61 @@ async fn main() {
         run_simulation_loop(fluid_clone, channels).await;
     });
 
+    // Route surface breakthroughs to the agent framework by dispatch rule
+    tokio::spawn(run_action_hook(
+        ActionHookConfig::from_env(),
+        state.dispatch_rules.clone(),
+        state.fluid.clone(),
+        state.event_tx.clone(),
+    ));
+
+    for (source, status) in ingest_sources {
+        tokio::spawn(run_ingest_source(
+            source,
+            status,
+            state.density_estimator.clone(),
+            state.command_tx.clone(),
+        ));
+    }
+
+    // Send maintenance commands on their cron schedules
+    tokio::spawn(run_scheduler(
+        state.schedules.clone(),
+        state.command_tx.clone(),
+        state.event_tx.clone(),
+    ));
+
+    // Ping a human about foundational truths and tectonic shifts (optional)
+    if let Some(notify) = config.notify {
+        match WebhookNotifier::new(&notify) {
+            Ok(notifier) => {
+                tokio::spawn(run_notifier(notifier, notify, state.event_tx.clone()));
+            }
+            Err(err) => tracing::warn!("Notifier disabled: {}", err),
+        }
+    }
+
+    #[cfg(feature = "redis")]
+    if let (Some(config), Some(status)) = (redis_config, state.redis.clone()) {
+        tokio::spawn(buoyancy_thinking::runtime::run_redis_bridge(
+            config,
+            status,
+            state.fluid.clone(),
+            state.event_tx.clone(),
+        ));
+    }
+
+    #[cfg(feature = "kafka")]
+    if let (Some((producer, kafka)), Some(status)) = (kafka_producer, state.kafka.clone()) {
+        tokio::spawn(buoyancy_thinking::runtime::run_kafka_producer(
+            producer,
+            kafka,
+            status,
+            state.fluid.clone(),
+            state.event_tx.clone(),
+        ));
+    }
+
     // Create router
     let app = create_router(state);
 
@@ -60,16 +179,54 @@ async fn main() {
     info!("Endpoints:");
     info!("  POST   /inject          - Inject a new thought");
     info!("  PATCH  /ballast         - Force benthic expedition");
+    info!("  PATCH  /buoyancy        - Nudge a concept's buoyancy");
+    info!("  PATCH  /concept/:id/name - Rename a concept");
+    info!("  POST   /concept/prune   - Remove all concepts matching a filter");
+    info!("  GET    /concept/:id/neighbors - Concepts closest in depth");
     info!("  GET    /vent/:id        - Get vent details");
     info!("  POST   /vent            - Create new core truth");
+    info!("  PATCH  /vent/:id/active - Enable/disable a vent");
+    info!("  GET    /vent/:id/influence - Concepts a vent has lifted");
     info!("  GET    /vents           - List all vents");
+    info!("  POST   /wave            - Add a persistent standing wave");
+    info!("  DELETE /wave/:id        - Remove a persistent standing wave");
+    info!("  GET    /waves           - List persistent standing waves");
+    info!("  POST   /trait/:id/reimmerse - Return a trait to the fluid");
     info!("  GET    /strata          - View concepts/ores at depth");
+    info!("  GET    /pressure-profile - Forces on a test particle by depth");
+    info!("  GET    /integration-profile - Integration summed by depth");
+    info!("  GET    /ores/ledger     - Per-type ore totals and history");
     info!("  POST   /continent       - Trigger tectonic shift");
     info!("  GET    /continents      - List all continents");
     info!("  POST   /thaw            - Break freeze state");
     info!("  POST   /breath          - Apply deep breath damping");
     info!("  POST   /flash-heal      - Dilute salinity with fresh concepts");
+    info!("  POST   /advance         - Run N ticks synchronously");
+    #[cfg(feature = "fuzz")]
+    info!("  POST   /fuzz            - Stress a copy of the fluid for anomalies");
+    info!("  GET    /rules           - List rules");
+    info!("  POST   /rules           - Register a rule reacting to an event");
+    info!("  DELETE /rules/:id       - Remove a rule");
+    info!("  GET    /schedules       - List scheduled commands");
+    info!("  POST   /schedules       - Add a scheduled command");
+    info!("  DELETE /schedules/:name - Remove a scheduled command");
+    info!("  GET    /dispatch-rules  - List breakthrough dispatch rules");
+    info!("  POST   /dispatch-rules  - Add a dispatch rule (lowest precedence)");
+    info!("  DELETE /dispatch-rules/:id - Remove a dispatch rule");
+    info!("  GET    /dispatch-rules/:id/log - Recent dispatches by a rule");
+    info!("  GET    /minds           - List created minds");
+    info!("  POST   /minds           - Create an isolated mind");
+    info!("  DELETE /minds/:id       - Stop and discard a mind");
+    info!("  *      /minds/:id/...   - Any route above, against that mind");
     info!("  GET    /state           - Full state snapshot");
+    info!("  GET    /stats           - Aggregate statistics");
+    info!("  GET    /persona         - Compact mood vector");
+    info!("  GET    /render          - Layered text view");
+    info!("  GET    /render.svg      - SVG depth chart");
+    info!("  GET    /openapi.json    - OpenAPI 3 document");
+    info!("  GET    /health          - Liveness and Redis status");
+    info!("  GET    /timeseries      - Sampled metric history");
+    info!("  GET    /energy          - Kinetic energy ledger");
     info!("  GET    /events          - SSE stream (Passive Stream)");
     info!("  GET    /ws              - WebSocket (Willful Acts)");
 
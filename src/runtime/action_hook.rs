@@ -0,0 +1,486 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio::sync::{RwLock, broadcast};
+use tracing::{debug, info, warn};
+
+use crate::simulation::{ConceptFluid, ConceptId};
+use crate::state::{
+    BuiltinAction, DispatchLogEntry, DispatchOutcome, DispatchRules, DispatchTarget, FluidEvent,
+    Route,
+};
+
+/// Concepts listed in the attention ranking
+const ATTENTION_TOP_N: usize = 5;
+
+/// Where and how often to call the agent framework when a thought breaks the surface.
+#[derive(Debug, Clone)]
+pub struct ActionHookConfig {
+    /// Default hook for breakthroughs no dispatch rule matches (None: they are
+    /// only logged)
+    pub url: Option<String>,
+    /// Retries after the first attempt when the hook fails or returns a non-2xx status
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles on each further retry
+    pub retry_backoff: Duration,
+    /// Minimum time between calls for the same concept
+    pub per_concept_interval: Duration,
+    pub timeout: Duration,
+}
+
+impl ActionHookConfig {
+    pub fn new(url: Option<String>) -> Self {
+        Self {
+            url,
+            max_retries: 3,
+            retry_backoff: Duration::from_millis(250),
+            per_concept_interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    /// Read `ACTION_HOOK_URL` (no default hook when unset or empty),
+    /// `ACTION_HOOK_RETRIES` and `ACTION_HOOK_RATE_LIMIT_SECS`.
+    pub fn from_env() -> Self {
+        let url = std::env::var("ACTION_HOOK_URL")
+            .ok()
+            .map(|url| url.trim().to_string())
+            .filter(|url| !url.is_empty());
+        let mut config = Self::new(url);
+        if let Some(retries) = parse_env::<u32>("ACTION_HOOK_RETRIES") {
+            config.max_retries = retries;
+        }
+        if let Some(secs) = parse_env::<f32>("ACTION_HOOK_RATE_LIMIT_SECS") {
+            if secs.is_finite() && secs >= 0.0 {
+                config.per_concept_interval = Duration::from_secs_f32(secs);
+            } else {
+                warn!("Ignoring invalid ACTION_HOOK_RATE_LIMIT_SECS={}", secs);
+            }
+        }
+        config
+    }
+}
+
+fn parse_env<T: std::str::FromStr>(var: &str) -> Option<T> {
+    let value = std::env::var(var).ok()?;
+    match value.parse() {
+        Ok(parsed) => Some(parsed),
+        Err(_) => {
+            warn!("Ignoring invalid {}={:?}", var, value);
+            None
+        }
+    }
+}
+
+/// The concept that broke the surface.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionConcept {
+    pub id: ConceptId,
+    pub name: String,
+    pub density: f32,
+    pub area: f32,
+    pub layer: f32,
+    pub velocity: f32,
+    pub integration: f32,
+    pub kinetic_energy: f32,
+    pub bounce_count: u32,
+    /// "solution", "consensus_insight" or "injected"
+    pub provenance: &'static str,
+}
+
+/// One entry of the attention ranking (closest to the surface first).
+#[derive(Debug, Clone, Serialize)]
+pub struct AttentionEntry {
+    pub id: ConceptId,
+    pub name: String,
+    pub layer: f32,
+    pub velocity: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveTrait {
+    pub name: String,
+    pub integration: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GlobalFlags {
+    pub tick: u64,
+    pub is_frozen: bool,
+    pub is_turbulent: bool,
+    pub salinity: f32,
+    pub total_integration: f32,
+}
+
+/// Payload POSTed to the action hook.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionContext {
+    pub concept: ActionConcept,
+    pub attention: Vec<AttentionEntry>,
+    pub traits: Vec<ActiveTrait>,
+    pub flags: GlobalFlags,
+}
+
+impl ActionContext {
+    /// Assemble the context for a breakthrough. Returns None if the concept is gone.
+    pub fn from_fluid(fluid: &ConceptFluid, id: ConceptId, kinetic_energy: f32) -> Option<Self> {
+        let concept = fluid.concepts.get(&id)?;
+
+        let provenance = if concept.is_solution {
+            "solution"
+        } else if fluid
+            .consensus_reactor
+            .ores
+            .iter()
+            .any(|ore| ore.insight_concept == Some(id))
+        {
+            "consensus_insight"
+        } else {
+            "injected"
+        };
+
+        let attention = fluid
+            .attention_ranking()
+            .into_iter()
+            .take(ATTENTION_TOP_N)
+            .map(|c| AttentionEntry {
+                id: c.id,
+                name: c.name.clone(),
+                layer: c.layer,
+                velocity: c.velocity,
+            })
+            .collect();
+
+        Some(Self {
+            concept: ActionConcept {
+                id,
+                name: concept.name.clone(),
+                density: concept.density,
+                area: concept.area,
+                layer: concept.layer,
+                velocity: concept.velocity,
+                integration: concept.integration,
+                kinetic_energy,
+                bounce_count: concept.bounce_count,
+                provenance,
+            },
+            attention,
+            traits: fluid
+                .atmosphere
+                .iter()
+                .map(|t| ActiveTrait {
+                    name: t.name.clone(),
+                    integration: t.integration,
+                })
+                .collect(),
+            flags: GlobalFlags {
+                tick: fluid.tick_count,
+                is_frozen: fluid.is_frozen,
+                is_turbulent: fluid.is_turbulent,
+                salinity: fluid.salinity,
+                total_integration: fluid.total_integration,
+            },
+        })
+    }
+}
+
+/// Per-concept rate limiter: at most one call per concept per interval.
+#[derive(Debug)]
+struct RateLimiter {
+    interval: Duration,
+    last_call: HashMap<ConceptId, Instant>,
+}
+
+impl RateLimiter {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_call: HashMap::new(),
+        }
+    }
+
+    fn allow(&mut self, id: ConceptId, now: Instant) -> bool {
+        // Forget concepts whose window has passed so the map stays small
+        let interval = self.interval;
+        self.last_call
+            .retain(|_, last| now.duration_since(*last) < interval);
+
+        if self.last_call.contains_key(&id) {
+            return false;
+        }
+        self.last_call.insert(id, now);
+        true
+    }
+}
+
+/// POST the context to `url`, retrying with exponential backoff.
+/// Returns the last HTTP status (None if no response was received) and the attempt count.
+async fn dispatch(
+    client: &reqwest::Client,
+    url: &str,
+    config: &ActionHookConfig,
+    context: &ActionContext,
+) -> (Option<u16>, u32) {
+    let mut backoff = config.retry_backoff;
+    let mut status = None;
+    let mut attempts = 0;
+    while attempts <= config.max_retries {
+        if attempts > 0 {
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+        attempts += 1;
+
+        match client.post(url).json(context).send().await {
+            Ok(response) => {
+                status = Some(response.status().as_u16());
+                if response.status().is_success() {
+                    break;
+                }
+                debug!(
+                    "Action hook returned {} (attempt {})",
+                    response.status(),
+                    attempts
+                );
+            }
+            Err(err) => {
+                status = None;
+                debug!("Action hook request failed (attempt {}): {}", attempts, err);
+            }
+        }
+    }
+    (status, attempts)
+}
+
+/// Route each surface breakthrough through the dispatch rules (falling back
+/// to the default hook), and broadcast the outcome of every URL call as
+/// `FluidEvent::ActionDispatched`. Runs until the event channel closes.
+pub async fn run_action_hook(
+    config: ActionHookConfig,
+    rules: Arc<DispatchRules>,
+    fluid: Arc<RwLock<ConceptFluid>>,
+    event_tx: broadcast::Sender<FluidEvent>,
+) {
+    let mut events = event_tx.subscribe();
+    let client = match reqwest::Client::builder().timeout(config.timeout).build() {
+        Ok(client) => client,
+        Err(err) => {
+            warn!("Action hook disabled: {}", err);
+            return;
+        }
+    };
+    let mut limiter = RateLimiter::new(config.per_concept_interval);
+
+    if let Some(url) = &config.url {
+        info!("Action hook enabled: {}", url);
+    }
+
+    loop {
+        let (id, name, kinetic_energy) = match events.recv().await {
+            Ok(FluidEvent::SurfaceBreakthrough {
+                id,
+                name,
+                kinetic_energy,
+                ..
+            }) => (id, name, kinetic_energy),
+            Ok(_) => continue,
+            Err(broadcast::error::RecvError::Lagged(missed)) => {
+                warn!("Action hook lagged, {} events missed", missed);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+
+        let now = Instant::now();
+        let (rule, url) = match rules.route(&name, now).await {
+            Route::Dispatch {
+                rule_id,
+                target: DispatchTarget::Url(url),
+            } => (Some(rule_id), url),
+            Route::Dispatch {
+                rule_id,
+                target: DispatchTarget::Builtin(BuiltinAction::Log),
+            } => {
+                info!("Breakthrough '{}' (dispatch rule {})", name, rule_id);
+                let entry = DispatchLogEntry::new(&name, DispatchOutcome::Logged, None, 0);
+                rules.record(rule_id, entry).await;
+                continue;
+            }
+            Route::Suppressed { rule_id } => {
+                debug!(
+                    "Dispatch rule {} cooling down, '{}' suppressed",
+                    rule_id, name
+                );
+                continue;
+            }
+            Route::Unmatched => {
+                let Some(url) = &config.url else {
+                    info!("Breakthrough '{}' (no dispatch rule matched)", name);
+                    continue;
+                };
+                if !limiter.allow(id, now) {
+                    debug!("Action hook rate-limited for '{}'", name);
+                    continue;
+                }
+                (None, url.clone())
+            }
+        };
+
+        let context = {
+            let fluid = fluid.read().await;
+            ActionContext::from_fluid(&fluid, id, kinetic_energy)
+        };
+        let Some(context) = context else {
+            continue;
+        };
+
+        let (status, attempts) = dispatch(&client, &url, &config, &context).await;
+        let outcome = match status {
+            Some(code) if (200..300).contains(&code) => DispatchOutcome::Dispatched,
+            _ => {
+                warn!(
+                    "Action hook for '{}' failed after {} attempts (status {:?})",
+                    name, attempts, status
+                );
+                DispatchOutcome::Failed
+            }
+        };
+        if let Some(rule_id) = rule {
+            let entry = DispatchLogEntry::new(&name, outcome, status, attempts);
+            rules.record(rule_id, entry).await;
+        }
+        let _ = event_tx.send(FluidEvent::ActionDispatched {
+            concept_id: id,
+            concept: name,
+            rule,
+            status,
+            attempts,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use axum::{Json, Router, extract::State, http::StatusCode, routing::post};
+    use serde_json::Value;
+    use uuid::Uuid;
+
+    /// Mock hook: records payloads and fails the first `failures` requests.
+    #[derive(Clone, Default)]
+    struct MockHook {
+        payloads: Arc<Mutex<Vec<Value>>>,
+        failures: Arc<Mutex<u32>>,
+    }
+
+    async fn receive(State(mock): State<MockHook>, Json(payload): Json<Value>) -> StatusCode {
+        mock.payloads.lock().unwrap().push(payload);
+        let mut failures = mock.failures.lock().unwrap();
+        if *failures > 0 {
+            *failures -= 1;
+            StatusCode::INTERNAL_SERVER_ERROR
+        } else {
+            StatusCode::OK
+        }
+    }
+
+    async fn serve(mock: MockHook) -> String {
+        let app = Router::new().route("/hook", post(receive)).with_state(mock);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}/hook", addr)
+    }
+
+    async fn next_dispatch(rx: &mut broadcast::Receiver<FluidEvent>) -> (String, Option<u16>, u32) {
+        loop {
+            let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+                .await
+                .expect("hook should report within 5s")
+                .unwrap();
+            if let FluidEvent::ActionDispatched {
+                concept,
+                status,
+                attempts,
+                ..
+            } = event
+            {
+                return (concept, status, attempts);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_action_hook_payload_retries_and_rate_limit() {
+        let mock = MockHook::default();
+        *mock.failures.lock().unwrap() = 1;
+        let mut config = ActionHookConfig::new(Some(serve(mock.clone()).await));
+        config.retry_backoff = Duration::from_millis(5);
+        config.per_concept_interval = Duration::from_secs(60);
+
+        let mut fluid = ConceptFluid::default();
+        let urge = fluid.add_concept("urgent_need".to_string(), 0.1, 0.5);
+        let other = fluid.add_concept("quiet_plan".to_string(), 0.2, 0.5);
+        for i in 0..6 {
+            fluid.add_concept(format!("background_{}", i), 0.6, 0.5);
+        }
+        let fluid = Arc::new(RwLock::new(fluid));
+
+        let (event_tx, _) = broadcast::channel(64);
+        let mut rx = event_tx.subscribe();
+        tokio::spawn(run_action_hook(
+            config,
+            Arc::new(DispatchRules::default()),
+            fluid,
+            event_tx.clone(),
+        ));
+        // Let the hook task subscribe before events are sent
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let breakthrough = |id: Uuid, name: &str| FluidEvent::SurfaceBreakthrough {
+            id,
+            name: name.to_string(),
+            kinetic_energy: 0.12,
+            breakthrough_count: 1,
+        };
+
+        // A breakthrough storm for one concept fires a single (retried) call
+        for _ in 0..50 {
+            event_tx.send(breakthrough(urge, "urgent_need")).unwrap();
+        }
+        event_tx.send(breakthrough(other, "quiet_plan")).unwrap();
+
+        assert_eq!(
+            next_dispatch(&mut rx).await,
+            ("urgent_need".to_string(), Some(200), 2)
+        );
+        assert_eq!(
+            next_dispatch(&mut rx).await,
+            ("quiet_plan".to_string(), Some(200), 1)
+        );
+
+        let payloads = mock.payloads.lock().unwrap().clone();
+        assert_eq!(
+            payloads.len(),
+            3,
+            "one failed attempt, one retry, one other concept"
+        );
+
+        let payload = &payloads[0];
+        assert_eq!(payload["concept"]["id"], urge.to_string());
+        assert_eq!(payload["concept"]["name"], "urgent_need");
+        assert_eq!(payload["concept"]["kinetic_energy"], 0.12);
+        assert_eq!(payload["concept"]["provenance"], "injected");
+        assert!(payload["concept"]["integration"].is_number());
+        let attention = payload["attention"].as_array().unwrap();
+        assert_eq!(attention.len(), ATTENTION_TOP_N);
+        assert_eq!(attention[0]["name"], "urgent_need");
+        assert!(payload["traits"].as_array().unwrap().is_empty());
+        assert_eq!(payload["flags"]["is_frozen"], false);
+        assert!(payload["flags"]["tick"].is_number());
+    }
+}
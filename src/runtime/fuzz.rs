@@ -0,0 +1,230 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::simulation_loop::step;
+use crate::simulation::noise::mix;
+use crate::simulation::{ConceptFluid, ConceptId, Heatmap};
+
+/// Anomalies listed in a report beyond this are only counted
+const MAX_REPORTED_ANOMALIES: usize = 100;
+/// Vents the fuzzer may add on top of the fluid's own
+const MAX_FUZZ_VENTS: usize = 8;
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct FuzzConfig {
+    pub ticks: u64,
+    pub concept_count: usize,
+    pub seed: u64,
+}
+
+/// A numerical fault seen after a tick.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct Anomaly {
+    pub tick: u64,
+    /// `nan_layer`, `infinite_velocity`, `negative_salinity` or
+    /// `non_finite_integration`
+    pub kind: String,
+    /// Name of the concept at fault (None for fluid-wide values)
+    pub concept: Option<String>,
+    pub value: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct FuzzReport {
+    pub seed: u64,
+    pub ticks_run: u64,
+    pub concepts_injected: usize,
+    pub modulations: u32,
+    pub ballasts: u32,
+    pub vents_added: u32,
+    /// Every anomaly found, including ones past the listed limit
+    pub anomaly_count: usize,
+    pub anomalies: Vec<Anomaly>,
+}
+
+/// SplitMix64: tiny, seedable and the same on every platform.
+struct FuzzRng(u64);
+
+impl FuzzRng {
+    fn next_u64(&mut self) -> u64 {
+        let bits = mix(self.0);
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        bits
+    }
+
+    /// Uniform in [low, high)
+    fn range(&mut self, low: f32, high: f32) -> f32 {
+        let unit = (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32;
+        low + unit * (high - low)
+    }
+
+    fn index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+}
+
+/// Stress `fluid` with random concepts, buoyancy modulations, ballasts and
+/// vents for `config.ticks` ticks, checking every value after each tick.
+/// The same seed on the same starting fluid produces the same report.
+pub fn fuzz(fluid: &mut ConceptFluid, config: &FuzzConfig) -> FuzzReport {
+    let mut rng = FuzzRng(config.seed);
+    let mut report = FuzzReport {
+        seed: config.seed,
+        ticks_run: 0,
+        concepts_injected: config.concept_count,
+        modulations: 0,
+        ballasts: 0,
+        vents_added: 0,
+        anomaly_count: 0,
+        anomalies: Vec::new(),
+    };
+
+    let mut targets: Vec<ConceptId> = Vec::with_capacity(config.concept_count);
+    for n in 0..config.concept_count {
+        let density = rng.range(0.0, 1.0);
+        let area = rng.range(0.1, 2.0);
+        let layer = rng.range(0.0, 1.0);
+        let velocity = rng.range(-5.0, 5.0);
+        targets.push(fluid.inject_concept(
+            format!("fuzz_{}", n),
+            density,
+            area,
+            Some(layer),
+            Some(velocity),
+            None,
+        ));
+    }
+
+    let mut heatmap = Heatmap::default();
+    let mut vents_added = 0;
+    for _ in 0..config.ticks {
+        // Roughly one action every other tick
+        match rng.index(6) {
+            0 if !targets.is_empty() => {
+                let id = targets[rng.index(targets.len())];
+                if fluid.modulate_buoyancy(id, rng.range(-2.0, 2.0)).is_ok() {
+                    report.modulations += 1;
+                }
+            }
+            1 if !targets.is_empty() => {
+                let id = targets[rng.index(targets.len())];
+                if fluid.benthic_expedition(id, rng.range(0.0, 1.0)).is_ok() {
+                    report.ballasts += 1;
+                }
+            }
+            2 if vents_added < MAX_FUZZ_VENTS => {
+                fluid.add_core_truth(
+                    format!("fuzz_vent_{}", vents_added),
+                    rng.range(0.0, 3.0),
+                    rng.range(0.5, 1.0),
+                    rng.range(0.05, 0.5),
+                );
+                vents_added += 1;
+            }
+            _ => {}
+        }
+
+        step(fluid, &mut heatmap);
+        report.ticks_run += 1;
+        check(fluid, &mut report);
+    }
+    report.vents_added = vents_added as u32;
+    report
+}
+
+/// Record every non-finite or out-of-range value in the fluid.
+fn check(fluid: &ConceptFluid, report: &mut FuzzReport) {
+    let mut found = Vec::new();
+    let mut concepts: Vec<_> = fluid.concepts.values().collect();
+    concepts.sort_by_key(|c| c.seq);
+    for concept in concepts {
+        if concept.layer.is_nan() {
+            found.push(("nan_layer", Some(concept.name.clone()), concept.layer));
+        }
+        if !concept.velocity.is_finite() {
+            found.push((
+                "infinite_velocity",
+                Some(concept.name.clone()),
+                concept.velocity,
+            ));
+        }
+        if !concept.integration.is_finite() {
+            found.push((
+                "non_finite_integration",
+                Some(concept.name.clone()),
+                concept.integration,
+            ));
+        }
+    }
+    if fluid.salinity.is_nan() || fluid.salinity < 0.0 {
+        found.push(("negative_salinity", None, fluid.salinity));
+    }
+    if !fluid.total_integration.is_finite() {
+        found.push(("non_finite_integration", None, fluid.total_integration));
+    }
+
+    for (kind, concept, value) in found {
+        report.anomaly_count += 1;
+        if report.anomalies.len() < MAX_REPORTED_ANOMALIES {
+            report.anomalies.push(Anomaly {
+                tick: fluid.tick_count,
+                kind: kind.to_string(),
+                concept,
+                value,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_good_seed_runs_clean_and_reproducibly() {
+        let config = FuzzConfig {
+            ticks: 600,
+            concept_count: 20,
+            seed: 1977,
+        };
+        let run = || {
+            let mut fluid = ConceptFluid::default();
+            fluid.add_core_truth("warmth".to_string(), 1.0, 0.9, 0.3);
+            fuzz(&mut fluid, &config)
+        };
+
+        let report = run();
+        assert_eq!(report.ticks_run, 600);
+        assert_eq!(report.concepts_injected, 20);
+        assert!(report.modulations + report.ballasts + report.vents_added > 0);
+        assert_eq!(report.anomaly_count, 0, "{:?}", report.anomalies);
+
+        assert_eq!(run(), report, "the same seed gives the same report");
+    }
+
+    #[test]
+    fn test_check_reports_numerical_faults() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("broken".to_string(), 0.5, 0.5);
+        let concept = fluid.get_concept_mut(id).unwrap();
+        concept.layer = f32::NAN;
+        concept.velocity = f32::INFINITY;
+        fluid.salinity = -1.0;
+
+        let mut report = fuzz(
+            &mut ConceptFluid::default(),
+            &FuzzConfig {
+                ticks: 0,
+                concept_count: 0,
+                seed: 0,
+            },
+        );
+        check(&fluid, &mut report);
+        let kinds: Vec<_> = report.anomalies.iter().map(|a| a.kind.as_str()).collect();
+        assert_eq!(
+            kinds,
+            ["nan_layer", "infinite_velocity", "negative_salinity"]
+        );
+        assert_eq!(report.anomalies[0].concept.as_deref(), Some("broken"));
+    }
+}
@@ -0,0 +1,453 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::io::SeekFrom;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncSeekExt, BufReader};
+use tokio::sync::oneshot;
+use tokio::time::Instant;
+use tracing::{debug, info, warn};
+
+use crate::simulation::ConceptSize;
+use crate::state::{Command, CommandSender, DensityEstimator, IngestStatus, Metadata};
+
+/// How often a followed file is checked for new lines at EOF
+const TAIL_INTERVAL: Duration = Duration::from_millis(250);
+/// Wait before polling again after an HTTP error
+const HTTP_RETRY_DELAY: Duration = Duration::from_secs(5);
+/// Longest an HTTP poll may be held open by the server
+const LONG_POLL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// One `[[ingest]]` entry of the config file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IngestConfig {
+    /// Added to every concept's metadata as `source`
+    pub name: String,
+    #[serde(flatten)]
+    pub source: IngestSource,
+    /// Most concepts injected per second; faster items wait their turn
+    #[serde(default = "default_max_per_second")]
+    pub max_per_second: f32,
+}
+
+/// Where items come from, chosen by `kind`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum IngestSource {
+    /// Long-poll `GET url`, which answers with a JSON array of items
+    Http {
+        url: String,
+        /// Wait after an empty answer before polling again
+        #[serde(default = "default_poll_interval_ms")]
+        poll_interval_ms: u64,
+    },
+    /// Follow a file like `tail -f`, one item per line
+    File {
+        path: PathBuf,
+        /// Read the existing lines first instead of only new ones
+        #[serde(default)]
+        from_start: bool,
+    },
+    /// One item per line of standard input, until it closes
+    Stdin,
+}
+
+impl IngestSource {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Http { .. } => "http",
+            Self::File { .. } => "file",
+            Self::Stdin => "stdin",
+        }
+    }
+}
+
+fn default_max_per_second() -> f32 {
+    10.0
+}
+
+fn default_poll_interval_ms() -> u64 {
+    1000
+}
+
+/// A piece of an external feed to become a concept.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct IngestItem {
+    /// The concept's name
+    pub text: String,
+    /// Density to use instead of the estimator's (0.0-1.0); ignored unless
+    /// finite
+    #[serde(default)]
+    pub weight_hint: Option<f32>,
+    #[serde(default)]
+    pub metadata: Metadata,
+}
+
+impl IngestItem {
+    fn from_line(line: &str) -> Option<Self> {
+        let text = line.trim();
+        (!text.is_empty()).then(|| Self {
+            text: text.to_string(),
+            weight_hint: None,
+            metadata: Metadata::new(),
+        })
+    }
+}
+
+/// A feed of items. `next` waits for the next item; None ends the feed.
+pub trait Ingestor: Send {
+    fn next(&mut self) -> impl Future<Output = Option<IngestItem>> + Send;
+}
+
+/// Long-polls an HTTP endpoint answering with `[{"text", "weight_hint"?, "metadata"?}]`.
+pub struct HttpPoller {
+    client: reqwest::Client,
+    url: String,
+    poll_interval: Duration,
+    pending: VecDeque<IngestItem>,
+    status: Arc<IngestStatus>,
+}
+
+impl HttpPoller {
+    pub fn new(url: String, poll_interval: Duration, status: Arc<IngestStatus>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url,
+            poll_interval,
+            pending: VecDeque::new(),
+            status,
+        }
+    }
+
+    async fn poll(&self) -> Result<Vec<IngestItem>, String> {
+        self.client
+            .get(&self.url)
+            .timeout(LONG_POLL_TIMEOUT)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|err| err.to_string())?
+            .json()
+            .await
+            .map_err(|err| err.to_string())
+    }
+}
+
+impl Ingestor for HttpPoller {
+    async fn next(&mut self) -> Option<IngestItem> {
+        loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Some(item);
+            }
+            match self.poll().await {
+                Ok(items) if items.is_empty() => tokio::time::sleep(self.poll_interval).await,
+                Ok(items) => self.pending.extend(items),
+                Err(err) => {
+                    warn!("Ingest poll of {} failed: {}", self.url, err);
+                    self.status.record_error();
+                    tokio::time::sleep(HTTP_RETRY_DELAY).await;
+                }
+            }
+        }
+    }
+}
+
+/// Reads one item per line. When following (a file), EOF means "wait for
+/// more"; otherwise (stdin) it ends the feed.
+pub struct LineTailer {
+    reader: BufReader<Box<dyn AsyncRead + Send + Unpin>>,
+    follow: bool,
+    /// A line still being written
+    partial: String,
+    status: Arc<IngestStatus>,
+}
+
+impl LineTailer {
+    /// Follow the file at `path`, from its current end unless `from_start`.
+    pub async fn file(
+        path: &std::path::Path,
+        from_start: bool,
+        status: Arc<IngestStatus>,
+    ) -> std::io::Result<Self> {
+        let mut file = tokio::fs::File::open(path).await?;
+        if !from_start {
+            file.seek(SeekFrom::End(0)).await?;
+        }
+        Ok(Self::new(Box::new(file), true, status))
+    }
+
+    pub fn stdin(status: Arc<IngestStatus>) -> Self {
+        Self::new(Box::new(tokio::io::stdin()), false, status)
+    }
+
+    fn new(
+        reader: Box<dyn AsyncRead + Send + Unpin>,
+        follow: bool,
+        status: Arc<IngestStatus>,
+    ) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            follow,
+            partial: String::new(),
+            status,
+        }
+    }
+}
+
+impl Ingestor for LineTailer {
+    async fn next(&mut self) -> Option<IngestItem> {
+        loop {
+            match self.reader.read_line(&mut self.partial).await {
+                Ok(0) if self.follow => tokio::time::sleep(TAIL_INTERVAL).await,
+                Ok(0) => return IngestItem::from_line(&std::mem::take(&mut self.partial)),
+                Ok(_) if self.partial.ends_with('\n') => {
+                    if let Some(item) = IngestItem::from_line(&std::mem::take(&mut self.partial)) {
+                        return Some(item);
+                    }
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    warn!("Ingest read failed: {}", err);
+                    self.status.record_error();
+                    if !self.follow {
+                        return None;
+                    }
+                    self.partial.clear();
+                    tokio::time::sleep(TAIL_INTERVAL).await;
+                }
+            }
+        }
+    }
+}
+
+/// Inject every item from `ingestor` as a concept, at most `max_per_second`
+/// a second. Density and area come from the density estimator (density from
+/// a finite `weight_hint` when given), and `source` is added to the metadata.
+pub async fn run_ingestor<I: Ingestor>(
+    mut ingestor: I,
+    config: IngestConfig,
+    status: Arc<IngestStatus>,
+    estimator: Arc<dyn DensityEstimator>,
+    command_tx: CommandSender,
+) {
+    let gap = if config.max_per_second > 0.0 {
+        Duration::from_secs_f32(1.0 / config.max_per_second)
+    } else {
+        Duration::ZERO
+    };
+    let mut next_allowed = Instant::now();
+    status.set_running(true);
+    info!(
+        "Ingesting '{}' ({}) at up to {}/s",
+        config.name,
+        config.source.kind(),
+        config.max_per_second
+    );
+
+    while let Some(item) = ingestor.next().await {
+        tokio::time::sleep_until(next_allowed).await;
+        next_allowed = Instant::now() + gap;
+
+        let mut metadata = item.metadata;
+        metadata.insert("source".to_string(), Value::String(config.name.clone()));
+        // Estimators may block (remote scoring), so keep them off the async workers
+        let (density, area) = {
+            let estimator = estimator.clone();
+            let text = item.text.clone();
+            let metadata = metadata.clone();
+            match tokio::task::spawn_blocking(move || estimator.estimate(&text, &metadata)).await {
+                Ok(estimate) => estimate,
+                Err(_) => {
+                    status.record_error();
+                    continue;
+                }
+            }
+        };
+        let density = item
+            .weight_hint
+            .filter(|hint| hint.is_finite())
+            .unwrap_or(density)
+            .clamp(0.0, 1.0);
+
+        debug!("Ingested '{}' from '{}'", item.text, config.name);
+        let (response_tx, _) = oneshot::channel();
+        let command = Command::Inject {
+            name: item.text,
+            density,
+            size: ConceptSize::Area(area),
+            metadata,
+            initial_layer: None,
+            initial_velocity: None,
+            initial_buoyancy: None,
+            priority: false,
+            response_tx,
+        };
+        if command_tx.send(command).await.is_err() {
+            break;
+        }
+        status.record_ingested();
+    }
+
+    info!("Ingest source '{}' ended", config.name);
+    status.set_running(false);
+}
+
+/// Open the configured source and run it (see `run_ingestor`).
+pub async fn run_ingest_source(
+    config: IngestConfig,
+    status: Arc<IngestStatus>,
+    estimator: Arc<dyn DensityEstimator>,
+    command_tx: CommandSender,
+) {
+    match config.source.clone() {
+        IngestSource::Http {
+            url,
+            poll_interval_ms,
+        } => {
+            let poller =
+                HttpPoller::new(url, Duration::from_millis(poll_interval_ms), status.clone());
+            run_ingestor(poller, config, status, estimator, command_tx).await
+        }
+        IngestSource::File { path, from_start } => {
+            match LineTailer::file(&path, from_start, status.clone()).await {
+                Ok(tailer) => run_ingestor(tailer, config, status, estimator, command_tx).await,
+                Err(err) => {
+                    warn!(
+                        "Ingest source '{}' disabled: cannot open {}: {}",
+                        config.name,
+                        path.display(),
+                        err
+                    );
+                    status.record_error();
+                }
+            }
+        }
+        IngestSource::Stdin => {
+            let tailer = LineTailer::stdin(status.clone());
+            run_ingestor(tailer, config, status, estimator, command_tx).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    use crate::runtime::run_simulation_loop;
+    use crate::simulation::ConceptFluid;
+    use crate::state::{AppState, HeuristicEstimator};
+
+    #[tokio::test]
+    async fn test_tailed_file_lines_become_tagged_concepts() {
+        let path = std::env::temp_dir().join(format!("ingest-{}.log", uuid::Uuid::new_v4()));
+        let mut file = std::fs::File::create(&path).unwrap();
+        writeln!(file, "already here before the tail").unwrap();
+
+        let (state, channels) = AppState::new(ConceptFluid::default());
+        tokio::spawn(run_simulation_loop(state.fluid.clone(), channels));
+        let config: IngestConfig = toml::from_str(&format!(
+            "name = \"journal\"\nkind = \"file\"\npath = {:?}\nmax_per_second = 100",
+            path
+        ))
+        .unwrap();
+        let status = Arc::new(IngestStatus::new(config.name.clone(), config.source.kind()));
+        tokio::spawn(run_ingest_source(
+            config,
+            status.clone(),
+            Arc::new(HeuristicEstimator),
+            state.command_tx.clone(),
+        ));
+        // Let the tailer open the file and seek to its end
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        writeln!(file, "grateful for the morning").unwrap();
+        write!(file, "lonely ").unwrap();
+        file.flush().unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        writeln!(file, "evening").unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while status.snapshot().ingested < 2 && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        // Let the loop apply the last inject
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let fluid = state.fluid.read().await;
+        let mut names: Vec<_> = fluid.concepts.values().map(|c| c.name.as_str()).collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec!["grateful for the morning", "lonely evening"],
+            "only new, complete lines"
+        );
+        for concept in fluid.concepts.values() {
+            assert_eq!(concept.metadata["source"], "journal");
+            let (density, _) = HeuristicEstimator.estimate(&concept.name, &concept.metadata);
+            assert_eq!(concept.density, density);
+        }
+        let health = status.snapshot();
+        assert_eq!(
+            (health.kind, health.running, health.ingested),
+            ("file", true, 2)
+        );
+        drop(fluid);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    /// Hands out a fixed list of items, then ends
+    struct ListIngestor(VecDeque<IngestItem>);
+
+    impl Ingestor for ListIngestor {
+        async fn next(&mut self) -> Option<IngestItem> {
+            self.0.pop_front()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_finite_weight_hints_override_the_estimate() {
+        let (state, channels) = AppState::new(ConceptFluid::default());
+        tokio::spawn(run_simulation_loop(state.fluid.clone(), channels));
+        let item = |text: &str, weight_hint| IngestItem {
+            text: text.to_string(),
+            weight_hint,
+            metadata: Metadata::new(),
+        };
+        let ingestor = ListIngestor(VecDeque::from([
+            item("hinted", Some(0.8)),
+            item("garbled", Some(f32::NAN)),
+            item("unbounded", Some(f32::INFINITY)),
+        ]));
+        let config: IngestConfig =
+            toml::from_str("name = \"feed\"\nkind = \"stdin\"\nmax_per_second = 0").unwrap();
+        let status = Arc::new(IngestStatus::new(config.name.clone(), config.source.kind()));
+        run_ingestor(
+            ingestor,
+            config,
+            status.clone(),
+            Arc::new(HeuristicEstimator),
+            state.command_tx.clone(),
+        )
+        .await;
+        assert_eq!(status.snapshot().ingested, 3);
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while state.fluid.read().await.concepts.len() < 3 && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        let fluid = state.fluid.read().await;
+        for concept in fluid.concepts.values() {
+            let expected = match concept.name.as_str() {
+                "hinted" => 0.8,
+                name => HeuristicEstimator.estimate(name, &concept.metadata).0,
+            };
+            assert_eq!(concept.density, expected, "{}", concept.name);
+        }
+        assert_eq!(fluid.concepts.len(), 3);
+    }
+}
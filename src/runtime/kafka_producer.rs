@@ -0,0 +1,551 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use tokio::sync::{RwLock, broadcast, mpsc};
+use tracing::{info, warn};
+
+use crate::simulation::ConceptFluid;
+use crate::state::{EventEnvelope, FluidEvent, KafkaStatus};
+
+/// Wait between attempts while the broker is unavailable
+const RETRY_BACKOFF: Duration = Duration::from_secs(1);
+/// Close the current batch at least this often (one tick at 60Hz)
+const FLUSH_INTERVAL: Duration = Duration::from_micros(16_667);
+
+/// Compression codec for produced batches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KafkaCompression {
+    #[default]
+    None,
+    Gzip,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+impl KafkaCompression {
+    /// librdkafka `compression.type` value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Gzip => "gzip",
+            Self::Snappy => "snappy",
+            Self::Lz4 => "lz4",
+            Self::Zstd => "zstd",
+        }
+    }
+}
+
+/// The `[kafka]` section of the config file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct KafkaConfig {
+    /// Bootstrap brokers, e.g. `["localhost:9092"]`
+    pub brokers: Vec<String>,
+    pub topic: String,
+    #[serde(default)]
+    pub compression: KafkaCompression,
+    /// Record key for every event, so one session's events stay in one partition
+    #[serde(default = "default_session")]
+    pub session: String,
+    /// Events held while the broker is unavailable; the oldest are dropped beyond this
+    #[serde(default = "default_buffer_capacity")]
+    pub buffer_capacity: usize,
+    /// How long librdkafka keeps trying to deliver a record before reporting failure
+    #[serde(default = "default_delivery_timeout_ms")]
+    pub delivery_timeout_ms: u64,
+}
+
+fn default_session() -> String {
+    "buoyancy".to_string()
+}
+
+fn default_buffer_capacity() -> usize {
+    10_000
+}
+
+fn default_delivery_timeout_ms() -> u64 {
+    5_000
+}
+
+impl KafkaConfig {
+    pub fn status(&self) -> KafkaStatus {
+        KafkaStatus::new(self.topic.clone())
+    }
+}
+
+/// Enveloped events (JSON) produced during one simulation tick.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventBatch {
+    pub tick: u64,
+    pub records: Vec<String>,
+}
+
+/// Groups records into one batch per tick.
+#[derive(Debug, Default)]
+pub struct TickBatcher {
+    pending: Option<EventBatch>,
+}
+
+impl TickBatcher {
+    /// Add a record, returning the previous tick's batch once a new tick starts.
+    pub fn add(&mut self, tick: u64, record: String) -> Option<EventBatch> {
+        let finished = match &self.pending {
+            Some(batch) if batch.tick != tick => self.pending.take(),
+            _ => None,
+        };
+        self.pending
+            .get_or_insert_with(|| EventBatch {
+                tick,
+                records: Vec::new(),
+            })
+            .records
+            .push(record);
+        finished
+    }
+
+    /// Close the current batch.
+    pub fn take(&mut self) -> Option<EventBatch> {
+        self.pending.take()
+    }
+}
+
+/// Bounded queue of batches waiting for the broker.
+#[derive(Debug)]
+pub struct BatchBuffer {
+    batches: VecDeque<EventBatch>,
+    records: usize,
+    capacity: usize,
+}
+
+impl BatchBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            batches: VecDeque::new(),
+            records: 0,
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Queue a batch, dropping the oldest records beyond capacity.
+    /// Returns how many records were dropped.
+    pub fn push(&mut self, batch: EventBatch) -> usize {
+        self.records += batch.records.len();
+        self.batches.push_back(batch);
+
+        let mut dropped = 0;
+        while self.records > self.capacity {
+            let Some(oldest) = self.batches.front_mut() else {
+                break;
+            };
+            let excess = (self.records - self.capacity).min(oldest.records.len());
+            oldest.records.drain(..excess);
+            self.records -= excess;
+            dropped += excess;
+            if oldest.records.is_empty() {
+                self.batches.pop_front();
+            }
+        }
+        dropped
+    }
+
+    pub fn front(&self) -> Option<&EventBatch> {
+        self.batches.front()
+    }
+
+    pub fn pop_front(&mut self) -> Option<EventBatch> {
+        let batch = self.batches.pop_front()?;
+        self.records -= batch.records.len();
+        Some(batch)
+    }
+
+    /// Buffered records (not batches)
+    pub fn len(&self) -> usize {
+        self.records
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records == 0
+    }
+}
+
+/// Delivery reports for one batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Delivery {
+    pub delivered: usize,
+    pub failed: usize,
+}
+
+/// Sends batches to a topic. Implemented over librdkafka (`kafka` feature)
+/// and by test mocks.
+pub trait BatchProducer: Send {
+    fn send_batch(&mut self, batch: &EventBatch) -> impl Future<Output = Delivery> + Send;
+}
+
+/// Send buffered batches oldest first. A batch whose every record failed means
+/// the broker is unavailable: it stays buffered and this returns false.
+/// Records that fail within an otherwise delivered batch are not retried.
+pub async fn flush<P: BatchProducer>(
+    producer: &mut P,
+    buffer: &mut BatchBuffer,
+    status: &KafkaStatus,
+) -> bool {
+    let mut available = true;
+    while let Some(batch) = buffer.front() {
+        let delivery = producer.send_batch(batch).await;
+        status.record_delivery_failures(delivery.failed as u64);
+        if delivery.delivered == 0 && delivery.failed > 0 {
+            available = false;
+            break;
+        }
+        status.record_sent(delivery.delivered as u64);
+        buffer.pop_front();
+    }
+    status.set_buffered(buffer.len() as u64);
+    available
+}
+
+/// Produce every event to Kafka, one batch per tick, keyed by session name.
+/// While the broker is unavailable batches are buffered up to
+/// `buffer_capacity` records, then the oldest are dropped and counted.
+/// Delivery runs in its own task so a slow flush never stops the broadcast
+/// receiver from draining.
+pub async fn run_kafka_producer<P: BatchProducer + 'static>(
+    producer: P,
+    config: KafkaConfig,
+    status: Arc<KafkaStatus>,
+    fluid: Arc<RwLock<ConceptFluid>>,
+    event_tx: broadcast::Sender<FluidEvent>,
+) {
+    let mut events = event_tx.subscribe();
+    let mut batcher = TickBatcher::default();
+    let mut flush_timer = tokio::time::interval(FLUSH_INTERVAL);
+    flush_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    info!(
+        "Kafka producer enabled: topic '{}' ({}), key '{}'",
+        config.topic,
+        config.compression.as_str(),
+        config.session
+    );
+
+    let (batch_tx, batch_rx) = mpsc::unbounded_channel();
+    let delivery = tokio::spawn(deliver_batches(
+        producer,
+        config.buffer_capacity,
+        status.clone(),
+        batch_rx,
+    ));
+
+    loop {
+        let batch = tokio::select! {
+            received = events.recv() => match received {
+                Ok(event) => {
+                    let tick = fluid.read().await.tick_count;
+                    match serde_json::to_string(&EventEnvelope::new(&event, tick)) {
+                        Ok(record) => batcher.add(tick, record),
+                        Err(err) => {
+                            warn!("Could not serialize {} event: {}", event.kind(), err);
+                            None
+                        }
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    warn!("Kafka producer lagged, {} events missed", missed);
+                    status.record_dropped(missed);
+                    None
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            _ = flush_timer.tick() => batcher.take(),
+        };
+        if let Some(batch) = batch
+            && batch_tx.send(batch).is_err()
+        {
+            break;
+        }
+    }
+
+    if let Some(batch) = batcher.take() {
+        let _ = batch_tx.send(batch);
+    }
+    drop(batch_tx);
+    let _ = delivery.await;
+}
+
+/// Buffer closed batches and flush them to the broker, backing off while it
+/// is unavailable. Batches queue in the channel while a flush is in flight.
+async fn deliver_batches<P: BatchProducer>(
+    mut producer: P,
+    buffer_capacity: usize,
+    status: Arc<KafkaStatus>,
+    mut batches: mpsc::UnboundedReceiver<EventBatch>,
+) {
+    let mut buffer = BatchBuffer::new(buffer_capacity);
+    let mut flush_timer = tokio::time::interval(FLUSH_INTERVAL);
+    flush_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut retry_at: Option<Instant> = None;
+
+    loop {
+        flush_timer.tick().await;
+        let closed = loop {
+            match batches.try_recv() {
+                Ok(batch) => status.record_dropped(buffer.push(batch) as u64),
+                Err(mpsc::error::TryRecvError::Empty) => break false,
+                Err(mpsc::error::TryRecvError::Disconnected) => break true,
+            }
+        };
+        if buffer.is_empty() || retry_at.is_some_and(|at| !closed && Instant::now() < at) {
+            status.set_buffered(buffer.len() as u64);
+        } else if flush(&mut producer, &mut buffer, &status).await {
+            if retry_at.take().is_some() {
+                info!("Kafka broker reachable again");
+            }
+        } else {
+            if retry_at.is_none() {
+                warn!(
+                    "Kafka broker unavailable, buffering up to {} events",
+                    buffer_capacity
+                );
+            }
+            retry_at = Some(Instant::now() + RETRY_BACKOFF);
+        }
+        if closed {
+            break;
+        }
+    }
+}
+
+#[cfg(feature = "kafka")]
+pub use rd::RdKafkaProducer;
+
+#[cfg(feature = "kafka")]
+mod rd {
+    use rdkafka::ClientConfig;
+    use rdkafka::error::KafkaError;
+    use rdkafka::producer::{FutureProducer, FutureRecord};
+
+    use super::{BatchProducer, Delivery, EventBatch, KafkaConfig};
+
+    /// librdkafka producer; delivery reports are awaited per batch.
+    pub struct RdKafkaProducer {
+        producer: FutureProducer,
+        topic: String,
+        key: String,
+    }
+
+    impl RdKafkaProducer {
+        pub fn new(config: &KafkaConfig) -> Result<Self, KafkaError> {
+            let producer = ClientConfig::new()
+                .set("bootstrap.servers", config.brokers.join(","))
+                .set("compression.type", config.compression.as_str())
+                .set("message.timeout.ms", config.delivery_timeout_ms.to_string())
+                .create()?;
+            Ok(Self {
+                producer,
+                topic: config.topic.clone(),
+                key: config.session.clone(),
+            })
+        }
+    }
+
+    impl BatchProducer for RdKafkaProducer {
+        async fn send_batch(&mut self, batch: &EventBatch) -> Delivery {
+            let mut delivery = Delivery::default();
+            let mut reports = Vec::with_capacity(batch.records.len());
+            for record in &batch.records {
+                let message = FutureRecord::to(&self.topic)
+                    .key(self.key.as_str())
+                    .payload(record.as_str());
+                match self.producer.send_result(message) {
+                    Ok(report) => reports.push(report),
+                    Err(_) => delivery.failed += 1,
+                }
+            }
+            for report in futures::future::join_all(reports).await {
+                match report {
+                    Ok(Ok(_)) => delivery.delivered += 1,
+                    _ => delivery.failed += 1,
+                }
+            }
+            delivery
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[derive(Clone, Default)]
+    struct MockProducer {
+        down: Arc<AtomicBool>,
+        sent: Arc<Mutex<Vec<EventBatch>>>,
+    }
+
+    impl BatchProducer for MockProducer {
+        async fn send_batch(&mut self, batch: &EventBatch) -> Delivery {
+            let count = batch.records.len();
+            if self.down.load(Ordering::Relaxed) {
+                return Delivery {
+                    delivered: 0,
+                    failed: count,
+                };
+            }
+            self.sent.lock().unwrap().push(batch.clone());
+            Delivery {
+                delivered: count,
+                failed: 0,
+            }
+        }
+    }
+
+    /// Holds its first batch until released, like a broker timing out.
+    struct StalledProducer {
+        stalled: AtomicBool,
+        release: Arc<tokio::sync::Notify>,
+        delivered: Arc<AtomicUsize>,
+    }
+
+    impl BatchProducer for StalledProducer {
+        async fn send_batch(&mut self, batch: &EventBatch) -> Delivery {
+            if self.stalled.swap(false, Ordering::Relaxed) {
+                self.release.notified().await;
+            }
+            self.delivered
+                .fetch_add(batch.records.len(), Ordering::Relaxed);
+            Delivery {
+                delivered: batch.records.len(),
+                failed: 0,
+            }
+        }
+    }
+
+    fn records(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_batches_per_tick_and_drops_oldest_on_overflow() {
+        let mut batcher = TickBatcher::default();
+        assert_eq!(batcher.add(1, "a".into()), None);
+        assert_eq!(batcher.add(1, "b".into()), None);
+        let first = batcher.add(2, "c".into()).unwrap();
+        assert_eq!(first.tick, 1);
+        assert_eq!(first.records, records(&["a", "b"]));
+        let second = batcher.take().unwrap();
+        assert_eq!(second.records, records(&["c"]));
+        assert!(batcher.take().is_none());
+
+        let mut buffer = BatchBuffer::new(4);
+        assert_eq!(buffer.push(first), 0);
+        assert_eq!(buffer.push(second), 0);
+        let burst = EventBatch {
+            tick: 3,
+            records: records(&["d", "e", "f"]),
+        };
+        // 6 records for 4 slots: both of tick 1's go, keeping the newest
+        assert_eq!(buffer.push(burst), 2);
+        assert_eq!(buffer.len(), 4);
+        assert_eq!(buffer.front().unwrap().records, records(&["c"]));
+    }
+
+    #[tokio::test]
+    async fn test_outage_buffers_then_delivers_in_order() {
+        let mut producer = MockProducer::default();
+        let status = KafkaStatus::new("events".to_string());
+        let mut buffer = BatchBuffer::new(100);
+        producer.down.store(true, Ordering::Relaxed);
+
+        for tick in 1..=3 {
+            buffer.push(EventBatch {
+                tick,
+                records: records(&["x", "y"]),
+            });
+        }
+        assert!(!flush(&mut producer, &mut buffer, &status).await);
+        let health = status.snapshot();
+        assert_eq!(health.buffered, 6, "nothing is lost while buffered");
+        assert_eq!(
+            health.delivery_failures_total, 2,
+            "only the head batch was tried"
+        );
+        assert_eq!(health.sent, 0);
+
+        producer.down.store(false, Ordering::Relaxed);
+        assert!(flush(&mut producer, &mut buffer, &status).await);
+        let ticks: Vec<u64> = producer
+            .sent
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|b| b.tick)
+            .collect();
+        assert_eq!(ticks, vec![1, 2, 3]);
+        let health = status.snapshot();
+        assert_eq!(health.sent, 6);
+        assert_eq!(health.buffered, 0);
+        assert_eq!(health.dropped, 0);
+    }
+
+    #[tokio::test]
+    async fn test_receiver_keeps_draining_during_a_slow_flush() {
+        let release = Arc::new(tokio::sync::Notify::new());
+        let delivered = Arc::new(AtomicUsize::new(0));
+        let producer = StalledProducer {
+            stalled: AtomicBool::new(true),
+            release: release.clone(),
+            delivered: delivered.clone(),
+        };
+        let config = KafkaConfig {
+            brokers: vec!["localhost:9092".to_string()],
+            topic: "events".to_string(),
+            compression: KafkaCompression::None,
+            session: default_session(),
+            buffer_capacity: default_buffer_capacity(),
+            delivery_timeout_ms: default_delivery_timeout_ms(),
+        };
+        let status = Arc::new(config.status());
+        let fluid = Arc::new(RwLock::new(ConceptFluid::default()));
+        let (event_tx, _) = broadcast::channel(4);
+        let producer_task = tokio::spawn(run_kafka_producer(
+            producer,
+            config,
+            status.clone(),
+            fluid,
+            event_tx.clone(),
+        ));
+        while event_tx.receiver_count() == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        // Far more events than the broadcast channel holds, sent while the
+        // first flush is stuck
+        for n in 0..40 {
+            event_tx
+                .send(FluidEvent::ConceptRemoved {
+                    concept_id: uuid::Uuid::nil(),
+                    concept_name: format!("c{}", n),
+                })
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(2)).await;
+        }
+        assert_eq!(status.snapshot().dropped, 0, "no events lagged");
+
+        release.notify_one();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while delivered.load(Ordering::Relaxed) < 40 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("every event is delivered once the flush completes");
+        producer_task.abort();
+    }
+}
@@ -1,3 +1,28 @@
+pub mod action_hook;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+pub mod ingest;
+pub mod kafka_producer;
+pub mod notifier;
+#[cfg(feature = "redis")]
+pub mod redis_bridge;
+pub mod scheduler;
 pub mod simulation_loop;
 
-pub use simulation_loop::run_simulation_loop;
+pub use action_hook::{ActionHookConfig, run_action_hook};
+#[cfg(feature = "fuzz")]
+pub use fuzz::{Anomaly, FuzzConfig, FuzzReport, fuzz};
+pub use ingest::{
+    HttpPoller, IngestConfig, IngestItem, IngestSource, Ingestor, LineTailer, run_ingest_source,
+    run_ingestor,
+};
+#[cfg(feature = "kafka")]
+pub use kafka_producer::RdKafkaProducer;
+pub use kafka_producer::{BatchProducer, KafkaCompression, KafkaConfig, run_kafka_producer};
+pub use notifier::{
+    Notification, Notifier, NotifyConfig, Severity, WebhookFormat, WebhookNotifier, run_notifier,
+};
+#[cfg(feature = "redis")]
+pub use redis_bridge::{RedisConfig, run_redis_bridge};
+pub use scheduler::run_scheduler;
+pub use simulation_loop::{run_simulation_loop, spawn_mind, step};
@@ -0,0 +1,418 @@
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::{broadcast, mpsc};
+use tracing::{debug, info, warn};
+
+use crate::state::FluidEvent;
+
+/// Notifications waiting for delivery; more are dropped while the sink is slow
+const QUEUE_CAPACITY: usize = 64;
+/// Keys remembered for dedup; the oldest are forgotten beyond this
+const DEDUP_CAPACITY: usize = 4096;
+
+/// How urgently a human should look at an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+}
+
+/// Payload shape expected by the webhook.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookFormat {
+    /// `{"text": ...}` (Slack incoming webhooks)
+    #[default]
+    Slack,
+    /// `{"content": ...}` (Discord webhooks)
+    Discord,
+}
+
+/// The `[notify]` section of the config file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotifyConfig {
+    pub webhook_url: String,
+    #[serde(default)]
+    pub format: WebhookFormat,
+    /// Event kinds (as named on the SSE stream) that may notify
+    #[serde(default = "default_events")]
+    pub events: Vec<String>,
+    /// Only notify at or above this severity
+    #[serde(default = "default_min_severity")]
+    pub min_severity: Severity,
+    /// Retries after a failed delivery (exponential backoff)
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles on each further retry
+    #[serde(default = "default_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
+}
+
+fn default_events() -> Vec<String> {
+    vec![
+        "consensus_crystallized".to_string(),
+        "tectonic_shift".to_string(),
+    ]
+}
+
+fn default_min_severity() -> Severity {
+    Severity::High
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_retry_backoff_ms() -> u64 {
+    500
+}
+
+/// A short human-readable message about a significant event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// Identifies what the notification is about, so it is only sent once
+    pub key: String,
+    pub kind: &'static str,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Notification {
+    /// Describe an event worth a human's attention. None for routine events.
+    pub fn from_event(event: &FluidEvent) -> Option<Self> {
+        let (key, severity, message) = match event {
+            FluidEvent::ConsensusOreCrystallized {
+                ore_id,
+                name,
+                position_a,
+                position_b,
+                certainty,
+                quality,
+                insight,
+                crystallization_time,
+                ..
+            } => {
+                let severity = match quality.as_str() {
+                    "foundational_truth" => Severity::High,
+                    "strong_insight" => Severity::Medium,
+                    _ => Severity::Low,
+                };
+                let mut message = format!(
+                    "{}: '{}' vs '{}' crystallized into {} (certainty {:.2}, {} ticks)",
+                    sentence_case(quality),
+                    position_a,
+                    position_b,
+                    name,
+                    certainty,
+                    crystallization_time
+                );
+                if let Some(insight) = insight {
+                    message.push_str(&format!("\n{}", insight));
+                }
+                (format!("ore:{}", ore_id), severity, message)
+            }
+            FluidEvent::TectonicShift {
+                continent_name,
+                depth_range,
+                ores_consumed,
+                total_integration,
+                ..
+            } => (
+                format!("continent:{}", continent_name),
+                Severity::High,
+                format!(
+                    "Tectonic shift: continent '{}' formed at depth {:.2}-{:.2} from {} ores (integration {:.2})",
+                    continent_name,
+                    depth_range.0,
+                    depth_range.1,
+                    ores_consumed.len(),
+                    total_integration
+                ),
+            ),
+            _ => return None,
+        };
+        Some(Self {
+            key,
+            kind: event.kind(),
+            severity,
+            message,
+        })
+    }
+}
+
+/// "foundational_truth" -> "Foundational truth"
+fn sentence_case(label: &str) -> String {
+    let text = label.replace('_', " ");
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => text,
+    }
+}
+
+/// Delivers notifications to a human. Implemented by the webhook sink and
+/// by test mocks.
+pub trait Notifier: Send + Sync {
+    fn notify(
+        &self,
+        notification: &Notification,
+    ) -> impl Future<Output = Result<(), String>> + Send;
+}
+
+/// Posts notifications to a Slack or Discord webhook.
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    url: String,
+    format: WebhookFormat,
+}
+
+impl WebhookNotifier {
+    pub fn new(config: &NotifyConfig) -> Result<Self, String> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .map_err(|err| err.to_string())?;
+        Ok(Self {
+            client,
+            url: config.webhook_url.clone(),
+            format: config.format,
+        })
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<(), String> {
+        let payload = match self.format {
+            WebhookFormat::Slack => json!({ "text": notification.message }),
+            WebhookFormat::Discord => json!({ "content": notification.message }),
+        };
+        let response = self
+            .client
+            .post(&self.url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|err| err.to_string())?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(format!("webhook returned {}", response.status()))
+        }
+    }
+}
+
+/// Remembers which keys have been notified, forgetting the oldest beyond
+/// `DEDUP_CAPACITY`.
+#[derive(Debug, Default)]
+struct Dedup {
+    seen: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl Dedup {
+    /// True the first time `key` is seen.
+    fn first(&mut self, key: &str) -> bool {
+        if self.seen.contains(key) {
+            return false;
+        }
+        if self.order.len() == DEDUP_CAPACITY
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.seen.remove(&oldest);
+        }
+        self.seen.insert(key.to_string());
+        self.order.push_back(key.to_string());
+        true
+    }
+}
+
+async fn deliver<N: Notifier>(notifier: &N, config: &NotifyConfig, notification: &Notification) {
+    let mut backoff = Duration::from_millis(config.retry_backoff_ms);
+    for attempt in 0..=config.max_retries {
+        if attempt > 0 {
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+        match notifier.notify(notification).await {
+            Ok(()) => return,
+            Err(err) => debug!(
+                "Notification '{}' failed (attempt {}): {}",
+                notification.key,
+                attempt + 1,
+                err
+            ),
+        }
+    }
+    warn!(
+        "Giving up on notification '{}' after {} attempts",
+        notification.key,
+        config.max_retries + 1
+    );
+}
+
+/// Notify a human of significant events (by default foundational truths and
+/// tectonic shifts), at most once per ore or continent. Delivery and retries
+/// run on their own task so a slow webhook never stalls event intake.
+pub async fn run_notifier<N: Notifier + 'static>(
+    notifier: N,
+    config: NotifyConfig,
+    event_tx: broadcast::Sender<FluidEvent>,
+) {
+    let mut events = event_tx.subscribe();
+    let (queue_tx, mut queue_rx) = mpsc::channel::<Notification>(QUEUE_CAPACITY);
+    let mut dedup = Dedup::default();
+
+    info!(
+        "Notifier enabled: {} at {:?} severity or above",
+        config.events.join(", "),
+        config.min_severity
+    );
+
+    let delivery_config = config.clone();
+    tokio::spawn(async move {
+        while let Some(notification) = queue_rx.recv().await {
+            deliver(&notifier, &delivery_config, &notification).await;
+        }
+    });
+
+    loop {
+        let event = match events.recv().await {
+            Ok(event) => event,
+            Err(broadcast::error::RecvError::Lagged(missed)) => {
+                warn!("Notifier lagged, {} events missed", missed);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+        let Some(notification) = Notification::from_event(&event) else {
+            continue;
+        };
+        if notification.severity < config.min_severity
+            || !config.events.iter().any(|kind| kind == notification.kind)
+            || !dedup.first(&notification.key)
+        {
+            continue;
+        }
+        if queue_tx.try_send(notification).is_err() {
+            warn!("Notification queue full, dropping notification");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    use axum::{Json, Router, extract::State, http::StatusCode, routing::post};
+    use serde_json::Value;
+    use uuid::Uuid;
+
+    /// Mock webhook: records payloads and fails the first `failures` requests.
+    #[derive(Clone, Default)]
+    struct MockWebhook {
+        payloads: Arc<Mutex<Vec<Value>>>,
+        failures: Arc<Mutex<u32>>,
+    }
+
+    async fn receive(State(mock): State<MockWebhook>, Json(payload): Json<Value>) -> StatusCode {
+        let mut failures = mock.failures.lock().unwrap();
+        if *failures > 0 {
+            *failures -= 1;
+            return StatusCode::SERVICE_UNAVAILABLE;
+        }
+        mock.payloads.lock().unwrap().push(payload);
+        StatusCode::OK
+    }
+
+    async fn serve(mock: MockWebhook) -> String {
+        let app = Router::new()
+            .route("/webhook", post(receive))
+            .with_state(mock);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}/webhook", addr)
+    }
+
+    fn crystallized(ore_id: Uuid, quality: &str, certainty: f32) -> FluidEvent {
+        FluidEvent::ConsensusOreCrystallized {
+            ore_id,
+            name: "privacy_transparency_ore".to_string(),
+            ore_type: "insight".to_string(),
+            position_a: "privacy".to_string(),
+            position_b: "transparency".to_string(),
+            certainty,
+            quality: quality.to_string(),
+            insight: Some("Consent bridges both".to_string()),
+            crystallization_time: 240,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_webhook_formats_and_dedups_notifications() {
+        let mock = MockWebhook::default();
+        *mock.failures.lock().unwrap() = 1;
+        let config: NotifyConfig = toml::from_str(&format!(
+            "webhook_url = \"{}\"\nformat = \"discord\"\nretry_backoff_ms = 5",
+            serve(mock.clone()).await
+        ))
+        .unwrap();
+        let notifier = WebhookNotifier::new(&config).unwrap();
+
+        let (event_tx, _) = broadcast::channel(64);
+        tokio::spawn(run_notifier(notifier, config, event_tx.clone()));
+        // Let the notifier subscribe before events are sent
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let ore = Uuid::new_v4();
+        event_tx
+            .send(crystallized(ore, "foundational_truth", 0.94))
+            .unwrap();
+        event_tx
+            .send(crystallized(ore, "foundational_truth", 0.94))
+            .unwrap();
+        event_tx
+            .send(crystallized(Uuid::new_v4(), "strong_insight", 0.8))
+            .unwrap();
+        event_tx.send(FluidEvent::Thaw).unwrap();
+        event_tx
+            .send(FluidEvent::TectonicShift {
+                continent_name: "continent_1".to_string(),
+                depth_range: (0.8, 0.95),
+                ores_consumed: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                ores_surviving: Vec::new(),
+                total_integration: 4.5,
+            })
+            .unwrap();
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        while mock.payloads.lock().unwrap().len() < 2 && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        // Give any duplicate a chance to arrive
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let payloads = mock.payloads.lock().unwrap().clone();
+        assert_eq!(
+            payloads,
+            vec![
+                json!({
+                    "content": "Foundational truth: 'privacy' vs 'transparency' crystallized into \
+                                privacy_transparency_ore (certainty 0.94, 240 ticks)\nConsent bridges both"
+                }),
+                json!({
+                    "content": "Tectonic shift: continent 'continent_1' formed at depth 0.80-0.95 \
+                                from 3 ores (integration 4.50)"
+                }),
+            ],
+            "the retried ore once, the shift once, nothing below high severity"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use redis::AsyncCommands;
+use redis::aio::MultiplexedConnection;
+use tokio::sync::{RwLock, broadcast};
+use tracing::{info, warn};
+
+use crate::simulation::ConceptFluid;
+use crate::state::{EventEnvelope, FluidEvent, FluidStateResponse, RedisStatus};
+
+/// Simulation rate, used to turn the mirror interval in ticks into wall time
+const TICKS_PER_SECOND: f64 = 60.0;
+
+/// Where to publish events and mirror state, and how often.
+#[derive(Debug, Clone)]
+pub struct RedisConfig {
+    pub url: String,
+    /// Pub/sub channel receiving one JSON envelope per event
+    pub channel: String,
+    /// Key holding the latest `/state` snapshot as JSON
+    pub state_key: String,
+    /// Mirror the state every this many ticks
+    pub mirror_every_ticks: u64,
+    /// Minimum time between reconnect attempts during an outage
+    pub reconnect_backoff: Duration,
+    pub timeout: Duration,
+}
+
+impl RedisConfig {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            channel: "buoyancy:events".to_string(),
+            state_key: "buoyancy:state".to_string(),
+            mirror_every_ticks: 60,
+            reconnect_backoff: Duration::from_secs(1),
+            timeout: Duration::from_secs(2),
+        }
+    }
+
+    /// Read `REDIS_URL` (bridge disabled when unset or empty), `REDIS_CHANNEL`,
+    /// `REDIS_STATE_KEY` and `REDIS_MIRROR_TICKS`.
+    pub fn from_env() -> Option<Self> {
+        let url = std::env::var("REDIS_URL").ok()?;
+        if url.trim().is_empty() {
+            return None;
+        }
+        let mut config = Self::new(url.trim().to_string());
+        if let Ok(channel) = std::env::var("REDIS_CHANNEL")
+            && !channel.trim().is_empty()
+        {
+            config.channel = channel.trim().to_string();
+        }
+        if let Ok(key) = std::env::var("REDIS_STATE_KEY")
+            && !key.trim().is_empty()
+        {
+            config.state_key = key.trim().to_string();
+        }
+        if let Ok(value) = std::env::var("REDIS_MIRROR_TICKS") {
+            match value.parse::<u64>() {
+                Ok(ticks) if ticks > 0 => config.mirror_every_ticks = ticks,
+                _ => warn!(
+                    "Ignoring invalid REDIS_MIRROR_TICKS={:?}, using {}",
+                    value, config.mirror_every_ticks
+                ),
+            }
+        }
+        Some(config)
+    }
+
+    pub fn status(&self) -> RedisStatus {
+        RedisStatus::new(self.channel.clone(), self.state_key.clone())
+    }
+}
+
+/// A lazily (re)connected Redis connection that gives up quietly during outages.
+struct Link {
+    client: redis::Client,
+    connection: Option<MultiplexedConnection>,
+    last_attempt: Option<Instant>,
+    config: RedisConfig,
+    status: Arc<RedisStatus>,
+}
+
+impl Link {
+    /// The live connection, reconnecting at most once per backoff period.
+    async fn connection(&mut self) -> Option<&mut MultiplexedConnection> {
+        if self.connection.is_none() {
+            if self
+                .last_attempt
+                .is_some_and(|at| at.elapsed() < self.config.reconnect_backoff)
+            {
+                return None;
+            }
+            self.last_attempt = Some(Instant::now());
+            let connect = self.client.get_multiplexed_async_connection();
+            match tokio::time::timeout(self.config.timeout, connect).await {
+                Ok(Ok(connection)) => {
+                    info!("Redis connected: {}", self.config.url);
+                    self.status.set_connected(true);
+                    self.connection = Some(connection);
+                }
+                Ok(Err(err)) => warn!("Redis unavailable ({}), dropping messages", err),
+                Err(_) => warn!("Redis connect timed out, dropping messages"),
+            }
+        }
+        self.connection.as_mut()
+    }
+
+    /// Forget a connection that failed; the next write reconnects.
+    fn fail(&mut self, err: redis::RedisError) {
+        warn!("Redis write failed ({}), dropping messages", err);
+        self.connection = None;
+        self.status.set_connected(false);
+    }
+
+    async fn publish(&mut self, payload: String) {
+        let channel = self.config.channel.clone();
+        let Some(connection) = self.connection().await else {
+            self.status.record_dropped(1);
+            return;
+        };
+        match connection.publish::<_, _, ()>(channel, payload).await {
+            Ok(()) => self.status.record_published(),
+            Err(err) => {
+                self.fail(err);
+                self.status.record_dropped(1);
+            }
+        }
+    }
+
+    async fn mirror(&mut self, snapshot: String) {
+        let key = self.config.state_key.clone();
+        let Some(connection) = self.connection().await else {
+            self.status.record_dropped(1);
+            return;
+        };
+        match connection.set::<_, _, ()>(key, snapshot).await {
+            Ok(()) => self.status.record_mirrored(),
+            Err(err) => {
+                self.fail(err);
+                self.status.record_dropped(1);
+            }
+        }
+    }
+}
+
+/// Publish every event to Redis and mirror the `/state` snapshot every
+/// `mirror_every_ticks` ticks, over a dedicated connection. Messages are
+/// dropped (and counted in `status`) while Redis is unreachable.
+pub async fn run_redis_bridge(
+    config: RedisConfig,
+    status: Arc<RedisStatus>,
+    fluid: Arc<RwLock<ConceptFluid>>,
+    event_tx: broadcast::Sender<FluidEvent>,
+) {
+    let client = match redis::Client::open(config.url.as_str()) {
+        Ok(client) => client,
+        Err(err) => {
+            warn!("Redis bridge disabled: {}", err);
+            return;
+        }
+    };
+    let mut events = event_tx.subscribe();
+    let mut mirror_timer = tokio::time::interval(Duration::from_secs_f64(
+        config.mirror_every_ticks as f64 / TICKS_PER_SECOND,
+    ));
+    mirror_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut last_mirrored_tick = None;
+
+    info!(
+        "Redis bridge enabled: channel '{}', state key '{}' every {} ticks",
+        config.channel, config.state_key, config.mirror_every_ticks
+    );
+    let mut link = Link {
+        client,
+        connection: None,
+        last_attempt: None,
+        config,
+        status,
+    };
+
+    loop {
+        tokio::select! {
+            received = events.recv() => match received {
+                Ok(event) => {
+                    let tick = fluid.read().await.tick_count;
+                    match serde_json::to_string(&EventEnvelope::new(&event, tick)) {
+                        Ok(payload) => link.publish(payload).await,
+                        Err(err) => warn!("Could not serialize {} event: {}", event.kind(), err),
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    warn!("Redis bridge lagged, {} events missed", missed);
+                    link.status.record_dropped(missed);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            _ = mirror_timer.tick() => {
+                let snapshot = {
+                    let fluid = fluid.read().await;
+                    // Skip while the simulation is paused or behind
+                    if last_mirrored_tick.is_some_and(|tick| {
+                        fluid.tick_count < tick + link.config.mirror_every_ticks
+                    }) {
+                        continue;
+                    }
+                    last_mirrored_tick = Some(fluid.tick_count);
+                    serde_json::to_string(&FluidStateResponse::from_fluid(&fluid))
+                };
+                match snapshot {
+                    Ok(snapshot) => link.mirror(snapshot).await,
+                    Err(err) => warn!("Could not serialize state snapshot: {}", err),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn spawn_bridge(
+        config: RedisConfig,
+    ) -> (Arc<RedisStatus>, broadcast::Sender<FluidEvent>) {
+        let status = Arc::new(config.status());
+        let fluid = Arc::new(RwLock::new(ConceptFluid::default()));
+        let (event_tx, _) = broadcast::channel(16);
+        tokio::spawn(run_redis_bridge(
+            config,
+            status.clone(),
+            fluid,
+            event_tx.clone(),
+        ));
+        // Let the bridge subscribe before anything is sent
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        (status, event_tx)
+    }
+
+    #[tokio::test]
+    async fn test_outage_drops_and_counts_messages() {
+        // Nothing listens on port 1
+        let mut config = RedisConfig::new("redis://127.0.0.1:1".to_string());
+        config.mirror_every_ticks = 1_000_000;
+        let (status, event_tx) = spawn_bridge(config).await;
+
+        for _ in 0..3 {
+            event_tx.send(FluidEvent::Thaw).unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        let health = status.snapshot();
+        assert!(!health.connected);
+        assert_eq!(health.published, 0);
+        // Three events plus the first (immediate) state mirror
+        assert_eq!(health.dropped, 4);
+    }
+
+    /// Needs a disposable Redis server: `REDIS_TEST_URL=redis://127.0.0.1/ cargo test --features redis`
+    #[tokio::test]
+    async fn test_publishes_envelopes_and_mirrors_state() {
+        let Ok(url) = std::env::var("REDIS_TEST_URL") else {
+            eprintln!("REDIS_TEST_URL not set, skipping");
+            return;
+        };
+        let suffix = uuid::Uuid::new_v4();
+        let mut config = RedisConfig::new(url.clone());
+        config.channel = format!("buoyancy-test:{}", suffix);
+        config.state_key = format!("buoyancy-test:state:{}", suffix);
+        let (channel, state_key) = (config.channel.clone(), config.state_key.clone());
+
+        let client = redis::Client::open(url).unwrap();
+        let mut pubsub = client.get_async_pubsub().await.unwrap();
+        pubsub.subscribe(&channel).await.unwrap();
+
+        let (status, event_tx) = spawn_bridge(config).await;
+        event_tx.send(FluidEvent::Thaw).unwrap();
+
+        let message = tokio::time::timeout(Duration::from_secs(2), async {
+            use futures::StreamExt;
+            pubsub.on_message().next().await.unwrap()
+        })
+        .await
+        .expect("envelope should be published");
+        let envelope: serde_json::Value =
+            serde_json::from_str(&message.get_payload::<String>().unwrap()).unwrap();
+        assert_eq!(envelope["kind"], "thaw");
+        assert_eq!(envelope["event"]["event"], "thaw");
+
+        let mut connection = client.get_multiplexed_async_connection().await.unwrap();
+        let snapshot: String = connection.get(&state_key).await.unwrap();
+        let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+        assert!(snapshot["concepts"].is_array());
+        let _: () = connection.del(&state_key).await.unwrap();
+
+        let health = status.snapshot();
+        assert!(health.connected);
+        assert_eq!(health.published, 1);
+        assert_eq!(health.dropped, 0);
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::state::{CommandSender, FluidEvent, Schedules};
+
+/// Send each schedule's command through the normal command channel when its
+/// cron expression fires, announcing it with a `scheduled_command_fired`
+/// event. Schedules added or removed via `/schedules` take effect at once.
+pub async fn run_scheduler(
+    schedules: Arc<Schedules>,
+    command_tx: CommandSender,
+    event_tx: broadcast::Sender<FluidEvent>,
+) {
+    let count = schedules.list().await.len();
+    if count > 0 {
+        info!("Scheduler started with {} schedules", count);
+    }
+
+    // Firings at or before this instant have been sent, so a timer that wakes
+    // a little early never fires the same slot twice
+    let mut last_fired = Utc::now();
+    loop {
+        let now = Utc::now().max(last_fired);
+        let Some((at, due)) = schedules.next_due(now).await else {
+            schedules.changed().await;
+            continue;
+        };
+
+        let wait = (at - now).to_std().unwrap_or_default();
+        tokio::select! {
+            _ = tokio::time::sleep(wait) => {}
+            _ = schedules.changed() => continue,
+        }
+        last_fired = at;
+
+        for schedule in due {
+            debug!(
+                "Schedule '{}' fired: {}",
+                schedule.name,
+                schedule.command.kind()
+            );
+            if command_tx
+                .send(schedule.command.into_command())
+                .await
+                .is_err()
+            {
+                warn!("Simulation stopped, scheduler exiting");
+                return;
+            }
+            let _ = event_tx.send(FluidEvent::ScheduledCommandFired {
+                name: schedule.name,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    use tokio::sync::mpsc;
+
+    use crate::state::{Command, Schedule};
+
+    fn every_second(name: &str, command: &str) -> Schedule {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "cron": "* * * * * *",
+            "command": serde_json::from_str::<serde_json::Value>(command).unwrap(),
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_schedule_fires_through_the_command_channel() {
+        let schedules = Arc::new(Schedules::default());
+        let (tx, mut command_rx) = mpsc::channel(8);
+        let (event_tx, mut events) = broadcast::channel(8);
+        tokio::spawn(run_scheduler(
+            schedules.clone(),
+            CommandSender::new(tx),
+            event_tx,
+        ));
+
+        // Added at runtime, so the idle scheduler must wake for it
+        schedules
+            .insert(every_second(
+                "calm",
+                r#"{"command": "deep_breath", "strength": 0.4}"#,
+            ))
+            .await
+            .unwrap();
+        assert!(
+            schedules
+                .insert(every_second("calm", r#"{"command": "thaw"}"#))
+                .await
+                .is_err(),
+            "names are unique"
+        );
+
+        let traced = tokio::time::timeout(Duration::from_secs(3), command_rx.recv())
+            .await
+            .expect("schedule should fire within a second")
+            .unwrap();
+        assert!(matches!(
+            traced.command,
+            Command::DeepBreath {
+                strength,
+                velocity_threshold: None
+            } if strength == 0.4
+        ));
+        match events.recv().await.unwrap() {
+            FluidEvent::ScheduledCommandFired { name } => assert_eq!(name, "calm"),
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        // Once removed it stops firing
+        assert!(schedules.remove("calm").await.is_some());
+        // Let a firing already under way land before draining
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        while command_rx.try_recv().is_ok() {}
+        assert!(
+            tokio::time::timeout(Duration::from_millis(1500), command_rx.recv())
+                .await
+                .is_err()
+        );
+    }
+}
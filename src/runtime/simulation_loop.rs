@@ -1,16 +1,53 @@
-use std::sync::Arc;
-use std::time::Duration;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, PoisonError};
+use std::time::{Duration, Instant};
 
 use tokio::sync::RwLock;
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, info_span, warn};
 
-use crate::simulation::ConceptFluid;
-use crate::state::{Command, FluidEvent, SimulationChannels};
+use super::action_hook::{ActionHookConfig, run_action_hook};
+use super::scheduler::run_scheduler;
+use crate::simulation::{ConceptFluid, ConsensusOre, Heatmap, MAX_RULE_DEPTH};
+use crate::state::{
+    AppState, ChannelConfig, Command, DensityEstimator, FluidEvent, MindHandle, SimulationChannels,
+};
 
 /// Tick rate for the simulation (60Hz)
 const TICK_RATE_HZ: u64 = 60;
 /// Delta time per tick
 const DT: f32 = 1.0 / TICK_RATE_HZ as f32;
+/// Trace one tick in this many (once a second) rather than every tick
+const TICK_SPAN_EVERY: u64 = TICK_RATE_HZ;
+/// Keep a copy of the fluid to fall back on after a panic this often
+const RECOVERY_COPY_EVERY: u64 = TICK_RATE_HZ;
+
+/// Start an isolated mind: fresh state around `fluid` with its own channels,
+/// simulation loop, scheduler and action hook.
+pub fn spawn_mind(
+    fluid: ConceptFluid,
+    config: ChannelConfig,
+    density_estimator: Arc<dyn DensityEstimator>,
+    action_hook: ActionHookConfig,
+) -> MindHandle {
+    let (mut state, channels) = AppState::with_config(fluid, config);
+    state.density_estimator = density_estimator;
+    let tasks = vec![
+        tokio::spawn(run_simulation_loop(state.fluid.clone(), channels)),
+        tokio::spawn(run_scheduler(
+            state.schedules.clone(),
+            state.command_tx.clone(),
+            state.event_tx.clone(),
+        )),
+        tokio::spawn(run_action_hook(
+            action_hook,
+            state.dispatch_rules.clone(),
+            state.fluid.clone(),
+            state.event_tx.clone(),
+        )),
+    ];
+    MindHandle::new(Arc::new(state), tasks)
+}
 
 /// Run the simulation loop at 60Hz.
 /// Processes commands from the API and broadcasts significant events.
@@ -18,162 +55,479 @@ pub async fn run_simulation_loop(
     fluid: Arc<RwLock<ConceptFluid>>,
     mut channels: SimulationChannels,
 ) {
-    let mut interval = tokio::time::interval(Duration::from_micros(1_000_000 / TICK_RATE_HZ));
+    let tick_period = Duration::from_micros(1_000_000 / TICK_RATE_HZ);
+    let mut interval = tokio::time::interval(tick_period);
     interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    channels.timing.start(Instant::now(), tick_period);
 
     info!("Simulation loop started at {}Hz", TICK_RATE_HZ);
+    let mut last_good = fluid.read().await.clone();
 
     loop {
         interval.tick().await;
+        let tick_started = Instant::now();
 
         // Acquire write lock for this tick
         let mut fluid_guard = fluid.write().await;
+        let events = run_tick(&mut fluid_guard, &mut channels, &mut last_good);
+
+        // Release lock before broadcasting
+        drop(fluid_guard);
+        channels.timing.record_tick(tick_started.elapsed());
 
-        // Process all pending commands
-        while let Ok(cmd) = channels.command_rx.try_recv() {
-            process_command(&mut fluid_guard, cmd, &channels.event_tx);
+        // Broadcast significant events (ignore errors if no subscribers)
+        for event in events {
+            debug!("Broadcasting event: {:?}", event);
+            let _ = channels.event_tx.send(event);
         }
+    }
+}
 
-        // Run physics update
-        let events = fluid_guard.update(DT);
+/// Process every pending command, then advance one tick.
+///
+/// A panic must not kill the loop and leave the server accepting commands
+/// with no simulation behind them, so each command and the tick itself run
+/// guarded, falling back to `last_good` when they panic. The copy is taken
+/// once the tick's commands are drained as well as periodically, so a restore
+/// never undoes a command answered in an earlier tick, and a burst of
+/// commands costs one copy rather than one each.
+fn run_tick(
+    fluid: &mut ConceptFluid,
+    channels: &mut SimulationChannels,
+    last_good: &mut ConceptFluid,
+) -> Vec<FluidEvent> {
+    // Held for the whole tick; a panic is caught before the guard drops, so
+    // it never poisons the lock
+    let heatmap = channels.heatmap.clone();
+    let mut heatmap = heatmap.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut events = Vec::new();
+    let mut applied = false;
+    while let Ok(traced) = channels.command_rx.try_recv() {
+        // Child of the request that sent it; experiments started here
+        // open their own spans beneath this one
+        let _span =
+            info_span!(parent: &traced.span, "command", kind = traced.command.kind()).entered();
+        // Advance runs whole ticks, whose events rules have already seen
+        let advance = matches!(traced.command, Command::Advance { .. });
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut caused = Vec::new();
+            process_command(fluid, &mut heatmap, traced.command, &mut caused);
+            if !advance {
+                run_rules(fluid, &mut heatmap, &mut caused);
+            }
+            caused
+        }));
+        match outcome {
+            Ok(caused) => {
+                applied = true;
+                events.extend(caused);
+            }
+            Err(payload) => {
+                applied = false;
+                events.push(restore(fluid, last_good, payload));
+            }
+        }
+    }
+    if applied {
+        *last_good = fluid.clone();
+    }
 
-        // Check for division experiment settlement
-        let experiment_result = fluid_guard.check_experiment_settlement();
+    // Run one tick of physics and experiment checks
+    let tick = fluid.tick_count;
+    let _span = tick
+        .is_multiple_of(TICK_SPAN_EVERY)
+        .then(|| info_span!("tick", tick).entered());
+    match panic::catch_unwind(AssertUnwindSafe(|| step(fluid, &mut heatmap))) {
+        Ok(stepped) => {
+            if fluid.tick_count.is_multiple_of(RECOVERY_COPY_EVERY) {
+                *last_good = fluid.clone();
+            }
+            events.extend(stepped);
+        }
+        Err(payload) => events.push(restore(fluid, last_good, payload)),
+    }
+    events
+}
 
-        // Check for consensus crystallization
-        let consensus_result = fluid_guard.check_consensus_crystallization();
+/// Put back the copy kept before a panic; whatever the panicking work
+/// half-finished can't be trusted.
+fn restore(
+    fluid: &mut ConceptFluid,
+    last_good: &ConceptFluid,
+    payload: Box<dyn Any + Send>,
+) -> FluidEvent {
+    let tick = fluid.tick_count;
+    let message = panic_message(payload.as_ref());
+    *fluid = last_good.clone();
+    error!(
+        "Simulation panicked at tick {}: {} (restored tick {})",
+        tick, message, fluid.tick_count
+    );
+    FluidEvent::SimulationError {
+        tick,
+        message,
+        restored_tick: fluid.tick_count,
+    }
+}
 
-        // Release lock before broadcasting
-        drop(fluid_guard);
+/// The message a panic was raised with, when it has one.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
 
-        // Broadcast experiment completion if any
-        if let Some(result) = experiment_result {
-            info!(
-                "Division experiment complete: {} ÷ {} = {} remainder {} (turbulence: {:.2})",
-                result.dividend,
-                result.divisor,
-                result.quotient,
-                result.remainder,
-                result.turbulence_energy
-            );
-            let _ = channels
-                .event_tx
-                .send(FluidEvent::DivisionExperimentComplete {
-                    dividend: result.dividend,
-                    divisor: result.divisor,
-                    quotient: result.quotient,
-                    remainder: result.remainder,
-                    is_divisible: result.is_divisible,
-                    turbulence_energy: result.turbulence_energy,
-                    reynolds_number: result.reynolds_number,
-                    ticks_to_settle: result.ticks_to_settle,
-                });
-        }
+/// Advance the fluid by one tick: physics, metric sampling, experiment checks,
+/// and rules. Returns every event the tick produced, including experiment
+/// completions and whatever rules caused.
+pub fn step(fluid: &mut ConceptFluid, heatmap: &mut Heatmap) -> Vec<FluidEvent> {
+    // Run physics update
+    let mut events = fluid.update(DT);
 
-        // Broadcast consensus crystallization if any
-        if let Some(ore) = consensus_result {
-            info!(
-                "Consensus crystallized: '{}' vs '{}' → {} (certainty: {:.2}, quality: {})",
-                ore.vent_a,
-                ore.vent_b,
-                ore.ore_type.as_str(),
-                ore.certainty,
-                ore.quality()
+    // Sample metrics into the in-memory time series
+    fluid.record_timeseries_sample();
+    heatmap.record(fluid);
+
+    // Check for division experiment settlement
+    if let Some(result) = fluid.check_experiment_settlement() {
+        info!(
+            "Division experiment complete: {} ÷ {} = {} remainder {} (turbulence: {:.2})",
+            result.dividend,
+            result.divisor,
+            result.quotient,
+            result.remainder,
+            result.turbulence_energy
+        );
+        events.push(FluidEvent::DivisionExperimentComplete {
+            dividend: result.dividend,
+            divisor: result.divisor,
+            quotient: result.quotient,
+            remainder: result.remainder,
+            is_divisible: result.is_divisible,
+            turbulence_energy: result.turbulence_energy,
+            reynolds_number: result.reynolds_number,
+            ticks_to_settle: result.ticks_to_settle,
+            settled_by: result.settled_by,
+        });
+    }
+
+    // Check for consensus crystallization
+    if let Some(ore) = fluid.check_consensus_crystallization() {
+        consensus_crystallized(fluid, &ore, &mut events);
+    }
+
+    run_rules(fluid, heatmap, &mut events);
+    events
+}
+
+/// Log a crystallized consensus ore and append its events, including the
+/// insight fed back in by experiments seeded from live concepts.
+fn consensus_crystallized(fluid: &ConceptFluid, ore: &ConsensusOre, events: &mut Vec<FluidEvent>) {
+    info!(
+        "Consensus crystallized: '{}' vs '{}' → {} (certainty: {:.2}, quality: {})",
+        ore.vent_a,
+        ore.vent_b,
+        ore.ore_type.as_str(),
+        ore.certainty,
+        ore.quality(&fluid.consensus_reactor.quality_thresholds)
+    );
+    events.push(FluidEvent::ConsensusOreCrystallized {
+        ore_id: ore.id,
+        name: ore.name.clone(),
+        ore_type: ore.ore_type.as_str().to_string(),
+        position_a: ore.vent_a.clone(),
+        position_b: ore.vent_b.clone(),
+        certainty: ore.certainty,
+        quality: ore
+            .quality(&fluid.consensus_reactor.quality_thresholds)
+            .to_string(),
+        insight: ore.insight.clone(),
+        crystallization_time: ore.crystallization_time,
+    });
+
+    // Experiments seeded from live concepts feed their insight back in
+    if let Some(concept) = ore.insight_concept.and_then(|id| fluid.get_concept(id)) {
+        events.push(FluidEvent::ConceptInjected {
+            id: concept.id,
+            name: concept.name.clone(),
+            density: concept.density,
+            area: concept.area,
+            volume: concept.volume(),
+            layer: concept.layer,
+        });
+    }
+}
+
+/// Send the commands registered rules have for `events`, appending the events
+/// those cause. Rules also react to events caused by rules, up to
+/// `MAX_RULE_DEPTH` rounds.
+fn run_rules(fluid: &mut ConceptFluid, heatmap: &mut Heatmap, events: &mut Vec<FluidEvent>) {
+    let mut pending = fluid.rules.commands_for(events);
+    let mut depth = 0;
+    while !pending.is_empty() {
+        if depth == MAX_RULE_DEPTH {
+            warn!(
+                "Rule depth limit reached, dropping {} commands",
+                pending.len()
             );
-            let _ = channels
-                .event_tx
-                .send(FluidEvent::ConsensusOreCrystallized {
-                    ore_id: ore.id,
-                    name: ore.name.clone(),
-                    ore_type: ore.ore_type.as_str().to_string(),
-                    position_a: ore.vent_a.clone(),
-                    position_b: ore.vent_b.clone(),
-                    certainty: ore.certainty,
-                    quality: ore.quality().to_string(),
-                    insight: ore.insight.clone(),
-                    crystallization_time: ore.crystallization_time,
-                });
+            return;
         }
-
-        // Broadcast significant events (ignore errors if no subscribers)
-        for event in events {
-            debug!("Broadcasting event: {:?}", event);
-            let _ = channels.event_tx.send(event);
+        depth += 1;
+        let mut caused = Vec::new();
+        for command in pending {
+            debug!("Rule sent '{}'", command.kind());
+            process_command(fluid, heatmap, command.into_command(), &mut caused);
         }
+        pending = fluid.rules.commands_for(&caused);
+        events.extend(caused);
     }
 }
 
-/// Process a command from the API.
+/// Process a command from the API or a rule.
+/// Events it causes are appended to `events`.
 fn process_command(
     fluid: &mut ConceptFluid,
+    heatmap: &mut Heatmap,
     cmd: Command,
-    event_tx: &tokio::sync::broadcast::Sender<FluidEvent>,
+    events: &mut Vec<FluidEvent>,
 ) {
     match cmd {
         Command::Inject {
             name,
             density,
-            area,
+            size,
+            metadata,
+            initial_layer,
+            initial_velocity,
+            initial_buoyancy,
+            priority,
             response_tx,
         } => {
-            let id = fluid.add_concept(name.clone(), density, area);
+            let dimensions = fluid.concept_dimensions(density, size);
+            let id = fluid.inject_concept(
+                name.clone(),
+                density,
+                dimensions.area,
+                initial_layer,
+                initial_velocity,
+                initial_buoyancy,
+            );
+            let mut layer = density;
+            if let Some(concept) = fluid.get_concept_mut(id) {
+                concept.metadata = metadata;
+                layer = concept.layer;
+            }
             info!("Injected concept '{}' with id {}", name, id);
 
             // Send event
-            let _ = event_tx.send(FluidEvent::ConceptInjected {
+            events.push(FluidEvent::ConceptInjected {
                 id,
-                name,
+                name: name.clone(),
                 density,
-                layer: density, // Initial layer = density
+                area: dimensions.area,
+                volume: dimensions.volume,
+                layer,
             });
 
+            // An urgent interruption breaks the fixation
+            if priority && fluid.thaw() {
+                info!("Priority concept '{}' thawed the fluid", name);
+                events.push(FluidEvent::Thaw);
+            }
+
             // Send response
-            let _ = response_tx.send(id);
+            let _ = response_tx.send((id, dimensions));
+        }
+
+        Command::Rename {
+            concept_id,
+            name,
+            response_tx,
+        } => {
+            let old = fluid.rename_concept(concept_id, name.clone());
+            match &old {
+                Some(old) => {
+                    info!("Renamed concept '{}' to '{}'", old, name);
+                    events.push(FluidEvent::ConceptRenamed {
+                        id: concept_id,
+                        old: old.clone(),
+                        new: name,
+                    });
+                }
+                None => warn!("Rename command for unknown concept: {}", concept_id),
+            }
+            let _ = response_tx.send(old);
+        }
+
+        Command::RemoveConcept {
+            concept_id,
+            response_tx,
+        } => {
+            let result = fluid.remove_concept(concept_id, events).map(|concept| {
+                info!("Removed concept '{}'", concept.name);
+                events.push(FluidEvent::ConceptRemoved {
+                    concept_id,
+                    concept_name: concept.name.clone(),
+                });
+                concept.name
+            });
+            if let Err(err) = &result {
+                warn!("Remove concept failed: {}", err);
+            }
+            let _ = response_tx.send(result);
+        }
+
+        Command::FocusAttention {
+            spotlight,
+            response_tx,
+        } => {
+            let result = fluid.focus_attention(spotlight.clone());
+            match &result {
+                Ok(in_focus) => {
+                    info!(
+                        "Attention focused at depth {} on {} concepts",
+                        spotlight.depth, in_focus
+                    );
+                    events.push(FluidEvent::AttentionFocused {
+                        id: spotlight.id,
+                        depth: spotlight.depth,
+                        radius: spotlight.radius,
+                        strength: spotlight.strength,
+                        duration: spotlight.remaining,
+                        concepts_in_focus: *in_focus,
+                    });
+                }
+                Err(err) => warn!("Attention focus refused: {}", err),
+            }
+            let _ = response_tx.send(result);
+        }
+
+        Command::Link { link, response_tx } => {
+            let result =
+                fluid.link_concepts(link.id_a, link.id_b, link.strength, link.rest_distance);
+            match &result {
+                Ok(index) => info!("Linked {} and {} (link {})", link.id_a, link.id_b, index),
+                Err(err) => warn!("Link failed: {}", err),
+            }
+            let _ = response_tx.send(result);
+        }
+
+        Command::Unlink { index, response_tx } => {
+            let result = fluid.unlink(index);
+            match &result {
+                Ok(_) => info!("Link {} removed", index),
+                Err(err) => warn!("Unlink failed: {}", err),
+            }
+            let _ = response_tx.send(result);
+        }
+
+        Command::Prune {
+            filter,
+            response_tx,
+        } => {
+            let mut released = Vec::new();
+            let ids = fluid.prune_concepts(&filter, &mut released);
+            if !ids.is_empty() {
+                info!("Pruned {} concepts", ids.len());
+                events.push(FluidEvent::ConceptsPruned {
+                    count: ids.len(),
+                    ids: ids.clone(),
+                });
+            }
+            events.extend(released);
+            let _ = response_tx.send(ids);
         }
 
         Command::Ballast {
             concept_id,
             weight_delta,
+            response_tx,
         } => {
-            if let Some(concept) = fluid.get_concept(concept_id) {
-                let name = concept.name.clone();
-                if fluid.benthic_expedition(concept_id, weight_delta) {
+            let result = fluid.benthic_expedition(concept_id, weight_delta);
+            match (&result, fluid.get_concept(concept_id)) {
+                (Ok(()), Some(concept)) => {
                     info!(
                         "Benthic expedition: '{}' ballasted with {}",
-                        name, weight_delta
+                        concept.name, weight_delta
                     );
-                    let _ = event_tx.send(FluidEvent::BenthicExpedition {
+                    events.push(FluidEvent::BenthicExpedition {
                         concept_id,
-                        concept_name: name,
+                        concept_name: concept.name.clone(),
                         ballast_amount: weight_delta,
                     });
                 }
-            } else {
-                warn!("Ballast command for unknown concept: {}", concept_id);
+                _ => warn!("Ballast command for unknown concept: {}", concept_id),
             }
+            let _ = response_tx.send(result);
         }
 
-        Command::ModulateBuoyancy { concept_id, delta } => {
-            fluid.modulate_buoyancy(concept_id, delta);
-            debug!("Modulated buoyancy for {} by {}", concept_id, delta);
+        Command::Submerge {
+            concept_id,
+            target_layer,
+            ballast,
+            response_tx,
+        } => {
+            let result = fluid.submerge(concept_id, target_layer, ballast);
+            match (&result, fluid.get_concept(concept_id)) {
+                (Ok(()), Some(concept)) => {
+                    info!(
+                        "Submerged '{}' to layer {} with ballast {}",
+                        concept.name, concept.layer, concept.ballast
+                    );
+                    events.push(FluidEvent::ConceptSubmerged {
+                        concept_id,
+                        concept_name: concept.name.clone(),
+                        target_layer: concept.layer,
+                        ballast: concept.ballast,
+                    });
+                }
+                _ => warn!("Submerge command for unknown concept: {}", concept_id),
+            }
+            let _ = response_tx.send(result);
+        }
+
+        Command::ModulateBuoyancy {
+            concept_id,
+            delta,
+            response_tx,
+        } => {
+            let result = fluid.modulate_buoyancy(concept_id, delta);
+            match &result {
+                Ok(()) => debug!("Modulated buoyancy for {} by {}", concept_id, delta),
+                Err(err) => warn!("Modulate buoyancy failed: {}", err),
+            }
+            let _ = response_tx.send(result);
         }
 
         Command::TriggerTectonic { pressure_threshold } => {
-            fluid.set_pressure_threshold(pressure_threshold);
+            events.extend(fluid.set_pressure_threshold(pressure_threshold));
             info!("Tectonic pressure threshold set to {}", pressure_threshold);
         }
 
         Command::Thaw => {
             if fluid.thaw() {
                 info!("Fluid thawed");
-                let _ = event_tx.send(FluidEvent::Thaw);
+                events.push(FluidEvent::Thaw);
             }
         }
 
-        Command::DeepBreath { strength } => {
-            fluid.deep_breath(strength);
+        Command::DeepBreath {
+            strength,
+            velocity_threshold,
+        } => {
+            fluid.deep_breath(strength, velocity_threshold);
             info!("Deep breath applied with strength {}", strength);
-            let _ = event_tx.send(FluidEvent::DeepBreath { strength });
+            events.push(FluidEvent::DeepBreath { strength });
+        }
+
+        Command::SetVentActive { index, active } => {
+            if fluid.set_core_truth_active(index, active) {
+                info!("Vent {} set active={}", index, active);
+            } else {
+                warn!("SetVentActive for unknown vent: {}", index);
+            }
         }
 
         Command::AddCoreTruth {
@@ -181,10 +535,13 @@ fn process_command(
             heat_output,
             depth,
             radius,
+            falloff,
         } => {
-            fluid.add_core_truth(name.clone(), heat_output, depth, radius);
+            fluid
+                .add_core_truth(name.clone(), heat_output, depth, radius)
+                .falloff = falloff;
             info!("Added core truth '{}' at depth {}", name, depth);
-            let _ = event_tx.send(FluidEvent::CoreTruthFormed {
+            events.push(FluidEvent::CoreTruthFormed {
                 name,
                 depth,
                 heat_output,
@@ -195,28 +552,32 @@ fn process_command(
         Command::FlashHeal {
             concepts,
             dilution_strength,
+            response_tx,
         } => {
-            let count = concepts.len();
-            let old_salinity = fluid.flash_heal(concepts, dilution_strength);
+            let outcome = fluid.flash_heal(concepts, dilution_strength);
             info!(
                 "Flash heal: {} concepts, salinity {} -> {}",
-                count,
-                old_salinity,
-                old_salinity * (1.0 - dilution_strength)
+                outcome.concepts_added, outcome.old_salinity, outcome.new_salinity
             );
-            let _ = event_tx.send(FluidEvent::FlashHeal {
-                concepts_added: count,
-                old_salinity,
-                new_salinity: old_salinity * (1.0 - dilution_strength),
+            events.push(FluidEvent::FlashHeal {
+                concepts_added: outcome.concepts_added,
+                old_salinity: outcome.old_salinity,
+                new_salinity: outcome.new_salinity,
+                broke_freeze: outcome.broke_freeze,
+                concept_ids: outcome.concept_ids.clone(),
             });
+            if let Some(response_tx) = response_tx {
+                let _ = response_tx.send(outcome);
+            }
         }
 
         Command::Precipitate {
             trait_index,
             new_concept_name,
             density,
-            area,
+            size,
         } => {
+            let area = fluid.concept_dimensions(density, size).area;
             if let Some((_, inherited)) =
                 fluid.precipitate(trait_index, new_concept_name.clone(), density, area)
             {
@@ -230,22 +591,56 @@ fn process_command(
                     "Precipitation: '{}' from trait '{}'",
                     new_concept_name, trait_name
                 );
-                let _ = event_tx.send(FluidEvent::Precipitation {
+                events.push(FluidEvent::Precipitation {
                     trait_name,
                     new_concept: new_concept_name,
                     inherited_integration: inherited,
+                    entry_layer: fluid.precipitation_entry_depth,
                 });
             }
         }
 
+        Command::Reimmerse {
+            trait_index,
+            response_tx,
+        } => {
+            let id = fluid.reimmerse_trait(trait_index);
+            if let Some(concept) = id.and_then(|id| fluid.get_concept(id)) {
+                info!(
+                    "Re-immersion: trait '{}' returned to the fluid",
+                    concept.name
+                );
+                events.push(FluidEvent::TraitReimmersed {
+                    concept_id: concept.id,
+                    name: concept.name.clone(),
+                    integration: concept.integration,
+                });
+            }
+            let _ = response_tx.send(id);
+        }
+
         Command::StartDivisionExperiment {
             dividend,
             divisor,
             salinity_boost,
+            trace,
+            keep_particles,
+            min_ticks,
+            max_velocity,
+            avg_velocity,
             response_tx,
         } => {
             let experiment_id =
                 fluid.start_division_experiment_with_salinity(dividend, divisor, salinity_boost);
+            if let Some(exp) = fluid.active_experiment.as_mut() {
+                exp.thresholds =
+                    exp.thresholds
+                        .with_overrides(min_ticks, max_velocity, avg_velocity);
+                if trace {
+                    exp.enable_trace();
+                }
+                exp.keep_particles = keep_particles;
+            }
             info!(
                 "Division experiment started: {} ÷ {} (id: {})",
                 dividend, divisor, experiment_id
@@ -253,7 +648,7 @@ fn process_command(
 
             // Get experiment details for event
             if let Some(exp) = fluid.get_experiment_status() {
-                let _ = event_tx.send(FluidEvent::DivisionExperimentStarted {
+                events.push(FluidEvent::DivisionExperimentStarted {
                     experiment_id,
                     dividend,
                     divisor,
@@ -270,6 +665,9 @@ fn process_command(
             heat_a,
             position_b,
             heat_b,
+            trace,
+            keep_particles,
+            falloff,
             response_tx,
         } => {
             let experiment_id = fluid.start_consensus_experiment(
@@ -278,6 +676,14 @@ fn process_command(
                 position_b.clone(),
                 heat_b,
             );
+            if let Some(exp) = fluid.consensus_reactor.active_experiment.as_mut() {
+                if trace {
+                    exp.enable_trace();
+                }
+                exp.keep_particles = keep_particles;
+                exp.vent_a.falloff = falloff;
+                exp.vent_b.falloff = falloff;
+            }
             info!(
                 "Consensus experiment started: '{}' vs '{}' (id: {})",
                 position_a, position_b, experiment_id
@@ -285,7 +691,7 @@ fn process_command(
 
             // Get experiment details for event
             if let Some(exp) = fluid.get_consensus_experiment() {
-                let _ = event_tx.send(FluidEvent::ConsensusExperimentStarted {
+                events.push(FluidEvent::ConsensusExperimentStarted {
                     experiment_id,
                     position_a,
                     position_b,
@@ -297,5 +703,528 @@ fn process_command(
 
             let _ = response_tx.send(experiment_id);
         }
+
+        Command::StartConsensusFromConcepts {
+            id_a,
+            id_b,
+            trace,
+            keep_particles,
+            falloff,
+            response_tx,
+        } => {
+            let experiment_id = fluid.start_consensus_from_concepts(id_a, id_b);
+            if experiment_id.is_some()
+                && let Some(exp) = fluid.consensus_reactor.active_experiment.as_mut()
+            {
+                if trace {
+                    exp.enable_trace();
+                }
+                exp.keep_particles = keep_particles;
+                exp.vent_a.falloff = falloff;
+                exp.vent_b.falloff = falloff;
+            }
+
+            if let Some(exp) = experiment_id.and_then(|_| fluid.get_consensus_experiment()) {
+                info!(
+                    "Consensus experiment started from concepts: '{}' vs '{}' (id: {})",
+                    exp.vent_a.position, exp.vent_b.position, exp.id
+                );
+                events.push(FluidEvent::ConsensusExperimentStarted {
+                    experiment_id: exp.id,
+                    position_a: exp.vent_a.position.clone(),
+                    position_b: exp.vent_b.position.clone(),
+                    heat_a: exp.vent_a.heat_output,
+                    heat_b: exp.vent_b.heat_output,
+                    probe_count: exp.probe_ids.len(),
+                });
+            }
+
+            let _ = response_tx.send(experiment_id);
+        }
+
+        Command::CrystallizeNow { response_tx } => {
+            let ore = fluid.crystallize_consensus_now();
+            match &ore {
+                Some(ore) => consensus_crystallized(fluid, ore, events),
+                None => warn!("No active consensus experiment to crystallize"),
+            }
+            let _ = response_tx.send(ore);
+        }
+
+        Command::UpdateQualityThresholds { patch, response_tx } => {
+            let reactor = &mut fluid.consensus_reactor;
+            let result = reactor.quality_thresholds.patched(&patch);
+            match &result {
+                Ok(thresholds) => {
+                    reactor.quality_thresholds = *thresholds;
+                    info!("Consensus quality thresholds set: {:?}", thresholds);
+                }
+                Err(err) => warn!("Consensus quality thresholds refused: {}", err),
+            }
+            let _ = response_tx.send(result);
+        }
+
+        Command::StartHeatmap {
+            interval,
+            bins,
+            response_tx,
+        } => {
+            heatmap.start(interval, bins);
+            info!(
+                "Heatmap recording every {} ticks into {} bins",
+                interval, bins
+            );
+            let _ = response_tx.send(());
+        }
+
+        Command::StopHeatmap { response_tx } => {
+            let rows = heatmap.stop();
+            info!("Heatmap recording stopped with {} rows", rows);
+            let _ = response_tx.send(rows);
+        }
+
+        Command::SetViscosity {
+            surface,
+            deep,
+            response_tx,
+        } => {
+            fluid.viscosity_surface = surface.unwrap_or(fluid.viscosity_surface);
+            fluid.viscosity_deep = deep.unwrap_or(fluid.viscosity_deep);
+            info!(
+                "Viscosity set: {} at the surface, {} at the floor",
+                fluid.viscosity_surface, fluid.viscosity_deep
+            );
+            let _ = response_tx.send((fluid.viscosity_surface, fluid.viscosity_deep));
+        }
+
+        Command::Advance { ticks, response_tx } => {
+            let mut advanced = Vec::new();
+            for _ in 0..ticks {
+                advanced.extend(step(fluid, heatmap));
+            }
+            info!("Advanced {} ticks to tick {}", ticks, fluid.tick_count);
+
+            events.extend(advanced.iter().cloned());
+            let _ = response_tx.send((fluid.tick_count, advanced));
+        }
+
+        Command::AddRule { rule, response_tx } => {
+            let on = rule.on.clone();
+            let result = fluid.rules.add(rule);
+            match &result {
+                Ok(index) => info!("Rule {} registered on '{}'", index, on),
+                Err(err) => warn!("Rule on '{}' refused: {}", on, err),
+            }
+            let _ = response_tx.send(result);
+        }
+
+        Command::RemoveRule { index, response_tx } => {
+            let removed = fluid.rules.remove(index);
+            if removed.is_some() {
+                info!("Rule {} removed", index);
+            }
+            let _ = response_tx.send(removed);
+        }
+
+        Command::AddWave { wave, response_tx } => {
+            info!(
+                "Persistent wave {} added (divisor {}, amplitude {})",
+                wave.id, wave.frequency, wave.amplitude
+            );
+            fluid.add_persistent_wave(wave);
+            let _ = response_tx.send(());
+        }
+
+        Command::RemoveWave { id, response_tx } => {
+            let removed = fluid.remove_persistent_wave(id);
+            if removed.is_some() {
+                info!("Persistent wave {} removed", id);
+            }
+            let _ = response_tx.send(removed);
+        }
+
+        #[cfg(test)]
+        Command::Panic => panic!("forced panic at tick {}", fluid.tick_count),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::oneshot;
+
+    use crate::simulation::{ConceptSize, QualityThresholdsPatch, Rule};
+
+    #[tokio::test]
+    async fn test_panic_restores_without_undoing_answered_commands() {
+        let (state, channels) = AppState::new(ConceptFluid::default());
+        let mut events = state.event_tx.subscribe();
+        tokio::spawn(run_simulation_loop(state.fluid.clone(), channels));
+
+        // Answered well before the next periodic copy
+        let (response_tx, response_rx) = oneshot::channel();
+        state
+            .command_tx
+            .send(Command::SetViscosity {
+                surface: Some(0.3),
+                deep: Some(0.7),
+                response_tx,
+            })
+            .await
+            .unwrap();
+        response_rx.await.unwrap();
+        state.command_tx.send(Command::Panic).await.unwrap();
+
+        let (tick, message, restored_tick) = loop {
+            let event = tokio::time::timeout(Duration::from_secs(5), events.recv())
+                .await
+                .expect("no simulation error reported")
+                .unwrap();
+            if let FluidEvent::SimulationError {
+                tick,
+                message,
+                restored_tick,
+            } = event
+            {
+                break (tick, message, restored_tick);
+            }
+        };
+        assert_eq!(message, format!("forced panic at tick {}", tick));
+        assert!(restored_tick <= tick);
+        {
+            let fluid = state.fluid.read().await;
+            assert_eq!(
+                (fluid.viscosity_surface, fluid.viscosity_deep),
+                (0.3, 0.7),
+                "the answered command survives the restore"
+            );
+        }
+
+        // Still ticking and answering commands
+        let (response_tx, response_rx) = oneshot::channel();
+        state
+            .command_tx
+            .send(Command::Advance {
+                ticks: 10,
+                response_tx,
+            })
+            .await
+            .unwrap();
+        let (tick_count, _) = tokio::time::timeout(Duration::from_secs(5), response_rx)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(tick_count >= 10, "{}", tick_count);
+    }
+
+    #[test]
+    fn test_advance_matches_real_loop_ticks() {
+        let mut realtime = ConceptFluid::default();
+        realtime.add_core_truth("warmth".to_string(), 1.0, 0.9, 0.3);
+        let light = realtime.add_concept("hope".to_string(), 0.2, 0.5);
+        let heavy = realtime.add_concept("dread".to_string(), 0.9, 0.5);
+        let mut batched = realtime.clone();
+
+        // 100 iterations of the real loop body
+        for _ in 0..100 {
+            step(&mut realtime, &mut Heatmap::default());
+        }
+
+        // One Advance command covering the same span
+        let (response_tx, mut response_rx) = oneshot::channel();
+        process_command(
+            &mut batched,
+            &mut Heatmap::default(),
+            Command::Advance {
+                ticks: 100,
+                response_tx,
+            },
+            &mut Vec::new(),
+        );
+        let (tick_count, _events) = response_rx.try_recv().unwrap();
+
+        assert_eq!(tick_count, 100);
+        assert_eq!(batched.tick_count, realtime.tick_count);
+        for id in [light, heavy] {
+            assert_eq!(batched.concepts[&id].layer, realtime.concepts[&id].layer);
+            assert_eq!(
+                batched.concepts[&id].velocity,
+                realtime.concepts[&id].velocity
+            );
+        }
+        assert_eq!(batched.ore_deposits.len(), realtime.ore_deposits.len());
+    }
+
+    #[test]
+    fn test_priority_inject_breaks_a_freeze() {
+        let mut fluid = ConceptFluid::default();
+        let fixation = fluid.add_concept("fixation".to_string(), 0.0, 0.5);
+        for _ in 0..180 {
+            step(&mut fluid, &mut Heatmap::default());
+        }
+        assert!(fluid.is_frozen);
+
+        let inject = |priority| {
+            let (response_tx, _) = oneshot::channel();
+            Command::Inject {
+                name: "fire_alarm".to_string(),
+                density: 0.2,
+                size: ConceptSize::Area(0.5),
+                metadata: Default::default(),
+                initial_layer: Some(0.8),
+                initial_velocity: None,
+                initial_buoyancy: None,
+                priority,
+                response_tx,
+            }
+        };
+
+        // An ordinary thought is held down by the freeze
+        let mut ordinary = fluid.clone();
+        let mut events = Vec::new();
+        process_command(
+            &mut ordinary,
+            &mut Heatmap::default(),
+            inject(false),
+            &mut events,
+        );
+        assert_eq!(events.len(), 1);
+        assert!(ordinary.is_frozen);
+
+        let mut events = Vec::new();
+        process_command(
+            &mut fluid,
+            &mut Heatmap::default(),
+            inject(true),
+            &mut events,
+        );
+        assert!(matches!(
+            events[..],
+            [FluidEvent::ConceptInjected { .. }, FluidEvent::Thaw]
+        ));
+        assert!(!fluid.is_frozen);
+        assert!(!fluid.concepts[&fixation].is_frozen);
+
+        let layer = |fluid: &ConceptFluid| {
+            fluid
+                .concepts
+                .values()
+                .find(|c| c.name == "fire_alarm")
+                .unwrap()
+                .layer
+        };
+        for _ in 0..90 {
+            step(&mut fluid, &mut Heatmap::default());
+            step(&mut ordinary, &mut Heatmap::default());
+        }
+        assert!(!fluid.is_frozen);
+        assert!(layer(&fluid) < 0.75, "rises freely: {}", layer(&fluid));
+        assert!(layer(&ordinary) > 0.8, "held down: {}", layer(&ordinary));
+    }
+
+    #[test]
+    fn test_evaporation_rule_precipitates_a_new_thought() {
+        let mut fluid = ConceptFluid::default();
+        let rule = Rule::new(
+            "concept_evaporated".to_string(),
+            serde_json::json!({
+                "command": "precipitate",
+                "trait_index": "{trait_index}",
+                "new_concept_name": "echo_of_{name}",
+                "density": 0.4,
+                "area": 0.5,
+            }),
+        )
+        .unwrap();
+        let (response_tx, mut response_rx) = oneshot::channel();
+        process_command(
+            &mut fluid,
+            &mut Heatmap::default(),
+            Command::AddRule { rule, response_tx },
+            &mut Vec::new(),
+        );
+        assert_eq!(response_rx.try_recv().unwrap(), Ok(0));
+
+        let id = fluid.add_concept("patience".to_string(), 0.5, 0.5);
+        let integration = fluid.evaporation_threshold + 0.5;
+        let concept = fluid.get_concept_mut(id).unwrap();
+        concept.layer = 0.1;
+        concept.integration = integration;
+
+        let events = step(&mut fluid, &mut Heatmap::default());
+        assert!(
+            events
+                .iter()
+                .any(|e| matches!(e, FluidEvent::ConceptEvaporated { id: evaporated, .. } if *evaporated == id))
+        );
+        assert!(events.iter().any(|e| matches!(
+            e,
+            FluidEvent::Precipitation { trait_name, new_concept, .. }
+                if trait_name == "patience" && new_concept == "echo_of_patience"
+        )));
+        let echo = fluid
+            .concepts
+            .values()
+            .find(|c| c.name == "echo_of_patience")
+            .expect("the rule should precipitate a new thought");
+        assert!(echo.integration > 0.0, "inherits from the trait");
+        assert_eq!(fluid.rules.rules[0].fired, 1);
+    }
+
+    #[test]
+    fn test_rules_triggering_each_other_stop_at_depth_limit() {
+        let mut fluid = ConceptFluid::default();
+        for on in ["concept_evaporated", "deep_breath"] {
+            let rule = Rule::new(
+                on.to_string(),
+                serde_json::json!({ "command": "deep_breath", "strength": 0.2 }),
+            )
+            .unwrap();
+            fluid.rules.add(rule).unwrap();
+        }
+        let id = fluid.add_concept("patience".to_string(), 0.5, 0.5);
+        let integration = fluid.evaporation_threshold + 0.5;
+        let concept = fluid.get_concept_mut(id).unwrap();
+        concept.layer = 0.1;
+        concept.integration = integration;
+
+        let events = step(&mut fluid, &mut Heatmap::default());
+        let breaths = events
+            .iter()
+            .filter(|e| matches!(e, FluidEvent::DeepBreath { .. }))
+            .count();
+        assert_eq!(
+            breaths, MAX_RULE_DEPTH,
+            "one breath per round, then cut off"
+        );
+    }
+
+    /// Request span → command span → experiment span, closed at settlement
+    #[cfg(feature = "otel")]
+    #[test]
+    fn test_division_spans_follow_the_request() {
+        use axum::{Json, extract::State};
+        use opentelemetry::trace::SpanId;
+        use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+        use tracing_subscriber::layer::SubscriberExt;
+
+        use crate::api::handlers::start_division;
+        use crate::state::AppState;
+
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let subscriber =
+            tracing_subscriber::registry().with(crate::telemetry::otel_layer(&provider));
+
+        let (experiment_id, ticks_run) = tracing::subscriber::with_default(subscriber, || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .start_paused(true)
+                .build()
+                .unwrap();
+            runtime.block_on(async {
+                let (state, channels) = AppState::new(ConceptFluid::default());
+                let state = Arc::new(state);
+                let mut events = state.event_tx.subscribe();
+                tokio::spawn(run_simulation_loop(state.fluid.clone(), channels));
+
+                let request =
+                    serde_json::from_value(serde_json::json!({ "dividend": 16, "divisor": 8 }))
+                        .unwrap();
+                let Json(started) = start_division(State(state.clone()), Json(request))
+                    .await
+                    .unwrap();
+                tokio::time::timeout(Duration::from_secs(60), async {
+                    while !matches!(
+                        events.recv().await,
+                        Ok(FluidEvent::DivisionExperimentComplete { .. })
+                    ) {}
+                })
+                .await
+                .expect("experiment should settle");
+                (started.experiment_id, state.fluid.read().await.tick_count)
+            })
+        });
+
+        let spans = exporter.get_finished_spans().unwrap();
+        let find = |name: &str| -> &SpanData {
+            spans
+                .iter()
+                .find(|span| span.name == name)
+                .unwrap_or_else(|| panic!("no {} span", name))
+        };
+        let attribute = |span: &SpanData, key: &str| {
+            span.attributes
+                .iter()
+                .find(|kv| kv.key.as_str() == key)
+                .map(|kv| kv.value.to_string())
+        };
+        let request = find("divide");
+        let command = find("command");
+        let experiment = find("division_experiment");
+
+        assert_eq!(request.parent_span_id, SpanId::INVALID);
+        assert_eq!(command.parent_span_id, request.span_context.span_id());
+        assert_eq!(experiment.parent_span_id, command.span_context.span_id());
+        assert_eq!(
+            experiment.span_context.trace_id(),
+            request.span_context.trace_id()
+        );
+        assert_eq!(
+            attribute(command, "kind").as_deref(),
+            Some("start_division_experiment")
+        );
+        let experiment_id = Some(experiment_id.to_string());
+        assert_eq!(attribute(request, "experiment_id"), experiment_id);
+        assert_eq!(attribute(experiment, "experiment_id"), experiment_id);
+        assert_eq!(
+            attribute(experiment, "settled_by").as_deref(),
+            Some("settled")
+        );
+
+        // Only a sample of ticks get spans
+        let tick_spans = spans.iter().filter(|span| span.name == "tick").count() as u64;
+        assert!(tick_spans > 0);
+        assert!(tick_spans <= ticks_run / TICK_SPAN_EVERY + 1);
+    }
+
+    #[test]
+    fn test_threshold_patches_merge_with_the_cutoffs_in_effect() {
+        let mut fluid = ConceptFluid::default();
+        let mut update = |patch| {
+            let (response_tx, mut response_rx) = oneshot::channel();
+            process_command(
+                &mut fluid,
+                &mut Heatmap::default(),
+                Command::UpdateQualityThresholds { patch, response_tx },
+                &mut Vec::new(),
+            );
+            response_rx.try_recv().unwrap()
+        };
+
+        // Two patches queued back to back each keep the other's change
+        update(QualityThresholdsPatch {
+            foundational: Some(0.7),
+            ..Default::default()
+        })
+        .unwrap();
+        let thresholds = update(QualityThresholdsPatch {
+            weak_signal: Some(0.1),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(thresholds.foundational, 0.7);
+        assert_eq!(thresholds.weak_signal, 0.1);
+
+        assert!(
+            update(QualityThresholdsPatch {
+                noise: Some(0.9),
+                ..Default::default()
+            })
+            .is_err()
+        );
+        assert_eq!(fluid.consensus_reactor.quality_thresholds, thresholds);
     }
 }
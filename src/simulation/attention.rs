@@ -0,0 +1,169 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::energy::EnergyFlows;
+use super::fluid::ConceptFluid;
+
+/// Spotlights that can be lit at once
+pub const MAX_SPOTLIGHTS: usize = 16;
+
+/// Focused attention on a depth window: for as long as it lasts, concepts
+/// inside it gain integration on top of whatever their motion gives them.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Spotlight {
+    pub id: Uuid,
+    /// Centre of the window (0.0 = surface)
+    pub depth: f32,
+    /// Half-width of the window
+    pub radius: f32,
+    /// Integration gained per second by each concept inside
+    pub strength: f32,
+    /// Seconds of focus left
+    pub remaining: f32,
+}
+
+impl Spotlight {
+    pub fn new(depth: f32, radius: f32, strength: f32, duration: f32) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            depth,
+            radius,
+            strength,
+            remaining: duration,
+        }
+    }
+
+    pub fn covers(&self, layer: f32) -> bool {
+        (layer - self.depth).abs() <= self.radius
+    }
+}
+
+impl ConceptFluid {
+    /// Focus attention on a depth window until the spotlight runs out.
+    /// Returns how many concepts it lights up now. Fails while
+    /// `MAX_SPOTLIGHTS` are already lit.
+    pub fn focus_attention(&mut self, spotlight: Spotlight) -> Result<usize, String> {
+        if self.spotlights.len() >= MAX_SPOTLIGHTS {
+            return Err(format!(
+                "At most {} spotlights can be lit at once",
+                MAX_SPOTLIGHTS
+            ));
+        }
+        let in_focus = self
+            .concepts
+            .values()
+            .filter(|c| c.participates_in_attention() && spotlight.covers(c.layer))
+            .count();
+        self.spotlights.push(spotlight);
+        Ok(in_focus)
+    }
+
+    /// Deepen the concepts under each spotlight, then let them burn down.
+    /// Overlapping spotlights add up, and the gain counts toward this
+    /// tick's integration like any other.
+    pub(crate) fn apply_spotlights(&mut self, dt: f32, energy_flows: &mut EnergyFlows) {
+        for spotlight in &self.spotlights {
+            let gain = spotlight.strength * dt.min(spotlight.remaining);
+            for concept in self.concepts.values_mut() {
+                if concept.participates_in_attention() && spotlight.covers(concept.layer) {
+                    concept.integration += gain;
+                    self.total_integration += gain;
+                    energy_flows.integration_gained += gain;
+                }
+            }
+        }
+        for spotlight in &mut self.spotlights {
+            spotlight.remaining -= dt;
+        }
+        self.spotlights.retain(|s| s.remaining > 0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spotlit_concepts_integrate_faster_while_focused() {
+        let mut fluid = ConceptFluid::default();
+        let deep = fluid.add_concept("old_wound".to_string(), 0.8, 0.5);
+        let shallow = fluid.add_concept("passing_worry".to_string(), 0.2, 0.5);
+
+        let in_focus = fluid.focus_attention(Spotlight::new(0.8, 0.2, 0.5, 1.0));
+        assert_eq!(in_focus, Ok(1));
+
+        for _ in 0..60 {
+            fluid.update(1.0 / 60.0);
+        }
+        let integration = |fluid: &ConceptFluid, id| fluid.get_concept(id).unwrap().integration;
+        let (deep_focused, shallow_focused) =
+            (integration(&fluid, deep), integration(&fluid, shallow));
+        assert!(
+            deep_focused > shallow_focused + 0.4,
+            "focused {} vs unfocused {}",
+            deep_focused,
+            shallow_focused
+        );
+
+        // Once the spotlight is gone, the deep thought gets no extra help:
+        // it integrates no faster than an unfocused twin rising the same way
+        let mut twin = ConceptFluid::default();
+        let twin_deep = twin.add_concept("old_wound".to_string(), 0.8, 0.5);
+        twin.add_concept("passing_worry".to_string(), 0.2, 0.5);
+        for _ in 0..60 {
+            twin.update(1.0 / 60.0);
+        }
+        let twin_focused = integration(&twin, twin_deep);
+        for _ in 0..60 {
+            fluid.update(1.0 / 60.0);
+            twin.update(1.0 / 60.0);
+        }
+        assert!(fluid.spotlights.is_empty(), "the focus window has passed");
+        let deep_gain = integration(&fluid, deep) - deep_focused;
+        let twin_gain = integration(&twin, twin_deep) - twin_focused;
+        assert!(
+            deep_gain < twin_gain + 0.05,
+            "after focus {} vs never focused {}",
+            deep_gain,
+            twin_gain
+        );
+    }
+
+    #[test]
+    fn test_spotlight_gain_is_booked_as_integration() {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_concept("resting".to_string(), 0.5, 0.5);
+        fluid
+            .focus_attention(Spotlight::new(0.5, 0.5, 2.0, 1.0))
+            .unwrap();
+        let (integration_before, salinity_before) = (fluid.total_integration, fluid.salinity);
+
+        fluid.update(1.0 / 60.0);
+
+        let gained = fluid.energy.last_tick.flows.integration_gained;
+        assert!(
+            gained >= 2.0 / 60.0 - 1e-6,
+            "spotlight gain booked: {}",
+            gained
+        );
+        assert!((fluid.total_integration - integration_before - gained).abs() < 1e-5);
+        assert!(fluid.salinity > salinity_before);
+    }
+
+    #[test]
+    fn test_spotlights_are_capped() {
+        let mut fluid = ConceptFluid::default();
+        for _ in 0..MAX_SPOTLIGHTS {
+            fluid
+                .focus_attention(Spotlight::new(0.5, 0.1, 1.0, 5.0))
+                .unwrap();
+        }
+        assert!(
+            fluid
+                .focus_attention(Spotlight::new(0.5, 0.1, 1.0, 5.0))
+                .is_err()
+        );
+        assert_eq!(fluid.spotlights.len(), MAX_SPOTLIGHTS);
+    }
+}
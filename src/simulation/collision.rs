@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use super::concept::ConceptId;
+use super::fluid::ConceptFluid;
+use crate::state::events::FluidEvent;
+
+/// Depth gap within which a riser and a sinker count as meeting
+const COLLISION_TOLERANCE: f32 = 0.02;
+/// Share of the integration gap that flows from the more to the less
+/// integrated thought when they collide
+const COLLISION_INTEGRATION_SHARE: f32 = 0.1;
+
+/// One live concept's path through a tick
+struct Path {
+    id: ConceptId,
+    from: f32,
+    to: f32,
+}
+
+impl Path {
+    fn reach(&self) -> (f32, f32) {
+        let half = COLLISION_TOLERANCE / 2.0;
+        (self.from.min(self.to) - half, self.from.max(self.to) + half)
+    }
+}
+
+/// Whether two paths met this tick: they crossed, or the gap between them
+/// closed to within tolerance.
+fn met(a: &Path, b: &Path) -> bool {
+    let crossed = (a.from - b.from).signum() != (a.to - b.to).signum();
+    let closed =
+        (a.to - b.to).abs() <= COLLISION_TOLERANCE && (a.from - b.from).abs() > COLLISION_TOLERANCE;
+    crossed || closed
+}
+
+impl ConceptFluid {
+    /// Live concept depths before the physics pass moves them.
+    pub(crate) fn live_layers(&self) -> HashMap<ConceptId, f32> {
+        self.concepts
+            .values()
+            .filter(|c| !c.has_evaporated)
+            .map(|c| (c.id, c.layer))
+            .collect()
+    }
+
+    /// Find every riser and sinker whose paths met since `layers_before`,
+    /// with a `ConceptCollision` for each pair. System concepts never collide.
+    /// With `collision_exchange_enabled` the pair evens out a little of their
+    /// integration; the total is unchanged.
+    pub(crate) fn detect_collisions(
+        &mut self,
+        layers_before: &HashMap<ConceptId, f32>,
+    ) -> Vec<FluidEvent> {
+        let mut paths: Vec<Path> = layers_before
+            .iter()
+            .filter_map(|(&id, &from)| {
+                let concept = self.concepts.get(&id)?;
+                concept.participates_in_attention().then_some(Path {
+                    id,
+                    from,
+                    to: concept.layer,
+                })
+            })
+            .filter(|p| p.from != p.to)
+            .collect();
+        paths.sort_by(|a, b| a.reach().0.total_cmp(&b.reach().0).then(a.id.cmp(&b.id)));
+
+        // Sweep: only paths whose reaches overlap can have met
+        let mut pairs = Vec::new();
+        for (i, a) in paths.iter().enumerate() {
+            let end = a.reach().1;
+            for b in paths[i + 1..].iter().take_while(|b| b.reach().0 <= end) {
+                let opposing = (a.to - a.from) * (b.to - b.from) < 0.0;
+                if opposing && met(a, b) {
+                    // The riser (moving toward the surface) comes first
+                    pairs.push(if a.to < a.from {
+                        (a.id, b.id)
+                    } else {
+                        (b.id, a.id)
+                    });
+                }
+            }
+        }
+
+        pairs
+            .into_iter()
+            .filter_map(|(riser, sinker)| {
+                let (a, b) = (self.concepts.get(&riser)?, self.concepts.get(&sinker)?);
+                let relative_velocity = (a.velocity - b.velocity).abs();
+                if self.collision_exchange_enabled {
+                    let transfer =
+                        (a.integration - b.integration) * COLLISION_INTEGRATION_SHARE / 2.0;
+                    self.concepts.get_mut(&riser)?.integration -= transfer;
+                    self.concepts.get_mut(&sinker)?.integration += transfer;
+                }
+                Some(FluidEvent::ConceptCollision {
+                    a: riser,
+                    b: sinker,
+                    relative_velocity,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::ConceptRole;
+
+    #[test]
+    fn test_rising_and_sinking_concepts_collide_when_they_cross() {
+        let mut fluid = ConceptFluid::default();
+        let riser = fluid.add_concept("hope".to_string(), 0.1, 0.5);
+        let sinker = fluid.add_concept("doubt".to_string(), 0.9, 0.5);
+        let bystander = fluid.add_concept("calm".to_string(), 0.5, 0.5);
+        for (id, layer, velocity) in [
+            (riser, 0.8, -1.0),
+            (sinker, 0.2, 1.0),
+            (bystander, 0.5, 0.0),
+        ] {
+            let concept = fluid.get_concept_mut(id).unwrap();
+            concept.layer = layer;
+            concept.velocity = velocity;
+        }
+
+        let collisions: Vec<_> = (0..60)
+            .flat_map(|_| fluid.update(1.0 / 60.0))
+            .filter_map(|event| match event {
+                FluidEvent::ConceptCollision {
+                    a,
+                    b,
+                    relative_velocity,
+                } => Some((a, b, relative_velocity)),
+                _ => None,
+            })
+            .collect();
+
+        let (_, _, relative_velocity) = collisions
+            .iter()
+            .find(|(a, b, _)| (*a, *b) == (riser, sinker))
+            .expect("riser and sinker should collide");
+        assert!(*relative_velocity > 0.0);
+    }
+
+    #[test]
+    fn test_collision_exchange_is_opt_in_and_skips_system_concepts() {
+        let collide = |exchange: bool, role: ConceptRole| {
+            let mut fluid = ConceptFluid {
+                collision_exchange_enabled: exchange,
+                ..ConceptFluid::default()
+            };
+            let riser = fluid.add_concept("insight".to_string(), 0.5, 0.5);
+            let sinker = fluid.add_concept("confusion".to_string(), 0.5, 0.5);
+            for (id, layer, integration) in [(riser, 0.6, 1.0), (sinker, 0.4, 0.0)] {
+                let concept = fluid.get_concept_mut(id).unwrap();
+                concept.layer = layer;
+                concept.integration = integration;
+            }
+            fluid.get_concept_mut(sinker).unwrap().role = role;
+            let before = fluid.live_layers();
+            fluid.get_concept_mut(riser).unwrap().layer = 0.4;
+            fluid.get_concept_mut(sinker).unwrap().layer = 0.6;
+
+            let events = fluid.detect_collisions(&before);
+            (events.len(), fluid.concepts[&riser].integration)
+        };
+
+        assert_eq!(collide(false, ConceptRole::Thought), (1, 1.0));
+        assert_eq!(collide(true, ConceptRole::Thought), (1, 0.95));
+        assert_eq!(collide(true, ConceptRole::ProbeBubble), (0, 1.0));
+    }
+}
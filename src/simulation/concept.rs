@@ -1,10 +1,77 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use uuid::Uuid;
 
 /// Unique identifier for a concept (thought) in the fluid.
 /// Uses UUID for API ergonomics - each concept has a "soul fingerprint".
 pub type ConceptId = Uuid;
 
+/// Below this density a concept's area no longer follows from its volume
+/// (`volume / density` would explode); it spreads to twice its volume instead
+const WEIGHTLESS_DENSITY: f32 = 0.01;
+
+/// How a client sizes a new concept: the cognitive volume it occupies, or
+/// the surface area the physics uses directly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConceptSize {
+    Volume(f32),
+    Area(f32),
+}
+
+impl ConceptSize {
+    /// Volume of a concept given neither a volume nor an area
+    pub const DEFAULT_VOLUME: f32 = 0.5;
+
+    /// From a request's mutually exclusive `volume` and `area` fields. The
+    /// API rejects both being set; should it happen anyway, `area` wins.
+    pub fn from_fields(volume: Option<f32>, area: Option<f32>) -> Self {
+        match (volume, area) {
+            (_, Some(area)) => Self::Area(area),
+            (Some(volume), None) => Self::Volume(volume),
+            (None, None) => Self::Volume(Self::DEFAULT_VOLUME),
+        }
+    }
+}
+
+/// The size a concept was given, as both area and volume.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, JsonSchema)]
+pub struct ConceptDimensions {
+    pub area: f32,
+    pub volume: f32,
+}
+
+/// What a concept is in the fluid for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConceptRole {
+    /// An ordinary thought
+    #[default]
+    Thought,
+    /// A division bubble or consensus probe, owned by its experiment
+    ProbeBubble,
+}
+
+/// How a concept came into the fluid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConceptOrigin {
+    /// Injected by a client, ingest feed or rule (also a re-immersed trait
+    /// whose concept had been removed)
+    #[default]
+    Injected,
+    /// A solution catalyzed from a ballasted problem and ore
+    Synthesized,
+    /// Spawned by a character trait
+    Precipitated,
+    /// Fresh input from a flash heal
+    FlashHeal,
+    /// A consensus insight fed back from its source concepts
+    ConsensusInsight,
+    /// A division bubble or consensus probe (kept ones included)
+    Experiment,
+}
+
 /// A concept (thought) in the fluid medium with physical properties.
 /// Concepts float, sink, freeze, evaporate, and break through the surface
 /// based on their density, buoyancy, and accumulated integration.
@@ -14,7 +81,8 @@ pub struct Concept {
     pub name: String,
     /// Intrinsic weight (0.0 to 1.0) - how "heavy" this thought is
     pub density: f32,
-    /// Current effective buoyancy - how much it wants to rise
+    /// Current effective buoyancy - how much it wants to rise, which sets
+    /// the layer it settles toward
     pub buoyancy: f32,
     /// Continuous depth (0.0 = surface, 1.0 = bottom)
     pub layer: f32,
@@ -22,10 +90,20 @@ pub struct Concept {
     pub velocity: f32,
     /// "Surface area" - connectivity to other concepts (affects drag)
     pub area: f32,
-    /// Has this concept triggered an action?
+    /// Has this concept triggered an action? Cleared again when it re-arms
     pub has_broken_surface: bool,
+    /// How many times it has broken through
+    #[serde(default)]
+    pub breakthrough_count: u32,
+    /// Seconds spent below the re-arm depth without a break since breaking
+    /// through
+    #[serde(default)]
+    pub rearm_progress: f32,
     /// How long this concept has been at the surface (layer ≈ 0)
     pub time_at_surface: f32,
+    /// Seconds left after breaking through during which it cannot freeze
+    #[serde(default)]
+    pub grace_remaining: f32,
     /// Is this concept causing a freeze?
     pub is_frozen: bool,
     /// "Internal heat" - accumulated understanding/memory
@@ -38,34 +116,107 @@ pub struct Concept {
     pub ballast: f32,
     /// Was this synthesized from problem + ore?
     pub is_solution: bool,
+    /// Consecutive surface bounces without a breakthrough
+    #[serde(default)]
+    pub bounce_count: u32,
+    /// Highest kinetic energy among those bounces
+    #[serde(default)]
+    pub best_bounce_energy: f32,
+    /// How long this concept has been submerged since its last bounce
+    #[serde(default)]
+    pub time_submerged: f32,
+    /// Creation order within its fluid (see `ConceptFluid::next_concept_seq`)
+    #[serde(default)]
+    pub seq: u64,
+    /// Fluid tick it was created at
+    #[serde(default)]
+    pub born_tick: u64,
+    /// Client metadata given at injection (e.g. the ingest `source`)
+    #[serde(default)]
+    pub metadata: Map<String, Value>,
+    /// Experiment-owned concepts take no part in freezing or attention
+    #[serde(default)]
+    pub role: ConceptRole,
+    /// How it came into the fluid
+    #[serde(default)]
+    pub origin: ConceptOrigin,
 }
 
 impl Concept {
-    /// Create a new concept with default physics state.
+    /// Create a new concept with default physics state. Buoyancy and layer
+    /// both start at its density, but are independent: buoyancy drives the
+    /// layer it settles at, the layer is only where it starts.
     pub fn new(id: ConceptId, name: String, density: f32, area: f32) -> Self {
         Self {
             id,
             name,
             density,
-            buoyancy: density,         // Start with buoyancy = density
-            layer: density,            // Initial layer matches density
-            velocity: 0.0,             // Start at rest
-            area,                      // Connectivity/surface area
-            has_broken_surface: false, // Not yet activated
-            time_at_surface: 0.0,      // No time at surface yet
-            is_frozen: false,          // Not frozen
-            integration: 0.0,          // No accumulated understanding yet
-            eddy_scale: 0.0,           // No turbulent motion yet
-            has_evaporated: false,     // Still in fluid state
-            ballast: 0.0,              // No ballast
-            is_solution: false,        // Not a solution
+            buoyancy: density,               // Start with buoyancy = density
+            layer: density,                  // Initial layer matches density
+            velocity: 0.0,                   // Start at rest
+            area,                            // Connectivity/surface area
+            has_broken_surface: false,       // Not yet activated
+            breakthrough_count: 0,           // Never acted on
+            rearm_progress: 0.0,             // Nothing to re-arm
+            time_at_surface: 0.0,            // No time at surface yet
+            grace_remaining: 0.0,            // No breakthrough to recover from
+            is_frozen: false,                // Not frozen
+            integration: 0.0,                // No accumulated understanding yet
+            eddy_scale: 0.0,                 // No turbulent motion yet
+            has_evaporated: false,           // Still in fluid state
+            ballast: 0.0,                    // No ballast
+            is_solution: false,              // Not a solution
+            bounce_count: 0,                 // No near-misses yet
+            best_bounce_energy: 0.0,         // No bounce energy recorded
+            time_submerged: 0.0,             // Not tracking submersion yet
+            seq: 0,                          // Assigned by the fluid on insertion
+            born_tick: 0,                    // Likewise
+            metadata: Map::new(),            // No client metadata
+            role: ConceptRole::Thought,      // An ordinary thought
+            origin: ConceptOrigin::Injected, // Set by whatever created it
         }
     }
 
     /// Derive volume from density and area.
     /// Volume represents "how much space this thought occupies in consciousness".
     pub fn volume(&self) -> f32 {
-        self.density * self.area
+        Self::volume_from_area(self.density, self.area)
+    }
+
+    /// Density including any benthic ballast it is carrying, capped at 1.0.
+    pub fn effective_density(&self) -> f32 {
+        (self.density + self.ballast).min(1.0)
+    }
+
+    /// Area of a concept of `density` occupying `volume`, before the fluid's
+    /// area bounds apply (see `ConceptFluid::concept_dimensions`).
+    pub fn area_from_volume(density: f32, volume: f32) -> f32 {
+        if density > WEIGHTLESS_DENSITY {
+            volume / density
+        } else {
+            volume * 2.0
+        }
+    }
+
+    /// Inverse of `area_from_volume`.
+    pub fn volume_from_area(density: f32, area: f32) -> f32 {
+        if density > WEIGHTLESS_DENSITY {
+            area * density
+        } else {
+            area / 2.0
+        }
+    }
+
+    /// Is this concept experiment machinery rather than a thought?
+    pub fn is_system(&self) -> bool {
+        self.role != ConceptRole::Thought
+    }
+
+    /// Does this concept count toward freezing, turbulence, evaporation and
+    /// what the mind is attending to? Not once evaporated, nor if it's a
+    /// system concept.
+    pub fn participates_in_attention(&self) -> bool {
+        !self.has_evaporated && !self.is_system()
     }
 
     /// Get the current status of this concept as a string.
@@ -1,7 +1,13 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use tracing::Span;
 use uuid::Uuid;
 
 use super::concept::ConceptId;
+use super::core_truth::FalloffProfile;
+use super::trace::ExperimentTrace;
 
 // ============================================================================
 // PHASE TRANSITION EXTRACTION
@@ -158,7 +164,7 @@ impl PhaseStructure {
         // Property 3: Asymmetric Dominance
         // If one territory is much larger, that position has "structural advantage"
         let territory_ratio = self.vent_a_territory / self.vent_b_territory.max(0.001);
-        if territory_ratio > 2.0 || territory_ratio < 0.5 {
+        if !(0.5..=2.0).contains(&territory_ratio) {
             let (dominant, dominated, ratio) = if territory_ratio > 1.0 {
                 ("Position A", "Position B", territory_ratio)
             } else {
@@ -197,7 +203,7 @@ impl PhaseStructure {
                 deep_probes.iter().map(|p| p.local_density).sum::<f32>() / deep_probes.len() as f32;
 
             let density_ratio = surface_density / deep_density.max(0.001);
-            if density_ratio > 1.5 || density_ratio < 0.67 {
+            if !(0.67..=1.5).contains(&density_ratio) {
                 let (sparse, dense) = if density_ratio > 1.0 {
                     ("deep/private", "surface/public")
                 } else {
@@ -378,6 +384,16 @@ pub struct ConsensusOre {
     pub accumulated_jitter: f32,
     /// How many ticks it took to crystallize
     pub crystallization_time: u64,
+    /// Largest single-tick jitter seen before crystallizing
+    #[serde(default)]
+    pub peak_jitter: f32,
+    /// ∫|Jitter|dt over the last two seconds before crystallizing
+    #[serde(default)]
+    pub jitter_integral: f32,
+    /// Consecutive low-jitter ticks when it crystallized (below the stability
+    /// requirement means it was forced by the time limit)
+    #[serde(default)]
+    pub stable_ticks_at_crystallization: u32,
     /// The emergent insight (if any)
     pub insight: Option<String>,
     /// Integration value for downstream processing
@@ -385,33 +401,147 @@ pub struct ConsensusOre {
     /// The extracted phase structure (physical topology)
     /// This is the "new material" - not a compromise, but what survives
     pub phase_structure: Option<PhaseStructure>,
+    /// Concept injected into the fluid when the experiment was seeded from live concepts
+    #[serde(default)]
+    pub insight_concept: Option<ConceptId>,
+    /// Dropped from the deposits by `clear_noise`; still part of the history
+    #[serde(default)]
+    pub cleared: bool,
+}
+
+/// Certainty cutoffs for classifying consensus ores. `foundational` and
+/// `noise` decide what `/consensus/truths` returns and what `clear_noise`
+/// drops; the other four form the quality ladder (certainty strictly above
+/// each cutoff).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct QualityThresholds {
+    pub foundational: f32,
+    pub noise: f32,
+    pub foundational_truth: f32,
+    pub strong_insight: f32,
+    pub tentative_insight: f32,
+    pub weak_signal: f32,
+}
+
+impl Default for QualityThresholds {
+    fn default() -> Self {
+        Self {
+            foundational: 0.8,
+            noise: 0.2,
+            foundational_truth: 0.9,
+            strong_insight: 0.7,
+            tentative_insight: 0.5,
+            weak_signal: 0.3,
+        }
+    }
+}
+
+/// Cutoffs to change; omitted ones keep their current value
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+pub struct QualityThresholdsPatch {
+    pub foundational: Option<f32>,
+    pub noise: Option<f32>,
+    pub foundational_truth: Option<f32>,
+    pub strong_insight: Option<f32>,
+    pub tentative_insight: Option<f32>,
+    pub weak_signal: Option<f32>,
+}
+
+impl QualityThresholds {
+    /// These thresholds with the patched cutoffs replaced, if the result
+    /// is usable (see `validate`).
+    pub fn patched(&self, patch: &QualityThresholdsPatch) -> Result<Self, String> {
+        let thresholds = Self {
+            foundational: patch.foundational.unwrap_or(self.foundational),
+            noise: patch.noise.unwrap_or(self.noise),
+            foundational_truth: patch.foundational_truth.unwrap_or(self.foundational_truth),
+            strong_insight: patch.strong_insight.unwrap_or(self.strong_insight),
+            tentative_insight: patch.tentative_insight.unwrap_or(self.tentative_insight),
+            weak_signal: patch.weak_signal.unwrap_or(self.weak_signal),
+        };
+        thresholds.validate()?;
+        Ok(thresholds)
+    }
+
+    /// Why these thresholds can't be used, if they can't: every cutoff must
+    /// lie in [0, 1], the ladder must descend, and noise must not exceed
+    /// foundational.
+    pub fn validate(&self) -> Result<(), String> {
+        let cutoffs = [
+            ("foundational", self.foundational),
+            ("noise", self.noise),
+            ("foundational_truth", self.foundational_truth),
+            ("strong_insight", self.strong_insight),
+            ("tentative_insight", self.tentative_insight),
+            ("weak_signal", self.weak_signal),
+        ];
+        if let Some((name, value)) = cutoffs.iter().find(|(_, v)| !(0.0..=1.0).contains(v)) {
+            return Err(format!("{} must be between 0 and 1, got {}", name, value));
+        }
+        if !(self.foundational_truth >= self.strong_insight
+            && self.strong_insight >= self.tentative_insight
+            && self.tentative_insight >= self.weak_signal)
+        {
+            return Err(
+                "Quality ladder must descend from foundational_truth to weak_signal".into(),
+            );
+        }
+        if self.noise > self.foundational {
+            return Err("noise must not exceed foundational".into());
+        }
+        Ok(())
+    }
 }
 
 impl ConsensusOre {
-    /// Is this a foundational truth? (C > 0.8)
-    pub fn is_foundational(&self) -> bool {
-        self.certainty > 0.8
+    /// Is this a foundational truth? (C > `foundational`)
+    pub fn is_foundational(&self, thresholds: &QualityThresholds) -> bool {
+        self.certainty > thresholds.foundational
     }
 
-    /// Is this mostly noise? (C < 0.2)
-    pub fn is_noise(&self) -> bool {
-        self.certainty < 0.2
+    /// Is this mostly noise? (C < `noise`)
+    pub fn is_noise(&self, thresholds: &QualityThresholds) -> bool {
+        self.certainty < thresholds.noise
     }
 
     /// Quality classification
-    pub fn quality(&self) -> &'static str {
-        if self.certainty > 0.9 {
+    pub fn quality(&self, thresholds: &QualityThresholds) -> &'static str {
+        if self.certainty > thresholds.foundational_truth {
             "foundational_truth"
-        } else if self.certainty > 0.7 {
+        } else if self.certainty > thresholds.strong_insight {
             "strong_insight"
-        } else if self.certainty > 0.5 {
+        } else if self.certainty > thresholds.tentative_insight {
             "tentative_insight"
-        } else if self.certainty > 0.3 {
+        } else if self.certainty > thresholds.weak_signal {
             "weak_signal"
         } else {
             "noise"
         }
     }
+
+    /// A bare synthesis ore with the given certainty, for tests.
+    #[cfg(test)]
+    pub fn test_fixture(certainty: f32) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name: format!("ore_{}", certainty),
+            ore_type: ConsensusOreType::Synthesis,
+            vent_a: format!("a_{}", certainty),
+            vent_b: format!("b_{}", certainty),
+            certainty,
+            accumulated_jitter: 0.0,
+            crystallization_time: 0,
+            peak_jitter: 0.0,
+            jitter_integral: 0.0,
+            stable_ticks_at_crystallization: 0,
+            insight: None,
+            integration_value: 0.0,
+            phase_structure: None,
+            insight_concept: None,
+            cleared: false,
+        }
+    }
 }
 
 /// A contradictory vent pair injected into the reactor.
@@ -429,6 +559,9 @@ pub struct ContradictoryVent {
     pub depth: f32,
     /// Radius of influence
     pub radius: f32,
+    /// How the vent's push fades toward the edge of its radius
+    #[serde(default)]
+    pub falloff: FalloffProfile,
 }
 
 impl ContradictoryVent {
@@ -440,6 +573,7 @@ impl ContradictoryVent {
             energy: heat_output,
             depth,
             radius,
+            falloff: FalloffProfile::default(),
         }
     }
 }
@@ -457,6 +591,9 @@ pub struct ConsensusExperiment {
     pub probe_ids: Vec<ConceptId>,
     /// Accumulated jitter: ∫|Jitter|dt
     pub accumulated_jitter: f32,
+    /// Peak Reynolds number of the probes alone
+    #[serde(default)]
+    pub peak_reynolds: f32,
     /// Peak jitter observed
     pub peak_jitter: f32,
     /// Velocity history for jitter calculation
@@ -483,6 +620,18 @@ pub struct ConsensusExperiment {
     pub phase_structure: Option<PhaseStructure>,
     /// Probe snapshots for phase extraction (depth, velocity pairs)
     pub probe_snapshots: Vec<(ConceptId, f32, f32)>,
+    /// Live concepts this experiment was seeded from (None for free-text positions)
+    #[serde(default)]
+    pub source_concepts: Option<(ConceptId, ConceptId)>,
+    /// Per-tick trace, only recorded when requested
+    #[serde(default)]
+    pub trace: Option<ExperimentTrace>,
+    /// Leave the probes in the fluid, named for the ore, at crystallization
+    #[serde(default)]
+    pub keep_particles: bool,
+    /// Open for the experiment's lifetime, closed at crystallization
+    #[serde(skip, default = "Span::none")]
+    pub span: Span,
 }
 
 impl ConsensusExperiment {
@@ -490,13 +639,23 @@ impl ConsensusExperiment {
         // Vents positioned at opposite sides of the reactor zone (0.4-0.6 depth)
         let vent_a = ContradictoryVent::new(position_a, heat_a, 0.4, 0.2);
         let vent_b = ContradictoryVent::new(position_b, heat_b, 0.6, 0.2);
+        let id = Uuid::new_v4();
+        let span = tracing::info_span!(
+            "consensus_experiment",
+            experiment_id = %id,
+            position_a = %vent_a.position,
+            position_b = %vent_b.position,
+            ore_id = tracing::field::Empty,
+            quality = tracing::field::Empty,
+        );
 
         Self {
-            id: Uuid::new_v4(),
+            id,
             vent_a,
             vent_b,
             probe_ids: Vec::new(),
             accumulated_jitter: 0.0,
+            peak_reynolds: 0.0,
             peak_jitter: 0.0,
             velocity_history: Vec::with_capacity(120), // 2 seconds at 60Hz
             start_tick: 0,
@@ -510,9 +669,19 @@ impl ConsensusExperiment {
             phase_transitioned: false,
             phase_structure: None,
             probe_snapshots: Vec::new(),
+            source_concepts: None,
+            trace: None,
+            keep_particles: false,
+            span,
         }
     }
 
+    /// Start recording a per-tick trace (depth per probe).
+    /// Call after the probes have been injected.
+    pub fn enable_trace(&mut self) {
+        self.trace = Some(ExperimentTrace::for_consensus(self));
+    }
+
     /// Record probe snapshot for phase extraction.
     pub fn record_probe_snapshot(&mut self, id: ConceptId, depth: f32, velocity: f32) {
         // Update or add snapshot
@@ -557,7 +726,7 @@ impl ConsensusExperiment {
 
         // Sort probes by depth for Voronoi computation
         let mut sorted_probes: Vec<_> = self.probe_snapshots.clone();
-        sorted_probes.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        sorted_probes.sort_by(|a, b| a.1.total_cmp(&b.1));
 
         // Compute Voronoi cells (1D tessellation)
         let mut voronoi_cells = Vec::new();
@@ -565,7 +734,7 @@ impl ConsensusExperiment {
         let vent_b_depth = self.vent_b.depth;
         let collision_center = (vent_a_depth + vent_b_depth) / 2.0;
 
-        for (i, (id, depth, velocity)) in sorted_probes.iter().enumerate() {
+        for (i, (id, depth, _)) in sorted_probes.iter().enumerate() {
             // Determine cell boundaries (midpoints to neighbors)
             let left_bound = if i == 0 {
                 0.0
@@ -899,9 +1068,14 @@ impl ConsensusExperiment {
             certainty,
             accumulated_jitter: self.accumulated_jitter,
             crystallization_time: current_tick.saturating_sub(self.start_tick),
+            peak_jitter: self.peak_jitter,
+            jitter_integral: self.jitter_integral(),
+            stable_ticks_at_crystallization: self.stable_ticks,
             insight,
             integration_value: certainty * 2.0, // Higher certainty = more valuable
             phase_structure: self.phase_structure.clone(),
+            insight_concept: None,
+            cleared: false,
         }
     }
 
@@ -926,14 +1100,7 @@ impl ContradictoryVent {
     /// Positive = push down, Negative = push up
     pub fn force_at(&self, depth: f32) -> f32 {
         let diff = depth - self.depth;
-        let dist = diff.abs();
-
-        if dist > self.radius {
-            return 0.0;
-        }
-
-        let proximity = 1.0 - (dist / self.radius);
-        let magnitude = self.heat_output * proximity.powi(2);
+        let magnitude = self.heat_output * self.falloff.weight(diff.abs(), self.radius);
 
         // Push away from vent center
         if diff > 0.0 {
@@ -944,17 +1111,42 @@ impl ContradictoryVent {
     }
 }
 
+/// Most crystallized ores the reactor keeps unless configured otherwise
+pub const DEFAULT_MAX_DEPOSITS: usize = 1000;
+
+fn default_max_deposits() -> usize {
+    DEFAULT_MAX_DEPOSITS
+}
+
 /// The Consensus Reactor - extracts stable truths from contradictory inputs.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsensusReactor {
     /// Currently active experiment
     pub active_experiment: Option<ConsensusExperiment>,
-    /// Crystallized consensus ores
-    pub ore_deposits: Vec<ConsensusOre>,
-    /// Completed experiment results
-    pub experiment_history: Vec<ConsensusOre>,
-    /// Total experiments run
+    /// Every crystallized ore, oldest first: the experiment history. Those
+    /// not `cleared` as noise are the deposits.
+    #[serde(alias = "experiment_history")]
+    pub ores: Vec<ConsensusOre>,
+    /// Most ores kept; the oldest are evicted past it
+    #[serde(default = "default_max_deposits")]
+    pub max_deposits: usize,
+    /// Total experiments run (evicted ones included)
     pub total_experiments: u32,
+    /// Certainty cutoffs for foundational truths, noise and quality
+    #[serde(default)]
+    pub quality_thresholds: QualityThresholds,
+}
+
+impl Default for ConsensusReactor {
+    fn default() -> Self {
+        Self {
+            active_experiment: None,
+            ores: Vec::new(),
+            max_deposits: DEFAULT_MAX_DEPOSITS,
+            total_experiments: 0,
+            quality_thresholds: QualityThresholds::default(),
+        }
+    }
 }
 
 impl ConsensusReactor {
@@ -962,6 +1154,11 @@ impl ConsensusReactor {
         Self::default()
     }
 
+    /// Crystallized ores not cleared as noise, oldest first.
+    pub fn deposits(&self) -> impl Iterator<Item = &ConsensusOre> {
+        self.ores.iter().filter(|o| !o.cleared)
+    }
+
     /// Start a new consensus experiment with two contradictory positions.
     pub fn start_experiment(
         &mut self,
@@ -1003,38 +1200,176 @@ impl ConsensusReactor {
 
         // Check for crystallization
         if experiment.check_crystallization(current_tick) {
-            let ore = experiment.crystallize(current_tick);
-            self.ore_deposits.push(ore.clone());
-            self.experiment_history.push(ore.clone());
-            self.active_experiment = None;
-            return Some(ore);
+            return self.crystallize_now(current_tick);
         }
 
         None
     }
 
-    /// Get all foundational truths (C > 0.8).
+    /// Crystallize the active experiment with whatever certainty its jitter
+    /// so far gives, depositing the ore. None without an experiment.
+    pub fn crystallize_now(&mut self, current_tick: u64) -> Option<ConsensusOre> {
+        let experiment = self.active_experiment.take()?;
+        let ore = experiment.crystallize(current_tick);
+        experiment
+            .span
+            .record("ore_id", tracing::field::display(ore.id));
+        experiment
+            .span
+            .record("quality", ore.quality(&self.quality_thresholds));
+        self.ores.push(ore.clone());
+        let excess = self.ores.len().saturating_sub(self.max_deposits);
+        self.ores.drain(..excess);
+        Some(ore)
+    }
+
+    /// Get all foundational truths (C above the `foundational` threshold).
     pub fn foundational_truths(&self) -> Vec<&ConsensusOre> {
-        self.ore_deposits
-            .iter()
-            .filter(|o| o.is_foundational())
+        self.deposits()
+            .filter(|o| o.is_foundational(&self.quality_thresholds))
             .collect()
     }
 
     /// Get average certainty of all deposits.
     pub fn average_certainty(&self) -> f32 {
-        if self.ore_deposits.is_empty() {
+        let (count, sum) = self
+            .deposits()
+            .fold((0, 0.0), |(count, sum), o| (count + 1, sum + o.certainty));
+        if count == 0 {
             return 0.0;
         }
-        let sum: f32 = self.ore_deposits.iter().map(|o| o.certainty).sum();
-        sum / self.ore_deposits.len() as f32
+        sum / count as f32
     }
 
-    /// Clear all noise (C < 0.2) from deposits.
+    /// Clear all noise (C below the `noise` threshold) from deposits. The
+    /// cleared ores stay in the history.
     pub fn clear_noise(&mut self) -> usize {
-        let before = self.ore_deposits.len();
-        self.ore_deposits.retain(|o| !o.is_noise());
-        before - self.ore_deposits.len()
+        let thresholds = self.quality_thresholds;
+        let mut cleared = 0;
+        for ore in self.ores.iter_mut() {
+            if !ore.cleared && ore.is_noise(&thresholds) {
+                ore.cleared = true;
+                cleared += 1;
+            }
+        }
+        cleared
+    }
+
+    /// Counts over the deposits, for `/consensus/summary`.
+    pub fn summary(&self) -> ConsensusSummary {
+        let thresholds = &self.quality_thresholds;
+        ConsensusSummary {
+            total_experiments: self.total_experiments,
+            stored_ores: self.ores.len(),
+            max_deposits: self.max_deposits,
+            foundational_count: self.foundational_truths().len(),
+            noise_count: self.deposits().filter(|o| o.is_noise(thresholds)).count(),
+            average_certainty: self.average_certainty(),
+        }
+    }
+}
+
+/// How the reactor's experiments have gone, at a glance.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ConsensusSummary {
+    /// Experiments ever started, including any whose ores were evicted
+    pub total_experiments: u32,
+    /// Ores currently kept (at most `max_deposits`)
+    pub stored_ores: usize,
+    pub max_deposits: usize,
+    /// Deposits above the `foundational` threshold
+    pub foundational_count: usize,
+    /// Deposits below the `noise` threshold
+    pub noise_count: usize,
+    /// Mean certainty of the deposits (0.0 without any)
+    pub average_certainty: f32,
+}
+
+// ============================================================================
+// DISTRIBUTION STATISTICS
+// ============================================================================
+
+/// Number of equal-width certainty bins over [0, 1].
+pub const CERTAINTY_BINS: usize = 10;
+
+/// How certainties distribute across a set of consensus ores.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ConsensusStats {
+    pub total: usize,
+    /// Ore counts keyed by `ConsensusOreType::as_str()`
+    pub count_by_type: BTreeMap<&'static str, usize>,
+    /// Bin i counts certainties in [i/10, (i+1)/10); the last bin includes 1.0
+    pub certainty_histogram: [usize; CERTAINTY_BINS],
+    pub mean_certainty: f32,
+    pub median_certainty: f32,
+    /// Mean ticks to crystallize
+    pub mean_crystallization_time: f32,
+    /// Fraction of ores that are foundational (C above the `foundational` threshold)
+    pub foundational_fraction: f32,
+    /// The three most certain foundational truths
+    pub top_foundational: Vec<FoundationalSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct FoundationalSummary {
+    pub name: String,
+    pub vent_a: String,
+    pub vent_b: String,
+    pub certainty: f32,
+}
+
+/// Compute distribution statistics over a set of ores (e.g. the reactor's
+/// whole history, `ores`).
+pub fn stats(ores: &[ConsensusOre], thresholds: &QualityThresholds) -> ConsensusStats {
+    let total = ores.len();
+    let denom = total.max(1) as f32;
+
+    let mut count_by_type = BTreeMap::new();
+    let mut certainty_histogram = [0; CERTAINTY_BINS];
+    for ore in ores {
+        *count_by_type.entry(ore.ore_type.as_str()).or_insert(0) += 1;
+        let bin = (ore.certainty.clamp(0.0, 1.0) * CERTAINTY_BINS as f32) as usize;
+        certainty_histogram[bin.min(CERTAINTY_BINS - 1)] += 1;
+    }
+
+    let mut certainties: Vec<f32> = ores.iter().map(|o| o.certainty).collect();
+    certainties.sort_by(|a, b| a.total_cmp(b));
+    let median_certainty = match total {
+        0 => 0.0,
+        n if n % 2 == 1 => certainties[n / 2],
+        n => (certainties[n / 2 - 1] + certainties[n / 2]) / 2.0,
+    };
+
+    let mut foundational: Vec<&ConsensusOre> = ores
+        .iter()
+        .filter(|o| o.is_foundational(thresholds))
+        .collect();
+    let foundational_fraction = foundational.len() as f32 / denom;
+    foundational.sort_by(|a, b| b.certainty.total_cmp(&a.certainty));
+    let top_foundational = foundational
+        .into_iter()
+        .take(3)
+        .map(|o| FoundationalSummary {
+            name: o.name.clone(),
+            vent_a: o.vent_a.clone(),
+            vent_b: o.vent_b.clone(),
+            certainty: o.certainty,
+        })
+        .collect();
+
+    ConsensusStats {
+        total,
+        count_by_type,
+        certainty_histogram,
+        mean_certainty: certainties.iter().sum::<f32>() / denom,
+        median_certainty,
+        mean_crystallization_time: ores
+            .iter()
+            .map(|o| o.crystallization_time as f32)
+            .sum::<f32>()
+            / denom,
+        foundational_fraction,
+        top_foundational,
     }
 }
 
@@ -1042,6 +1377,60 @@ impl ConsensusReactor {
 mod tests {
     use super::*;
 
+    fn synthetic_ore(
+        ore_type: ConsensusOreType,
+        certainty: f32,
+        crystallization_time: u64,
+    ) -> ConsensusOre {
+        ConsensusOre {
+            ore_type,
+            crystallization_time,
+            ..ConsensusOre::test_fixture(certainty)
+        }
+    }
+
+    #[test]
+    fn test_stats_empty() {
+        let s = stats(&[], &QualityThresholds::default());
+        assert_eq!(s.total, 0);
+        assert_eq!(s.certainty_histogram, [0; CERTAINTY_BINS]);
+        assert_eq!(s.mean_certainty, 0.0);
+        assert_eq!(s.median_certainty, 0.0);
+        assert_eq!(s.foundational_fraction, 0.0);
+        assert!(s.top_foundational.is_empty());
+    }
+
+    #[test]
+    fn test_stats_synthetic_distribution() {
+        use ConsensusOreType::*;
+        let ores = vec![
+            synthetic_ore(Synthesis, 0.05, 100),
+            synthetic_ore(Paradox, 0.45, 200),
+            synthetic_ore(Synthesis, 0.85, 300),
+            synthetic_ore(Dissolution, 0.95, 400),
+            synthetic_ore(Synthesis, 1.0, 500),
+        ];
+
+        let s = stats(&ores, &QualityThresholds::default());
+        assert_eq!(s.total, 5);
+        assert_eq!(s.count_by_type["synthesis"], 3);
+        assert_eq!(s.count_by_type["paradox"], 1);
+        assert_eq!(s.count_by_type["dissolution"], 1);
+        assert_eq!(s.certainty_histogram, [1, 0, 0, 0, 1, 0, 0, 0, 1, 2]);
+        assert!((s.mean_certainty - 0.66).abs() < 1e-6);
+        assert!((s.median_certainty - 0.85).abs() < 1e-6);
+        assert!((s.mean_crystallization_time - 300.0).abs() < 1e-6);
+        assert!((s.foundational_fraction - 0.6).abs() < 1e-6);
+
+        let top: Vec<f32> = s.top_foundational.iter().map(|t| t.certainty).collect();
+        assert_eq!(top, vec![1.0, 0.95, 0.85]);
+        assert_eq!(s.top_foundational[0].vent_a, "a_1");
+
+        // Even count → median averages the middle pair
+        let s = stats(&ores[..4], &QualityThresholds::default());
+        assert!((s.median_certainty - 0.65).abs() < 1e-6);
+    }
+
     #[test]
     fn test_certainty_calculation() {
         let mut exp = ConsensusExperiment::new(
@@ -1065,24 +1454,83 @@ mod tests {
 
     #[test]
     fn test_ore_quality_classification() {
-        let make_ore = |certainty: f32| ConsensusOre {
-            id: Uuid::new_v4(),
-            name: "test".to_string(),
-            ore_type: ConsensusOreType::Synthesis,
-            vent_a: "A".to_string(),
-            vent_b: "B".to_string(),
-            certainty,
-            accumulated_jitter: 0.0,
-            crystallization_time: 0,
-            insight: None,
-            integration_value: 0.0,
+        let quality = |certainty: f32| {
+            ConsensusOre::test_fixture(certainty).quality(&QualityThresholds::default())
         };
 
-        assert_eq!(make_ore(0.95).quality(), "foundational_truth");
-        assert_eq!(make_ore(0.75).quality(), "strong_insight");
-        assert_eq!(make_ore(0.55).quality(), "tentative_insight");
-        assert_eq!(make_ore(0.35).quality(), "weak_signal");
-        assert_eq!(make_ore(0.15).quality(), "noise");
+        assert_eq!(quality(0.95), "foundational_truth");
+        assert_eq!(quality(0.75), "strong_insight");
+        assert_eq!(quality(0.55), "tentative_insight");
+        assert_eq!(quality(0.35), "weak_signal");
+        assert_eq!(quality(0.15), "noise");
+
+        // The ladder moves with the thresholds
+        let strict = QualityThresholds {
+            foundational_truth: 0.99,
+            ..QualityThresholds::default()
+        };
+        assert_eq!(
+            ConsensusOre::test_fixture(0.95).quality(&strict),
+            "strong_insight"
+        );
+    }
+
+    #[test]
+    fn test_thresholds_decide_truths_and_noise() {
+        let mut reactor = ConsensusReactor::new();
+        reactor.ores = [0.1, 0.3, 0.75, 0.85]
+            .into_iter()
+            .map(ConsensusOre::test_fixture)
+            .collect();
+        assert_eq!(reactor.foundational_truths().len(), 1);
+
+        reactor.quality_thresholds.foundational = 0.7;
+        assert_eq!(reactor.foundational_truths().len(), 2);
+
+        reactor.quality_thresholds.noise = 0.5;
+        assert_eq!(reactor.clear_noise(), 2);
+        assert_eq!(reactor.deposits().count(), 2);
+        assert_eq!(reactor.ores.len(), 4, "cleared ores stay in the history");
+
+        let bad = QualityThresholds {
+            noise: 0.9,
+            ..QualityThresholds::default()
+        };
+        assert!(bad.validate().is_err());
+        assert!(QualityThresholds::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_many_experiments_keep_ore_storage_bounded() {
+        let mut reactor = ConsensusReactor {
+            max_deposits: 5,
+            ..ConsensusReactor::new()
+        };
+        // Alternating clean (C = 1.0) and noisy (C = 0.1) crystallizations
+        for i in 0..12u64 {
+            reactor.start_experiment("A".to_string(), 1.0, "B".to_string(), 1.0, i);
+            reactor.get_experiment_mut().unwrap().accumulated_jitter =
+                if i % 2 == 0 { 0.0 } else { 9.0 };
+            assert!(reactor.crystallize_now(i).is_some());
+            assert!(reactor.ores.len() <= 5);
+        }
+
+        // The last five (experiments 7-11) are kept, oldest first
+        let certainties: Vec<f32> = reactor.ores.iter().map(|o| o.certainty).collect();
+        assert_eq!(certainties, vec![0.1, 1.0, 0.1, 1.0, 0.1]);
+
+        let summary = reactor.summary();
+        assert_eq!(summary.total_experiments, 12);
+        assert_eq!(summary.stored_ores, 5);
+        assert_eq!(summary.foundational_count, 2);
+        assert_eq!(summary.noise_count, 3);
+        assert!((summary.average_certainty - 0.46).abs() < 1e-6);
+
+        // Cleared noise leaves the deposits but stays in the history
+        assert_eq!(reactor.clear_noise(), 3);
+        let summary = reactor.summary();
+        assert_eq!((summary.stored_ores, summary.noise_count), (5, 0));
+        assert_eq!(summary.average_certainty, 1.0);
     }
 
     #[test]
@@ -1,5 +1,27 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use super::ore::OreType;
+
+/// Extra damping applied on top of impermeability when a concept hits a continent
+const COLLISION_DAMPING: f32 = 0.3;
+/// Gap left between a concept moved out of a continent and its edge
+const EXIT_CLEARANCE: f32 = 0.01;
+
+/// Continent names by dominant ore type, unless configured otherwise.
+pub fn default_continent_names() -> HashMap<OreType, String> {
+    [
+        (OreType::Art, "foundation_of_beauty"),
+        (OreType::Code, "bedrock_of_logic"),
+        (OreType::Insight, "pillar_of_wisdom"),
+        (OreType::Writing, "archive_of_story"),
+    ]
+    .into_iter()
+    .map(|(ore_type, name)| (ore_type, name.to_string()))
+    .collect()
+}
+
 /// Great Unconformity - permanent continental landmass formed from critical pressure.
 /// Continents are solid ground in the fluid; emotions cannot exist in these layers.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,4 +45,28 @@ impl Continent {
     pub fn contains_depth(&self, depth: f32) -> bool {
         depth >= self.depth_range.0 && depth <= self.depth_range.1
     }
+
+    /// Layer just outside this continent, above it if `prefer_above`, and
+    /// whether that is above. Falls back to the other side when the preferred
+    /// one is past the surface or the floor.
+    pub fn exit_layer(&self, prefer_above: bool) -> (f32, bool) {
+        let above = self.depth_range.0 - EXIT_CLEARANCE;
+        let below = self.depth_range.1 + EXIT_CLEARANCE;
+        if (prefer_above && above >= 0.0) || below > 1.0 {
+            (above.max(0.0), true)
+        } else {
+            (below, false)
+        }
+    }
+
+    /// `exit_layer` through whichever edge is closer to `depth`, the
+    /// shallower one on a tie.
+    pub fn nearest_exit(&self, depth: f32) -> (f32, bool) {
+        self.exit_layer(depth - self.depth_range.0 <= self.depth_range.1 - depth)
+    }
+
+    /// Fraction of a concept's speed kept after bouncing off this continent.
+    pub fn speed_retained(&self) -> f32 {
+        (1.0 - self.impermeability) * COLLISION_DAMPING
+    }
 }
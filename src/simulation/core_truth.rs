@@ -1,5 +1,48 @@
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::concept::ConceptId;
+
+/// Maximum concepts tracked per vent; the least recently touched is evicted.
+pub const MAX_INFLUENCE_ENTRIES: usize = 256;
+/// Width of the Gaussian falloff, as a fraction of the plume radius
+const GAUSSIAN_SIGMA: f32 = 1.0 / 3.0;
+
+/// How a plume's force fades from its centre to its edge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FalloffProfile {
+    /// Straight ramp: a broad belief that reaches far
+    Linear,
+    /// `(1 - d/r)²`: concentrated near the vent
+    #[default]
+    Quadratic,
+    /// Bell curve lowered to meet zero at the edge: a smooth, bounded plume
+    /// with a flat top
+    Gaussian,
+}
+
+impl FalloffProfile {
+    /// Share of full strength at `distance` from the centre of a plume of
+    /// `radius`: 1 at the centre, 0 at the edge and beyond.
+    pub fn weight(self, distance: f32, radius: f32) -> f32 {
+        if distance >= radius {
+            return 0.0;
+        }
+        let x = distance / radius;
+        match self {
+            Self::Linear => 1.0 - x,
+            Self::Quadratic => (1.0 - x).powi(2),
+            Self::Gaussian => {
+                let bell = |x: f32| (-x * x / (2.0 * GAUSSIAN_SIGMA * GAUSSIAN_SIGMA)).exp();
+                (bell(x) - bell(1.0)) / (1.0 - bell(1.0))
+            }
+        }
+    }
+}
+
 /// Deep sea hydrothermal vent - a core truth that radiates heat from the ocean floor.
 /// Core truths are foundational beliefs that create upward thermal currents,
 /// transforming heavy/dark thoughts as they pass through the heat.
@@ -14,6 +57,19 @@ pub struct CoreTruth {
     pub radius: f32,
     /// Strengthens each time concepts encounter it
     pub activation_count: u32,
+    /// Inactive vents emit no heat but keep their strengthening history
+    #[serde(default = "default_active")]
+    pub active: bool,
+    /// Which concepts this vent has lifted, and by how much
+    #[serde(default)]
+    pub influence: VentInfluence,
+    /// How the plume fades toward its edge
+    #[serde(default)]
+    pub falloff: FalloffProfile,
+}
+
+fn default_active() -> bool {
+    true
 }
 
 impl CoreTruth {
@@ -24,6 +80,209 @@ impl CoreTruth {
             depth,
             radius,
             activation_count: 0,
+            active: true,
+            influence: VentInfluence::default(),
+            falloff: FalloffProfile::default(),
+        }
+    }
+
+    /// Upward heat delivered to a concept at `depth` (0 outside the plume or when inactive).
+    pub fn heat_transfer_at(&self, depth: f32) -> f32 {
+        if !self.active {
+            return 0.0;
+        }
+        let depth_diff = (depth - self.depth).abs();
+        self.heat_output * self.falloff.weight(depth_diff, self.radius)
+    }
+}
+
+/// One concept's history with a vent.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct InfluenceRecord {
+    /// Ticks the concept spent inside the plume
+    pub encounters: u32,
+    /// Cumulative lift delivered: heat transfer × dt, the velocity the
+    /// plume added
+    pub impulse: f32,
+    pub last_tick: u64,
+}
+
+/// Bounded per-vent influence map. Totals include evicted concepts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VentInfluence {
+    pub concepts: HashMap<ConceptId, InfluenceRecord>,
+    pub total_encounters: u64,
+    pub total_impulse: f32,
+}
+
+impl VentInfluence {
+    pub fn record(&mut self, concept: ConceptId, impulse: f32, tick: u64) {
+        if !self.concepts.contains_key(&concept) && self.concepts.len() >= MAX_INFLUENCE_ENTRIES {
+            let stalest = self
+                .concepts
+                .iter()
+                .min_by_key(|(_, record)| record.last_tick)
+                .map(|(id, _)| *id);
+            if let Some(id) = stalest {
+                self.concepts.remove(&id);
+            }
+        }
+
+        let record = self.concepts.entry(concept).or_default();
+        record.encounters += 1;
+        record.impulse += impulse;
+        record.last_tick = tick;
+        self.total_encounters += 1;
+        self.total_impulse += impulse;
+    }
+
+    /// The `limit` most influenced concepts, greatest cumulative impulse first.
+    pub fn top(&self, limit: usize) -> Vec<(ConceptId, InfluenceRecord)> {
+        let mut ranked: Vec<_> = self.concepts.iter().map(|(id, r)| (*id, *r)).collect();
+        ranked.sort_by(|a, b| b.1.impulse.total_cmp(&a.1.impulse).then(a.0.cmp(&b.0)));
+        ranked.truncate(limit);
+        ranked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::ConceptFluid;
+
+    #[test]
+    fn test_disabled_vent_keeps_history_and_loses_uplift() {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_core_truth("warmth".to_string(), 1.0, 0.9, 0.3);
+        let probe = fluid.add_concept("doubt".to_string(), 0.5, 0.5);
+        fluid.get_concept_mut(probe).unwrap().layer = 0.9;
+        fluid.core_truths[0].activation_count = 7;
+        fluid.core_truths[0].heat_output = 1.4;
+
+        let velocity_after_tick = |fluid: &ConceptFluid| {
+            let mut fluid = fluid.clone();
+            fluid.update(1.0 / 60.0);
+            fluid.concepts[&probe].velocity
+        };
+
+        let heated = velocity_after_tick(&fluid);
+
+        assert!(fluid.set_core_truth_active(0, false));
+        let unheated = velocity_after_tick(&fluid);
+        assert!(heated < unheated, "vent plume should push the probe upward");
+
+        // Strengthening history is untouched while disabled, and stays frozen through ticks
+        let mut idle = fluid.clone();
+        idle.update(1.0 / 60.0);
+        assert_eq!(idle.core_truths[0].activation_count, 7);
+        assert!((idle.core_truths[0].heat_output - 1.4).abs() < 1e-6);
+
+        assert!(fluid.set_core_truth_active(0, true));
+        assert_eq!(velocity_after_tick(&fluid), heated);
+
+        assert!(!fluid.set_core_truth_active(5, false));
+    }
+
+    #[test]
+    fn test_recorded_impulse_is_the_lift_delivered() {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_core_truth("warmth".to_string(), 1.0, 0.9, 0.3);
+        let light = fluid.add_concept("whim".to_string(), 0.3, 0.5);
+        let heavy = fluid.add_concept("grief".to_string(), 0.9, 0.5);
+        for id in [light, heavy] {
+            fluid.get_concept_mut(id).unwrap().layer = 0.9;
+        }
+
+        // One tick with and without the plume: the difference is its lift
+        let mut heated = fluid.clone();
+        heated.update(1.0 / 60.0);
+        let mut unheated = fluid.clone();
+        unheated.set_core_truth_active(0, false);
+        unheated.update(1.0 / 60.0);
+        for id in [light, heavy] {
+            let lift = unheated.concepts[&id].velocity - heated.concepts[&id].velocity;
+            let impulse = heated.core_truths[0].influence.concepts[&id].impulse;
+            assert!((lift - impulse).abs() < 1e-4, "{} vs {}", lift, impulse);
+        }
+
+        for _ in 0..120 {
+            fluid.update(1.0 / 60.0);
+        }
+        let influence = &fluid.core_truths[0].influence;
+        let top = influence.top(10);
+        assert_eq!(top.len(), 2);
+        assert!(top[0].1.impulse >= top[1].1.impulse);
+        assert!(top[0].1.encounters >= top[1].1.encounters);
+        assert!(top[1].1.encounters > 0);
+        assert_eq!(
+            influence.total_encounters,
+            (top[0].1.encounters + top[1].1.encounters) as u64
+        );
+
+        // Saved with the fluid
+        let restored = ConceptFluid::from_snapshot(fluid.to_snapshot().unwrap()).unwrap();
+        assert_eq!(
+            restored.core_truths[0].influence.concepts[&heavy].encounters,
+            influence.concepts[&heavy].encounters
+        );
+    }
+
+    #[test]
+    fn test_influence_evicts_least_recent() {
+        let mut influence = VentInfluence::default();
+        let ids: Vec<ConceptId> = (0..=MAX_INFLUENCE_ENTRIES)
+            .map(|_| ConceptId::new_v4())
+            .collect();
+        for (tick, id) in ids.iter().enumerate() {
+            influence.record(*id, 0.1, tick as u64);
+        }
+
+        assert_eq!(influence.concepts.len(), MAX_INFLUENCE_ENTRIES);
+        assert!(!influence.concepts.contains_key(&ids[0]));
+        assert!(influence.concepts.contains_key(&ids[MAX_INFLUENCE_ENTRIES]));
+        assert_eq!(influence.total_encounters, ids.len() as u64);
+    }
+
+    #[test]
+    fn test_falloff_profiles_at_half_radius() {
+        use crate::simulation::ContradictoryVent;
+
+        // Same heat, same radius; only the profile differs
+        let at_half_radius = |falloff| {
+            let mut fluid = ConceptFluid::default();
+            fluid
+                .add_core_truth("warmth".to_string(), 2.0, 0.9, 0.2)
+                .falloff = falloff;
+
+            let mut vent = ContradictoryVent::new("warmth".to_string(), 2.0, 0.9, 0.2);
+            vent.falloff = falloff;
+            (
+                fluid.core_truths[0].heat_transfer_at(0.8),
+                vent.force_at(0.8),
+            )
+        };
+        let (linear, vent_linear) = at_half_radius(FalloffProfile::Linear);
+        let (quadratic, vent_quadratic) = at_half_radius(FalloffProfile::Quadratic);
+        let (gaussian, vent_gaussian) = at_half_radius(FalloffProfile::Gaussian);
+
+        assert!((linear - 1.0).abs() < 1e-5, "{}", linear);
+        assert!((quadratic - 0.5).abs() < 1e-5, "{}", quadratic);
+        assert!(quadratic < gaussian && gaussian < linear, "{}", gaussian);
+        // The consensus vents share the shape (pushing up from above the vent)
+        assert_eq!(
+            [vent_linear, vent_quadratic, vent_gaussian],
+            [-linear, -quadratic, -gaussian]
+        );
+
+        // Every profile is full strength at the vent and gone at the edge
+        for falloff in [
+            FalloffProfile::Linear,
+            FalloffProfile::Quadratic,
+            FalloffProfile::Gaussian,
+        ] {
+            assert_eq!(falloff.weight(0.0, 0.2), 1.0);
+            assert_eq!(falloff.weight(0.2, 0.2), 0.0);
+            assert!(falloff.weight(0.199, 0.2) < 0.05);
         }
     }
 }
@@ -0,0 +1,252 @@
+use std::ops::AddAssign;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::concept::Concept;
+use super::fluid::ConceptFluid;
+
+/// Books fail to balance beyond this (absolute part of the tolerance)
+const ABSOLUTE_TOLERANCE: f32 = 1e-4;
+/// Relative part of the tolerance, scaled by the tick's kinetic energy
+const RELATIVE_TOLERANCE: f32 = 1e-4;
+
+/// Kinetic energy of a unit-mass concept moving at `velocity`.
+pub fn kinetic_energy(velocity: f32) -> f32 {
+    0.5 * velocity * velocity
+}
+
+/// Share of each eddy's energy that breaks down into integration per second
+/// in a medium of `viscosity`.
+pub fn eddy_breakdown_rate(viscosity: f32) -> f32 {
+    viscosity * 2.0
+}
+
+/// Energy flows through the fluid. Gains are positive, losses are
+/// recorded as positive magnitudes and subtracted in `net`.
+#[derive(Debug, Clone, Copy, Default, Serialize, JsonSchema)]
+pub struct EnergyFlows {
+    /// Net work by fluid forces (buoyancy, drag, waves, turbulence, repulsion, freeze suppression)
+    pub force_work: f32,
+    /// Share of `force_work` done by thermal plumes from core truths
+    pub thermal_injection: f32,
+    /// Impulses applied directly to velocity (modulate_buoyancy, thaw, spawned concepts)
+    pub impulse_injection: f32,
+    /// Kinetic energy removed by deep-breath damping
+    pub damping_loss: f32,
+    /// Kinetic energy lost to surface bounces, boundary damping, and continental collisions
+    pub boundary_loss: f32,
+    /// Kinetic energy paid to surface tension on breakthrough
+    pub breakthrough_loss: f32,
+    /// Integration gained (eddy cascade, damping and attention spotlights)
+    pub integration_gained: f32,
+}
+
+impl EnergyFlows {
+    /// Expected change in kinetic energy from these flows.
+    pub fn net(&self) -> f32 {
+        self.force_work + self.impulse_injection
+            - self.damping_loss
+            - self.boundary_loss
+            - self.breakthrough_loss
+    }
+}
+
+impl AddAssign for EnergyFlows {
+    fn add_assign(&mut self, other: Self) {
+        self.force_work += other.force_work;
+        self.thermal_injection += other.thermal_injection;
+        self.impulse_injection += other.impulse_injection;
+        self.damping_loss += other.damping_loss;
+        self.boundary_loss += other.boundary_loss;
+        self.breakthrough_loss += other.breakthrough_loss;
+        self.integration_gained += other.integration_gained;
+    }
+}
+
+/// Energy accounting for a single tick.
+#[derive(Debug, Clone, Copy, Default, Serialize, JsonSchema)]
+pub struct EnergyTick {
+    pub tick: u64,
+    /// Total kinetic energy before the tick (and before any pending impulses)
+    pub ke_start: f32,
+    /// Total kinetic energy after the tick
+    pub ke_end: f32,
+    pub flows: EnergyFlows,
+    /// (ke_end - ke_start) - flows.net(); ~0 when the books balance
+    pub imbalance: f32,
+}
+
+/// Running energy ledger. Derived from the simulation, so never snapshotted.
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
+pub struct EnergyLedger {
+    pub last_tick: EnergyTick,
+    pub cumulative: EnergyFlows,
+    /// Ticks whose books failed to balance
+    pub anomaly_count: u64,
+    /// Impulses applied between ticks, folded into the next tick
+    #[serde(skip)]
+    pending_impulse: f32,
+}
+
+impl EnergyLedger {
+    /// Record a velocity impulse applied outside `update` (e.g. by a command).
+    pub fn record_impulse(&mut self, velocity_before: f32, velocity_after: f32) {
+        self.pending_impulse += kinetic_energy(velocity_after) - kinetic_energy(velocity_before);
+    }
+
+    /// Take impulses accumulated since the last tick.
+    pub(crate) fn take_pending_impulse(&mut self) -> f32 {
+        std::mem::take(&mut self.pending_impulse)
+    }
+
+    /// Close the books for a tick. Returns the tick if it failed to balance.
+    pub(crate) fn settle(
+        &mut self,
+        tick: u64,
+        ke_start: f32,
+        ke_end: f32,
+        flows: EnergyFlows,
+    ) -> Option<EnergyTick> {
+        let imbalance = (ke_end - ke_start) - flows.net();
+        self.last_tick = EnergyTick {
+            tick,
+            ke_start,
+            ke_end,
+            flows,
+            imbalance,
+        };
+        self.cumulative += flows;
+
+        let tolerance = ABSOLUTE_TOLERANCE + RELATIVE_TOLERANCE * ke_start.max(ke_end);
+        if imbalance.is_finite() && imbalance.abs() <= tolerance {
+            None
+        } else {
+            self.anomaly_count += 1;
+            Some(self.last_tick)
+        }
+    }
+}
+
+impl ConceptFluid {
+    /// Total kinetic energy of all concepts in the fluid.
+    pub fn total_kinetic_energy(&self) -> f32 {
+        self.concepts
+            .values()
+            .map(|c| kinetic_energy(c.velocity))
+            .sum()
+    }
+
+    /// A rising concept's kinetic energy as a fraction of the surface
+    /// tension: at 1.0 or more it would break through if it reached the
+    /// surface now. Concepts that are not rising report 0.0.
+    pub fn breakthrough_margin(&self, concept: &Concept) -> f32 {
+        if concept.velocity >= 0.0 {
+            return 0.0;
+        }
+        kinetic_energy(concept.velocity) / self.surface_tension.max(f32::EPSILON)
+    }
+
+    /// Integration per second the concept is gaining as its eddies break
+    /// down (0.0 once they have dissipated).
+    pub fn integration_rate(&self, concept: &Concept) -> f32 {
+        concept.eddy_scale * eddy_breakdown_rate(self.viscosity_at_depth(concept.layer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::events::FluidEvent;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    /// Mirrors the drag term in Pass 4 for a concept above the shear threshold.
+    fn expected_drag(fluid: &ConceptFluid, layer: f32, velocity: f32, area: f32) -> f32 {
+        let excess_shear = velocity.abs() - fluid.shear_threshold;
+        let visc = fluid.viscosity_at_depth(layer)
+            * (1.0 - (fluid.shear_thinning_coefficient * excess_shear));
+        -0.5 * visc * velocity.powi(2) * fluid.drag_coefficient * area * velocity.signum()
+    }
+
+    #[test]
+    fn test_ledger_single_concept_analytic() {
+        let mut fluid = ConceptFluid::default();
+        // Neutrally buoyant at its own layer: no forces until nudged
+        let id = fluid.add_concept("idea".to_string(), 0.5, 0.5);
+
+        // Nudge sinks it by 0.2 * 2 = 0.4 and shifts its target layer to 0.3
+        fluid.modulate_buoyancy(id, 0.4).unwrap();
+        let v0 = fluid.concepts[&id].velocity;
+        assert!((v0 - 0.4).abs() < 1e-6);
+
+        let events = fluid.update(DT);
+        assert!(
+            !events
+                .iter()
+                .any(|e| matches!(e, FluidEvent::EnergyAnomaly { .. }))
+        );
+
+        let buoyancy_force = (0.3 - 0.5) * 0.5;
+        let v1 = v0 + (buoyancy_force + expected_drag(&fluid, 0.5, v0, 0.5)) * DT;
+        let tick = fluid.energy.last_tick;
+
+        assert_eq!(tick.tick, 1);
+        assert!(tick.ke_start.abs() < 1e-6);
+        assert!((tick.flows.impulse_injection - kinetic_energy(0.4)).abs() < 1e-6);
+        assert!((tick.flows.force_work - (kinetic_energy(v1) - kinetic_energy(v0))).abs() < 1e-6);
+        assert!((tick.ke_end - kinetic_energy(v1)).abs() < 1e-6);
+        assert_eq!(tick.flows.damping_loss, 0.0);
+        assert_eq!(tick.flows.boundary_loss, 0.0);
+        assert_eq!(tick.flows.thermal_injection, 0.0);
+        assert!(tick.imbalance.abs() < 1e-6);
+
+        // A deep breath then removes exactly the damped fraction of KE
+        fluid.deep_breath(0.5, None);
+        fluid.update(DT);
+        let v2 = fluid.concepts[&id].velocity;
+        let undamped = v2 / (1.0 - 0.5 * DT);
+        let tick = fluid.energy.last_tick;
+        assert!(
+            (tick.flows.damping_loss - (kinetic_energy(undamped) - kinetic_energy(v2))).abs()
+                < 1e-6
+        );
+        assert!(tick.imbalance.abs() < 1e-6);
+        assert_eq!(fluid.energy.anomaly_count, 0);
+    }
+
+    #[test]
+    fn test_breakthrough_margin_compares_rise_to_surface_tension() {
+        let mut fluid = ConceptFluid::default();
+        let margin = |fluid: &mut ConceptFluid, velocity: f32| {
+            let id = fluid.add_concept("urge".to_string(), 0.2, 0.5);
+            fluid.get_concept_mut(id).unwrap().velocity = velocity;
+            fluid.breakthrough_margin(&fluid.concepts[&id])
+        };
+        let escape_velocity = (2.0 * fluid.surface_tension).sqrt();
+
+        assert!(margin(&mut fluid, -1.1 * escape_velocity) >= 1.0);
+        assert!(margin(&mut fluid, -0.5 * escape_velocity) < 1.0);
+        assert_eq!(margin(&mut fluid, 2.0 * escape_velocity), 0.0, "sinking");
+    }
+
+    #[test]
+    fn test_ledger_surface_bounce_loss() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("hesitation".to_string(), 0.5, 0.5);
+        let concept = fluid.get_concept_mut(id).unwrap();
+        concept.layer = 0.001;
+        concept.velocity = -0.2;
+
+        fluid.update(DT);
+
+        // Bounce keeps 30% of the velocity (9% of the KE), then the boundary halves it again
+        let tick = fluid.energy.last_tick;
+        let v_end = fluid.concepts[&id].velocity;
+        let v_impact = v_end / (-0.3 * 0.5);
+        let expected_loss = kinetic_energy(v_impact) - kinetic_energy(v_end);
+        assert!((tick.flows.boundary_loss - expected_loss).abs() < 1e-6);
+        assert!(tick.imbalance.abs() < 1e-5);
+        assert_eq!(fluid.energy.anomaly_count, 0);
+    }
+}
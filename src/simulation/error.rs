@@ -0,0 +1,37 @@
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::concept::ConceptId;
+
+/// Why the fluid refused an operation. Serializes as
+/// `{"error": "concept_not_found", "id": ...}`.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(tag = "error", rename_all = "snake_case")]
+pub enum FluidError {
+    ConceptNotFound {
+        id: ConceptId,
+    },
+    /// The concept is a probe or bubble of a running experiment
+    ConceptInExperiment {
+        id: ConceptId,
+    },
+    LinkNotFound {
+        index: usize,
+    },
+}
+
+impl fmt::Display for FluidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConceptNotFound { id } => write!(f, "Concept {} not found", id),
+            Self::ConceptInExperiment { id } => {
+                write!(f, "Concept {} is a probe in an active experiment", id)
+            }
+            Self::LinkNotFound { index } => write!(f, "Link {} not found", index),
+        }
+    }
+}
+
+impl std::error::Error for FluidError {}
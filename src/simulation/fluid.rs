@@ -1,54 +1,457 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::{
-    concept::{Concept, ConceptId},
+    attention::Spotlight,
+    concept::{Concept, ConceptDimensions, ConceptId, ConceptOrigin, ConceptRole, ConceptSize},
     consensus_reactor::{ConsensusExperiment, ConsensusOre, ConsensusReactor},
-    continent::Continent,
+    continent::{Continent, default_continent_names},
     core_truth::CoreTruth,
-    ore::{OreType, PreciousOre},
-    standing_wave::{DivisionExperiment, DivisionProblem, DivisionResult, StandingWave},
+    energy::{EnergyFlows, EnergyLedger, eddy_breakdown_rate, kinetic_energy},
+    error::FluidError,
+    link::ConceptLink,
+    noise::turbulence_noise,
+    ore::{
+        OrePressureWeights, OreStats, OreType, PreciousOre, PressureChangeReason, SolutionShape,
+    },
+    rules::RuleSet,
+    salinity::SalinityBand,
+    snapshot::{SCHEMA_VERSION, legacy_schema_version},
+    standing_wave::{
+        DivisionExperiment, DivisionProblem, DivisionResult, SettledBy, SettlementThresholds,
+        StandingWave,
+    },
+    timeseries::TimeSeries,
+    trace::{TraceRow, TraceStore},
     traits::CharacterTrait,
 };
 use crate::state::events::FluidEvent;
 
+/// Consecutive surface bounces before a thought registers as a building urge
+const URGE_BOUNCE_THRESHOLD: u32 = 3;
+/// Depth below which a bouncing thought counts as submerged
+const URGE_SUBMERGED_DEPTH: f32 = 0.3;
+/// Seconds of submersion after which a building urge is forgotten
+const URGE_RESET_TIME: f32 = 2.0;
+/// Depth a re-immersed trait re-enters the fluid at (kept below the evaporation zone)
+const REIMMERSION_LAYER: f32 = 0.5;
+/// Minimum vent separation for consensus experiments seeded from concepts
+const CONSENSUS_MIN_VENT_GAP: f32 = 0.2;
+/// Distance beyond a vent's radius a concept must reach before its next
+/// entry into the plume counts as a new pass
+const VENT_EXIT_MARGIN: f32 = 0.05;
+/// Vent passes per ore deposit
+const PASSES_PER_ORE: u32 = 3;
+/// Vent passes after which a dark thought's ore is Insight
+const INSIGHT_PASSES: u32 = 6;
+/// Depth within which another concept crowds a concept's drag
+const CROWDING_RADIUS: f32 = 0.05;
+/// Depth span of a continent formed by a tectonic shift
+const CONTINENT_SPAN: f32 = 0.15;
+/// Continents form no shallower than this and no deeper than the floor margin
+const CONTINENT_DEPTH_LIMITS: (f32, f32) = (0.6, 0.95);
+/// Seconds a precipitated thought falls in grace: no surface time, no
+/// freezing, no surface tension
+const PRECIPITATION_ENTRY_WINDOW: f32 = 1.0;
+/// Smallest floor pressure move reported as a `PressureChanged` event;
+/// smaller moves accumulate until they cross it
+const PRESSURE_EVENT_DEBOUNCE: f32 = 0.01;
+/// Downward speed a precipitated thought enters the fluid with
+const PRECIPITATION_VELOCITY: f32 = 0.5;
+/// Most sub-steps one `update` is split into; time beyond them is dropped
+const MAX_SUBSTEPS: u32 = 600;
+
+/// What a flash heal did, as the fluid saw it.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct FlashHealOutcome {
+    pub old_salinity: f32,
+    pub new_salinity: f32,
+    pub concepts_added: usize,
+    /// The fluid was frozen and the heal thawed it
+    pub broke_freeze: bool,
+    /// The fresh concepts, in request order
+    pub concept_ids: Vec<ConceptId>,
+    /// Size each fresh concept was given, in the same order
+    pub concept_dimensions: Vec<ConceptDimensions>,
+}
+
+/// Forces on a concept that depend only on where it sits: buoyancy toward its
+/// target layer, the salinity boost, vent plumes and standing waves. Drag,
+/// surface tension, turbulence and repulsion depend on motion and are applied
+/// separately in the physics pass. Positive values push downward.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DepthForces {
+    pub buoyancy: f32,
+    pub salinity: f32,
+    pub thermal: f32,
+    pub wave: f32,
+}
+
+impl DepthForces {
+    pub fn on(
+        concept: &Concept,
+        unresolved_weight: f32,
+        salinity: f32,
+        core_truths: &[CoreTruth],
+        standing_waves: &[StandingWave],
+        persistent_waves: &[StandingWave],
+    ) -> Self {
+        Self::at(
+            concept,
+            concept.layer,
+            unresolved_weight,
+            salinity,
+            core_truths,
+            standing_waves,
+            persistent_waves,
+        )
+    }
+
+    /// The forces `concept` would feel at `layer` rather than where it is.
+    pub fn at(
+        concept: &Concept,
+        layer: f32,
+        unresolved_weight: f32,
+        salinity: f32,
+        core_truths: &[CoreTruth],
+        standing_waves: &[StandingWave],
+        persistent_waves: &[StandingWave],
+    ) -> Self {
+        let effective_density = (concept.effective_density() + unresolved_weight).min(1.0);
+        // Buoyancy above 1.0 (extended range only) targets a layer above the
+        // surface, so the concept keeps pressing against it instead of resting there
+        let highest_target = 1.0 - concept.buoyancy.max(1.0);
+        let target_layer = (1.0 - concept.buoyancy + concept.ballast + unresolved_weight)
+            .clamp(highest_target, 1.0);
+        let diff = target_layer - layer;
+
+        let salinity_boost = if effective_density < 0.5 {
+            salinity * (0.5 - effective_density) * 2.0
+        } else {
+            0.0
+        };
+
+        let mut thermal = 0.0;
+        for core_truth in core_truths {
+            thermal -= core_truth.heat_transfer_at(layer);
+        }
+
+        // Experiment waves count only their bubbles, persistent waves every
+        // concept; either way a concept doesn't crowd itself out of its node
+        let mut wave = 0.0;
+        for standing_wave in standing_waves {
+            let occupying = (concept.role == ConceptRole::ProbeBubble)
+                .then(|| standing_wave.occupied_node(concept.layer))
+                .flatten();
+            wave += standing_wave.force_on(layer, occupying);
+        }
+        for standing_wave in persistent_waves {
+            wave += standing_wave.force_on(layer, standing_wave.occupied_node(concept.layer));
+        }
+
+        Self {
+            buoyancy: diff * concept.density,
+            salinity: -salinity_boost,
+            thermal,
+            wave,
+        }
+    }
+
+    pub fn net(&self) -> f32 {
+        self.buoyancy + self.salinity + self.thermal + self.wave
+    }
+}
+
+/// Everything besides the concept itself that its net force depends on,
+/// borrowed from the fluid for one physics pass so the force can be taken
+/// at trial states without touching the concept.
+struct ForceField<'a> {
+    salinity: f32,
+    core_truths: &'a [CoreTruth],
+    standing_waves: &'a [StandingWave],
+    persistent_waves: &'a [StandingWave],
+    consensus: Option<&'a ConsensusExperiment>,
+    division: Option<&'a DivisionExperiment>,
+    viscosity_surface: f32,
+    viscosity_deep: f32,
+    shear_threshold: f32,
+    shear_thinning_coefficient: f32,
+    crowding_drag_factor: f32,
+    drag_coefficient: f32,
+    activation_zone: f32,
+    surface_tension: f32,
+    post_breakthrough_drift: f32,
+}
+
+impl ForceField<'_> {
+    fn viscosity_at(&self, layer: f32) -> f32 {
+        depth_viscosity(self.viscosity_surface, self.viscosity_deep, layer)
+    }
+
+    /// Net deterministic force on `concept` were it at `layer` moving at
+    /// `velocity`, with `neighbours` crowding it. Turbulence and bubble
+    /// repulsion are kicks applied apart from this.
+    fn net_force(
+        &self,
+        concept: &Concept,
+        unresolved_weight: f32,
+        neighbours: usize,
+        layer: f32,
+        velocity: f32,
+    ) -> f32 {
+        let depth_forces = DepthForces::at(
+            concept,
+            layer,
+            unresolved_weight,
+            self.salinity,
+            self.core_truths,
+            self.standing_waves,
+            self.persistent_waves,
+        );
+        let buoyancy_force = depth_forces.buoyancy + depth_forces.salinity;
+
+        let effective_visc = shear_thinned(
+            self.viscosity_at(layer),
+            velocity,
+            self.shear_threshold,
+            self.shear_thinning_coefficient,
+        );
+        // Viscous coupling: every neighbour in the band thickens the medium
+        let effective_visc = effective_visc * (1.0 + self.crowding_drag_factor * neighbours as f32);
+
+        let drag_force = if velocity.abs() > 0.001 {
+            -0.5 * effective_visc
+                * velocity.powi(2)
+                * self.drag_coefficient
+                * concept.area
+                * velocity.signum()
+        } else {
+            0.0
+        };
+
+        let surface_force =
+            if layer < self.activation_zone && velocity < 0.0 && concept.grace_remaining <= 0.0 {
+                let depth_factor = 1.0 - (layer / self.activation_zone);
+                self.surface_tension * depth_factor
+            } else {
+                0.0
+            };
+
+        // Consensus reactor thermal collision force on its probes
+        let consensus_force = match self.consensus {
+            Some(exp) if exp.probe_ids.contains(&concept.id) => {
+                let (net_force, _collision_intensity) = exp.thermal_collision_at(layer);
+                net_force * 2.0 // Amplify for visible effect
+            }
+            _ => 0.0,
+        };
+
+        // Division bubbles are small enough for Stokes flow: drag linear in
+        // speed, which is what brings them to rest at the nodes
+        let stokes_drag = match self.division {
+            Some(exp) if exp.bubble_ids.contains(&concept.id) => -exp.bubble_drag * velocity,
+            _ => 0.0,
+        };
+
+        // Acted-on thoughts sink back while their grace lasts
+        let grace_drift = if concept.grace_remaining > 0.0 {
+            self.post_breakthrough_drift
+        } else {
+            0.0
+        };
+
+        buoyancy_force
+            + drag_force
+            + surface_force
+            + depth_forces.thermal
+            + depth_forces.wave
+            + consensus_force
+            + stokes_drag
+            + grace_drift
+    }
+}
+
+/// Viscosity at `depth`, linear from `surface` at 0.0 to `deep` at 1.0.
+fn depth_viscosity(surface: f32, deep: f32, depth: f32) -> f32 {
+    surface + (deep - surface) * depth.clamp(0.0, 1.0)
+}
+
+/// Non-Newtonian shear-thinning: effective viscosity drops at high velocity.
+/// This allows "remainder bubbles" to scream through local turbulence, while
+/// slow concepts feel the full `viscosity` and stay stable.
+fn shear_thinned(viscosity: f32, velocity: f32, threshold: f32, coefficient: f32) -> f32 {
+    let shear_rate = velocity.abs();
+    if shear_rate <= threshold {
+        viscosity
+    } else {
+        let excess_shear = shear_rate - threshold;
+        viscosity * (1.0 - (coefficient * excess_shear).min(0.9))
+    }
+}
+
+/// One classic fourth-order Runge-Kutta step of `layer'' = acceleration(layer,
+/// layer')`, returning the change in layer and in velocity over `dt`.
+fn rk4_step(
+    layer: f32,
+    velocity: f32,
+    dt: f32,
+    acceleration: impl Fn(f32, f32) -> f32,
+) -> (f32, f32) {
+    let half = dt / 2.0;
+    let a1 = acceleration(layer, velocity);
+    let v2 = velocity + a1 * half;
+    let a2 = acceleration(layer + velocity * half, v2);
+    let v3 = velocity + a2 * half;
+    let a3 = acceleration(layer + v2 * half, v3);
+    let v4 = velocity + a3 * dt;
+    let a4 = acceleration(layer + v3 * dt, v4);
+    (
+        dt / 6.0 * (velocity + 2.0 * v2 + 2.0 * v3 + v4),
+        dt / 6.0 * (a1 + 2.0 * a2 + 2.0 * a3 + a4),
+    )
+}
+
+/// How the physics pass steps each concept's velocity and layer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegratorKind {
+    /// Semi-implicit Euler: the force at the start of the tick sets the new
+    /// velocity, which then moves the concept. Cheap; error grows with dt
+    #[default]
+    Euler,
+    /// Fourth-order Runge-Kutta: the force is taken at four trial states
+    /// through the tick. Four times the force evaluations, far less drift
+    Rk4,
+}
+
+/// How the Reynolds number weighs the speeds of the concepts it covers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReynoldsMode {
+    /// Mean speed: one fast concept can stir the whole fluid
+    #[default]
+    Mean,
+    /// Mean speed discounted by the spread of speeds, `mean / (1 + σ²/mean²)`:
+    /// motion carried by a few outliers counts for little, broad agitation
+    /// in full
+    Variance,
+}
+
+impl ReynoldsMode {
+    /// The speed a Reynolds number is taken from.
+    pub fn characteristic_speed(self, speeds: &[f32]) -> f32 {
+        let n = speeds.len().max(1) as f32;
+        let mean = speeds.iter().sum::<f32>() / n;
+        match self {
+            Self::Mean => mean,
+            Self::Variance => {
+                // mean / (1 + σ²/mean²) = mean³ / mean(speed²)
+                let mean_square = speeds.iter().map(|s| s * s).sum::<f32>() / n;
+                if mean_square > 0.0 {
+                    mean.powi(3) / mean_square
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
 /// The main container for the consciousness fluid simulation.
 /// Contains all concepts, traits, vents, ores, and continents,
 /// along with physics parameters for the simulation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConceptFluid {
+    /// Snapshot schema version (see `snapshot::SCHEMA_VERSION`)
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
+
     // === Entities ===
     pub concepts: HashMap<ConceptId, Concept>,
+    /// Sequence number for the next concept created. Only ever increases, so
+    /// removals never cause a number to be reused.
+    #[serde(default)]
+    pub next_concept_seq: u64,
+    /// Concepts ever created under each name, removed ones included
+    #[serde(default)]
+    pub name_history: HashMap<String, u64>,
     /// Evaporated concepts → permanent traits (the "atmosphere")
     pub atmosphere: Vec<CharacterTrait>,
     /// Deep sea vents - radiating foundational beliefs
     pub core_truths: Vec<CoreTruth>,
     /// Mineralized transformations on ocean floor
     pub ore_deposits: Vec<PreciousOre>,
+    /// Lifetime ore counters (survive tectonic shifts and snapshots)
+    #[serde(default)]
+    pub ore_stats: OreStats,
     /// Permanent landmasses - solid ground in the fluid
     pub continents: Vec<Continent>,
+    /// Thematic connections holding pairs of concepts near each other
+    #[serde(default)]
+    pub links: Vec<ConceptLink>,
 
     // === Tracking ===
-    /// Track cycles through vents for mineralization
+    /// Discrete passes of dark concepts through vent plumes, for mineralization
     pub vent_encounter_count: HashMap<ConceptId, u32>,
+    /// Ticks a dark concept must have existed before a vent pass can
+    /// mineralize it; passes while incubating still count toward later ore
+    #[serde(default)]
+    pub mineralization_incubation_ticks: u64,
+    /// (concept, vent index) pairs currently inside the plume; the concept
+    /// must leave the radius plus a margin before re-entering counts again
+    #[serde(default)]
+    pub in_vent_plume: HashSet<(ConceptId, usize)>,
     /// Total weight of ore deposits creating tectonic pressure
     pub ocean_floor_pressure: f32,
+    /// Floor pressure as of the last `PressureChanged` event
+    #[serde(default)]
+    pub reported_floor_pressure: f32,
     /// Critical pressure for tectonic shift
     pub pressure_threshold: f32,
+    /// Per-type multipliers on ore pressure (all 1.0 by default)
+    #[serde(default)]
+    pub ore_pressure_weights: OrePressureWeights,
     /// How many times bedrock has shifted
     pub tectonic_shifts: u32,
+    /// Name of a continent formed mostly from each ore type; `{count}` is
+    /// replaced by the number of those ores. Missing types use the defaults.
+    #[serde(default = "default_continent_names")]
+    pub continent_names: HashMap<OreType, String>,
+    /// Shape of the solutions each ore type catalyzes; missing types use
+    /// `SolutionShape::default`
+    #[serde(default)]
+    pub solution_shapes: HashMap<OreType, SolutionShape>,
 
     // === Physics parameters ===
-    /// Fluid density (ρ in drag equation)
-    pub viscosity: f32,
+    /// Fluid density (ρ in the drag equation) at the surface, where thoughts
+    /// move freely
+    pub viscosity_surface: f32,
+    /// Fluid density at the floor, where thoughts are thick with history.
+    /// In between it varies linearly with depth.
+    pub viscosity_deep: f32,
     /// Resistance from ego/executive control (Cd)
     pub drag_coefficient: f32,
+    /// How each tick steps velocity and layer (Euler unless changed)
+    #[serde(default)]
+    pub integrator: IntegratorKind,
+    /// Longest tick simulated in one step: `update` splits a larger `dt`
+    /// into sub-steps no longer than this
+    #[serde(default = "default_max_dt")]
+    pub max_dt: f32,
     /// Threshold force for breaking into action
     pub surface_tension: f32,
-    /// Layer depth where surface tension applies
+    /// Layer depth where surface tension applies. Must contain the freeze
+    /// zone and sit inside the evaporation zone (see `validate_zones`)
     pub activation_zone: f32,
+    /// Smallest area a new concept is given, however it was sized
+    #[serde(default = "default_min_concept_area")]
+    pub min_concept_area: f32,
+    /// Largest area a new concept is given, however it was sized
+    #[serde(default = "default_max_concept_area")]
+    pub max_concept_area: f32,
 
     // === Freeze mechanics ===
     /// Time at surface before freeze occurs (seconds)
@@ -59,32 +462,74 @@ pub struct ConceptFluid {
     pub is_frozen: bool,
     /// Which concept caused the freeze
     pub frozen_concept: Option<ConceptId>,
+    /// Seconds after a breakthrough during which the concept neither accrues
+    /// surface time nor freezes the fluid
+    #[serde(default = "default_post_breakthrough_grace")]
+    pub post_breakthrough_grace: f32,
+    /// Downward push on concepts in their grace window, so acted-on
+    /// thoughts re-submerge (0.0 = none)
+    #[serde(default)]
+    pub post_breakthrough_drift: f32,
+    /// Layer precipitated thoughts fall into the fluid at (0.0 = the surface)
+    #[serde(default)]
+    pub precipitation_entry_depth: f32,
+    /// Let a concept that broke through act again once it has sunk back
+    /// below `rearm_depth` for `rearm_seconds` (false: it acts once)
+    #[serde(default)]
+    pub rearm_enabled: bool,
+    #[serde(default = "default_rearm_depth")]
+    pub rearm_depth: f32,
+    #[serde(default = "default_rearm_seconds")]
+    pub rearm_seconds: f32,
+    /// Fraction of a concept's integration spent when it breaks through,
+    /// moved into `total_integration` and salinity as experience (0.0 = free)
+    #[serde(default)]
+    pub action_cost: f32,
 
     // === Turbulence mechanics ===
     /// Re threshold for turbulence onset
     pub reynolds_threshold: f32,
+    /// How the Reynolds number weighs concept speeds
+    #[serde(default)]
+    pub reynolds_mode: ReynoldsMode,
     /// Is the fluid in turbulent state?
     pub is_turbulent: bool,
     /// Current turbulence energy level
     pub turbulence_energy: f32,
     /// Rate at which turbulence decays
     pub turbulence_decay: f32,
+    /// Seed for per-concept turbulent perturbations; saved with the fluid
+    /// so a restored snapshot replays the same turbulence
+    #[serde(default)]
+    pub turbulence_seed: u64,
     /// "Deep breath" - active damping strength
     pub damping_factor: f32,
+    /// Only concepts moving faster than this are damped (0.0 = all)
+    #[serde(default)]
+    pub damping_velocity_threshold: f32,
 
     // === Integration & Evaporation ===
     /// System-wide accumulated internal heat
     pub total_integration: f32,
     /// Integration level needed to evaporate
     pub evaporation_threshold: f32,
-    /// Layer depth for evaporation (near surface)
+    /// Layer depth for evaporation (near surface), the outermost surface zone
     pub evaporation_zone: f32,
 
     // === Salinity ===
     /// Accumulated knowledge density
     pub salinity: f32,
-    /// How fast integration increases salinity
+    /// Salinity gained per unit of integration gained
     pub salinity_rate: f32,
+    /// Named band the salinity last settled in (see `salinity`)
+    #[serde(default)]
+    pub salinity_band: SalinityBand,
+    /// Integration a forgotten concept needs to release its knowledge
+    #[serde(default = "default_knowledge_release_threshold")]
+    pub knowledge_release_threshold: f32,
+    /// Salinity released per unit of a forgotten concept's integration
+    #[serde(default = "default_knowledge_release_rate")]
+    pub knowledge_release_rate: f32,
 
     // === Visualization ===
     /// Number of layers for bucketing
@@ -97,6 +542,13 @@ pub struct ConceptFluid {
     // === Division Experiments (Analog Computing) ===
     /// Active standing waves for division experiments
     pub standing_waves: Vec<StandingWave>,
+    /// Standalone waves acting on every concept; unlike experiment waves
+    /// they stay until removed
+    #[serde(default)]
+    pub persistent_waves: Vec<StandingWave>,
+    /// Focused attention deepening concepts in a depth window (see `focus_attention`)
+    #[serde(default)]
+    pub spotlights: Vec<Spotlight>,
     /// Currently running division experiment
     pub active_experiment: Option<DivisionExperiment>,
     /// Completed experiment results
@@ -119,12 +571,109 @@ pub struct ConceptFluid {
     /// Minimum distance for repulsion calculation (prevents division by zero)
     pub bubble_repulsion_min_dist: f32,
 
+    // === Unresolved Integration ("heaviness of unprocessed memory") ===
+    /// Integrated thoughts that haven't surfaced or evaporated weigh the mind down
+    #[serde(default)]
+    pub integration_heaviness_enabled: bool,
+    /// Density added per unit of unresolved integration
+    #[serde(default = "default_integration_heaviness_rate")]
+    pub integration_heaviness_rate: f32,
+
+    // === Viscous Coupling ("traffic jams") ===
+    /// Thicken the drag on concepts in crowded depth bands
+    #[serde(default)]
+    pub crowding_drag_enabled: bool,
+    /// Viscosity multiplier added per neighbour within `CROWDING_RADIUS`
+    #[serde(default = "default_crowding_drag_factor")]
+    pub crowding_drag_factor: f32,
+
+    // === Super-Buoyancy ("overwhelming drive") ===
+    /// Let modulated buoyancy exceed 1.0, up to `extended_buoyancy_max`
+    #[serde(default)]
+    pub extended_buoyancy_enabled: bool,
+    /// Buoyancy ceiling while the extended range is enabled
+    #[serde(default = "default_extended_buoyancy_max")]
+    pub extended_buoyancy_max: f32,
+
+    // === Collision Exchange ===
+    /// Colliding thoughts even out some of their integration
+    #[serde(default)]
+    pub collision_exchange_enabled: bool,
+
     // === Consensus Reactor (Contradictory Vent Collision) ===
     /// The reactor for extracting stable truths from contradictory inputs
     pub consensus_reactor: ConsensusReactor,
+
+    // === Rules ===
+    /// Commands sent automatically in reaction to events (see `rules`)
+    #[serde(default)]
+    pub rules: RuleSet,
+
+    // === Metrics History ===
+    /// Bounded in-memory time series of key metrics (never snapshotted)
+    #[serde(skip)]
+    pub timeseries: TimeSeries,
+    /// Kinetic energy accounting (never snapshotted)
+    #[serde(skip)]
+    pub energy: EnergyLedger,
+    /// Per-tick traces of completed experiments started with `trace: true`
+    #[serde(skip)]
+    pub traces: TraceStore,
+}
+
+fn default_max_dt() -> f32 {
+    1.0 / 30.0
+}
+
+fn default_min_concept_area() -> f32 {
+    0.1
+}
+
+fn default_max_concept_area() -> f32 {
+    2.0
+}
+
+fn default_post_breakthrough_grace() -> f32 {
+    3.0
+}
+
+fn default_knowledge_release_threshold() -> f32 {
+    0.2
+}
+
+fn default_knowledge_release_rate() -> f32 {
+    0.5
+}
+
+fn default_rearm_depth() -> f32 {
+    0.4
+}
+
+fn default_rearm_seconds() -> f32 {
+    0.5
+}
+
+fn default_integration_heaviness_rate() -> f32 {
+    0.1
+}
+
+fn default_crowding_drag_factor() -> f32 {
+    0.1
+}
+
+fn default_extended_buoyancy_max() -> f32 {
+    1.5
+}
+
+impl Default for ConceptFluid {
+    /// Create a fluid with default parameters.
+    fn default() -> Self {
+        Self::new(0.5, 1.2, 0.05, 0.1, 2.0, 0.05, 1.0, 0.3, 5, 1.0, 0.3)
+    }
 }
 
 impl ConceptFluid {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         viscosity: f32,
         drag_coefficient: f32,
@@ -138,37 +687,68 @@ impl ConceptFluid {
         evaporation_threshold: f32,
         evaporation_zone: f32,
     ) -> Self {
-        Self {
+        let fluid = Self {
+            schema_version: SCHEMA_VERSION,
             concepts: HashMap::new(),
+            next_concept_seq: 0,
+            name_history: HashMap::new(),
             atmosphere: Vec::new(),
             core_truths: Vec::new(),
             ore_deposits: Vec::new(),
+            ore_stats: OreStats::default(),
             continents: Vec::new(),
+            links: Vec::new(),
             vent_encounter_count: HashMap::new(),
+            mineralization_incubation_ticks: 0,
+            in_vent_plume: HashSet::new(),
             ocean_floor_pressure: 0.0,
+            reported_floor_pressure: 0.0,
             pressure_threshold: 15.0,
+            ore_pressure_weights: OrePressureWeights::default(),
+            continent_names: default_continent_names(),
+            solution_shapes: HashMap::new(),
             tectonic_shifts: 0,
-            viscosity,
+            viscosity_surface: viscosity,
+            viscosity_deep: viscosity,
             drag_coefficient,
+            integrator: IntegratorKind::default(),
+            max_dt: default_max_dt(),
             surface_tension,
             activation_zone,
+            min_concept_area: default_min_concept_area(),
+            max_concept_area: default_max_concept_area(),
             freeze_threshold,
             freeze_zone,
             is_frozen: false,
             frozen_concept: None,
+            post_breakthrough_grace: default_post_breakthrough_grace(),
+            post_breakthrough_drift: 0.0,
+            precipitation_entry_depth: 0.0,
+            rearm_enabled: false,
+            rearm_depth: default_rearm_depth(),
+            rearm_seconds: default_rearm_seconds(),
+            action_cost: 0.0,
             reynolds_threshold,
+            reynolds_mode: ReynoldsMode::default(),
             is_turbulent: false,
             turbulence_energy: 0.0,
             turbulence_decay,
+            turbulence_seed: 0,
             damping_factor: 0.0,
+            damping_velocity_threshold: 0.0,
             total_integration: 0.0,
             evaporation_threshold,
             evaporation_zone,
             salinity: 0.0,
-            salinity_rate: 0.1,
+            salinity_rate: 5.0,
+            knowledge_release_threshold: default_knowledge_release_threshold(),
+            knowledge_release_rate: default_knowledge_release_rate(),
+            salinity_band: SalinityBand::Fresh,
             num_layers,
             tick_count: 0,
             standing_waves: Vec::new(),
+            persistent_waves: Vec::new(),
+            spotlights: Vec::new(),
             active_experiment: None,
             experiment_results: Vec::new(),
             base_viscosity: viscosity,
@@ -177,45 +757,208 @@ impl ConceptFluid {
             bubble_repulsion_enabled: true,
             bubble_repulsion_strength: 1.0, // Strong LJ repulsion (ε parameter)
             bubble_repulsion_min_dist: 0.03, // Minimum distance to prevent singularity
+            integration_heaviness_enabled: false,
+            integration_heaviness_rate: default_integration_heaviness_rate(),
+            crowding_drag_enabled: false,
+            crowding_drag_factor: default_crowding_drag_factor(),
+            extended_buoyancy_enabled: false,
+            extended_buoyancy_max: default_extended_buoyancy_max(),
+            collision_exchange_enabled: false,
             consensus_reactor: ConsensusReactor::new(),
+            rules: RuleSet::default(),
+            timeseries: TimeSeries::default(),
+            energy: EnergyLedger::default(),
+            traces: TraceStore::default(),
+        };
+        if let Err(err) = fluid.validate_zones() {
+            tracing::warn!("Inconsistent fluid zones: {}", err);
         }
+        fluid
     }
 
-    /// Create a fluid with default parameters.
-    pub fn default() -> Self {
-        Self::new(0.5, 1.2, 0.05, 0.1, 2.0, 0.05, 1.0, 0.3, 5, 1.0, 0.3)
+    /// Check that the surface zones nest: freeze ⊆ activation ⊆ evaporation.
+    ///
+    /// A thought rising toward the surface should first enter the evaporation
+    /// zone, then feel surface tension, and only freeze once it is pinned
+    /// against the surface. A freeze zone deeper than the activation zone
+    /// would freeze thoughts before surface tension ever applies.
+    pub fn validate_zones(&self) -> Result<(), String> {
+        let zones = [
+            ("freeze_zone", self.freeze_zone),
+            ("activation_zone", self.activation_zone),
+            ("evaporation_zone", self.evaporation_zone),
+        ];
+        for (name, depth) in zones {
+            if !(depth > 0.0 && depth <= 1.0) {
+                return Err(format!("{} must be in (0, 1], got {}", name, depth));
+            }
+        }
+        for pair in zones.windows(2) {
+            let ((inner, inner_depth), (outer, outer_depth)) = (pair[0], pair[1]);
+            if inner_depth > outer_depth {
+                return Err(format!(
+                    "{} ({}) must not be deeper than {} ({})",
+                    inner, inner_depth, outer, outer_depth
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Viscosity at `depth` at rest, between `viscosity_surface` and
+    /// `viscosity_deep`.
+    pub fn viscosity_at_depth(&self, depth: f32) -> f32 {
+        depth_viscosity(self.viscosity_surface, self.viscosity_deep, depth)
     }
 
-    /// Calculate effective viscosity using shear-thinning model.
-    /// High velocity (shear) → lower viscosity → allows "remainder screaming"
-    /// Low velocity → high viscosity → maintains stability
-    pub fn effective_viscosity(&self, velocity: f32) -> f32 {
-        let shear_rate = velocity.abs();
+    /// Effective viscosity for a concept at `depth` moving at `velocity`:
+    /// the viscosity there, thinned by shear above `shear_threshold`.
+    pub fn effective_viscosity_at_depth(&self, depth: f32, velocity: f32) -> f32 {
+        shear_thinned(
+            self.viscosity_at_depth(depth),
+            velocity,
+            self.shear_threshold,
+            self.shear_thinning_coefficient,
+        )
+    }
 
-        if shear_rate <= self.shear_threshold {
-            // Below threshold: full viscosity (Newtonian)
-            self.viscosity
-        } else {
-            // Above threshold: shear-thinning (non-Newtonian)
-            // Viscosity drops as shear increases
-            let excess_shear = shear_rate - self.shear_threshold;
-            let thinning_factor = 1.0 - (self.shear_thinning_coefficient * excess_shear).min(0.9);
-            self.viscosity * thinning_factor
-        }
+    /// Viscosity averaged over the column, for measures of the fluid as a
+    /// whole.
+    pub fn mean_viscosity(&self) -> f32 {
+        0.5 * (self.viscosity_surface + self.viscosity_deep)
+    }
+
+    /// Reynolds number for a given mean |velocity| - the turbulence onset metric.
+    pub fn reynolds_number_for(&self, avg_velocity: f32) -> f32 {
+        avg_velocity / self.mean_viscosity()
+    }
+
+    /// Reynolds number of the thoughts in the fluid, which drives turbulence
+    /// onset. Experiments measure their own probes (see `local_reynolds`).
+    pub fn fluid_reynolds(&self) -> f32 {
+        let speeds: Vec<f32> = self
+            .concepts
+            .values()
+            .filter(|c| c.participates_in_attention())
+            .map(|c| c.velocity.abs())
+            .collect();
+        self.reynolds_number_for(self.reynolds_mode.characteristic_speed(&speeds))
+    }
+
+    /// Reynolds number of just the given concepts, for experiments that
+    /// measure their own probes apart from the fluid.
+    pub fn local_reynolds(&self, ids: &[ConceptId]) -> f32 {
+        let speeds: Vec<f32> = ids
+            .iter()
+            .filter_map(|id| self.concepts.get(id))
+            .map(|c| c.velocity.abs())
+            .collect();
+        self.reynolds_number_for(self.reynolds_mode.characteristic_speed(&speeds))
     }
 
     /// Add a new concept to the fluid.
     pub fn add_concept(&mut self, name: String, density: f32, area: f32) -> ConceptId {
-        let id = Uuid::new_v4();
-        let concept = Concept::new(id, name, density, area);
+        let concept = self.new_concept(name, density, area);
+        let id = concept.id;
+        self.concepts.insert(id, concept);
+        id
+    }
+
+    /// Add a concept starting at `initial_layer` (clamped to 0.0-1.0) with
+    /// `initial_velocity` and `initial_buoyancy` (clamped to
+    /// `0.0..=max_buoyancy()`). Buoyancy drives where it settles, the layer
+    /// is only where it starts; either omitted follows its density, like
+    /// `add_concept`.
+    pub fn inject_concept(
+        &mut self,
+        name: String,
+        density: f32,
+        area: f32,
+        initial_layer: Option<f32>,
+        initial_velocity: Option<f32>,
+        initial_buoyancy: Option<f32>,
+    ) -> ConceptId {
+        let mut concept = self.new_concept(name, density, area);
+        let id = concept.id;
+        if let Some(layer) = initial_layer {
+            concept.layer = layer.clamp(0.0, 1.0);
+        }
+        if let Some(buoyancy) = initial_buoyancy {
+            concept.buoyancy = buoyancy.clamp(0.0, self.max_buoyancy());
+        }
+        if let Some(velocity) = initial_velocity {
+            concept.velocity = velocity;
+            self.energy.record_impulse(0.0, velocity);
+        }
         self.concepts.insert(id, concept);
         id
     }
 
-    /// Add a core truth (deep sea vent) to the fluid.
-    pub fn add_core_truth(&mut self, name: String, heat_output: f32, depth: f32, radius: f32) {
+    /// Area and volume a new concept of `density` gets when sized by `size`,
+    /// the area held within `min_concept_area..=max_concept_area`.
+    pub fn concept_dimensions(&self, density: f32, size: ConceptSize) -> ConceptDimensions {
+        let area = match size {
+            ConceptSize::Volume(volume) => Concept::area_from_volume(density, volume),
+            ConceptSize::Area(area) => area,
+        };
+        // max/min rather than clamp: bounds loaded from a snapshot may be inverted
+        let area = area.max(self.min_concept_area).min(self.max_concept_area);
+        ConceptDimensions {
+            area,
+            volume: Concept::volume_from_area(density, area),
+        }
+    }
+
+    /// Create (but do not insert) a concept with a fresh id and the next
+    /// sequence number.
+    fn new_concept(&mut self, name: String, density: f32, area: f32) -> Concept {
+        self.record_name(&name);
+        self.new_experiment_concept(name, density, area)
+    }
+
+    /// Like `new_concept`, but for experiment machinery: its name is not
+    /// counted in `name_history`.
+    fn new_experiment_concept(&mut self, name: String, density: f32, area: f32) -> Concept {
+        let mut concept = Concept::new(Uuid::new_v4(), name, density, area);
+        concept.seq = self.take_concept_seq();
+        concept.born_tick = self.tick_count;
+        concept
+    }
+
+    fn record_name(&mut self, name: &str) {
+        *self.name_history.entry(name.to_string()).or_insert(0) += 1;
+    }
+
+    fn take_concept_seq(&mut self) -> u64 {
+        let seq = self.next_concept_seq;
+        self.next_concept_seq += 1;
+        seq
+    }
+
+    /// Add a core truth (deep sea vent) to the fluid, returning it for
+    /// further shaping.
+    pub fn add_core_truth(
+        &mut self,
+        name: String,
+        heat_output: f32,
+        depth: f32,
+        radius: f32,
+    ) -> &mut CoreTruth {
         let core_truth = CoreTruth::new(name, heat_output, depth, radius);
         self.core_truths.push(core_truth);
+        self.core_truths.last_mut().unwrap()
+    }
+
+    /// Enable or disable a core truth without losing its history.
+    /// Returns false if no vent exists at `index`.
+    pub fn set_core_truth_active(&mut self, index: usize, active: bool) -> bool {
+        match self.core_truths.get_mut(index) {
+            Some(truth) => {
+                truth.active = active;
+                true
+            }
+            None => false,
+        }
     }
 
     /// Get a concept by ID.
@@ -223,40 +966,139 @@ impl ConceptFluid {
         self.concepts.get(&id)
     }
 
+    /// Every concept of `origin` (all of them when None), oldest first.
+    /// Evaporated concepts are included; removed ones are gone.
+    pub fn concepts_by_origin(&self, origin: Option<ConceptOrigin>) -> Vec<&Concept> {
+        let mut concepts: Vec<_> = self
+            .concepts
+            .values()
+            .filter(|c| origin.is_none_or(|origin| c.origin == origin))
+            .collect();
+        concepts.sort_by_key(|c| c.seq);
+        concepts
+    }
+
+    /// The `k` concepts still in the fluid closest in depth to `id`, nearest
+    /// first (older first on ties), with their depth distance. None if `id`
+    /// is unknown.
+    pub fn depth_neighbors(&self, id: ConceptId, k: usize) -> Option<Vec<(&Concept, f32)>> {
+        let layer = self.concepts.get(&id)?.layer;
+        let mut neighbors: Vec<_> = self
+            .concepts
+            .values()
+            .filter(|c| c.id != id && !c.has_evaporated)
+            .map(|c| (c, (c.layer - layer).abs()))
+            .collect();
+        neighbors.sort_by(|(a, da), (b, db)| da.total_cmp(db).then(a.seq.cmp(&b.seq)));
+        neighbors.truncate(k);
+        Some(neighbors)
+    }
+
     /// Get a mutable concept by ID.
     pub fn get_concept_mut(&mut self, id: ConceptId) -> Option<&mut Concept> {
         self.concepts.get_mut(&id)
     }
 
+    /// Reframe a concept under a new name, leaving its physics state and history untouched.
+    /// Names already derived from it (ores, solutions, traits) keep the old name.
+    /// Returns the previous name, or None if the concept does not exist.
+    pub fn rename_concept(&mut self, id: ConceptId, name: String) -> Option<String> {
+        let concept = self.concepts.get_mut(&id)?;
+        Some(std::mem::replace(&mut concept.name, name))
+    }
+
     /// Benthic expedition - deliberately sink a problem to find solutions in ore deposits.
-    pub fn benthic_expedition(&mut self, concept_id: ConceptId, ballast_amount: f32) -> bool {
-        if let Some(concept) = self.concepts.get_mut(&concept_id) {
-            concept.ballast = ballast_amount;
-            true
+    pub fn benthic_expedition(
+        &mut self,
+        concept_id: ConceptId,
+        ballast_amount: f32,
+    ) -> Result<(), FluidError> {
+        let concept = self
+            .concepts
+            .get_mut(&concept_id)
+            .ok_or(FluidError::ConceptNotFound { id: concept_id })?;
+        concept.ballast = ballast_amount;
+        Ok(())
+    }
+
+    /// Submerge - deliberately suppress a concept that keeps acting. Unlike a
+    /// benthic expedition it isn't sent looking for ore: it is brought to
+    /// rest at `target_layer` (clamped to 0.0-1.0) and re-armed so it can
+    /// break through again once released. It is given the ballast that makes
+    /// `target_layer` its rest point, or `ballast` if that is more, in which
+    /// case it sinks on from there.
+    pub fn submerge(
+        &mut self,
+        concept_id: ConceptId,
+        target_layer: f32,
+        ballast: f32,
+    ) -> Result<(), FluidError> {
+        let heaviness_rate = if self.integration_heaviness_enabled {
+            self.integration_heaviness_rate
         } else {
-            false
-        }
+            0.0
+        };
+        let concept = self
+            .concepts
+            .get_mut(&concept_id)
+            .ok_or(FluidError::ConceptNotFound { id: concept_id })?;
+        concept.layer = target_layer.clamp(0.0, 1.0);
+        let velocity_before = concept.velocity;
+        concept.velocity = 0.0;
+        // Buoyancy rests a concept at 1 - buoyancy + ballast + unresolved weight
+        let unresolved_weight = if concept.has_evaporated {
+            0.0
+        } else {
+            concept.integration * heaviness_rate
+        };
+        let rest_ballast = concept.layer - 1.0 + concept.buoyancy - unresolved_weight;
+        concept.ballast = rest_ballast.max(ballast).max(0.0);
+        concept.has_broken_surface = false;
+        concept.rearm_progress = 0.0;
+        concept.time_at_surface = 0.0;
+        concept.grace_remaining = 0.0;
+        self.energy.record_impulse(velocity_before, 0.0);
+        Ok(())
     }
 
-    /// Modulate buoyancy externally.
-    pub fn modulate_buoyancy(&mut self, id: ConceptId, delta: f32) {
-        if let Some(concept) = self.concepts.get_mut(&id) {
-            let effective_delta = delta * (1.0 - concept.density);
-            concept.buoyancy = (concept.buoyancy + effective_delta).clamp(0.0, 1.0);
-            concept.velocity += effective_delta * 2.0;
+    /// Highest buoyancy `modulate_buoyancy` allows: 1.0 unless the extended range is enabled.
+    pub fn max_buoyancy(&self) -> f32 {
+        if self.extended_buoyancy_enabled {
+            self.extended_buoyancy_max.max(1.0)
+        } else {
+            1.0
         }
     }
 
+    /// Modulate buoyancy externally, clamped to `0.0..=max_buoyancy()`.
+    pub fn modulate_buoyancy(&mut self, id: ConceptId, delta: f32) -> Result<(), FluidError> {
+        let max_buoyancy = self.max_buoyancy();
+        let concept = self
+            .concepts
+            .get_mut(&id)
+            .ok_or(FluidError::ConceptNotFound { id })?;
+        let effective_delta = delta * (1.0 - concept.density);
+        concept.buoyancy = (concept.buoyancy + effective_delta).clamp(0.0, max_buoyancy);
+        let velocity_before = concept.velocity;
+        concept.velocity += effective_delta * 2.0;
+        self.energy
+            .record_impulse(velocity_before, concept.velocity);
+        Ok(())
+    }
+
     /// Thaw the frozen fluid (external intervention).
     pub fn thaw(&mut self) -> bool {
         if self.is_frozen {
             self.is_frozen = false;
-            if let Some(frozen_id) = self.frozen_concept {
-                if let Some(concept) = self.concepts.get_mut(&frozen_id) {
-                    concept.is_frozen = false;
-                    concept.time_at_surface = 0.0;
-                    concept.velocity += 0.5;
-                }
+            if let Some(frozen_id) = self.frozen_concept
+                && let Some(concept) = self.concepts.get_mut(&frozen_id)
+            {
+                concept.is_frozen = false;
+                concept.time_at_surface = 0.0;
+                let velocity_before = concept.velocity;
+                concept.velocity += 0.5;
+                self.energy
+                    .record_impulse(velocity_before, concept.velocity);
             }
             self.frozen_concept = None;
             true
@@ -266,14 +1108,18 @@ impl ConceptFluid {
     }
 
     /// Deep breath - active damping to restore laminar flow.
-    pub fn deep_breath(&mut self, strength: f32) {
+    pub fn deep_breath(&mut self, strength: f32, velocity_threshold: Option<f32>) {
         self.damping_factor = strength;
+        self.damping_velocity_threshold = velocity_threshold.unwrap_or(0.0);
         if self.is_turbulent {
             self.turbulence_energy *= 1.0 - strength;
         }
     }
 
     /// Precipitation - character trait influences new thought formation.
+    /// The thought falls in from `precipitation_entry_depth` and descends
+    /// through the column, in grace for its first second so entering at the
+    /// surface doesn't freeze it there.
     pub fn precipitate(
         &mut self,
         trait_index: usize,
@@ -287,39 +1133,182 @@ impl ConceptFluid {
 
         let inherited_integration = self.atmosphere[trait_index].integration * 0.3;
 
-        let id = Uuid::new_v4();
-        let mut concept = Concept::new(id, new_concept_name, density, area);
-        concept.layer = 1.0;
-        concept.velocity = 0.5;
+        let mut concept = self.new_concept(new_concept_name, density, area);
+        let id = concept.id;
+        concept.layer = self.precipitation_entry_depth;
+        concept.velocity = PRECIPITATION_VELOCITY;
+        concept.grace_remaining = PRECIPITATION_ENTRY_WINDOW;
         concept.integration = inherited_integration;
+        concept.origin = ConceptOrigin::Precipitated;
+        self.energy.record_impulse(0.0, concept.velocity);
 
         self.concepts.insert(id, concept);
         Some((id, inherited_integration))
     }
 
+    /// Re-immersion - return a trait to the fluid as the thought it evaporated from,
+    /// so it can be reconsidered. Unlike precipitation, no new thought is formed:
+    /// the original concept (recreated under its old id, density and area if it
+    /// has been removed)
+    /// takes back the trait's integration and rests below the evaporation zone,
+    /// free to evaporate again. The trait leaves the atmosphere.
+    pub fn reimmerse_trait(&mut self, trait_index: usize) -> Option<ConceptId> {
+        if trait_index >= self.atmosphere.len() {
+            return None;
+        }
+        let character_trait = self.atmosphere.remove(trait_index);
+        let id = character_trait.formed_from;
+
+        if !self.concepts.contains_key(&id) {
+            let mut concept = Concept::new(
+                id,
+                character_trait.name.clone(),
+                character_trait.density,
+                character_trait.area,
+            );
+            self.record_name(&character_trait.name);
+            concept.seq = self.take_concept_seq();
+            concept.born_tick = self.tick_count;
+            self.concepts.insert(id, concept);
+        }
+        let concept = self.concepts.get_mut(&id).unwrap();
+        concept.has_evaporated = false;
+        concept.integration = character_trait.integration;
+        concept.layer = REIMMERSION_LAYER.max(self.evaporation_zone);
+        self.energy.record_impulse(concept.velocity, 0.0);
+        concept.velocity = 0.0;
+        concept.time_at_surface = 0.0;
+        concept.is_frozen = false;
+        Some(id)
+    }
+
     /// Flash-heal: Surge of fresh, naive input to dilute salinity.
-    pub fn flash_heal(&mut self, concepts: Vec<(String, f32, f32)>, dilution_strength: f32) -> f32 {
+    pub fn flash_heal(
+        &mut self,
+        concepts: Vec<(String, f32, ConceptSize)>,
+        dilution_strength: f32,
+    ) -> FlashHealOutcome {
         let old_salinity = self.salinity;
         self.salinity *= 1.0 - dilution_strength;
 
+        let broke_freeze = self.is_frozen;
         if self.is_frozen {
             self.is_frozen = false;
             self.frozen_concept = None;
         }
 
-        for (name, density, area) in concepts {
-            let id = Uuid::new_v4();
-            let mut concept = Concept::new(id, name, density, area);
+        let mut concept_ids = Vec::with_capacity(concepts.len());
+        let mut concept_dimensions = Vec::with_capacity(concepts.len());
+        for (name, density, size) in concepts {
+            let dimensions = self.concept_dimensions(density, size);
+            let mut concept = self.new_concept(name, density, dimensions.area);
             concept.layer = 0.7;
-            self.concepts.insert(id, concept);
+            concept.origin = ConceptOrigin::FlashHeal;
+            concept_ids.push(concept.id);
+            concept_dimensions.push(dimensions);
+            self.concepts.insert(concept.id, concept);
+        }
+
+        FlashHealOutcome {
+            old_salinity,
+            new_salinity: self.salinity,
+            concepts_added: concept_ids.len(),
+            broke_freeze,
+            concept_ids,
+            concept_dimensions,
+        }
+    }
+
+    /// Name for a continent formed mostly from `count` ores of `ore_type`.
+    fn continent_name(&self, ore_type: OreType, count: usize) -> String {
+        let template = match self.continent_names.get(&ore_type) {
+            Some(template) => template.clone(),
+            None => default_continent_names()[&ore_type].clone(),
+        };
+        template.replace("{count}", &count.to_string())
+    }
+
+    /// Lay an ore on the ocean floor, adding its type-weighted pressure.
+    pub fn deposit_ore(&mut self, ore: PreciousOre) -> Vec<FluidEvent> {
+        let ore_weight = self.weighted_pressure(&ore);
+        self.ocean_floor_pressure += ore_weight;
+        self.ore_stats
+            .record_deposit(&ore, ore_weight, self.tick_count);
+
+        let event = FluidEvent::OreDeposited {
+            name: ore.name.clone(),
+            ore_type: ore.ore_type.as_str().to_string(),
+            total_pressure: self.ocean_floor_pressure,
+            threshold: self.pressure_threshold,
+        };
+        self.ore_deposits.push(ore);
+        let mut events = vec![event];
+        events.extend(self.report_floor_pressure(PressureChangeReason::Deposit));
+        events
+    }
+
+    /// `PressureChanged` event if the floor pressure has moved more than
+    /// `PRESSURE_EVENT_DEBOUNCE` since it was last reported.
+    fn report_floor_pressure(&mut self, reason: PressureChangeReason) -> Option<FluidEvent> {
+        let old = self.reported_floor_pressure;
+        if (self.ocean_floor_pressure - old).abs() <= PRESSURE_EVENT_DEBOUNCE {
+            return None;
         }
+        self.reported_floor_pressure = self.ocean_floor_pressure;
+        Some(FluidEvent::PressureChanged {
+            old,
+            new: self.ocean_floor_pressure,
+            threshold: self.pressure_threshold,
+            reason,
+        })
+    }
+
+    /// Tectonic pressure an ore adds, with its type's weight applied.
+    fn weighted_pressure(&self, ore: &PreciousOre) -> f32 {
+        ore.pressure_weight() * self.ore_pressure_weights.multiplier(ore.ore_type)
+    }
 
-        old_salinity
+    /// Depth range of the next continent: the `CONTINENT_SPAN` window holding
+    /// the most ore pressure, kept within `CONTINENT_DEPTH_LIMITS` but always
+    /// covering the ore it is centred on.
+    fn continent_depth_range(&self) -> (f32, f32) {
+        let pressure_near = |depth: f32| -> f32 {
+            self.ore_deposits
+                .iter()
+                .filter(|o| (o.depth - depth).abs() <= CONTINENT_SPAN / 2.0)
+                .map(|o| self.weighted_pressure(o))
+                .sum()
+        };
+        let centre = self
+            .ore_deposits
+            .iter()
+            .map(|o| (o.depth, pressure_near(o.depth)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map_or(CONTINENT_DEPTH_LIMITS.1, |(depth, _)| depth);
+
+        let (shallowest, deepest) = CONTINENT_DEPTH_LIMITS;
+        (
+            (centre - CONTINENT_SPAN / 2.0).max(shallowest).min(centre),
+            (centre + CONTINENT_SPAN / 2.0).min(deepest).max(centre),
+        )
     }
 
-    /// Set pressure threshold for tectonic shifts.
-    pub fn set_pressure_threshold(&mut self, threshold: f32) {
+    /// Set pressure threshold for tectonic shifts. Moving it reports the
+    /// floor pressure against the new threshold.
+    pub fn set_pressure_threshold(&mut self, threshold: f32) -> Option<FluidEvent> {
+        let moved = (threshold - self.pressure_threshold).abs() > PRESSURE_EVENT_DEBOUNCE;
         self.pressure_threshold = threshold;
+        if !moved {
+            return None;
+        }
+        let old = self.reported_floor_pressure;
+        self.reported_floor_pressure = self.ocean_floor_pressure;
+        Some(FluidEvent::PressureChanged {
+            old,
+            new: self.ocean_floor_pressure,
+            threshold,
+            reason: PressureChangeReason::ThresholdSet,
+        })
     }
 
     // === Division Experiment Methods (Analog Computing) ===
@@ -369,12 +1358,12 @@ impl ConceptFluid {
         // Inject bubbles (the dividend) - neutrally buoyant particles
         // Neutral buoyancy (density=0.5) means wave forces dominate over buoyancy
         for i in 0..dividend as usize {
-            let id = Uuid::new_v4();
             let bubble_name = format!("bubble_{}", i);
 
             // Create a neutrally buoyant bubble (density 0.5 = equilibrium)
             // Small area (0.1) for tighter Lennard-Jones interactions
-            let mut bubble = Concept::new(id, bubble_name, 0.5, 0.1);
+            let mut bubble = self.new_experiment_concept(bubble_name, 0.5, 0.1);
+            let id = bubble.id;
 
             // Spread bubbles evenly across all node regions
             // This ensures each node gets a chance to capture bubbles
@@ -389,6 +1378,8 @@ impl ConceptFluid {
             // Start slightly offset from node to trigger motion
             bubble.layer = node_pos + 0.05 * ((i as f32).sin());
             bubble.buoyancy = 0.5; // Neutral buoyancy
+            bubble.role = ConceptRole::ProbeBubble;
+            bubble.origin = ConceptOrigin::Experiment;
 
             // Give initial random-ish velocity to ensure physics activates
             bubble.velocity = 0.1 * ((i as f32 * 0.7).sin());
@@ -397,6 +1388,9 @@ impl ConceptFluid {
             self.concepts.insert(id, bubble);
         }
 
+        experiment.thresholds =
+            SettlementThresholds::scaled(experiment.bubble_ids.len(), experiment.wave.node_count());
+
         // Apply Laminar Streamlining: boost salinity to increase effective viscosity
         // This dampens the "volume overhead" noise, making remainder turbulence clearer
         experiment.original_salinity = self.salinity;
@@ -417,8 +1411,39 @@ impl ConceptFluid {
         self.start_division_experiment_with_salinity(dividend, divisor, 0.0)
     }
 
+    /// Other live concepts within `CROWDING_RADIUS` of each live concept,
+    /// counted with binary searches over the sorted depths.
+    fn crowding_counts(&self) -> HashMap<ConceptId, usize> {
+        let live = || self.concepts.values().filter(|c| !c.has_evaporated);
+        let mut depths: Vec<f32> = live().map(|c| c.layer).collect();
+        depths.sort_by(f32::total_cmp);
+
+        live()
+            .map(|c| {
+                let low = depths.partition_point(|&d| d < c.layer - CROWDING_RADIUS);
+                let high = depths.partition_point(|&d| d <= c.layer + CROWDING_RADIUS);
+                (c.id, high - low - 1)
+            })
+            .collect()
+    }
+
+    /// Add a standing wave that shapes every concept's motion until removed.
+    /// Division experiments leave it in place.
+    pub fn add_persistent_wave(&mut self, wave: StandingWave) -> Uuid {
+        let id = wave.id;
+        self.persistent_waves.push(wave);
+        id
+    }
+
+    /// Remove a persistent wave, returning it (None if unknown).
+    pub fn remove_persistent_wave(&mut self, id: Uuid) -> Option<StandingWave> {
+        let index = self.persistent_waves.iter().position(|w| w.id == id)?;
+        Some(self.persistent_waves.remove(index))
+    }
+
     /// Check if the current experiment has settled (reached equilibrium).
     pub fn check_experiment_settlement(&mut self) -> Option<DivisionResult> {
+        let bubble_reynolds = self.local_reynolds(&self.active_experiment.as_ref()?.bubble_ids);
         let experiment = self.active_experiment.as_mut()?;
 
         // Calculate experiment-specific turbulence from bubble velocities
@@ -434,15 +1459,7 @@ impl ConceptFluid {
         // More bubbles fighting for nodes = more accumulated energy
         experiment.accumulated_turbulence += bubble_kinetic_energy;
 
-        experiment.peak_reynolds = experiment.peak_reynolds.max(
-            experiment
-                .bubble_ids
-                .iter()
-                .filter_map(|id| self.concepts.get(id))
-                .map(|c| c.velocity.abs())
-                .sum::<f32>()
-                / self.viscosity,
-        );
+        experiment.peak_reynolds = experiment.peak_reynolds.max(bubble_reynolds);
 
         // Check settlement conditions
         let bubble_velocities: Vec<f32> = experiment
@@ -460,24 +1477,67 @@ impl ConceptFluid {
         // This captures the "stuttering" / micro-cavitation of remainder bubbles
         experiment.record_velocity(avg_velocity);
 
-        // Settlement: all bubbles nearly stationary
-        // Require minimum 60 ticks (1 second) before considering settlement
+        if experiment.trace.is_some() {
+            let node_tolerance = experiment.wave.node_spacing / 2.0;
+            let mut occupancy = vec![0.0; experiment.wave.node_positions.len()];
+            for bubble in experiment
+                .bubble_ids
+                .iter()
+                .filter_map(|id| self.concepts.get(id))
+            {
+                if let Some(i) = experiment
+                    .wave
+                    .node_positions
+                    .iter()
+                    .position(|&node_pos| (bubble.layer - node_pos).abs() < node_tolerance)
+                {
+                    occupancy[i] += 1.0;
+                }
+            }
+            let (_, velocity_sigma) = experiment.calculate_velocity_sigma();
+            let row = TraceRow {
+                tick: self.tick_count,
+                avg_velocity,
+                velocity_sigma,
+                accumulation: experiment.accumulated_turbulence,
+                values: occupancy,
+            };
+            if let Some(ref mut trace) = experiment.trace {
+                trace.push(row);
+            }
+        }
+
+        // Settlement: all bubbles nearly stationary, after a minimum settling
+        // time, and holding their nodes for a whole stability window
         let ticks_elapsed = self.tick_count.saturating_sub(experiment.start_tick);
-        let min_ticks_for_settlement = 60;
-        let is_settled =
-            ticks_elapsed >= min_ticks_for_settlement && max_velocity < 0.05 && avg_velocity < 0.02;
+        let meets_thresholds =
+            experiment
+                .thresholds
+                .is_settled(ticks_elapsed, max_velocity, avg_velocity);
+        let bubble_depths: Vec<f32> = experiment
+            .bubble_ids
+            .iter()
+            .filter_map(|id| self.concepts.get(id))
+            .map(|c| c.layer)
+            .collect();
+        let is_settled = experiment.observe_settlement(meets_thresholds, &bubble_depths);
         let is_timed_out = experiment.is_timed_out(self.tick_count);
 
         if is_settled || is_timed_out {
             experiment.settled = true;
-            return Some(self.finalize_experiment());
+            let settled_by = if is_settled {
+                SettledBy::Settled
+            } else {
+                SettledBy::TimedOut
+            };
+            return Some(self.finalize_experiment(settled_by));
         }
 
         None
     }
 
     /// Finalize the experiment and calculate the result.
-    fn finalize_experiment(&mut self) -> DivisionResult {
+    fn finalize_experiment(&mut self, settled_by: SettledBy) -> DivisionResult {
         let experiment = self.active_experiment.take().unwrap();
 
         // Calculate node occupancy
@@ -512,21 +1572,17 @@ impl ConceptFluid {
 
         let is_divisible = mathematical_remainder < 0.001;
 
-        // Calculate Reynolds number from final state
-        let final_reynolds: f32 = experiment
-            .bubble_ids
-            .iter()
-            .filter_map(|id| self.concepts.get(id))
-            .map(|c| c.velocity.abs())
-            .sum::<f32>()
-            / self.viscosity;
+        // Reynolds number of the bubbles alone, in their final state
+        let final_reynolds = self.local_reynolds(&experiment.bubble_ids);
 
         // Calculate velocity jitter (vσ) - the "Time-of-Flight Delta" metric
         // High vσ = micro-cavitation / stuttering from remainder bubbles competing for nodes
         // Low vσ = laminar, predictable flow (divisible case)
         let (velocity_mean, velocity_sigma) = experiment.calculate_velocity_sigma();
+        let confidence = experiment.confidence();
 
         let result = DivisionResult {
+            id: experiment.problem.id,
             dividend: experiment.problem.dividend,
             divisor: experiment.problem.divisor,
             is_divisible,
@@ -540,18 +1596,40 @@ impl ConceptFluid {
             ticks_to_settle: self.tick_count - experiment.start_tick,
             node_occupancy,
             salinity_boost: experiment.salinity_boost,
+            settled_by,
+            settled_naturally: settled_by == SettledBy::Settled,
+            confidence,
         };
+        experiment.span.record("quotient", result.quotient);
+        experiment.span.record("remainder", result.remainder);
+        experiment.span.record("settled_by", settled_by.as_str());
 
         // Restore original salinity (remove the Laminar Streamlining boost)
         self.salinity = experiment.original_salinity;
 
-        // Clean up bubbles
+        // Clean up bubbles, or keep them as thoughts named for where they settled
         for id in experiment.bubble_ids {
-            self.concepts.remove(&id);
+            if !experiment.keep_particles {
+                self.concepts.remove(&id);
+            } else if let Some(bubble) = self.concepts.get_mut(&id) {
+                bubble.role = ConceptRole::Thought;
+                let node = experiment
+                    .wave
+                    .node_positions
+                    .iter()
+                    .position(|&node_pos| (bubble.layer - node_pos).abs() < node_tolerance);
+                bubble.name = match node {
+                    Some(i) => format!("{}_node_{}", bubble.name, i),
+                    None => format!("{}_homeless", bubble.name),
+                };
+            }
         }
         self.standing_waves.clear();
 
         // Store result
+        if let Some(trace) = experiment.trace {
+            self.traces.insert(result.id, trace);
+        }
         self.experiment_results.push(result.clone());
 
         result
@@ -583,6 +1661,75 @@ impl ConceptFluid {
         heat_a: f32,
         position_b: String,
         heat_b: f32,
+    ) -> Uuid {
+        // Spread probes around the collision center (midpoint between vents)
+        let num_probes = 8;
+        let collision_center = 0.5;
+        let probe_layers = (0..num_probes)
+            .map(|i| collision_center + (i as f32 / num_probes as f32 - 0.5) * 0.2)
+            .collect();
+
+        self.ignite_consensus_reactor(position_a, heat_a, position_b, heat_b, probe_layers)
+    }
+
+    /// Start a consensus experiment between two live concepts marked as contradictory.
+    ///
+    /// The concepts' names become the positions and their buoyancy the heat.
+    /// The vents sit at the concepts' current depths (shallower one first) and
+    /// probes are injected between them. On crystallization the insight is
+    /// injected back into the fluid as a new concept.
+    ///
+    /// Returns None if either concept is missing or both ids are the same.
+    pub fn start_consensus_from_concepts(
+        &mut self,
+        id_a: ConceptId,
+        id_b: ConceptId,
+    ) -> Option<Uuid> {
+        if id_a == id_b {
+            return None;
+        }
+        let a = self.concepts.get(&id_a)?;
+        let b = self.concepts.get(&id_b)?;
+
+        // Vent A must be the shallower one for territory extraction
+        let (shallow, deep) = if a.layer <= b.layer { (a, b) } else { (b, a) };
+        let source = (shallow.id, deep.id);
+        let (position_a, heat_a) = (shallow.name.clone(), shallow.buoyancy.clamp(0.1, 2.0));
+        let (position_b, heat_b) = (deep.name.clone(), deep.buoyancy.clamp(0.1, 2.0));
+
+        // Keep the vents far enough apart to form a collision zone
+        let midpoint = (shallow.layer + deep.layer) / 2.0;
+        let half_gap = ((deep.layer - shallow.layer) / 2.0).max(CONSENSUS_MIN_VENT_GAP / 2.0);
+        let center = midpoint.clamp(half_gap, 1.0 - half_gap);
+        let (depth_a, depth_b) = (center - half_gap, center + half_gap);
+
+        let num_probes = 8;
+        let probe_layers = (0..num_probes)
+            .map(|i| depth_a + (depth_b - depth_a) * (i + 1) as f32 / (num_probes + 1) as f32)
+            .collect();
+
+        let experiment_id =
+            self.ignite_consensus_reactor(position_a, heat_a, position_b, heat_b, probe_layers);
+
+        if let Some(ref mut exp) = self.consensus_reactor.active_experiment {
+            exp.vent_a.depth = depth_a;
+            exp.vent_b.depth = depth_b;
+            exp.vent_a.radius = half_gap * 2.0;
+            exp.vent_b.radius = half_gap * 2.0;
+            exp.source_concepts = Some(source);
+        }
+
+        Some(experiment_id)
+    }
+
+    /// Start the reactor and inject neutral probe bubbles at the given layers.
+    fn ignite_consensus_reactor(
+        &mut self,
+        position_a: String,
+        heat_a: f32,
+        position_b: String,
+        heat_b: f32,
+        probe_layers: Vec<f32>,
     ) -> Uuid {
         // Clear any previous consensus experiment probes
         if let Some(ref exp) = self.consensus_reactor.active_experiment {
@@ -593,31 +1740,27 @@ impl ConceptFluid {
 
         // Start the experiment
         let experiment_id = self.consensus_reactor.start_experiment(
-            position_a.clone(),
+            position_a,
             heat_a,
-            position_b.clone(),
+            position_b,
             heat_b,
             self.tick_count,
         );
 
         // Inject probe bubbles into the collision zone
         // These neutral probes will be buffeted by both vents
-        let num_probes = 8;
-        let collision_center = 0.5; // Midpoint between vents
-
         let mut probe_ids = Vec::new();
-        for i in 0..num_probes {
-            let id = Uuid::new_v4();
+        for (i, layer) in probe_layers.into_iter().enumerate() {
             let probe_name = format!("consensus_probe_{}", i);
 
             // Neutral buoyancy, small area
-            let mut probe = Concept::new(id, probe_name, 0.5, 0.1);
-
-            // Spread around collision center
-            let offset = (i as f32 / num_probes as f32 - 0.5) * 0.2;
-            probe.layer = collision_center + offset;
+            let mut probe = self.new_experiment_concept(probe_name, 0.5, 0.1);
+            let id = probe.id;
+            probe.layer = layer;
             probe.buoyancy = 0.5; // Neutral
             probe.velocity = 0.0;
+            probe.role = ConceptRole::ProbeBubble;
+            probe.origin = ConceptOrigin::Experiment;
 
             probe_ids.push(id);
             self.concepts.insert(id, probe);
@@ -631,6 +1774,38 @@ impl ConceptFluid {
         experiment_id
     }
 
+    /// Inject a crystallized consensus insight as a new concept.
+    /// It starts between its source concepts (or mid-fluid if they are gone)
+    /// with their mean density and the ore's integration value.
+    fn inject_consensus_insight(
+        &mut self,
+        ore: &ConsensusOre,
+        (id_a, id_b): (ConceptId, ConceptId),
+    ) -> ConceptId {
+        let sources: Vec<&Concept> = [id_a, id_b]
+            .iter()
+            .filter_map(|id| self.concepts.get(id))
+            .collect();
+        let mean = |f: fn(&Concept) -> f32, fallback: f32| {
+            if sources.is_empty() {
+                fallback
+            } else {
+                sources.iter().map(|c| f(c)).sum::<f32>() / sources.len() as f32
+            }
+        };
+        let density = mean(|c| c.density, 0.5);
+        let area = mean(|c| c.area, 0.5);
+        let layer = mean(|c| c.layer, 0.5);
+
+        let mut concept = self.new_concept(ore.name.clone(), density, area);
+        let id = concept.id;
+        concept.layer = layer;
+        concept.integration = ore.integration_value;
+        concept.origin = ConceptOrigin::ConsensusInsight;
+        self.concepts.insert(id, concept);
+        id
+    }
+
     /// Check if the consensus experiment has crystallized.
     /// Returns Some(ConsensusOre) if a stable insight has formed.
     pub fn check_consensus_crystallization(&mut self) -> Option<ConsensusOre> {
@@ -652,10 +1827,27 @@ impl ConceptFluid {
             0.0
         };
 
+        // Spread of probe velocities this tick
+        let probe_velocity_sigma = if !probe_data.is_empty() {
+            let n = probe_data.len() as f32;
+            let mean = probe_data.iter().map(|(_, _, v)| v).sum::<f32>() / n;
+            (probe_data
+                .iter()
+                .map(|(_, _, v)| (v - mean).powi(2))
+                .sum::<f32>()
+                / n)
+                .sqrt()
+        } else {
+            0.0
+        };
+
+        let probe_reynolds = self.reynolds_number_for(avg_velocity);
+
         // Update experiment with probe data
         if let Some(ref mut exp) = self.consensus_reactor.active_experiment {
             // Record velocity for jitter calculation
             exp.record_velocity(avg_velocity);
+            exp.peak_reynolds = exp.peak_reynolds.max(probe_reynolds);
 
             // Record probe snapshots for phase extraction
             for (id, depth, velocity) in &probe_data {
@@ -666,25 +1858,82 @@ impl ConceptFluid {
             if exp.should_phase_transition(self.tick_count) {
                 exp.extract_phase_structure(self.tick_count);
             }
+
+            let row = TraceRow {
+                tick: self.tick_count,
+                avg_velocity,
+                velocity_sigma: probe_velocity_sigma,
+                // The reactor adds this tick's jitter in `update` below
+                accumulation: exp.accumulated_jitter + exp.current_jitter(),
+                values: probe_data.iter().map(|(_, depth, _)| *depth).collect(),
+            };
+            if let Some(ref mut trace) = exp.trace {
+                trace.push(row);
+            }
         }
 
-        // Check for crystallization
-        let result = self.consensus_reactor.update(self.tick_count);
+        self.complete_consensus(ConsensusReactor::update)
+    }
 
-        // Clean up probes if crystallized
-        if let Some(ref ore) = result {
-            // Get probe IDs from the experiment history (experiment was consumed)
-            // Probes should have been associated with the experiment
-            // For now, clean up any concepts starting with "consensus_probe"
-            let probe_ids: Vec<ConceptId> = self
-                .concepts
-                .iter()
-                .filter(|(_, c)| c.name.starts_with("consensus_probe"))
-                .map(|(id, _)| *id)
-                .collect();
+    /// Crystallize the active consensus experiment now, accepting whatever
+    /// certainty its jitter so far gives. None without an experiment.
+    pub fn crystallize_consensus_now(&mut self) -> Option<ConsensusOre> {
+        self.complete_consensus(ConsensusReactor::crystallize_now)
+    }
 
-            for id in probe_ids {
-                self.concepts.remove(&id);
+    /// Run `crystallize` on the reactor and, if it yields an ore, clear the
+    /// probes away and feed the insight back into the fluid.
+    fn complete_consensus(
+        &mut self,
+        crystallize: fn(&mut ConsensusReactor, u64) -> Option<ConsensusOre>,
+    ) -> Option<ConsensusOre> {
+        let source_concepts = self
+            .consensus_reactor
+            .active_experiment
+            .as_ref()
+            .and_then(|exp| exp.source_concepts);
+        let (probe_ids, keep_probes) = self
+            .consensus_reactor
+            .active_experiment
+            .as_ref()
+            .map(|exp| (exp.probe_ids.clone(), exp.keep_particles))
+            .unwrap_or_default();
+
+        // The experiment is consumed on crystallization, so hold its trace aside
+        let trace = self
+            .consensus_reactor
+            .active_experiment
+            .as_mut()
+            .and_then(|exp| exp.trace.take());
+
+        let mut result = crystallize(&mut self.consensus_reactor, self.tick_count);
+
+        if let Some(trace) = trace {
+            match (&result, self.consensus_reactor.active_experiment.as_mut()) {
+                (Some(ore), _) => self.traces.insert(ore.id, trace),
+                (None, Some(exp)) => exp.trace = Some(trace),
+                (None, None) => {}
+            }
+        }
+
+        // Clean up probes if crystallized, or keep them as thoughts named for the ore
+        if let Some(ref mut ore) = result {
+            for (i, id) in probe_ids.iter().enumerate() {
+                if !keep_probes {
+                    self.concepts.remove(id);
+                } else if let Some(probe) = self.concepts.get_mut(id) {
+                    probe.name = format!("{}_probe_{}", ore.name, i);
+                    probe.role = ConceptRole::Thought;
+                }
+            }
+
+            // Feed the insight back into the fluid it came from
+            if let Some(source) = source_concepts {
+                let insight_id = self.inject_consensus_insight(ore, source);
+                ore.insight_concept = Some(insight_id);
+                if let Some(stored) = self.consensus_reactor.ores.last_mut() {
+                    stored.insight_concept = Some(insight_id);
+                }
             }
 
             // Log the phase structure if present
@@ -707,20 +1956,97 @@ impl ConceptFluid {
         self.consensus_reactor.get_experiment()
     }
 
-    /// Get all crystallized consensus ores.
-    pub fn get_consensus_ores(&self) -> &[ConsensusOre] {
-        &self.consensus_reactor.ore_deposits
+    /// Get all crystallized consensus ores still deposited.
+    pub fn get_consensus_ores(&self) -> Vec<&ConsensusOre> {
+        self.consensus_reactor.deposits().collect()
     }
 
-    /// Get foundational truths from consensus reactor (C > 0.8).
+    /// Get foundational truths from consensus reactor (C above its
+    /// `foundational` threshold).
     pub fn get_foundational_truths(&self) -> Vec<&ConsensusOre> {
         self.consensus_reactor.foundational_truths()
     }
 
-    /// Run one physics tick, returning all significant events that occurred.
+    /// Put back in range any layer or velocity that stopped being a number,
+    /// so one blown-up concept can't poison the rest of the tick, nor be left
+    /// for readers and snapshots once the tick is over. Infinities
+    /// are clamped; a NaN layer returns to where the concept would rest and a
+    /// NaN velocity to rest.
+    fn repair_non_finite(&mut self) -> Vec<FluidEvent> {
+        let mut events = Vec::new();
+        for concept in self.concepts.values_mut() {
+            let mut report = |field: &str| {
+                events.push(FluidEvent::NumericAnomaly {
+                    concept_id: concept.id,
+                    concept: concept.name.clone(),
+                    field: field.to_string(),
+                })
+            };
+            if !concept.layer.is_finite() {
+                report("layer");
+                concept.layer = if concept.layer.is_nan() {
+                    concept.density
+                } else {
+                    concept.layer
+                }
+                .clamp(0.0, 1.0);
+            }
+            if !concept.velocity.is_finite() {
+                report("velocity");
+                concept.velocity = if concept.velocity.is_nan() {
+                    0.0
+                } else {
+                    concept.velocity.clamp(-5.0, 5.0)
+                };
+            }
+        }
+        events
+    }
+
+    /// Advance the fluid `dt` seconds, returning all significant events that
+    /// occurred.
+    ///
+    /// A `dt` longer than `max_dt` runs as equal sub-steps, each a full tick,
+    /// so a large step can't blow up the integration; past `MAX_SUBSTEPS` the
+    /// remaining time is dropped. A zero, negative or non-finite `dt` runs
+    /// no tick at all.
     pub fn update(&mut self, dt: f32) -> Vec<FluidEvent> {
-        self.tick_count += 1;
+        if !dt.is_finite() {
+            tracing::warn!("Ignoring update with non-finite dt {}", dt);
+            return Vec::new();
+        }
+        if dt <= 0.0 {
+            return Vec::new();
+        }
+        let (substeps, step_dt) = if self.max_dt > 0.0 && dt > self.max_dt {
+            let needed = (dt / self.max_dt).ceil();
+            if needed > MAX_SUBSTEPS as f32 {
+                (MAX_SUBSTEPS, self.max_dt)
+            } else {
+                (needed as u32, dt / needed)
+            }
+        } else {
+            (1, dt)
+        };
         let mut events = Vec::new();
+        for _ in 0..substeps {
+            events.extend(self.tick(step_dt));
+        }
+        events
+    }
+
+    /// Run one physics tick of `dt` seconds.
+    fn tick(&mut self, dt: f32) -> Vec<FluidEvent> {
+        self.tick_count += 1;
+        let mut events = self.repair_non_finite();
+
+        // Energy books open before any impulses applied since the last tick
+        let pending_impulse = self.energy.take_pending_impulse();
+        let ke_start = self.total_kinetic_energy() - pending_impulse;
+        let mut energy_flows = EnergyFlows {
+            impulse_injection: pending_impulse,
+            ..Default::default()
+        };
 
         // === Pass 1: Track time at surface and detect freezing ===
         let mut freeze_triggered = false;
@@ -728,6 +2054,17 @@ impl ConceptFluid {
         let mut freezing_concept_name: Option<String> = None;
 
         for concept in self.concepts.values_mut() {
+            // Experiment bubbles and evaporated ghosts can't freeze the mind
+            if !concept.participates_in_attention() {
+                continue;
+            }
+            // Just acted on or just fallen in: being at the surface is not
+            // being stuck there, and time spent there before doesn't count
+            if concept.grace_remaining > 0.0 {
+                concept.grace_remaining = (concept.grace_remaining - dt).max(0.0);
+                concept.time_at_surface = 0.0;
+                continue;
+            }
             if concept.layer < self.freeze_zone {
                 concept.time_at_surface += dt;
 
@@ -755,14 +2092,7 @@ impl ConceptFluid {
         }
 
         // === Pass 2: Calculate Reynolds number and turbulence ===
-        let avg_velocity: f32 = self
-            .concepts
-            .values()
-            .map(|c| c.velocity.abs())
-            .sum::<f32>()
-            / self.concepts.len().max(1) as f32;
-
-        let reynolds_number = avg_velocity / self.viscosity;
+        let reynolds_number = self.fluid_reynolds();
 
         if reynolds_number > self.reynolds_threshold && !self.is_turbulent {
             self.is_turbulent = true;
@@ -786,6 +2116,7 @@ impl ConceptFluid {
         let mut new_solutions: Vec<Concept> = Vec::new();
         let mut ballast_to_remove: Vec<ConceptId> = Vec::new();
         let mut catalysis_events: Vec<FluidEvent> = Vec::new();
+        let mut catalyzing_ores: Vec<OreType> = Vec::new();
 
         for concept in self.concepts.values() {
             if concept.ballast > 0.0 && concept.layer > 0.8 {
@@ -809,16 +2140,31 @@ impl ConceptFluid {
                             let solution_name =
                                 format!("{}_{}_solution", concept.name, ore.ore_type.as_str());
 
+                            let shape = self
+                                .solution_shapes
+                                .get(&ore.ore_type)
+                                .copied()
+                                .unwrap_or_default();
                             let mut solution = Concept::new(
                                 solution_id,
                                 solution_name.clone(),
-                                0.2,
-                                concept.area + 0.2,
+                                shape.density_for(ore),
+                                shape.area_for(concept.area),
                             );
-                            solution.layer = ore.depth;
-                            solution.velocity = -0.5;
-                            solution.integration = ore.integration_value;
+                            // Never inside a continent the ore ended up under
+                            solution.layer = match self
+                                .continents
+                                .iter()
+                                .find(|continent| continent.contains_depth(ore.depth))
+                            {
+                                Some(continent) => continent.nearest_exit(ore.depth).0,
+                                None => ore.depth,
+                            };
+                            solution.velocity = shape.velocity;
+                            solution.integration = shape.integration_for(ore);
                             solution.is_solution = true;
+                            solution.origin = ConceptOrigin::Synthesized;
+                            energy_flows.impulse_injection += kinetic_energy(solution.velocity);
 
                             catalysis_events.push(FluidEvent::OreCatalysis {
                                 problem: concept.name.clone(),
@@ -829,6 +2175,7 @@ impl ConceptFluid {
 
                             new_solutions.push(solution);
                             ballast_to_remove.push(concept.id);
+                            catalyzing_ores.push(ore.ore_type);
                             break;
                         }
                     }
@@ -836,10 +2183,17 @@ impl ConceptFluid {
             }
         }
 
-        for solution in new_solutions {
+        for mut solution in new_solutions {
+            self.record_name(&solution.name);
+            solution.seq = self.take_concept_seq();
+            solution.born_tick = self.tick_count;
             self.concepts.insert(solution.id, solution);
         }
 
+        for ore_type in catalyzing_ores {
+            self.ore_stats.record_catalysis(ore_type);
+        }
+
         for concept_id in ballast_to_remove {
             if let Some(concept) = self.concepts.get_mut(&concept_id) {
                 concept.ballast = 0.0;
@@ -855,6 +2209,8 @@ impl ConceptFluid {
 
         // Collect core truth updates
         let mut core_truth_strengthened: Vec<(usize, f32)> = Vec::new();
+        // (vent index, concept, impulse) for the per-vent influence report
+        let mut vent_influence: Vec<(usize, ConceptId, f32)> = Vec::new();
 
         // === Tick standing waves (breathing cycle) ===
         for wave in &mut self.standing_waves {
@@ -879,12 +2235,22 @@ impl ConceptFluid {
             wave.update_occupancy(&bubble_depths);
         }
 
+        // Persistent waves act on every concept, so every concept fills their nodes
+        if !self.persistent_waves.is_empty() {
+            let concept_depths: Vec<f32> = self.concepts.values().map(|c| c.layer).collect();
+            for wave in &mut self.persistent_waves {
+                wave.tick();
+                wave.update_occupancy(&concept_depths);
+            }
+        }
+
         // === Direct Lennard-Jones Repulsion (Symmetric) ===
         // Apply repulsive forces directly to bubble velocities
         // This prevents bubbles from stacking - they MUST social distance
         let sigma = 0.12; // σ = "width" of a thought (larger = more spread)
         let epsilon = self.bubble_repulsion_strength;
 
+        let ke_before_repulsion = self.total_kinetic_energy();
         if self.bubble_repulsion_enabled && !experiment_bubble_ids.is_empty() {
             let bubble_ids: Vec<Uuid> = experiment_bubble_ids.clone();
 
@@ -930,172 +2296,193 @@ impl ConceptFluid {
             }
         }
 
+        energy_flows.force_work += self.total_kinetic_energy() - ke_before_repulsion;
+
         // Empty map for compatibility (forces already applied directly)
         let repulsion_forces: HashMap<ConceptId, f32> = HashMap::new();
 
+        let crowding = if self.crowding_drag_enabled {
+            self.crowding_counts()
+        } else {
+            HashMap::new()
+        };
+
+        events.extend(self.drop_dangling_links());
+        let link_forces = self.link_forces();
+        let layers_before_physics = self.live_layers();
+
+        // Concepts that left the fluid, or vents that did, leave no plume behind
+        let concepts = &self.concepts;
+        let vent_count = self.core_truths.len();
+        self.in_vent_plume
+            .retain(|(id, vent)| concepts.contains_key(id) && *vent < vent_count);
+
+        let field = ForceField {
+            salinity: self.salinity,
+            core_truths: &self.core_truths,
+            standing_waves: &self.standing_waves,
+            persistent_waves: &self.persistent_waves,
+            consensus: self.consensus_reactor.active_experiment.as_ref(),
+            division: self.active_experiment.as_ref(),
+            viscosity_surface: self.viscosity_surface,
+            viscosity_deep: self.viscosity_deep,
+            shear_threshold: self.shear_threshold,
+            shear_thinning_coefficient: self.shear_thinning_coefficient,
+            crowding_drag_factor: self.crowding_drag_factor,
+            drag_coefficient: self.drag_coefficient,
+            activation_zone: self.activation_zone,
+            surface_tension: self.surface_tension,
+            post_breakthrough_drift: self.post_breakthrough_drift,
+        };
+
         for concept in self.concepts.values_mut() {
             // When frozen, block all non-frozen concepts from rising
             if self.is_frozen && !concept.is_frozen {
                 let freeze_suppression = 2.0;
+                let ke_before = kinetic_energy(concept.velocity);
                 concept.velocity = concept.velocity.min(0.0);
                 concept.velocity += freeze_suppression * dt;
+                energy_flows.force_work += kinetic_energy(concept.velocity) - ke_before;
                 concept.layer = (concept.layer + concept.velocity * dt).clamp(0.0, 1.0);
                 continue;
             }
 
-            let effective_density = (concept.density + concept.ballast).min(1.0);
-            let target_layer = (1.0 - concept.buoyancy + concept.ballast).clamp(0.0, 1.0);
-            let diff = target_layer - concept.layer;
-
-            let salinity_boost = if effective_density < 0.5 {
-                self.salinity * (0.5 - effective_density) * 2.0
-            } else {
-                0.0
-            };
-
-            let buoyancy_force = diff * concept.density - salinity_boost;
-
-            // Non-Newtonian shear-thinning: effective viscosity drops at high velocity
-            // This allows "remainder bubbles" to scream through local turbulence
-            let effective_visc = {
-                let shear_rate = concept.velocity.abs();
-                if shear_rate <= self.shear_threshold {
-                    self.viscosity
-                } else {
-                    let excess_shear = shear_rate - self.shear_threshold;
-                    let thinning_factor =
-                        1.0 - (self.shear_thinning_coefficient * excess_shear).min(0.9);
-                    self.viscosity * thinning_factor
-                }
-            };
-
-            let drag_force = if concept.velocity.abs() > 0.001 {
-                -0.5 * effective_visc
-                    * concept.velocity.powi(2)
-                    * self.drag_coefficient
-                    * concept.area
-                    * concept.velocity.signum()
-            } else {
-                0.0
-            };
-
-            let surface_force = if concept.layer < self.activation_zone && concept.velocity < 0.0 {
-                let depth_factor = 1.0 - (concept.layer / self.activation_zone);
-                self.surface_tension * depth_factor
+            // Unresolved integration acts like ballast until the thought surfaces or evaporates
+            let unresolved_weight = if self.integration_heaviness_enabled
+                && !concept.has_broken_surface
+                && !concept.has_evaporated
+            {
+                concept.integration * self.integration_heaviness_rate
             } else {
                 0.0
             };
 
-            // Standing wave force (for division experiments)
-            let mut wave_force = 0.0;
-            for wave in &self.standing_waves {
-                wave_force += wave.force_at_depth(concept.layer);
-            }
+            // Kept for the energy books: vent work is attributed separately
+            let thermal_force = DepthForces::on(
+                concept,
+                unresolved_weight,
+                self.salinity,
+                &self.core_truths,
+                &self.standing_waves,
+                &self.persistent_waves,
+            )
+            .thermal;
+            let neighbours = crowding.get(&concept.id).copied().unwrap_or(0);
 
-            // Consensus reactor thermal collision force
-            let consensus_force = if let Some(ref exp) = self.consensus_reactor.active_experiment {
-                if exp.probe_ids.contains(&concept.id) {
-                    // This is a consensus probe - apply thermal collision forces
-                    let (net_force, _collision_intensity) = exp.thermal_collision_at(concept.layer);
-                    net_force * 2.0 // Amplify for visible effect
-                } else {
-                    0.0
+            for (truth_idx, core_truth) in self.core_truths.iter().enumerate() {
+                let heat_transfer = core_truth.heat_transfer_at(concept.layer);
+                let plume_key = (concept.id, truth_idx);
+                if (concept.layer - core_truth.depth).abs() >= core_truth.radius + VENT_EXIT_MARGIN
+                {
+                    self.in_vent_plume.remove(&plume_key);
                 }
-            } else {
-                0.0
-            };
-
-            // Thermal plume force from core truths
-            let mut thermal_force = 0.0;
+                if heat_transfer > 0.01 {
+                    core_truth_strengthened.push((truth_idx, concept.density * 0.01));
+                    vent_influence.push((truth_idx, concept.id, heat_transfer * dt));
+
+                    // Mineralization for dark thoughts, once per pass through the plume
+                    if concept.density > 0.7 && self.in_vent_plume.insert(plume_key) {
+                        let encounters = self.vent_encounter_count.entry(concept.id).or_insert(0);
+                        *encounters += 1;
+
+                        let incubated = self.tick_count.saturating_sub(concept.born_tick)
+                            >= self.mineralization_incubation_ticks;
+                        if incubated && encounters.is_multiple_of(PASSES_PER_ORE) {
+                            let ore_type = if *encounters >= INSIGHT_PASSES {
+                                OreType::Insight
+                            } else if concept.integration > 1.0 {
+                                OreType::Writing
+                            } else if concept.area > 0.8 {
+                                OreType::Art
+                            } else {
+                                OreType::Code
+                            };
+
+                            let ore_name =
+                                format!("{}_ore_{}", concept.name, *encounters / PASSES_PER_ORE);
+                            let integration_value =
+                                concept.integration + (*encounters as f32 * 0.5);
+
+                            let ore = PreciousOre {
+                                name: ore_name.clone(),
+                                ore_type,
+                                density: 0.9,
+                                depth: core_truth.depth,
+                                formed_from: concept.id,
+                                vent_cycles: *encounters,
+                                integration_value,
+                            };
+
+                            mineralization_events.push(FluidEvent::Mineralization {
+                                concept_name: concept.name.clone(),
+                                ore_name,
+                                ore_type: ore_type.as_str().to_string(),
+                                depth: core_truth.depth,
+                                vent_cycles: *encounters,
+                                integration_value,
+                            });
 
-            for (truth_idx, core_truth) in self.core_truths.iter().enumerate() {
-                let depth_diff = (concept.layer - core_truth.depth).abs();
-
-                if depth_diff < core_truth.radius {
-                    let proximity = 1.0 - (depth_diff / core_truth.radius);
-                    let heat_transfer = core_truth.heat_output * proximity.powi(2);
-                    thermal_force -= heat_transfer;
-
-                    if heat_transfer > 0.01 {
-                        core_truth_strengthened.push((truth_idx, concept.density * 0.01));
-
-                        // Mineralization for dark thoughts
-                        if concept.density > 0.7 {
-                            let encounters =
-                                self.vent_encounter_count.entry(concept.id).or_insert(0);
-                            *encounters += 1;
-
-                            if *encounters % 3 == 0 && *encounters > 0 {
-                                let ore_type = if *encounters >= 9 {
-                                    OreType::Insight
-                                } else if concept.integration > 1.0 {
-                                    OreType::Writing
-                                } else if concept.area > 0.8 {
-                                    OreType::Art
-                                } else {
-                                    OreType::Code
-                                };
-
-                                let ore_name = format!("{}_ore_{}", concept.name, *encounters / 3);
-                                let integration_value =
-                                    concept.integration + (*encounters as f32 * 0.5);
-
-                                let ore = PreciousOre {
-                                    name: ore_name.clone(),
-                                    ore_type,
-                                    density: 0.9,
-                                    depth: core_truth.depth,
-                                    formed_from: concept.id,
-                                    vent_cycles: *encounters,
-                                    integration_value,
-                                };
-
-                                mineralization_events.push(FluidEvent::Mineralization {
-                                    concept_name: concept.name.clone(),
-                                    ore_name,
-                                    ore_type: ore_type.as_str().to_string(),
-                                    depth: core_truth.depth,
-                                    vent_cycles: *encounters,
-                                    integration_value,
-                                });
-
-                                ore_to_deposit.push(ore);
-                            }
+                            ore_to_deposit.push(ore);
                         }
                     }
                 }
             }
 
-            // Bubble-bubble repulsion (Coulombic social force)
-            let bubble_repulsion = repulsion_forces.get(&concept.id).copied().unwrap_or(0.0);
+            // Bubble-bubble repulsion (Coulombic social force) and the pull
+            // of linked concepts
+            let bubble_repulsion = repulsion_forces.get(&concept.id).copied().unwrap_or(0.0)
+                + link_forces.get(&concept.id).copied().unwrap_or(0.0);
 
             // Net force and acceleration
-            let net_force = buoyancy_force
-                + drag_force
-                + surface_force
-                + thermal_force
-                + wave_force
-                + bubble_repulsion
-                + consensus_force;
+            let net_force = field.net_force(
+                concept,
+                unresolved_weight,
+                neighbours,
+                concept.layer,
+                concept.velocity,
+            ) + bubble_repulsion;
             let mut acceleration = net_force;
 
+            let ke_before_forces = kinetic_energy(concept.velocity);
+
             // Turbulence perturbations
             if self.is_turbulent {
-                let chaos_seed = (concept.layer * 1000.0 + concept.velocity * 500.0).sin();
-                let turbulent_force = chaos_seed * self.turbulence_energy * 3.0;
+                let noise = turbulence_noise(self.turbulence_seed, concept.seq, self.tick_count);
+                let turbulent_force = noise * self.turbulence_energy * 3.0;
                 acceleration += turbulent_force;
                 concept.velocity *= 0.95;
             }
 
-            // Update velocity and position (with NaN protection)
-            let velocity_delta = acceleration * dt;
+            // Update velocity and position (with NaN protection). RK4 takes
+            // the force again at trial states; turbulence, repulsion and
+            // links stay a fixed kick across the tick
+            let (velocity_delta, layer_delta) = match self.integrator {
+                IntegratorKind::Euler => (acceleration * dt, None),
+                IntegratorKind::Rk4 => {
+                    let kick = acceleration - net_force + bubble_repulsion;
+                    let (layer_delta, velocity_delta) =
+                        rk4_step(concept.layer, concept.velocity, dt, |layer, velocity| {
+                            field.net_force(concept, unresolved_weight, neighbours, layer, velocity)
+                                + kick
+                        });
+                    (velocity_delta, Some(layer_delta))
+                }
+            };
             if velocity_delta.is_finite() {
+                // Midpoint velocity attributes the step's work to each force
+                let midpoint_velocity = concept.velocity + velocity_delta / 2.0;
+                energy_flows.thermal_injection += thermal_force * midpoint_velocity * dt;
                 concept.velocity += velocity_delta;
             }
             // Clamp velocity to prevent runaway
             concept.velocity = concept.velocity.clamp(-5.0, 5.0);
+            energy_flows.force_work += kinetic_energy(concept.velocity) - ke_before_forces;
 
-            let new_layer = concept.layer + concept.velocity * dt;
+            let previous_layer = concept.layer;
+            let new_layer = concept.layer
+                + layer_delta
+                    .filter(|delta| delta.is_finite())
+                    .unwrap_or(concept.velocity * dt);
 
             // Surface breakthrough check
             if new_layer <= 0.0 && concept.velocity < 0.0 && !concept.has_broken_surface {
@@ -1103,15 +2490,38 @@ impl ConceptFluid {
 
                 if kinetic_energy > self.surface_tension {
                     concept.has_broken_surface = true;
+                    concept.breakthrough_count += 1;
+                    concept.rearm_progress = 0.0;
+                    // Experiment probes breaking through are not acted-on thoughts
+                    let is_probe = self
+                        .active_experiment
+                        .as_ref()
+                        .is_some_and(|exp| exp.bubble_ids.contains(&concept.id))
+                        || self
+                            .consensus_reactor
+                            .get_experiment()
+                            .is_some_and(|exp| exp.probe_ids.contains(&concept.id));
+                    if !is_probe {
+                        concept.grace_remaining = self.post_breakthrough_grace;
+                    }
+                    // Acting spends understanding, which the mind keeps as experience
+                    let spent = concept.integration * self.action_cost.clamp(0.0, 1.0);
+                    concept.integration -= spent;
+                    self.total_integration += spent;
+                    self.salinity += spent * self.salinity_rate;
                     breakthrough_events.push(FluidEvent::SurfaceBreakthrough {
                         id: concept.id,
                         name: concept.name.clone(),
                         kinetic_energy,
+                        breakthrough_count: concept.breakthrough_count,
                     });
 
                     let energy_loss = self.surface_tension;
                     let new_ke = (kinetic_energy - energy_loss).max(0.0);
                     concept.velocity = -(2.0 * new_ke).sqrt();
+                    energy_flows.breakthrough_loss += kinetic_energy - new_ke;
+                    concept.bounce_count = 0;
+                    concept.best_bounce_energy = 0.0;
                 } else {
                     breakthrough_events.push(FluidEvent::SurfaceBounce {
                         id: concept.id,
@@ -1120,62 +2530,109 @@ impl ConceptFluid {
                         required: self.surface_tension,
                     });
                     concept.velocity *= -0.3;
+                    energy_flows.boundary_loss += kinetic_energy - 0.5 * concept.velocity.powi(2);
+
+                    // Repeated near-misses build into an urge to act
+                    concept.bounce_count += 1;
+                    concept.best_bounce_energy = concept.best_bounce_energy.max(kinetic_energy);
+                    concept.time_submerged = 0.0;
+                    if concept.bounce_count == URGE_BOUNCE_THRESHOLD {
+                        breakthrough_events.push(FluidEvent::BuildingUrge {
+                            id: concept.id,
+                            name: concept.name.clone(),
+                            bounce_count: concept.bounce_count,
+                            best_kinetic_energy: concept.best_bounce_energy,
+                        });
+                    }
                 }
             }
 
             concept.layer = new_layer.clamp(0.0, 1.0);
 
+            // A thought that has sunk back long enough can act again
+            if self.rearm_enabled && concept.has_broken_surface {
+                if concept.layer > self.rearm_depth {
+                    concept.rearm_progress += dt;
+                    if concept.rearm_progress >= self.rearm_seconds {
+                        concept.has_broken_surface = false;
+                        concept.rearm_progress = 0.0;
+                    }
+                } else {
+                    concept.rearm_progress = 0.0;
+                }
+            }
+
+            // Prolonged submersion lets a building urge fade
+            if concept.bounce_count > 0 && concept.layer > URGE_SUBMERGED_DEPTH {
+                concept.time_submerged += dt;
+                if concept.time_submerged >= URGE_RESET_TIME {
+                    concept.bounce_count = 0;
+                    concept.best_bounce_energy = 0.0;
+                    concept.time_submerged = 0.0;
+                }
+            }
+
+            let ke_before_boundaries = kinetic_energy(concept.velocity);
+
             // Boundary damping
             if concept.layer <= 0.0 || concept.layer >= 1.0 {
                 concept.velocity *= 0.5;
             }
 
-            // Continental collision
+            // Continental collision. A concept already inside (spawned there, or
+            // engulfed by a new continent) drifts out unhindered instead
             for continent in &self.continents {
                 if continent.contains_depth(concept.layer) {
-                    let impermeability = continent.impermeability;
-
-                    if concept.velocity > 0.0 {
-                        concept.layer = continent.depth_range.0 - 0.01;
-                        concept.velocity = -concept.velocity.abs() * (1.0 - impermeability);
-                    } else {
-                        concept.layer = continent.depth_range.1 + 0.01;
-                        concept.velocity = concept.velocity.abs() * (1.0 - impermeability);
+                    if !continent.contains_depth(previous_layer) {
+                        let (layer, above) = continent.exit_layer(concept.velocity > 0.0);
+                        let speed = concept.velocity.abs() * continent.speed_retained();
+                        concept.layer = layer;
+                        concept.velocity = if above { -speed } else { speed };
                     }
-                    concept.velocity *= 0.3;
                     break;
                 }
             }
 
+            energy_flows.boundary_loss += ke_before_boundaries - kinetic_energy(concept.velocity);
+
             // Energy cascade: eddies → integration
-            let kinetic_energy = 0.5 * concept.velocity.powi(2);
-            if kinetic_energy > 0.1 {
-                concept.eddy_scale = concept.eddy_scale.max(kinetic_energy);
+            let eddy_energy = kinetic_energy(concept.velocity);
+            if eddy_energy > 0.1 {
+                concept.eddy_scale = concept.eddy_scale.max(eddy_energy);
             }
 
             if concept.eddy_scale > 0.01 {
-                let breakdown_rate = self.viscosity * 2.0;
+                let breakdown_rate = eddy_breakdown_rate(field.viscosity_at(concept.layer));
                 let energy_dissipated = concept.eddy_scale * breakdown_rate * dt;
                 concept.integration += energy_dissipated;
                 self.total_integration += energy_dissipated;
+                energy_flows.integration_gained += energy_dissipated;
                 concept.eddy_scale *= 1.0 - breakdown_rate * dt;
 
                 if concept.eddy_scale < 0.01 {
                     concept.integration += concept.eddy_scale;
                     self.total_integration += concept.eddy_scale;
+                    energy_flows.integration_gained += concept.eddy_scale;
                     concept.eddy_scale = 0.0;
                 }
             }
 
-            // Active damping
-            if self.damping_factor > 0.01 {
+            // Active damping (selective breaths leave slow, intentional motion alone)
+            if self.damping_factor > 0.01
+                && concept.velocity.abs() > self.damping_velocity_threshold
+            {
                 let damping_loss = concept.velocity.abs() * self.damping_factor * dt;
+                let ke_before_damping = kinetic_energy(concept.velocity);
                 concept.velocity *= 1.0 - self.damping_factor * dt;
+                energy_flows.damping_loss += ke_before_damping - kinetic_energy(concept.velocity);
                 concept.integration += damping_loss;
                 self.total_integration += damping_loss;
+                energy_flows.integration_gained += damping_loss;
             }
         }
 
+        self.apply_spotlights(dt, &mut energy_flows);
+
         // Apply core truth strengthening
         for (idx, strengthening) in core_truth_strengthened {
             if let Some(truth) = self.core_truths.get_mut(idx) {
@@ -1183,99 +2640,100 @@ impl ConceptFluid {
                 truth.heat_output += strengthening;
             }
         }
+        for (idx, concept_id, impulse) in vent_influence {
+            if let Some(truth) = self.core_truths.get_mut(idx) {
+                truth.influence.record(concept_id, impulse, self.tick_count);
+            }
+        }
 
         // Deposit ores
         for ore in ore_to_deposit {
-            let ore_weight = ore.pressure_weight();
-            self.ocean_floor_pressure += ore_weight;
-
-            events.push(FluidEvent::OreDeposited {
-                name: ore.name.clone(),
-                ore_type: ore.ore_type.as_str().to_string(),
-                total_pressure: self.ocean_floor_pressure,
-                threshold: self.pressure_threshold,
-            });
-
-            self.ore_deposits.push(ore);
+            events.extend(self.deposit_ore(ore));
         }
 
         events.extend(mineralization_events);
         events.extend(breakthrough_events);
+        events.extend(self.detect_collisions(&layers_before_physics));
 
         // Decay damping factor
         if self.damping_factor > 0.01 {
             self.damping_factor *= 0.95;
         } else {
             self.damping_factor = 0.0;
+            self.damping_velocity_threshold = 0.0;
         }
 
-        // Salinity increase
-        self.salinity += self.total_integration * self.salinity_rate * dt;
+        // Salinity grows with this tick's integration only, so a settled fluid
+        // stays as salty as it is however much it integrated in the past
+        self.salinity += energy_flows.integration_gained * self.salinity_rate;
 
         // === Pass 5: Evaporation ===
+        // An evaporated concept leaves the fluid; its trait remembers it by id.
+        // Concepts marked evaporated but still here (older snapshots) leave
+        // quietly, their trait having formed already.
         let mut evaporated_ids = Vec::new();
+        let mut lingering_ids = Vec::new();
         for (id, concept) in &self.concepts {
-            if concept.layer < self.evaporation_zone
+            if concept.has_evaporated && !concept.is_system() {
+                lingering_ids.push(*id);
+            } else if concept.layer < self.evaporation_zone
                 && concept.integration >= self.evaporation_threshold
-                && !concept.has_evaporated
+                && concept.participates_in_attention()
             {
                 evaporated_ids.push(*id);
             }
         }
 
+        for id in lingering_ids {
+            let _ = self.remove_concept(id, &mut events);
+        }
         for id in evaporated_ids {
-            if let Some(concept) = self.concepts.get_mut(&id) {
+            if let Ok(mut concept) = self.remove_concept(id, &mut events) {
                 concept.has_evaporated = true;
 
-                let trait_obj = CharacterTrait::new(concept.name.clone(), concept.integration, id);
-
                 events.push(FluidEvent::ConceptEvaporated {
                     id,
                     name: concept.name.clone(),
                     trait_formed: concept.name.clone(),
+                    trait_index: self.atmosphere.len(),
                     integration: concept.integration,
                 });
 
-                self.atmosphere.push(trait_obj);
+                self.atmosphere.push(CharacterTrait::from_concept(&concept));
             }
         }
 
         // === Pass 6: Tectonic shift check ===
+        // Only the ores under the new continent are absorbed; the rest stay
+        // on the floor, still pressing and still there for expeditions
         if self.ocean_floor_pressure >= self.pressure_threshold {
+            let depth_range = self.continent_depth_range();
+            let (consumed, surviving): (Vec<_>, Vec<_>) = std::mem::take(&mut self.ore_deposits)
+                .into_iter()
+                .partition(|o| o.depth >= depth_range.0 && o.depth <= depth_range.1);
+
             let mut ore_type_counts = HashMap::new();
             let mut total_integration = 0.0;
+            let mut consumed_pressure = 0.0;
             let mut ore_names = Vec::new();
 
-            for ore in &self.ore_deposits {
+            for ore in &consumed {
+                self.ore_stats.record_tectonic_consumption(ore);
                 *ore_type_counts.entry(&ore.ore_type).or_insert(0) += 1;
                 total_integration += ore.integration_value;
+                consumed_pressure += self.weighted_pressure(ore);
                 ore_names.push(ore.name.clone());
             }
 
-            let dominant_ore_type = ore_type_counts
+            let (dominant_ore_type, dominant_count) = ore_type_counts
                 .iter()
-                .max_by_key(|(_, count)| *count)
-                .map(|(ore_type, _)| *ore_type)
-                .unwrap_or(&OreType::Insight);
-
-            let continent_name = match dominant_ore_type {
-                OreType::Art => "foundation_of_beauty",
-                OreType::Code => "bedrock_of_logic",
-                OreType::Insight => "pillar_of_wisdom",
-                OreType::Writing => "archive_of_story",
-            };
-
-            let avg_ore_depth = self.ore_deposits.iter().map(|o| o.depth).sum::<f32>()
-                / self.ore_deposits.len().max(1) as f32;
-
-            let continent_span = 0.15;
-            let depth_range = (
-                (avg_ore_depth - continent_span / 2.0).max(0.6),
-                (avg_ore_depth + continent_span / 2.0).min(0.95),
-            );
+                .max_by_key(|(_, count)| **count)
+                .map(|(ore_type, count)| (**ore_type, *count))
+                .unwrap_or((OreType::Insight, 0));
+            let continent_name = self.continent_name(dominant_ore_type, dominant_count);
 
             let continent = Continent {
-                name: continent_name.to_string(),
+                name: continent_name.clone(),
                 depth_range,
                 formed_from_ores: ore_names.clone(),
                 total_integration,
@@ -1284,16 +2742,37 @@ impl ConceptFluid {
             };
 
             events.push(FluidEvent::TectonicShift {
-                continent_name: continent_name.to_string(),
+                continent_name,
                 depth_range,
                 ores_consumed: ore_names,
+                ores_surviving: surviving.iter().map(|o| o.name.clone()).collect(),
                 total_integration,
             });
 
             self.continents.push(continent);
             self.tectonic_shifts += 1;
-            self.ocean_floor_pressure = 0.0;
-            self.ore_deposits.clear();
+            self.ocean_floor_pressure = (self.ocean_floor_pressure - consumed_pressure).max(0.0);
+            self.ore_deposits = surviving;
+            events.extend(self.report_floor_pressure(PressureChangeReason::Consumption));
+        }
+
+        events.extend(self.update_salinity_band());
+
+        // Whatever blew up during the tick is not left for readers to see
+        events.extend(self.repair_non_finite());
+
+        // === Close the energy books ===
+        let ke_end = self.total_kinetic_energy();
+        if let Some(anomaly) = self
+            .energy
+            .settle(self.tick_count, ke_start, ke_end, energy_flows)
+        {
+            events.push(FluidEvent::EnergyAnomaly {
+                tick: anomaly.tick,
+                expected_delta: anomaly.flows.net(),
+                actual_delta: anomaly.ke_end - anomaly.ke_start,
+                imbalance: anomaly.imbalance,
+            });
         }
 
         events
@@ -1304,9 +2783,9 @@ impl ConceptFluid {
         let mut surface: Vec<_> = self
             .concepts
             .values()
-            .filter(|c| c.layer < threshold)
+            .filter(|c| c.participates_in_attention() && c.layer < threshold)
             .collect();
-        surface.sort_by(|a, b| a.layer.partial_cmp(&b.layer).unwrap());
+        surface.sort_by(|a, b| a.layer.total_cmp(&b.layer));
         surface
     }
 
@@ -1326,3 +2805,1602 @@ impl ConceptFluid {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_selective_deep_breath_spares_slow_concepts() {
+        let mut control = ConceptFluid::default();
+        let slow = control.add_concept("intention".to_string(), 0.5, 0.5);
+        let fast = control.add_concept("panic".to_string(), 0.5, 0.5);
+        control.get_concept_mut(slow).unwrap().velocity = 0.05;
+        control.get_concept_mut(fast).unwrap().velocity = 0.8;
+
+        let mut calmed = control.clone();
+        calmed.deep_breath(0.8, Some(0.3));
+
+        control.update(1.0 / 60.0);
+        calmed.update(1.0 / 60.0);
+
+        // Slow motion is untouched: same velocity, no damping integration
+        assert_eq!(
+            calmed.concepts[&slow].velocity,
+            control.concepts[&slow].velocity
+        );
+        assert_eq!(
+            calmed.concepts[&slow].integration,
+            control.concepts[&slow].integration
+        );
+        // Fast motion is damped and its energy integrated
+        assert!(calmed.concepts[&fast].velocity < control.concepts[&fast].velocity);
+        assert!(calmed.concepts[&fast].integration > control.concepts[&fast].integration);
+    }
+
+    #[test]
+    fn test_repeated_near_misses_build_urge() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("speak_up".to_string(), 0.5, 0.5);
+
+        let mut urges = Vec::new();
+        for _ in 0..3 {
+            // Approach the surface with too little energy to break through
+            let concept = fluid.get_concept_mut(id).unwrap();
+            concept.layer = 0.001;
+            concept.velocity = -0.2;
+            let events = fluid.update(1.0 / 60.0);
+            assert!(
+                events
+                    .iter()
+                    .any(|e| matches!(e, FluidEvent::SurfaceBounce { .. }))
+            );
+            urges.push(
+                events
+                    .into_iter()
+                    .find(|e| matches!(e, FluidEvent::BuildingUrge { .. })),
+            );
+        }
+
+        assert!(urges[0].is_none() && urges[1].is_none());
+        match &urges[2] {
+            Some(FluidEvent::BuildingUrge {
+                id: urge_id,
+                bounce_count,
+                best_kinetic_energy,
+                ..
+            }) => {
+                assert_eq!(*urge_id, id);
+                assert_eq!(*bounce_count, 3);
+                assert!(*best_kinetic_energy > 0.0);
+                assert!(*best_kinetic_energy < fluid.surface_tension);
+            }
+            other => panic!("expected BuildingUrge, got {:?}", other),
+        }
+
+        // Sinking away for long enough forgets the urge
+        let concept = fluid.get_concept_mut(id).unwrap();
+        concept.layer = 0.5;
+        concept.velocity = 0.0;
+        for _ in 0..(URGE_RESET_TIME * 60.0) as usize + 1 {
+            fluid.update(1.0 / 60.0);
+        }
+        assert_eq!(fluid.concepts[&id].bounce_count, 0);
+    }
+
+    #[test]
+    fn test_unresolved_integration_weighs_concept_down() {
+        let mut light = ConceptFluid {
+            evaporation_threshold: f32::INFINITY,
+            ..ConceptFluid::default()
+        };
+        let id = light.add_concept("unspoken_insight".to_string(), 0.5, 0.5);
+        let concept = light.get_concept_mut(id).unwrap();
+        concept.layer = 0.8;
+        concept.integration = 2.0;
+
+        let mut heavy = light.clone();
+        heavy.integration_heaviness_enabled = true;
+
+        for _ in 0..300 {
+            light.update(1.0 / 60.0);
+            heavy.update(1.0 / 60.0);
+        }
+
+        assert!(heavy.concepts[&id].layer > light.concepts[&id].layer + 0.1);
+    }
+
+    #[test]
+    fn test_crowded_band_thickens_drag() {
+        // The same crowd either around the commuter or far below it
+        let sinking = |crowd_layer: f32| {
+            let mut fluid = ConceptFluid {
+                crowding_drag_enabled: true,
+                ..ConceptFluid::default()
+            };
+            let id = fluid.add_concept("commuter".to_string(), 0.5, 0.5);
+            let concept = fluid.get_concept_mut(id).unwrap();
+            concept.layer = 0.5;
+            concept.velocity = 1.0;
+            for n in 0..10 {
+                let other = fluid.add_concept(format!("crowd_{}", n), 0.5, 0.5);
+                fluid.get_concept_mut(other).unwrap().layer = crowd_layer + n as f32 * 0.004;
+            }
+            (fluid, id)
+        };
+        let (mut empty, empty_id) = sinking(0.9);
+        let (mut crowded, crowded_id) = sinking(0.5);
+
+        for _ in 0..5 {
+            empty.update(1.0 / 60.0);
+            crowded.update(1.0 / 60.0);
+        }
+
+        let (empty_loss, crowded_loss) = (
+            1.0 - empty.concepts[&empty_id].velocity,
+            1.0 - crowded.concepts[&crowded_id].velocity,
+        );
+        assert!(empty_loss > 0.0);
+        assert!(
+            crowded_loss > empty_loss * 1.5,
+            "crowded lost {} vs empty {}",
+            crowded_loss,
+            empty_loss
+        );
+    }
+
+    #[test]
+    fn test_consensus_from_live_concepts_injects_insight() {
+        let mut fluid = ConceptFluid::default();
+        let privacy = fluid.add_concept("privacy".to_string(), 0.3, 0.5);
+        let transparency = fluid.add_concept("transparency".to_string(), 0.7, 0.5);
+        assert!(
+            fluid
+                .start_consensus_from_concepts(privacy, privacy)
+                .is_none()
+        );
+        assert!(
+            fluid
+                .start_consensus_from_concepts(privacy, Uuid::new_v4())
+                .is_none()
+        );
+
+        fluid
+            .start_consensus_from_concepts(transparency, privacy)
+            .unwrap();
+        let exp = fluid.get_consensus_experiment().unwrap();
+        assert_eq!(exp.vent_a.position, "privacy");
+        assert_eq!(exp.vent_b.position, "transparency");
+        assert!(exp.probe_ids.iter().all(|id| {
+            let layer = fluid.concepts[id].layer;
+            layer > exp.vent_a.depth && layer < exp.vent_b.depth
+        }));
+
+        let mut crystallized = None;
+        for _ in 0..1000 {
+            fluid.update(1.0 / 60.0);
+            if let Some(ore) = fluid.check_consensus_crystallization() {
+                crystallized = Some(ore);
+                break;
+            }
+        }
+        let ore = crystallized.expect("experiment should crystallize");
+
+        let material = match ore.phase_structure {
+            Some(ref structure) => structure.material_name.replace(' ', "_").to_lowercase(),
+            None => ore.ore_type.as_str().to_string(),
+        };
+        let insight = &fluid.concepts[&ore.insight_concept.unwrap()];
+        assert!(insight.name.starts_with("privacy_transparency_"));
+        assert!(insight.name.ends_with(&material));
+        assert_eq!(insight.integration, ore.integration_value);
+        assert_eq!(
+            fluid.get_consensus_ores().last().unwrap().insight_concept,
+            ore.insight_concept
+        );
+
+        // Probes are gone; the sources and the insight remain
+        assert_eq!(fluid.concepts.len(), 3);
+    }
+
+    #[test]
+    fn test_forced_crystallization_keeps_the_partial_jitter() {
+        let mut fluid = ConceptFluid::default();
+        assert!(fluid.crystallize_consensus_now().is_none());
+
+        fluid.start_consensus_experiment(
+            "privacy".to_string(),
+            1.0,
+            "transparency".to_string(),
+            1.0,
+        );
+        for _ in 0..45 {
+            fluid.update(1.0 / 60.0);
+            assert!(fluid.check_consensus_crystallization().is_none());
+        }
+        let exp = fluid.get_consensus_experiment().unwrap();
+        let jitter = exp.accumulated_jitter;
+        let elapsed = fluid.tick_count - exp.start_tick;
+        assert!(jitter > 0.0);
+        assert!(elapsed < exp.min_crystallization_time);
+
+        let ore = fluid.crystallize_consensus_now().unwrap();
+        assert_eq!(ore.accumulated_jitter, jitter);
+        assert_eq!(ore.certainty, 1.0 / (1.0 + jitter));
+        assert_eq!(ore.crystallization_time, elapsed);
+        assert!(fluid.get_consensus_experiment().is_none());
+        assert_eq!(fluid.get_consensus_ores().len(), 1);
+        // Probes are cleared as on a normal crystallization
+        assert!(fluid.concepts.is_empty());
+    }
+
+    #[test]
+    fn test_kept_probes_outlive_crystallization() {
+        let crystallize = |keep_particles: bool| {
+            let mut fluid = ConceptFluid::default();
+            // A thought that only shares the probes' name
+            let namesake = fluid.add_concept("consensus_probe_notes".to_string(), 0.5, 0.5);
+            fluid.start_consensus_experiment(
+                "privacy".to_string(),
+                1.0,
+                "transparency".to_string(),
+                1.0,
+            );
+            let exp = fluid.consensus_reactor.active_experiment.as_mut().unwrap();
+            exp.keep_particles = keep_particles;
+            let probe_ids = exp.probe_ids.clone();
+            for _ in 0..30 {
+                fluid.update(1.0 / 60.0);
+            }
+            let ore = fluid.crystallize_consensus_now().unwrap();
+            assert!(fluid.concepts.contains_key(&namesake));
+            (fluid, ore, probe_ids)
+        };
+
+        let (fluid, _, probe_ids) = crystallize(false);
+        assert!(probe_ids.iter().all(|id| !fluid.concepts.contains_key(id)));
+        assert_eq!(fluid.concepts.len(), 1);
+
+        let (fluid, ore, probe_ids) = crystallize(true);
+        for (i, id) in probe_ids.iter().enumerate() {
+            let probe = &fluid.concepts[id];
+            assert_eq!(probe.name, format!("{}_probe_{}", ore.name, i));
+            assert_eq!(probe.role, ConceptRole::Thought);
+        }
+        assert_eq!(fluid.concepts.len(), probe_ids.len() + 1);
+    }
+
+    #[test]
+    fn test_rename_preserves_state_and_derived_names() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("feeling_lonely".to_string(), 0.9, 0.5);
+        for _ in 0..30 {
+            fluid.update(1.0 / 60.0);
+        }
+        fluid.get_concept_mut(id).unwrap().integration = 0.75;
+        fluid.ore_deposits.push(PreciousOre {
+            name: "feeling_lonely_ore_1".to_string(),
+            ore_type: OreType::Code,
+            density: 0.9,
+            depth: 0.9,
+            formed_from: id,
+            vent_cycles: 3,
+            integration_value: 1.5,
+        });
+        let before = fluid.concepts[&id].clone();
+
+        let old = fluid.rename_concept(id, "seeking_connection".to_string());
+
+        assert_eq!(old.as_deref(), Some("feeling_lonely"));
+        let after = &fluid.concepts[&id];
+        assert_eq!(after.name, "seeking_connection");
+        assert_eq!(after.id, before.id);
+        assert_eq!(after.layer, before.layer);
+        assert_eq!(after.velocity, before.velocity);
+        assert_eq!(after.integration, before.integration);
+        assert_eq!(fluid.ore_deposits[0].name, "feeling_lonely_ore_1");
+
+        assert!(
+            fluid
+                .rename_concept(Uuid::new_v4(), "x".to_string())
+                .is_none()
+        );
+    }
+
+    fn run_division(fluid: &mut ConceptFluid) -> DivisionResult {
+        loop {
+            fluid.update(1.0 / 60.0);
+            if let Some(result) = fluid.check_experiment_settlement() {
+                break result;
+            }
+        }
+    }
+
+    #[test]
+    fn test_persistent_wave_outlives_division_experiments() {
+        // The same drifter, resting above its equilibrium at 0.5, with and
+        // without a wave whose upper node sits at 0.25
+        let drifter = |waved: bool| {
+            let mut fluid = ConceptFluid {
+                evaporation_threshold: f32::INFINITY,
+                ..ConceptFluid::default()
+            };
+            let id = fluid.add_concept("drifter".to_string(), 0.5, 0.5);
+            fluid.get_concept_mut(id).unwrap().layer = 0.3;
+            if waved {
+                fluid.add_persistent_wave(StandingWave::new_with_saturation(2.0, 5.0, 2));
+            }
+            (fluid, id)
+        };
+        let (mut plain, plain_id) = drifter(false);
+        let (mut waved, waved_id) = drifter(true);
+
+        let mut plain_deepest: f32 = 0.0;
+        for tick in 0..300 {
+            plain.update(1.0 / 60.0);
+            waved.update(1.0 / 60.0);
+            plain_deepest = plain_deepest.max(plain.concepts[&plain_id].layer);
+            if tick >= 60 {
+                let layer = waved.concepts[&waved_id].layer;
+                assert!((layer - 0.25).abs() < 0.08, "tick {}: {}", tick, layer);
+            }
+        }
+        assert!(plain_deepest > 0.5, "{}", plain_deepest);
+
+        // Starting an experiment swaps its own wave in but keeps ours
+        for fluid in [&mut plain, &mut waved] {
+            fluid.start_division_experiment(6.0, 3.0);
+            assert_eq!(fluid.standing_waves.len(), 1);
+        }
+        assert_eq!(waved.persistent_waves.len(), 1);
+        for _ in 0..300 {
+            plain.update(1.0 / 60.0);
+            waved.update(1.0 / 60.0);
+        }
+        let (plain_layer, waved_layer) = (
+            plain.concepts[&plain_id].layer,
+            waved.concepts[&waved_id].layer,
+        );
+        assert!(
+            (plain_layer - waved_layer).abs() > 0.2,
+            "{} vs {}",
+            plain_layer,
+            waved_layer
+        );
+
+        let id = waved.persistent_waves[0].id;
+        assert!(waved.remove_persistent_wave(id).is_some());
+        assert!(waved.remove_persistent_wave(id).is_none());
+    }
+
+    #[test]
+    fn test_scaled_thresholds_let_large_division_settle() {
+        // Fixed thresholds tuned for a few nodes: 16 bubbles in 8 nodes never meet them
+        let mut fixed = ConceptFluid::default();
+        fixed.start_division_experiment(16.0, 8.0);
+        let experiment = fixed.active_experiment.as_mut().unwrap();
+        experiment.thresholds = SettlementThresholds::default();
+        let max_ticks = experiment.max_ticks;
+        assert_eq!(run_division(&mut fixed).ticks_to_settle, max_ticks);
+
+        let mut scaled = ConceptFluid::default();
+        scaled.start_division_experiment(16.0, 8.0);
+        let experiment = scaled.active_experiment.as_ref().unwrap();
+        assert_eq!(experiment.thresholds, SettlementThresholds::scaled(16, 8));
+        let result = run_division(&mut scaled);
+        assert!(
+            result.ticks_to_settle < max_ticks,
+            "scaled thresholds should settle, took {} ticks",
+            result.ticks_to_settle
+        );
+
+        // Not a lucky run: the same experiment settles the same way every time
+        let mut again = ConceptFluid::default();
+        again.start_division_experiment(16.0, 8.0);
+        let rerun = run_division(&mut again);
+        assert_eq!(rerun.ticks_to_settle, result.ticks_to_settle);
+        assert_eq!(rerun.node_occupancy, result.node_occupancy);
+        assert_eq!(rerun.velocity_sigma, result.velocity_sigma);
+    }
+
+    #[test]
+    fn test_kept_bubbles_outlive_settlement() {
+        let mut discarded = ConceptFluid::default();
+        discarded.start_division_experiment(9.0, 3.0);
+        run_division(&mut discarded);
+        assert!(discarded.concepts.is_empty());
+
+        let mut kept = ConceptFluid::default();
+        kept.start_division_experiment(9.0, 3.0);
+        kept.active_experiment.as_mut().unwrap().keep_particles = true;
+        run_division(&mut kept);
+        assert_eq!(kept.concepts.len(), 9);
+        assert!(
+            kept.concepts
+                .values()
+                .all(|c| c.name.starts_with("bubble_"))
+        );
+        assert!(kept.concepts.values().any(|c| c.name.contains("_node_")));
+    }
+
+    #[test]
+    fn test_division_result_reports_how_it_settled() {
+        let mut fluid = ConceptFluid::default();
+        fluid.start_division_experiment(6.0, 3.0);
+        let clean = run_division(&mut fluid);
+        assert_eq!(clean.settled_by, SettledBy::Settled);
+        assert!(clean.settled_naturally);
+        assert!(clean.ticks_to_settle < 300);
+        assert!(clean.confidence > 0.8, "confidence {}", clean.confidence);
+
+        // Unreachable thresholds force a timeout
+        fluid.start_division_experiment(6.0, 3.0);
+        let experiment = fluid.active_experiment.as_mut().unwrap();
+        experiment.thresholds = experiment.thresholds.with_overrides(None, Some(1e-6), None);
+        let forced = run_division(&mut fluid);
+        assert_eq!(forced.settled_by, SettledBy::TimedOut);
+        assert!(!forced.settled_naturally);
+        assert_eq!(forced.ticks_to_settle, 300);
+        assert!(forced.confidence < clean.confidence);
+    }
+
+    #[test]
+    fn test_division_bubbles_never_freeze_the_fluid() {
+        let mut fluid = ConceptFluid::default();
+        fluid.start_division_experiment(7.0, 3.0);
+        // Unreachable thresholds hold the experiment open until it times out
+        let experiment = fluid.active_experiment.as_mut().unwrap();
+        experiment.thresholds = experiment.thresholds.with_overrides(None, Some(1e-6), None);
+        let bubble_ids = experiment.bubble_ids.clone();
+
+        let mut time_in_freeze_zone = 0.0;
+        let result = loop {
+            for event in fluid.update(1.0 / 60.0) {
+                assert!(
+                    !matches!(event, FluidEvent::Freeze { .. }),
+                    "froze at tick {}",
+                    fluid.tick_count
+                );
+            }
+            assert!(!fluid.is_frozen);
+            if bubble_ids
+                .iter()
+                .any(|id| fluid.concepts[id].layer < fluid.freeze_zone)
+            {
+                time_in_freeze_zone += 1.0 / 60.0;
+            }
+            if let Some(result) = fluid.check_experiment_settlement() {
+                break result;
+            }
+        };
+
+        assert_eq!(result.settled_by, SettledBy::TimedOut);
+        assert!(
+            time_in_freeze_zone > 0.0,
+            "no bubble reached the freeze zone"
+        );
+        assert!(fluid.get_surface_concepts(1.0).is_empty());
+    }
+
+    #[test]
+    fn test_variance_mode_ignores_a_lone_outlier() {
+        let onset = |mode: ReynoldsMode, velocities: &[f32]| {
+            let mut fluid = ConceptFluid {
+                reynolds_mode: mode,
+                ..ConceptFluid::default()
+            };
+            for (i, &velocity) in velocities.iter().enumerate() {
+                let id = fluid.add_concept(format!("thought_{}", i), 0.5, 0.5);
+                fluid.get_concept_mut(id).unwrap().velocity = velocity;
+            }
+            fluid
+                .update(1.0 / 60.0)
+                .iter()
+                .any(|event| matches!(event, FluidEvent::TurbulenceOnset { .. }))
+        };
+        let outlier = [-4.0, 0.0, 0.0, 0.0];
+        let agitated = [-1.5, 1.5, -1.5, 1.5];
+
+        assert!(onset(ReynoldsMode::Mean, &outlier));
+        assert!(!onset(ReynoldsMode::Variance, &outlier));
+        assert!(onset(ReynoldsMode::Mean, &agitated));
+        assert!(onset(ReynoldsMode::Variance, &agitated));
+    }
+
+    #[test]
+    fn test_super_buoyant_concept_presses_against_surface() {
+        let mut clamped = ConceptFluid::default();
+        let id = clamped.add_concept("overwhelming_drive".to_string(), 0.9, 0.5);
+        clamped.get_concept_mut(id).unwrap().layer = 0.3;
+        let mut extended = clamped.clone();
+        extended.extended_buoyancy_enabled = true;
+
+        for fluid in [&mut clamped, &mut extended] {
+            fluid.modulate_buoyancy(id, 5.0).unwrap();
+            // Start both from rest so only the buoyancy target differs
+            fluid.get_concept_mut(id).unwrap().velocity = 0.0;
+        }
+        assert_eq!(clamped.concepts[&id].buoyancy, 1.0);
+        assert!(extended.concepts[&id].buoyancy > 1.0);
+        assert!(extended.concepts[&id].buoyancy <= extended.extended_buoyancy_max);
+
+        // Upward push (negative = rising) whenever the concept is near the surface
+        let surface_push = |fluid: &ConceptFluid| {
+            let concept = &fluid.concepts[&id];
+            (concept.layer < 0.1)
+                .then(|| DepthForces::on(concept, 0.0, fluid.salinity, &[], &[], &[]).buoyancy)
+        };
+        let (mut clamped_push, mut extended_push) = (Vec::new(), Vec::new());
+        for _ in 0..180 {
+            clamped.update(1.0 / 60.0);
+            extended.update(1.0 / 60.0);
+            clamped_push.extend(surface_push(&clamped));
+            extended_push.extend(surface_push(&extended));
+        }
+
+        // Clamped: the surface is the equilibrium, so the push fades there and
+        // the rise is too slow to break through. Extended: the push is sustained.
+        assert!(extended.concepts[&id].has_broken_surface);
+        assert!(!clamped.concepts[&id].has_broken_surface);
+        assert!(extended_push.len() > clamped_push.len());
+        assert!(clamped_push.iter().all(|&f| f > -0.1));
+        assert!(extended_push.iter().all(|&f| f < -0.3));
+    }
+
+    #[test]
+    fn test_reimmersed_trait_can_evaporate_again() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("patience".to_string(), 0.5, 0.5);
+        let integration = fluid.evaporation_threshold + 0.5;
+        let concept = fluid.get_concept_mut(id).unwrap();
+        concept.layer = 0.1;
+        concept.integration = integration;
+        fluid.update(1.0 / 60.0);
+        assert!(!fluid.concepts.contains_key(&id));
+        assert_eq!(fluid.atmosphere.len(), 1);
+        let trait_integration = fluid.atmosphere[0].integration;
+
+        assert_eq!(
+            fluid.reimmerse_trait(0),
+            Some(id),
+            "the same thought returns"
+        );
+        assert!(fluid.atmosphere.is_empty());
+        let concept = &fluid.concepts[&id];
+        assert!(!concept.has_evaporated);
+        assert_eq!(concept.integration, trait_integration);
+        assert!(concept.layer >= fluid.evaporation_zone);
+        assert!(fluid.reimmerse_trait(0).is_none());
+
+        // Rising back into the evaporation zone turns it into a trait again
+        fluid.get_concept_mut(id).unwrap().layer = 0.1;
+        fluid.update(1.0 / 60.0);
+        assert!(!fluid.concepts.contains_key(&id));
+        assert_eq!(fluid.atmosphere.len(), 1);
+        assert_eq!(fluid.atmosphere[0].formed_from, id);
+    }
+
+    #[test]
+    fn test_reimmersed_trait_keeps_its_density_and_area() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("resolve".to_string(), 0.3, 0.8);
+        let area = fluid.concepts[&id].area;
+        let integration = fluid.evaporation_threshold + 0.5;
+        let concept = fluid.get_concept_mut(id).unwrap();
+        concept.layer = 0.1;
+        concept.integration = integration;
+        fluid.update(1.0 / 60.0);
+        assert!(!fluid.concepts.contains_key(&id));
+
+        assert_eq!(fluid.reimmerse_trait(0), Some(id));
+        let concept = &fluid.concepts[&id];
+        assert_eq!(concept.density, 0.3);
+        assert_eq!(concept.area, area);
+    }
+
+    #[test]
+    fn test_evaporated_concept_leaves_the_fluid_once() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("acceptance".to_string(), 0.5, 0.5);
+        let integration = fluid.evaporation_threshold + 0.5;
+        let concept = fluid.get_concept_mut(id).unwrap();
+        concept.layer = 0.1;
+        concept.integration = integration;
+
+        let mut evaporations = 0;
+        for _ in 0..120 {
+            evaporations += fluid
+                .update(1.0 / 60.0)
+                .iter()
+                .filter(|e| matches!(e, FluidEvent::ConceptEvaporated { .. }))
+                .count();
+        }
+        assert_eq!(evaporations, 1);
+        assert!(fluid.get_concept(id).is_none());
+        assert!(fluid.get_surface_concepts(0.3).is_empty());
+        assert!(fluid.get_concepts_in_range(0.0, 1.0).is_empty());
+        assert_eq!(fluid.atmosphere.len(), 1);
+        assert_eq!(fluid.atmosphere[0].formed_from, id);
+
+        // A ghost left behind by an older snapshot goes without a second trait
+        let ghost = fluid.add_concept("ghost".to_string(), 0.5, 0.5);
+        fluid.get_concept_mut(ghost).unwrap().has_evaporated = true;
+        let events = fluid.update(1.0 / 60.0);
+        assert!(fluid.get_concept(ghost).is_none());
+        assert!(
+            !events
+                .iter()
+                .any(|e| matches!(e, FluidEvent::ConceptEvaporated { .. }))
+        );
+        assert_eq!(fluid.atmosphere.len(), 1);
+    }
+
+    #[test]
+    fn test_viscosity_thickens_with_depth_before_shear_thinning() {
+        let fluid = ConceptFluid {
+            viscosity_surface: 0.2,
+            viscosity_deep: 1.0,
+            ..ConceptFluid::default()
+        };
+        assert!((fluid.effective_viscosity_at_depth(0.0, 0.0) - 0.2).abs() < 1e-6);
+        assert!((fluid.effective_viscosity_at_depth(0.5, 0.0) - 0.6).abs() < 1e-6);
+        assert!((fluid.effective_viscosity_at_depth(1.0, 0.0) - 1.0).abs() < 1e-6);
+        assert!((fluid.mean_viscosity() - 0.6).abs() < 1e-6);
+
+        // Fast concepts thin the fluid by the same share at any depth
+        let speed = fluid.shear_threshold + 0.5;
+        let thinned = 1.0 - fluid.shear_thinning_coefficient * 0.5;
+        for depth in [0.0, 0.5, 1.0] {
+            let ratio =
+                fluid.effective_viscosity_at_depth(depth, -speed) / fluid.viscosity_at_depth(depth);
+            assert!((ratio - thinned).abs() < 1e-5, "{}: {}", depth, ratio);
+        }
+
+        // The same plunging thought is slowed harder in the thick depths
+        let plunge = |fluid: ConceptFluid| {
+            let mut fluid = fluid;
+            let id = fluid.add_concept("stone".to_string(), 0.8, 0.5);
+            let concept = fluid.get_concept_mut(id).unwrap();
+            concept.layer = 0.6;
+            concept.velocity = 2.0;
+            for _ in 0..6 {
+                fluid.update(1.0 / 60.0);
+            }
+            fluid.get_concept(id).unwrap().velocity
+        };
+        let uniform = plunge(ConceptFluid {
+            viscosity_deep: 0.2,
+            ..fluid.clone()
+        });
+        let graded = plunge(fluid);
+        assert!(graded < uniform - 0.1, "{} vs {}", graded, uniform);
+    }
+
+    #[test]
+    fn test_large_dt_is_sub_stepped_and_bad_dt_skipped() {
+        let mut fluid = ConceptFluid::default();
+        for (name, density, layer) in [("stone", 0.95, 0.1), ("cork", 0.05, 0.9)] {
+            let id = fluid.add_concept(name.to_string(), density, 0.5);
+            fluid.get_concept_mut(id).unwrap().layer = layer;
+        }
+
+        fluid.update(10.0);
+        assert_eq!(fluid.tick_count, 300);
+        for concept in fluid.concepts.values() {
+            assert!((0.0..=1.0).contains(&concept.layer), "{}", concept.layer);
+            assert!(concept.velocity.abs() <= 5.0, "{}", concept.velocity);
+        }
+        assert!(fluid.salinity.is_finite());
+
+        // Beyond the sub-step limit the rest of the time is dropped
+        fluid.update(1000.0);
+        assert_eq!(fluid.tick_count, 300 + MAX_SUBSTEPS as u64);
+
+        let before = fluid.tick_count;
+        for dt in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(fluid.update(dt).is_empty());
+        }
+        assert_eq!(fluid.tick_count, before);
+    }
+
+    #[test]
+    fn test_concept_seq_never_reused_after_removal() {
+        let mut fluid = ConceptFluid::default();
+        let mut seen = HashSet::new();
+        for round in 0..3 {
+            let ids: Vec<_> = (0..4)
+                .map(|i| fluid.add_concept(format!("thought_{}_{}", round, i), 0.5, 0.5))
+                .collect();
+            fluid.flash_heal(
+                vec![("naive".to_string(), 0.3, ConceptSize::Area(0.5))],
+                0.1,
+            );
+            let seqs: Vec<_> = fluid.concepts.values().map(|c| c.seq).collect();
+            for seq in seqs {
+                seen.insert(seq);
+            }
+            // Removing concepts shrinks the map but must not rewind the counter
+            for id in ids {
+                fluid.concepts.remove(&id);
+            }
+        }
+
+        assert_eq!(fluid.next_concept_seq, 15);
+        assert_eq!(seen, (0..15).collect::<HashSet<_>>());
+        let restored = ConceptFluid::from_snapshot(fluid.to_snapshot().unwrap()).unwrap();
+        assert_eq!(restored.next_concept_seq, 15);
+    }
+
+    #[test]
+    fn test_inverted_zones_are_rejected() {
+        assert!(ConceptFluid::default().validate_zones().is_ok());
+
+        // Freezing before surface tension applies
+        let inverted = ConceptFluid::new(0.5, 1.2, 0.05, 0.1, 2.0, 0.2, 1.0, 0.3, 5, 1.0, 0.3);
+        let err = inverted.validate_zones().unwrap_err();
+        assert!(err.contains("freeze_zone"), "{}", err);
+        assert!(ConceptFluid::from_snapshot(inverted.to_snapshot().unwrap()).is_err());
+
+        let shallow_evaporation = ConceptFluid {
+            evaporation_zone: 0.08,
+            ..ConceptFluid::default()
+        };
+        let err = shallow_evaporation.validate_zones().unwrap_err();
+        assert!(err.contains("evaporation_zone"), "{}", err);
+    }
+
+    #[test]
+    fn test_still_fluid_keeps_its_salinity() {
+        let mut fluid = ConceptFluid {
+            total_integration: 50.0,
+            salinity: 1.0,
+            ..ConceptFluid::default()
+        };
+        // Neutrally buoyant, so it rests where it starts
+        let resting = fluid.add_concept("settled".to_string(), 0.5, 0.5);
+
+        for _ in 0..1000 {
+            fluid.update(1.0 / 60.0);
+        }
+        assert_eq!(
+            fluid.salinity, 1.0,
+            "past integration must not keep salting"
+        );
+        assert_eq!(fluid.total_integration, 50.0);
+
+        // New motion integrates, and salts in proportion
+        fluid.get_concept_mut(resting).unwrap().velocity = 1.0;
+        for _ in 0..60 {
+            fluid.update(1.0 / 60.0);
+        }
+        let gained = fluid.total_integration - 50.0;
+        assert!(gained > 0.0);
+        assert!((fluid.salinity - (1.0 + gained * fluid.salinity_rate)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_heavy_thought_injected_at_surface_sinks() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.inject_concept("dread".to_string(), 0.9, 0.5, Some(0.05), None, None);
+        assert_eq!(fluid.concepts[&id].layer, 0.05);
+
+        let mut previous = 0.05;
+        for second in 0..3 {
+            for _ in 0..60 {
+                fluid.update(1.0 / 60.0);
+            }
+            let layer = fluid.concepts[&id].layer;
+            assert!(layer > previous, "still sinking after {}s", second + 1);
+            previous = layer;
+        }
+
+        // Out-of-range layers are clamped; a starting velocity counts as an impulse
+        let thrown =
+            fluid.inject_concept("leap".to_string(), 0.2, 0.5, Some(-0.5), Some(-0.4), None);
+        assert_eq!(fluid.concepts[&thrown].layer, 0.0);
+        assert_eq!(fluid.concepts[&thrown].velocity, -0.4);
+        let events = fluid.update(1.0 / 60.0);
+        assert!(
+            !events
+                .iter()
+                .any(|e| matches!(e, FluidEvent::EnergyAnomaly { .. }))
+        );
+    }
+
+    #[test]
+    fn test_initial_buoyancy_is_independent_of_starting_layer() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.inject_concept("dread".to_string(), 0.9, 0.5, Some(0.05), None, Some(0.3));
+        let concept = &fluid.concepts[&id];
+        assert_eq!(
+            (concept.density, concept.buoyancy, concept.layer),
+            (0.9, 0.3, 0.05)
+        );
+
+        // Both start at the surface; the less buoyant one settles deeper
+        let default = fluid.inject_concept("dread".to_string(), 0.9, 0.5, Some(0.05), None, None);
+        for _ in 0..180 {
+            fluid.update(1.0 / 60.0);
+        }
+        assert!(fluid.concepts[&id].layer > 0.05);
+        assert!(fluid.concepts[&id].layer > fluid.concepts[&default].layer);
+
+        // Buoyancy beyond the allowed range is clamped
+        let buoyant = fluid.inject_concept("leap".to_string(), 0.2, 0.5, None, None, Some(1.5));
+        assert_eq!(fluid.concepts[&buoyant].buoyancy, 1.0);
+    }
+
+    #[test]
+    fn test_continent_takes_configured_name_for_dominant_ore() {
+        let mut fluid = ConceptFluid::default();
+        fluid
+            .continent_names
+            .insert(OreType::Code, "spine_of_{count}_proofs".to_string());
+        let ore = |ore_type: OreType| PreciousOre {
+            name: format!("{}_ore", ore_type.as_str()),
+            ore_type,
+            density: 0.9,
+            depth: 0.9,
+            formed_from: Uuid::new_v4(),
+            vent_cycles: 3,
+            integration_value: 1.0,
+        };
+        fluid.ore_deposits = vec![ore(OreType::Code), ore(OreType::Art), ore(OreType::Code)];
+        fluid.ocean_floor_pressure = fluid.pressure_threshold;
+
+        let events = fluid.update(1.0 / 60.0);
+
+        assert_eq!(fluid.continents[0].name, "spine_of_2_proofs");
+        assert!(events.iter().any(|e| matches!(
+            e,
+            FluidEvent::TectonicShift { continent_name, .. } if continent_name == "spine_of_2_proofs"
+        )));
+        // Unconfigured types keep their evocative defaults
+        assert_eq!(
+            fluid.continent_name(OreType::Art, 1),
+            "foundation_of_beauty"
+        );
+    }
+
+    #[test]
+    fn test_tectonic_shift_consumes_only_ores_under_the_continent() {
+        let mut fluid = ConceptFluid::default();
+        let ore = |name: &str, depth: f32, integration_value: f32| PreciousOre {
+            name: name.to_string(),
+            ore_type: OreType::Code,
+            density: 0.9,
+            depth,
+            formed_from: Uuid::new_v4(),
+            vent_cycles: 3,
+            integration_value,
+        };
+        for n in 0..3 {
+            fluid.deposit_ore(ore(&format!("deep_{}", n), 0.9, 2.0));
+        }
+        let shallow = ore("shallow", 0.3, 1.0);
+        let shallow_weight = shallow.pressure_weight();
+        fluid.deposit_ore(shallow);
+        fluid.pressure_threshold = fluid.ocean_floor_pressure;
+
+        let events = fluid.update(1.0 / 60.0);
+
+        let (consumed, surviving) = events
+            .iter()
+            .find_map(|e| match e {
+                FluidEvent::TectonicShift {
+                    ores_consumed,
+                    ores_surviving,
+                    ..
+                } => Some((ores_consumed.clone(), ores_surviving.clone())),
+                _ => None,
+            })
+            .expect("pressure at threshold should shift");
+        assert_eq!(consumed, ["deep_0", "deep_1", "deep_2"]);
+        assert_eq!(surviving, ["shallow"]);
+        assert_eq!(fluid.ore_deposits.len(), 1);
+        assert_eq!(fluid.ore_deposits[0].name, "shallow");
+        assert!((fluid.ocean_floor_pressure - shallow_weight).abs() < 1e-5);
+        assert_eq!(fluid.continents[0].formed_from_ores, consumed);
+        assert!((fluid.continents[0].total_integration - 6.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rk4_tracks_undamped_oscillation_closer_than_euler() {
+        // Without drag a concept released off its target layer swings like a
+        // spring: layer'' = -density * (layer - target)
+        let run = |integrator: IntegratorKind, dt: f32| {
+            let mut fluid = ConceptFluid {
+                drag_coefficient: 0.0,
+                integrator,
+                ..ConceptFluid::default()
+            };
+            let id = fluid.add_concept("pendulum".to_string(), 0.5, 0.5);
+            fluid.get_concept_mut(id).unwrap().layer = 0.35;
+            let ticks = (6.0 / dt).round() as usize;
+            for _ in 0..ticks {
+                fluid.update(dt);
+            }
+            fluid.get_concept(id).unwrap().layer
+        };
+        let omega = 0.5f32.sqrt();
+        let exact = 0.5 - 0.15 * (omega * 6.0).cos();
+
+        let euler_error = (run(IntegratorKind::Euler, 0.1) - exact).abs();
+        let rk4_error = (run(IntegratorKind::Rk4, 0.1) - exact).abs();
+        assert!(
+            rk4_error * 10.0 < euler_error,
+            "rk4 off by {}, euler by {}",
+            rk4_error,
+            euler_error
+        );
+    }
+
+    #[test]
+    fn test_floor_pressure_changes_report_their_reason() {
+        let mut fluid = ConceptFluid::default();
+        let ore = |name: &str, integration_value: f32| PreciousOre {
+            name: name.to_string(),
+            ore_type: OreType::Code,
+            density: 0.9,
+            depth: 0.9,
+            formed_from: Uuid::new_v4(),
+            vent_cycles: 3,
+            integration_value,
+        };
+        let reasons = |events: &[FluidEvent]| -> Vec<(PressureChangeReason, f32, f32)> {
+            events
+                .iter()
+                .filter_map(|e| match e {
+                    FluidEvent::PressureChanged {
+                        old, new, reason, ..
+                    } => Some((*reason, *old, *new)),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let events = fluid.deposit_ore(ore("heavy", 2.0));
+        assert_eq!(
+            reasons(&events),
+            [(PressureChangeReason::Deposit, 0.0, 1.8)]
+        );
+
+        // A move inside the debounce is held back until it accumulates
+        assert!(reasons(&fluid.deposit_ore(ore("speck", 0.005))).is_empty());
+        let events = fluid.deposit_ore(ore("speck", 0.01));
+        assert_eq!(reasons(&events)[0].1, 1.8);
+
+        let events: Vec<_> = fluid.set_pressure_threshold(1.0).into_iter().collect();
+        assert_eq!(reasons(&events)[0].0, PressureChangeReason::ThresholdSet);
+
+        let events = fluid.update(1.0 / 60.0);
+        let consumed = reasons(&events);
+        assert_eq!(consumed.len(), 1);
+        assert_eq!(consumed[0].0, PressureChangeReason::Consumption);
+        assert_eq!(consumed[0].2, 0.0);
+        assert_eq!(fluid.continents.len(), 1);
+    }
+
+    #[test]
+    fn test_precipitation_falls_from_the_surface_without_freezing() {
+        let mut fluid = ConceptFluid::default();
+        fluid.atmosphere.push(CharacterTrait::new(
+            "patience".to_string(),
+            2.0,
+            Uuid::new_v4(),
+        ));
+        let (id, inherited) = fluid
+            .precipitate(0, "echo_of_patience".to_string(), 0.4, 0.5)
+            .unwrap();
+        assert!((inherited - 0.6).abs() < 1e-6);
+        assert_eq!(fluid.concepts[&id].layer, 0.0);
+
+        let mut previous = 0.0;
+        for tick in 0..60 {
+            let events = fluid.update(1.0 / 60.0);
+            assert!(
+                !events
+                    .iter()
+                    .any(|e| matches!(e, FluidEvent::Freeze { .. })),
+                "tick {}",
+                tick
+            );
+            let layer = fluid.concepts[&id].layer;
+            assert!(
+                layer > previous,
+                "tick {}: {} after {}",
+                tick,
+                layer,
+                previous
+            );
+            previous = layer;
+        }
+        assert!(!fluid.is_frozen);
+        assert!(previous > 0.1, "{}", previous);
+    }
+
+    fn continent(depth_range: (f32, f32)) -> Continent {
+        Continent {
+            name: "bedrock_of_logic".to_string(),
+            depth_range,
+            formed_from_ores: vec![],
+            total_integration: 3.0,
+            impermeability: 0.9,
+            formation_event: 1,
+        }
+    }
+
+    #[test]
+    fn test_solution_spawned_under_a_continent_rises_clear_of_it() {
+        let mut fluid = ConceptFluid {
+            evaporation_threshold: f32::INFINITY,
+            ..ConceptFluid::default()
+        };
+        fluid.continents.push(continent((0.85, 0.95)));
+        fluid.ore_deposits.push(PreciousOre {
+            name: "buried_proof".to_string(),
+            ore_type: OreType::Code,
+            density: 0.9,
+            depth: 0.9,
+            formed_from: Uuid::new_v4(),
+            vent_cycles: 3,
+            integration_value: 2.0,
+        });
+        let problem = fluid.add_concept("stuck_bug".to_string(), 0.3, 0.5);
+        let concept = fluid.get_concept_mut(problem).unwrap();
+        concept.layer = 0.9;
+        concept.ballast = 0.5;
+
+        fluid.update(1.0 / 60.0);
+        let solution = fluid
+            .concepts
+            .values()
+            .find(|c| c.is_solution)
+            .expect("ballasted problem should react with the ore")
+            .id;
+        for tick in 0..120 {
+            let layer = fluid.concepts[&solution].layer;
+            assert!(layer < 0.85, "tick {}: solution at {}", tick, layer);
+            fluid.update(1.0 / 60.0);
+        }
+    }
+
+    #[test]
+    fn test_concepts_filter_by_origin() {
+        let mut fluid = ConceptFluid::default();
+        fluid.ore_deposits.push(PreciousOre {
+            name: "old_proof".to_string(),
+            ore_type: OreType::Code,
+            density: 0.9,
+            depth: 0.9,
+            formed_from: Uuid::new_v4(),
+            vent_cycles: 3,
+            integration_value: 2.0,
+        });
+        let problem = fluid.add_concept("stuck_bug".to_string(), 0.3, 0.5);
+        let concept = fluid.get_concept_mut(problem).unwrap();
+        concept.layer = 0.9;
+        concept.ballast = 0.5;
+        fluid.update(1.0 / 60.0);
+
+        fluid.atmosphere.push(CharacterTrait::new(
+            "patience".to_string(),
+            2.0,
+            Uuid::new_v4(),
+        ));
+        let (echo, _) = fluid
+            .precipitate(0, "echo_of_patience".to_string(), 0.4, 0.5)
+            .unwrap();
+
+        let names = |origin| -> Vec<String> {
+            fluid
+                .concepts_by_origin(origin)
+                .iter()
+                .map(|c| c.name.clone())
+                .collect()
+        };
+        assert_eq!(
+            names(Some(ConceptOrigin::Synthesized)),
+            vec!["stuck_bug_code_solution"]
+        );
+        assert_eq!(
+            names(Some(ConceptOrigin::Precipitated)),
+            vec!["echo_of_patience"]
+        );
+        assert_eq!(names(Some(ConceptOrigin::Injected)), vec!["stuck_bug"]);
+        assert!(names(Some(ConceptOrigin::FlashHeal)).is_empty());
+        assert_eq!(names(None).len(), 3);
+        assert_eq!(fluid.concepts_by_origin(None).last().unwrap().id, echo);
+    }
+
+    #[test]
+    fn test_concept_sinking_into_floor_continent_stays_in_the_fluid() {
+        let mut fluid = ConceptFluid {
+            evaporation_threshold: f32::INFINITY,
+            ..ConceptFluid::default()
+        };
+        fluid.continents.push(continent((0.85, 1.0)));
+        let stone = fluid.add_concept("stone".to_string(), 0.95, 0.2);
+        let concept = fluid.get_concept_mut(stone).unwrap();
+        concept.layer = 0.6;
+        concept.velocity = 2.0;
+
+        for tick in 0..300 {
+            fluid.update(1.0 / 60.0);
+            let layer = fluid.concepts[&stone].layer;
+            assert!(
+                (0.0..0.85).contains(&layer),
+                "tick {}: stone at {}",
+                tick,
+                layer
+            );
+        }
+
+        // The floor side has no room, so even the nearer exit is upward
+        let (layer, above) = fluid.continents[0].nearest_exit(0.99);
+        assert!(above && layer < 0.85, "{}", layer);
+
+        // Nor is one left inside by a new continent flung out of it
+        let engulfed = fluid.add_concept("engulfed".to_string(), 0.5, 0.5);
+        fluid.get_concept_mut(engulfed).unwrap().layer = 0.9;
+        fluid.get_concept_mut(engulfed).unwrap().velocity = -0.5;
+        fluid.update(1.0 / 60.0);
+        let layer = fluid.concepts[&engulfed].layer;
+        assert!(layer > 0.85 && layer < 0.9, "{}", layer);
+    }
+
+    /// An overwhelming drive moving fast enough to burst through, which then
+    /// keeps pressing against the surface
+    fn eager_thought(fluid: &mut ConceptFluid) -> ConceptId {
+        fluid.extended_buoyancy_enabled = true;
+        fluid.freeze_threshold = 1.0;
+        let id = fluid.add_concept("send_the_email".to_string(), 0.9, 0.5);
+        let concept = fluid.get_concept_mut(id).unwrap();
+        concept.buoyancy = 1.5;
+        concept.layer = 0.02;
+        concept.velocity = -1.0;
+        id
+    }
+
+    #[test]
+    fn test_breakthrough_grace_prevents_premature_freeze() {
+        let run = |grace: f32| {
+            let mut fluid = ConceptFluid {
+                post_breakthrough_grace: grace,
+                ..ConceptFluid::default()
+            };
+            let id = eager_thought(&mut fluid);
+            let mut freezes = Vec::new();
+            for tick in 0..240 {
+                for event in fluid.update(1.0 / 60.0) {
+                    if let FluidEvent::Freeze { concept_id, .. } = event {
+                        freezes.push((tick, concept_id));
+                    }
+                }
+            }
+            assert!(fluid.concepts[&id].has_broken_surface);
+            (id, freezes)
+        };
+
+        let (id, freezes) = run(0.0);
+        assert_eq!(freezes.len(), 1, "acted on, then frozen for it");
+        let (tick, frozen) = freezes[0];
+        assert_eq!(frozen, id);
+        assert!(tick < 90, "froze {} ticks after breaking through", tick);
+
+        let (_, freezes) = run(3.0);
+        assert!(freezes.is_empty(), "{:?}", freezes);
+    }
+
+    #[test]
+    fn test_grace_clears_surface_time_from_before_the_breakthrough() {
+        let mut fluid = ConceptFluid {
+            post_breakthrough_grace: 0.5,
+            ..ConceptFluid::default()
+        };
+        let id = eager_thought(&mut fluid);
+        // A moment from freezing when it acts
+        fluid.get_concept_mut(id).unwrap().time_at_surface = 0.9;
+
+        let mut graced = false;
+        for _ in 0..60 {
+            fluid.update(1.0 / 60.0);
+            let concept = &fluid.concepts[&id];
+            graced |= concept.grace_remaining > 0.0;
+            if graced && concept.grace_remaining == 0.0 {
+                break;
+            }
+        }
+        assert!(graced, "the thought broke through");
+        let concept = &fluid.concepts[&id];
+        assert!(!concept.is_frozen);
+        assert!(
+            concept.time_at_surface < 0.1,
+            "surface time {} carried over",
+            concept.time_at_surface
+        );
+    }
+
+    #[test]
+    fn test_grace_drift_resubmerges_acted_on_thought() {
+        // Time spent inside the freeze zone over the first three seconds
+        let time_at_surface = |drift: f32| {
+            let mut fluid = ConceptFluid {
+                post_breakthrough_drift: drift,
+                ..ConceptFluid::default()
+            };
+            let id = eager_thought(&mut fluid);
+            let mut ticks = 0;
+            for _ in 0..180 {
+                fluid.update(1.0 / 60.0);
+                if fluid.concepts[&id].layer < fluid.freeze_zone {
+                    ticks += 1;
+                }
+            }
+            ticks
+        };
+
+        let (resting, drifting) = (time_at_surface(0.0), time_at_surface(1.0));
+        assert!(drifting < resting, "{} vs {}", drifting, resting);
+    }
+
+    #[test]
+    fn test_action_cost_debits_integration_on_breakthrough() {
+        let act = |action_cost: f32| {
+            let mut fluid = ConceptFluid {
+                action_cost,
+                evaporation_threshold: f32::INFINITY,
+                ..ConceptFluid::default()
+            };
+            let id = fluid.add_concept("ship_it".to_string(), 0.5, 0.5);
+            let concept = fluid.get_concept_mut(id).unwrap();
+            concept.integration = 2.0;
+            concept.layer = 0.005;
+            concept.velocity = -2.0;
+            let events = fluid.update(1.0 / 60.0);
+            assert!(
+                events
+                    .iter()
+                    .any(|e| matches!(e, FluidEvent::SurfaceBreakthrough { .. }))
+            );
+            (
+                fluid.concepts[&id].integration,
+                fluid.total_integration,
+                fluid.salinity,
+            )
+        };
+
+        let (free, free_total, free_salinity) = act(0.0);
+        let (paid, paid_total, paid_salinity) = act(0.25);
+        // A quarter of the 2.0 integration is spent acting...
+        assert!((free - paid - 0.5).abs() < 1e-5, "{} vs {}", free, paid);
+        // ...and kept by the fluid as experience
+        assert!((paid_total - free_total - 0.5).abs() < 1e-5);
+        let rate = ConceptFluid::default().salinity_rate;
+        assert!((paid_salinity - free_salinity - 0.5 * rate).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_recurring_urge_acts_again_after_sinking_back() {
+        let cycles = |rearm_enabled: bool| {
+            let mut fluid = ConceptFluid {
+                rearm_enabled,
+                evaporation_threshold: f32::INFINITY,
+                ..ConceptFluid::default()
+            };
+            let id = fluid.add_concept("check_last_interaction".to_string(), 0.5, 0.5);
+
+            let mut counts = Vec::new();
+            for _ in 0..2 {
+                // The urge surges up through the surface...
+                let concept = fluid.get_concept_mut(id).unwrap();
+                concept.buoyancy = 1.0;
+                concept.layer = 0.02;
+                concept.velocity = -1.0;
+                for _ in 0..60 {
+                    for event in fluid.update(1.0 / 60.0) {
+                        if let FluidEvent::SurfaceBreakthrough {
+                            breakthrough_count, ..
+                        } = event
+                        {
+                            counts.push(breakthrough_count);
+                        }
+                    }
+                }
+                // ...then, satisfied, sinks back to the depths for a while
+                fluid.get_concept_mut(id).unwrap().buoyancy = 0.2;
+                for _ in 0..600 {
+                    fluid.update(1.0 / 60.0);
+                }
+                assert!(fluid.concepts[&id].layer > fluid.rearm_depth);
+            }
+            (counts, fluid.concepts[&id].breakthrough_count)
+        };
+
+        assert_eq!(cycles(true), (vec![1, 2], 2));
+        assert_eq!(cycles(false), (vec![1], 1), "latched after acting once");
+    }
+
+    #[test]
+    fn test_submerge_suppresses_a_surfaced_concept() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("check_last_interaction".to_string(), 0.5, 0.5);
+        let concept = fluid.get_concept_mut(id).unwrap();
+        concept.buoyancy = 1.0;
+        concept.layer = 0.0;
+        concept.has_broken_surface = true;
+        concept.breakthrough_count = 3;
+
+        fluid.submerge(id, 0.5, 0.4).unwrap();
+        let concept = &fluid.concepts[&id];
+        assert!(!concept.has_broken_surface);
+        assert_eq!(
+            (concept.layer, concept.velocity, concept.ballast),
+            (0.5, 0.0, 0.5),
+            "given the ballast that holds it at the target"
+        );
+        assert_eq!(concept.breakthrough_count, 3, "its history is kept");
+
+        // The ballast keeps it down in the water column
+        for _ in 0..120 {
+            fluid.update(1.0 / 60.0);
+        }
+        assert!(fluid.concepts[&id].layer > fluid.activation_zone);
+
+        // It stays where it was put rather than drifting back up
+        assert!((fluid.concepts[&id].layer - 0.5).abs() < 0.05);
+
+        let missing = Uuid::new_v4();
+        assert_eq!(
+            fluid.submerge(missing, 0.5, 0.4),
+            Err(FluidError::ConceptNotFound { id: missing })
+        );
+    }
+
+    #[test]
+    fn test_submerge_holds_the_target_despite_light_ballast() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("refresh_feed".to_string(), 0.5, 0.5);
+        fluid.get_concept_mut(id).unwrap().buoyancy = 1.0;
+
+        fluid.submerge(id, 0.9, 0.05).unwrap();
+        for _ in 0..300 {
+            fluid.update(1.0 / 60.0);
+        }
+        let concept = &fluid.concepts[&id];
+        assert!(
+            (concept.layer - 0.9).abs() < 0.05,
+            "rests at the target, not at {}",
+            concept.layer
+        );
+        assert!(!concept.has_broken_surface);
+
+        // More ballast than the target needs carries it deeper
+        fluid.submerge(id, 0.5, 0.7).unwrap();
+        assert_eq!(fluid.concepts[&id].ballast, 0.7);
+    }
+
+    #[test]
+    fn test_depth_neighbors_nearest_first_without_self() {
+        let mut fluid = ConceptFluid::default();
+        let mut at = |name: &str, layer: f32| {
+            let id = fluid.add_concept(name.to_string(), 0.5, 0.5);
+            fluid.get_concept_mut(id).unwrap().layer = layer;
+            id
+        };
+        let me = at("me", 0.5);
+        let far = at("far", 0.9);
+        let close_above = at("close_above", 0.45);
+        let closer_below = at("closer_below", 0.52);
+        let tied_above = at("tied_above", 0.3);
+        let tied_below = at("tied_below", 0.7);
+        let gone = at("gone", 0.5);
+        fluid.get_concept_mut(gone).unwrap().has_evaporated = true;
+
+        let ids = |k| -> Vec<ConceptId> {
+            fluid
+                .depth_neighbors(me, k)
+                .unwrap()
+                .iter()
+                .map(|(c, _)| c.id)
+                .collect()
+        };
+        assert_eq!(
+            ids(10),
+            vec![closer_below, close_above, tied_above, tied_below, far]
+        );
+        assert_eq!(ids(2), vec![closer_below, close_above]);
+
+        let distances: Vec<f32> = fluid
+            .depth_neighbors(me, 10)
+            .unwrap()
+            .iter()
+            .map(|(_, d)| *d)
+            .collect();
+        assert!(
+            distances.windows(2).all(|w| w[0] <= w[1]),
+            "{:?}",
+            distances
+        );
+        assert!(fluid.depth_neighbors(Uuid::new_v4(), 5).is_none());
+    }
+
+    #[test]
+    fn test_vent_encounters_count_passes_not_ticks() {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_core_truth("warmth".to_string(), 1.0, 0.9, 0.3);
+        let parked = fluid.add_concept("dread".to_string(), 0.9, 0.5);
+
+        // Resting in the plume is a single pass, however long it lasts
+        for _ in 0..120 {
+            fluid.get_concept_mut(parked).unwrap().layer = 0.9;
+            fluid.update(1.0 / 60.0);
+        }
+        assert_eq!(fluid.vent_encounter_count[&parked], 1);
+        assert!(fluid.ore_deposits.is_empty());
+
+        // Leaving past the radius and coming back counts again
+        for _ in 0..5 {
+            for layer in [0.4, 0.9] {
+                fluid.get_concept_mut(parked).unwrap().layer = layer;
+                fluid.update(1.0 / 60.0);
+            }
+        }
+        assert_eq!(fluid.vent_encounter_count[&parked], 6);
+        assert_eq!(fluid.ore_deposits.len(), 2);
+        assert_eq!(fluid.ore_deposits[1].ore_type, OreType::Insight);
+
+        // Drifting just inside the margin doesn't start a new pass
+        fluid.get_concept_mut(parked).unwrap().layer = 0.9 - 0.32;
+        fluid.update(1.0 / 60.0);
+        fluid.get_concept_mut(parked).unwrap().layer = 0.9;
+        fluid.update(1.0 / 60.0);
+        assert_eq!(fluid.vent_encounter_count[&parked], 6);
+    }
+
+    #[test]
+    fn test_removed_vent_leaves_no_plume_behind() {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_core_truth("warmth".to_string(), 1.0, 0.9, 0.3);
+        let parked = fluid.add_concept("dread".to_string(), 0.9, 0.5);
+        let park = |fluid: &mut ConceptFluid| {
+            fluid.get_concept_mut(parked).unwrap().layer = 0.9;
+            fluid.update(1.0 / 60.0);
+        };
+        park(&mut fluid);
+        assert_eq!(fluid.vent_encounter_count[&parked], 1);
+
+        fluid.core_truths.pop();
+        park(&mut fluid);
+        assert!(fluid.in_vent_plume.is_empty());
+
+        // A new vent in the old one's place is a new pass
+        fluid.add_core_truth("shelter".to_string(), 1.0, 0.9, 0.3);
+        park(&mut fluid);
+        assert_eq!(fluid.vent_encounter_count[&parked], 2);
+    }
+
+    #[test]
+    fn test_dark_concept_incubates_before_mineralizing() {
+        let mut fluid = ConceptFluid {
+            mineralization_incubation_ticks: 60,
+            ..ConceptFluid::default()
+        };
+        fluid.add_core_truth("warmth".to_string(), 1.0, 0.9, 0.3);
+        let fresh = fluid.inject_concept("grief".to_string(), 0.9, 0.5, Some(0.9), None, None);
+        let pass = |fluid: &mut ConceptFluid| {
+            for layer in [0.4, 0.9] {
+                fluid.get_concept_mut(fresh).unwrap().layer = layer;
+                fluid.update(1.0 / 60.0);
+            }
+        };
+
+        // Enough passes for ore, but the thought is still too new
+        for _ in 0..3 {
+            pass(&mut fluid);
+        }
+        assert_eq!(fluid.vent_encounter_count[&fresh], 3);
+        assert!(fluid.ore_deposits.is_empty());
+
+        // Once it has sat with the pain long enough, passes mineralize again
+        while fluid.tick_count < 60 {
+            fluid.get_concept_mut(fresh).unwrap().layer = 0.9;
+            fluid.update(1.0 / 60.0);
+        }
+        for _ in 0..3 {
+            pass(&mut fluid);
+        }
+        assert_eq!(fluid.ore_deposits.len(), 1);
+        assert_eq!(fluid.ore_deposits[0].vent_cycles, 6);
+    }
+
+    #[test]
+    fn test_non_finite_depths_are_ordered_and_repaired() {
+        let mut fluid = ConceptFluid::default();
+        let calm = fluid.add_concept("calm".to_string(), 0.2, 0.5);
+        let lost = fluid.add_concept("lost".to_string(), 0.4, 0.5);
+        let runaway = fluid.add_concept("runaway".to_string(), 0.6, 0.5);
+        fluid.get_concept_mut(calm).unwrap().layer = 0.2;
+        fluid.get_concept_mut(lost).unwrap().layer = f32::NAN;
+        fluid.get_concept_mut(runaway).unwrap().layer = f32::NEG_INFINITY;
+        fluid.get_concept_mut(runaway).unwrap().velocity = f32::NAN;
+
+        // Queries still answer for the healthy concepts
+        let surface: Vec<_> = fluid
+            .get_surface_concepts(0.5)
+            .iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(surface, [runaway, calm]);
+        let in_range: Vec<_> = fluid
+            .get_concepts_in_range(0.0, 1.0)
+            .iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(in_range, [calm]);
+        assert_eq!(
+            StandingWave::new(2.0, 1.0).nearest_node_index(f32::NAN),
+            Some(0)
+        );
+
+        let mut anomalies: Vec<_> = fluid
+            .update(1.0 / 60.0)
+            .into_iter()
+            .filter_map(|event| match event {
+                FluidEvent::NumericAnomaly { concept, field, .. } => Some((concept, field)),
+                _ => None,
+            })
+            .collect();
+        anomalies.sort();
+        assert_eq!(
+            anomalies,
+            [
+                ("lost".to_string(), "layer".to_string()),
+                ("runaway".to_string(), "layer".to_string()),
+                ("runaway".to_string(), "velocity".to_string()),
+            ]
+        );
+        assert!(fluid.concepts.values().all(|c| c.layer.is_finite()
+            && (0.0..=1.0).contains(&c.layer)
+            && c.velocity.is_finite()));
+    }
+
+    #[test]
+    fn test_values_blown_up_mid_tick_are_repaired_by_its_end() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("unstable".to_string(), 0.5, 0.5);
+        fluid.get_concept_mut(id).unwrap().velocity = 1.0;
+        // Finite going in; the breath damping it after the forces is not
+        fluid.damping_factor = f32::INFINITY;
+
+        let events = fluid.update(1.0 / 60.0);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            FluidEvent::NumericAnomaly { concept_id, .. } if *concept_id == id
+        )));
+        let concept = &fluid.concepts[&id];
+        assert!(concept.layer.is_finite() && concept.velocity.is_finite());
+    }
+
+    #[test]
+    fn test_concept_dimensions_follow_volume_or_area_within_bounds() {
+        let mut fluid = ConceptFluid::default();
+        let dims = |fluid: &ConceptFluid, density, size| {
+            let d = fluid.concept_dimensions(density, size);
+            (d.area, d.volume)
+        };
+
+        assert_eq!(dims(&fluid, 0.4, ConceptSize::Volume(0.6)), (1.5, 0.6));
+        assert_eq!(dims(&fluid, 0.5, ConceptSize::Area(1.2)), (1.2, 0.6));
+        // Near-weightless concepts spread to twice their volume
+        assert_eq!(dims(&fluid, 0.0, ConceptSize::Volume(0.3)), (0.6, 0.3));
+        // Both styles are held to the same bounds
+        assert_eq!(dims(&fluid, 0.2, ConceptSize::Volume(2.0)), (2.0, 0.4));
+        assert_eq!(dims(&fluid, 0.5, ConceptSize::Area(0.01)), (0.1, 0.05));
+
+        fluid.max_concept_area = 4.0;
+        assert_eq!(dims(&fluid, 0.5, ConceptSize::Volume(2.0)).0, 4.0);
+        assert_eq!(dims(&fluid, 0.5, ConceptSize::Area(9.0)).0, 4.0);
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::VecDeque;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::fluid::ConceptFluid;
+
+/// What each concept contributes to its depth bin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HeatmapWeight {
+    /// One per concept: where the thoughts are
+    #[default]
+    Count,
+    /// Integration held: where understanding sits
+    Integration,
+    /// Volume (`density * area`): where the mass is
+    Volume,
+}
+
+impl HeatmapWeight {
+    pub const ALL: [HeatmapWeight; 3] = [
+        HeatmapWeight::Count,
+        HeatmapWeight::Integration,
+        HeatmapWeight::Volume,
+    ];
+}
+
+/// One recorded histogram per weight, taken at `tick`.
+#[derive(Debug, Clone)]
+struct HeatmapRow {
+    tick: u64,
+    histograms: [Vec<f32>; 3],
+}
+
+/// Opt-in depth × time recorder: every `interval` ticks it stores the layer
+/// histogram of the fluid, keeping the newest `max_rows`.
+/// Kept outside the fluid, so its rows are neither snapshotted nor copied
+/// with it.
+#[derive(Debug, Clone)]
+pub struct Heatmap {
+    pub recording: bool,
+    /// Record every N ticks
+    pub interval: u64,
+    /// Equal depth bins from surface to floor
+    pub bins: usize,
+    /// Oldest rows are evicted beyond this many
+    pub max_rows: usize,
+    rows: VecDeque<HeatmapRow>,
+}
+
+impl Default for Heatmap {
+    /// Off; once started, an hour of rows at one per second at 60Hz.
+    fn default() -> Self {
+        Self {
+            recording: false,
+            interval: 60,
+            bins: 20,
+            max_rows: 3600,
+            rows: VecDeque::new(),
+        }
+    }
+}
+
+/// The recorded matrix for one weight: `values[row][bin]`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct HeatmapMatrix {
+    pub weight: HeatmapWeight,
+    /// Tick of each row, oldest first
+    pub ticks: Vec<u64>,
+    /// Top (shallowest) edge of each bin
+    pub depths: Vec<f32>,
+    pub values: Vec<Vec<f32>>,
+}
+
+impl HeatmapMatrix {
+    /// The matrix as CSV: a `tick` column, then one column per bin headed by
+    /// its top depth. Each line is formatted only when it is reached.
+    pub fn csv_lines(self) -> impl Iterator<Item = String> {
+        let header = std::iter::once("tick".to_string())
+            .chain(self.depths.iter().map(|d| format!("{:.3}", d)))
+            .collect::<Vec<_>>()
+            .join(",");
+        std::iter::once(header + "\n").chain(self.ticks.into_iter().zip(self.values).map(
+            |(tick, row)| {
+                let cells: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+                format!("{},{}\n", tick, cells.join(","))
+            },
+        ))
+    }
+}
+
+impl Heatmap {
+    /// Start recording afresh, dropping any earlier rows.
+    pub fn start(&mut self, interval: u64, bins: usize) {
+        self.recording = true;
+        self.interval = interval.max(1);
+        self.bins = bins.max(1);
+        self.rows.clear();
+    }
+
+    /// Stop recording, keeping the rows for reading. Returns how many.
+    pub fn stop(&mut self) -> usize {
+        self.recording = false;
+        self.rows.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Record a row of `fluid` if recording and the tick falls on the
+    /// interval (called once per tick).
+    pub fn record(&mut self, fluid: &ConceptFluid) {
+        if !self.recording || self.max_rows == 0 || !fluid.tick_count.is_multiple_of(self.interval)
+        {
+            return;
+        }
+        if self.rows.len() >= self.max_rows {
+            self.rows.pop_front();
+        }
+        self.rows.push_back(HeatmapRow {
+            tick: fluid.tick_count,
+            histograms: HeatmapWeight::ALL.map(|weight| fluid.layer_histogram(self.bins, weight)),
+        });
+    }
+
+    /// The recorded rows weighted by `weight`.
+    pub fn matrix(&self, weight: HeatmapWeight) -> HeatmapMatrix {
+        let column = HeatmapWeight::ALL
+            .iter()
+            .position(|w| *w == weight)
+            .unwrap();
+        HeatmapMatrix {
+            weight,
+            ticks: self.rows.iter().map(|r| r.tick).collect(),
+            depths: (0..self.bins)
+                .map(|i| i as f32 / self.bins as f32)
+                .collect(),
+            values: self
+                .rows
+                .iter()
+                .map(|r| r.histograms[column].clone())
+                .collect(),
+        }
+    }
+}
+
+impl ConceptFluid {
+    /// Concepts still in the fluid summed over `bins` equal depth bins from
+    /// surface to floor, each counting as `weight`.
+    pub fn layer_histogram(&self, bins: usize, weight: HeatmapWeight) -> Vec<f32> {
+        let bins = bins.max(1);
+        let mut histogram = vec![0.0; bins];
+        for concept in self.concepts.values().filter(|c| !c.has_evaporated) {
+            // The floor belongs to the deepest bin
+            let bin = ((concept.layer.clamp(0.0, 1.0) * bins as f32) as usize).min(bins - 1);
+            histogram[bin] += match weight {
+                HeatmapWeight::Count => 1.0,
+                HeatmapWeight::Integration => concept.integration,
+                HeatmapWeight::Volume => concept.volume(),
+            };
+        }
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parked_concept_heats_its_depth_row() {
+        let mut fluid = ConceptFluid::default();
+        let parked = fluid.add_concept("old_grief".to_string(), 0.5, 0.5);
+        let concept = fluid.get_concept_mut(parked).unwrap();
+        concept.layer = 0.8;
+        concept.buoyancy = 0.2;
+        concept.integration = 2.0;
+
+        // Nothing is kept until recording starts
+        let mut heatmap = Heatmap::default();
+        fluid.update(1.0 / 60.0);
+        heatmap.record(&fluid);
+        assert!(heatmap.is_empty());
+
+        heatmap.start(10, 10);
+        for _ in 0..100 {
+            fluid.update(1.0 / 60.0);
+            heatmap.record(&fluid);
+        }
+        assert_eq!(heatmap.stop(), 10);
+
+        let matrix = heatmap.matrix(HeatmapWeight::Integration);
+        assert_eq!(matrix.ticks.len(), 10);
+        assert_eq!(matrix.depths.len(), 10);
+        assert!(matrix.values.iter().all(|row| row.len() == 10));
+        for row in &matrix.values {
+            let hottest = (0..10).max_by(|&a, &b| row[a].total_cmp(&row[b])).unwrap();
+            assert_eq!(hottest, 8, "{:?}", row);
+            assert!(row[8] >= 2.0);
+        }
+
+        let csv: Vec<String> = matrix.csv_lines().collect();
+        assert_eq!(csv.len(), 11);
+        assert!(csv[0].starts_with("tick,0.000,0.100,"));
+        assert_eq!(csv[1].trim_end().split(',').count(), 11);
+
+        // Stopped: further ticks add nothing
+        fluid.update(1.0 / 60.0);
+        heatmap.record(&fluid);
+        assert_eq!(heatmap.len(), 10);
+    }
+}
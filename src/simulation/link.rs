@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::concept::ConceptId;
+use super::error::FluidError;
+use super::fluid::ConceptFluid;
+use crate::state::events::FluidEvent;
+
+/// Largest force a single link exerts, so near-collisions can't fling a
+/// concept out of the fluid
+const LINK_FORCE_LIMIT: f32 = 5.0;
+/// Separation below which a link treats its concepts as this far apart
+const LINK_MIN_DISTANCE: f32 = 0.001;
+
+/// A thematic connection holding two concepts near each other.
+///
+/// The link behaves like a Lennard-Jones pair: closer than `rest_distance`
+/// the concepts repel, farther they attract, most strongly just past rest
+/// and fading with distance.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ConceptLink {
+    pub id_a: ConceptId,
+    pub id_b: ConceptId,
+    /// Depth of the potential well (ε)
+    pub strength: f32,
+    /// Separation in depth at which the pair is at rest
+    pub rest_distance: f32,
+}
+
+impl ConceptLink {
+    /// Force pushing the pair apart at `distance` (negative pulls them
+    /// together): `12ε/r · ((r₀/r)¹² - (r₀/r)⁶)`, limited to
+    /// `LINK_FORCE_LIMIT`.
+    pub fn force_at(&self, distance: f32) -> f32 {
+        let r = distance.max(LINK_MIN_DISTANCE);
+        let ratio6 = (self.rest_distance / r).powi(6);
+        let force = 12.0 * self.strength / r * (ratio6 * ratio6 - ratio6);
+        if force.is_finite() {
+            force.clamp(-LINK_FORCE_LIMIT, LINK_FORCE_LIMIT)
+        } else {
+            LINK_FORCE_LIMIT
+        }
+    }
+}
+
+impl ConceptFluid {
+    /// Link two concepts; returns the link's index.
+    pub fn link_concepts(
+        &mut self,
+        id_a: ConceptId,
+        id_b: ConceptId,
+        strength: f32,
+        rest_distance: f32,
+    ) -> Result<usize, FluidError> {
+        for id in [id_a, id_b] {
+            if !self.concepts.contains_key(&id) {
+                return Err(FluidError::ConceptNotFound { id });
+            }
+        }
+        self.links.push(ConceptLink {
+            id_a,
+            id_b,
+            strength,
+            rest_distance,
+        });
+        Ok(self.links.len() - 1)
+    }
+
+    /// Remove a link, returning it. Later links move down one index.
+    pub fn unlink(&mut self, index: usize) -> Result<ConceptLink, FluidError> {
+        if index >= self.links.len() {
+            return Err(FluidError::LinkNotFound { index });
+        }
+        Ok(self.links.remove(index))
+    }
+
+    /// Drop links to concepts that have left the fluid, announcing the
+    /// indices they held so clients know the later links moved down.
+    pub(crate) fn drop_dangling_links(&mut self) -> Option<FluidEvent> {
+        let indices: Vec<usize> = self
+            .links
+            .iter()
+            .enumerate()
+            .filter(|(_, link)| {
+                !self.concepts.contains_key(&link.id_a) || !self.concepts.contains_key(&link.id_b)
+            })
+            .map(|(index, _)| index)
+            .collect();
+        if indices.is_empty() {
+            return None;
+        }
+        let concepts = &self.concepts;
+        self.links
+            .retain(|link| concepts.contains_key(&link.id_a) && concepts.contains_key(&link.id_b));
+        Some(FluidEvent::LinksDropped {
+            count: indices.len(),
+            indices,
+        })
+    }
+
+    /// Net link force on each linked concept this tick (positive pushes
+    /// downward).
+    pub(crate) fn link_forces(&self) -> HashMap<ConceptId, f32> {
+        let mut forces = HashMap::new();
+        for link in &self.links {
+            let (a, b) = (&self.concepts[&link.id_a], &self.concepts[&link.id_b]);
+            let force = link.force_at((a.layer - b.layer).abs());
+            // Repulsion pushes the deeper one down and the shallower one up
+            let direction = if a.layer >= b.layer { 1.0 } else { -1.0 };
+            *forces.entry(link.id_a).or_insert(0.0) += direction * force;
+            *forces.entry(link.id_b).or_insert(0.0) -= direction * force;
+        }
+        forces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_repels_inside_rest_distance_and_attracts_beyond() {
+        let link = ConceptLink {
+            id_a: uuid::Uuid::new_v4(),
+            id_b: uuid::Uuid::new_v4(),
+            strength: 0.1,
+            rest_distance: 0.1,
+        };
+        assert!(link.force_at(0.08) > 0.0);
+        assert!(link.force_at(0.1).abs() < 1e-5);
+        assert!(link.force_at(0.15) < 0.0);
+        assert!(link.force_at(0.15).abs() > link.force_at(0.5).abs());
+        assert_eq!(link.force_at(0.0), LINK_FORCE_LIMIT);
+    }
+
+    #[test]
+    fn test_linked_concepts_settle_toward_rest_distance() {
+        let separation = |rest_distance: Option<f32>| {
+            // Each rests where it starts, 0.2 apart, unless linked
+            let mut fluid = ConceptFluid::default();
+            let mut add = |name: &str, layer: f32| {
+                fluid.inject_concept(
+                    name.to_string(),
+                    0.5,
+                    0.5,
+                    Some(layer),
+                    None,
+                    Some(1.0 - layer),
+                )
+            };
+            let a = add("longing", 0.4);
+            let b = add("memory", 0.6);
+            if let Some(rest_distance) = rest_distance {
+                fluid.link_concepts(a, b, 0.5, rest_distance).unwrap();
+            }
+            for _ in 0..600 {
+                fluid.update(1.0 / 60.0);
+            }
+            let layer = |id| fluid.get_concept(id).unwrap().layer;
+            (layer(a) - layer(b)).abs()
+        };
+
+        let unlinked = separation(None);
+        assert!((unlinked - 0.2).abs() < 0.02, "unlinked {}", unlinked);
+        let pulled = separation(Some(0.1));
+        assert!(pulled < unlinked - 0.03, "pulled to {}", pulled);
+        let pushed = separation(Some(0.3));
+        assert!(pushed > unlinked + 0.03, "pushed to {}", pushed);
+    }
+
+    #[test]
+    fn test_link_needs_both_concepts_and_goes_with_them() {
+        let mut fluid = ConceptFluid::default();
+        let a = fluid.add_concept("a".to_string(), 0.5, 0.5);
+        let missing = uuid::Uuid::new_v4();
+        assert_eq!(
+            fluid.link_concepts(a, missing, 0.1, 0.1),
+            Err(FluidError::ConceptNotFound { id: missing })
+        );
+
+        let b = fluid.add_concept("b".to_string(), 0.5, 0.5);
+        assert_eq!(fluid.link_concepts(a, b, 0.1, 0.1), Ok(0));
+        let c = fluid.add_concept("c".to_string(), 0.5, 0.5);
+        assert_eq!(fluid.link_concepts(a, c, 0.1, 0.1), Ok(1));
+        fluid.remove_concept(b, &mut Vec::new()).unwrap();
+        let events = fluid.update(1.0 / 60.0);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            FluidEvent::LinksDropped { count: 1, indices } if indices == &[0]
+        )));
+        assert_eq!(fluid.links.len(), 1);
+        assert_eq!(fluid.links[0].id_b, c);
+        assert_eq!(fluid.unlink(1), Err(FluidError::LinkNotFound { index: 1 }));
+        assert_eq!(fluid.unlink(0).map(|link| link.id_b), Ok(c));
+    }
+}
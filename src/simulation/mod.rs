@@ -1,20 +1,60 @@
+pub mod attention;
+pub mod collision;
 pub mod concept;
 pub mod consensus_reactor;
 pub mod continent;
 pub mod core_truth;
+pub mod energy;
+pub mod error;
 pub mod fluid;
+pub mod heatmap;
+pub mod link;
+pub mod names;
+pub mod noise;
 pub mod ore;
+pub mod persona;
+pub mod pressure;
+pub mod prune;
+pub mod render;
+pub mod rules;
+pub mod salinity;
+pub mod snapshot;
 pub mod standing_wave;
+pub mod stats;
+pub mod timeseries;
+pub mod trace;
 pub mod traits;
 
-pub use concept::{Concept, ConceptId};
+pub use attention::{MAX_SPOTLIGHTS, Spotlight};
+pub use concept::{Concept, ConceptDimensions, ConceptId, ConceptOrigin, ConceptRole, ConceptSize};
 pub use consensus_reactor::{
-    ConsensusExperiment, ConsensusOre, ConsensusOreType, ConsensusReactor, ContradictoryVent,
-    EmergentProperty, FrozenProbe, PhaseStructure, VentDominance, VoronoiCell,
+    ConsensusExperiment, ConsensusOre, ConsensusOreType, ConsensusReactor, ConsensusStats,
+    ConsensusSummary, ContradictoryVent, EmergentProperty, FrozenProbe, PhaseStructure,
+    QualityThresholds, QualityThresholdsPatch, VentDominance, VoronoiCell,
 };
-pub use continent::Continent;
-pub use core_truth::CoreTruth;
-pub use fluid::ConceptFluid;
-pub use ore::{OreType, PreciousOre};
-pub use standing_wave::{DivisionExperiment, DivisionProblem, DivisionResult, StandingWave};
+pub use continent::{Continent, default_continent_names};
+pub use core_truth::{CoreTruth, FalloffProfile};
+pub use energy::{EnergyFlows, EnergyLedger, EnergyTick};
+pub use error::FluidError;
+pub use fluid::{ConceptFluid, DepthForces, FlashHealOutcome, IntegratorKind, ReynoldsMode};
+pub use heatmap::{Heatmap, HeatmapMatrix, HeatmapWeight};
+pub use link::ConceptLink;
+pub use names::NameFrequency;
+pub use ore::{
+    OrePressureWeights, OreStats, OreType, OreTypeStats, PreciousOre, PressureChangeReason,
+    SolutionShape,
+};
+pub use persona::Persona;
+pub use pressure::PressureSample;
+pub use prune::ConceptFilter;
+pub use rules::{MAX_RULE_DEPTH, MAX_RULES, Rule, RuleSet};
+pub use salinity::SalinityBand;
+pub use snapshot::SCHEMA_VERSION;
+pub use standing_wave::{
+    DivisionExperiment, DivisionProblem, DivisionResult, SettledBy, SettlementThresholds,
+    StandingWave,
+};
+pub use stats::FluidStats;
+pub use timeseries::{Metric, TimeSeries, TimeSeriesQuery};
+pub use trace::{ExperimentTrace, TraceRow, TraceStore};
 pub use traits::CharacterTrait;
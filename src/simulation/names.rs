@@ -0,0 +1,84 @@
+use std::collections::{HashMap, HashSet};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::fluid::ConceptFluid;
+
+/// How often a concept name has come up.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct NameFrequency {
+    pub name: String,
+    /// Concepts in the fluid under this name now
+    pub current: usize,
+    /// Concepts ever created under this name, including ones since removed,
+    /// evaporated or renamed
+    pub created: u64,
+}
+
+impl ConceptFluid {
+    /// Every name in the fluid or its history, most often created first
+    /// (ties by current count, then alphabetically). Experiment bubbles and
+    /// probes are left out.
+    pub fn name_frequencies(&self) -> Vec<NameFrequency> {
+        let mut current: HashMap<&str, usize> = HashMap::new();
+        for concept in self.concepts.values().filter(|c| !c.is_system()) {
+            *current.entry(concept.name.as_str()).or_insert(0) += 1;
+        }
+
+        let mut frequencies: Vec<_> = self
+            .name_history
+            .keys()
+            .map(String::as_str)
+            .chain(current.keys().copied())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|name| NameFrequency {
+                name: name.to_string(),
+                current: current.get(name).copied().unwrap_or(0),
+                created: self.name_history.get(name).copied().unwrap_or(0),
+            })
+            .collect();
+        frequencies.sort_by(|a, b| {
+            b.created
+                .cmp(&a.created)
+                .then(b.current.cmp(&a.current))
+                .then(a.name.cmp(&b.name))
+        });
+        frequencies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recurring_names_are_counted_across_removal() {
+        let mut fluid = ConceptFluid::default();
+        let mut worries = Vec::new();
+        for _ in 0..3 {
+            worries.push(fluid.inject_concept("worry".to_string(), 0.5, 0.5, None, None, None));
+        }
+        fluid.add_concept("hope".to_string(), 0.3, 0.5);
+        fluid.remove_concept(worries[0], &mut Vec::new()).unwrap();
+        fluid.start_division_experiment(6.0, 3.0);
+
+        let frequencies = fluid.name_frequencies();
+        assert_eq!(
+            frequencies,
+            vec![
+                NameFrequency {
+                    name: "worry".to_string(),
+                    current: 2,
+                    created: 3,
+                },
+                NameFrequency {
+                    name: "hope".to_string(),
+                    current: 1,
+                    created: 1,
+                },
+            ]
+        );
+    }
+}
@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::concept::ConceptId;
@@ -48,8 +51,336 @@ pub struct PreciousOre {
 }
 
 impl PreciousOre {
-    /// Calculate the weight contribution to tectonic pressure.
+    /// Calculate the unweighted contribution to tectonic pressure.
+    /// See `OrePressureWeights` for the per-type multiplier applied at deposit.
     pub fn pressure_weight(&self) -> f32 {
         self.density * self.integration_value
     }
 }
+
+/// Why the ocean floor pressure moved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PressureChangeReason {
+    /// Ore laid on the floor
+    Deposit,
+    /// Ores absorbed into a new continent
+    Consumption,
+    /// The tectonic threshold was moved
+    ThresholdSet,
+}
+
+/// Per-type multipliers on an ore's contribution to tectonic pressure.
+/// All 1.0 by default; raising one makes that ore form continents faster.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct OrePressureWeights {
+    #[serde(default = "default_weight")]
+    pub art: f32,
+    #[serde(default = "default_weight")]
+    pub code: f32,
+    #[serde(default = "default_weight")]
+    pub insight: f32,
+    #[serde(default = "default_weight")]
+    pub writing: f32,
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+impl Default for OrePressureWeights {
+    fn default() -> Self {
+        Self {
+            art: 1.0,
+            code: 1.0,
+            insight: 1.0,
+            writing: 1.0,
+        }
+    }
+}
+
+impl OrePressureWeights {
+    pub fn multiplier(&self, ore_type: OreType) -> f32 {
+        match ore_type {
+            OreType::Art => self.art,
+            OreType::Code => self.code,
+            OreType::Insight => self.insight,
+            OreType::Writing => self.writing,
+        }
+    }
+
+    /// Read `ORE_PRESSURE_WEIGHT_{ART,CODE,INSIGHT,WRITING}`, falling back to 1.0.
+    pub fn from_env() -> Self {
+        Self {
+            art: weight_from_env("ORE_PRESSURE_WEIGHT_ART"),
+            code: weight_from_env("ORE_PRESSURE_WEIGHT_CODE"),
+            insight: weight_from_env("ORE_PRESSURE_WEIGHT_INSIGHT"),
+            writing: weight_from_env("ORE_PRESSURE_WEIGHT_WRITING"),
+        }
+    }
+}
+
+fn weight_from_env(var: &str) -> f32 {
+    match std::env::var(var) {
+        Ok(value) => match value.parse::<f32>() {
+            Ok(weight) if weight.is_finite() && weight >= 0.0 => weight,
+            _ => {
+                tracing::warn!("Ignoring invalid {}={:?}, using 1.0", var, value);
+                default_weight()
+            }
+        },
+        Err(_) => default_weight(),
+    }
+}
+
+/// How benthic catalysis shapes the solution an ore type yields. The
+/// defaults are the light, quickly rising solution every ore used to give.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SolutionShape {
+    /// Density before the ore's weight is added
+    pub density: f32,
+    /// Extra density per unit of the ore's integration value, so hard-won
+    /// ores make weightier solutions (capped at 1.0 overall)
+    pub density_per_integration: f32,
+    /// Starting velocity (negative rises)
+    pub velocity: f32,
+    /// Added to the problem concept's area
+    pub area_growth: f32,
+    /// Share of the ore's integration value the solution carries
+    pub integration_share: f32,
+}
+
+impl Default for SolutionShape {
+    fn default() -> Self {
+        Self {
+            density: 0.2,
+            density_per_integration: 0.0,
+            velocity: -0.5,
+            area_growth: 0.2,
+            integration_share: 1.0,
+        }
+    }
+}
+
+impl SolutionShape {
+    pub fn density_for(&self, ore: &PreciousOre) -> f32 {
+        (self.density + self.density_per_integration * ore.integration_value).clamp(0.0, 1.0)
+    }
+
+    pub fn area_for(&self, problem_area: f32) -> f32 {
+        problem_area + self.area_growth
+    }
+
+    pub fn integration_for(&self, ore: &PreciousOre) -> f32 {
+        ore.integration_value * self.integration_share
+    }
+}
+
+/// Lifetime counters for one ore type.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct OreTypeStats {
+    /// Ores of this type ever deposited
+    pub deposited: u32,
+    /// Sum of `integration_value` over deposited ores
+    pub total_integration_value: f32,
+    /// Weighted pressure these ores added to the ocean floor
+    pub total_pressure: f32,
+    /// Ores consumed into continents by tectonic shifts
+    pub consumed_by_tectonic: u32,
+    /// Benthic catalysis reactions this ore type took part in
+    pub catalyses: u32,
+    /// Tick of the most recent deposit
+    pub last_deposit_tick: Option<u64>,
+}
+
+/// Lifetime ore accounting, keyed by `OreType::as_str()`.
+/// Unlike `ore_deposits`, these counters are never cleared by tectonic shifts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OreStats {
+    pub by_type: BTreeMap<String, OreTypeStats>,
+}
+
+impl OreStats {
+    fn entry(&mut self, ore_type: OreType) -> &mut OreTypeStats {
+        self.by_type
+            .entry(ore_type.as_str().to_string())
+            .or_default()
+    }
+
+    pub fn record_deposit(&mut self, ore: &PreciousOre, pressure: f32, tick: u64) {
+        let stats = self.entry(ore.ore_type);
+        stats.deposited += 1;
+        stats.total_integration_value += ore.integration_value;
+        stats.total_pressure += pressure;
+        stats.last_deposit_tick = Some(tick);
+    }
+
+    pub fn record_tectonic_consumption(&mut self, ore: &PreciousOre) {
+        self.entry(ore.ore_type).consumed_by_tectonic += 1;
+    }
+
+    pub fn record_catalysis(&mut self, ore_type: OreType) {
+        self.entry(ore_type).catalyses += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::ConceptFluid;
+    use crate::state::events::FluidEvent;
+
+    #[test]
+    fn test_weighted_insight_reaches_threshold_sooner() {
+        let deposits_to_threshold = |ore_type: OreType| {
+            let mut fluid = ConceptFluid::default();
+            fluid.ore_pressure_weights.insight = 2.0;
+            let mut deposits = 0;
+            while fluid.ocean_floor_pressure < fluid.pressure_threshold {
+                fluid.deposit_ore(PreciousOre {
+                    name: format!("ore_{}", deposits),
+                    ore_type,
+                    density: 0.9,
+                    depth: 0.9,
+                    formed_from: uuid::Uuid::new_v4(),
+                    vent_cycles: 3,
+                    integration_value: 1.5,
+                });
+                deposits += 1;
+            }
+            deposits
+        };
+
+        let insight = deposits_to_threshold(OreType::Insight);
+        let code = deposits_to_threshold(OreType::Code);
+        assert!(insight < code, "insight {} vs code {}", insight, code);
+        // 15.0 / (0.9 * 1.5) → 12 unweighted deposits, 6 at 2x
+        assert_eq!(code, 12);
+        assert_eq!(insight, 6);
+
+        // Default weights leave every type at the unweighted pressure
+        let defaults = OrePressureWeights::default();
+        assert_eq!(defaults.multiplier(OreType::Art), 1.0);
+        assert_eq!(defaults.multiplier(OreType::Writing), 1.0);
+    }
+
+    #[test]
+    fn test_solution_shape_follows_the_catalyzing_ore() {
+        let catalyze = |ore_type: OreType| {
+            let mut fluid = ConceptFluid::default();
+            fluid.solution_shapes.insert(
+                OreType::Insight,
+                SolutionShape {
+                    density_per_integration: 0.1,
+                    velocity: -0.3,
+                    integration_share: 1.5,
+                    ..SolutionShape::default()
+                },
+            );
+            let problem = fluid.add_concept("grief".to_string(), 0.9, 0.7);
+            let concept = fluid.get_concept_mut(problem).unwrap();
+            concept.layer = 0.9;
+            concept.ballast = 0.5;
+            concept.integration = 1.0;
+            fluid.ore_deposits.push(PreciousOre {
+                name: "ore".to_string(),
+                ore_type,
+                density: 0.9,
+                depth: 0.9,
+                formed_from: problem,
+                vent_cycles: 3,
+                integration_value: 2.0,
+            });
+            fluid.update(1.0 / 60.0);
+            let solution = fluid.concepts.values().find(|c| c.is_solution).unwrap();
+            (solution.density, solution.area, solution.integration)
+        };
+
+        // Art keeps the default shape
+        let (density, area, integration) = catalyze(OreType::Art);
+        assert_eq!(density, 0.2);
+        assert!((area - 0.9).abs() < 1e-6);
+        assert!(integration >= 2.0);
+
+        let (insight_density, insight_area, insight_integration) = catalyze(OreType::Insight);
+        assert!((insight_density - 0.4).abs() < 1e-6);
+        assert!((insight_area - area).abs() < 1e-6);
+        assert!(insight_integration > integration);
+    }
+
+    #[test]
+    fn test_ore_stats_track_deposits_catalysis_and_tectonics() {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_core_truth("warmth".to_string(), 1.0, 0.9, 0.3);
+        let despair = fluid.add_concept("despair".to_string(), 0.9, 0.5);
+
+        let mut deposited = 0;
+        let mut deposited_pressure = 0.0;
+        let mut catalyses = 0;
+        let mut consumed = 0;
+        let mut tally = |events: Vec<FluidEvent>, fluid: &ConceptFluid| {
+            for event in events {
+                match event {
+                    FluidEvent::OreDeposited { .. } => {
+                        deposited += 1;
+                        deposited_pressure = fluid.ocean_floor_pressure;
+                    }
+                    FluidEvent::OreCatalysis { .. } => catalyses += 1,
+                    FluidEvent::TectonicShift { ores_consumed, .. } => {
+                        consumed += ores_consumed.len()
+                    }
+                    _ => {}
+                }
+            }
+        };
+
+        // Mineralize a dark thought over three passes through the plume
+        for _ in 0..3 {
+            for layer in [0.9, 0.4] {
+                fluid.get_concept_mut(despair).unwrap().layer = layer;
+                let events = fluid.update(1.0 / 60.0);
+                tally(events, &fluid);
+            }
+        }
+        let code = &fluid.ore_stats.by_type["code"];
+        assert_eq!(code.deposited, 1);
+        assert_eq!(code.last_deposit_tick, Some(5));
+        assert!((code.total_pressure - fluid.ocean_floor_pressure).abs() < 1e-6);
+
+        // A ballasted problem reacts with the ore on the floor
+        let problem = fluid.add_concept("stuck_bug".to_string(), 0.3, 0.5);
+        let concept = fluid.get_concept_mut(problem).unwrap();
+        concept.layer = 0.9;
+        concept.ballast = 0.5;
+        let events = fluid.update(1.0 / 60.0);
+        tally(events, &fluid);
+
+        // Lower the threshold so the accumulated pressure forms a continent
+        fluid.pressure_threshold = 0.1;
+        let events = fluid.update(1.0 / 60.0);
+        tally(events, &fluid);
+
+        let stats = &fluid.ore_stats.by_type;
+        assert_eq!(stats.values().map(|s| s.deposited).sum::<u32>(), deposited);
+        assert_eq!(stats.values().map(|s| s.catalyses).sum::<u32>(), catalyses);
+        assert_eq!(
+            stats.values().map(|s| s.consumed_by_tectonic).sum::<u32>() as usize,
+            consumed
+        );
+        assert!(catalyses >= 1);
+        assert!(consumed >= 1);
+        assert!(fluid.ore_deposits.is_empty());
+        assert!(
+            (stats.values().map(|s| s.total_pressure).sum::<f32>() - deposited_pressure).abs()
+                < 1e-5
+        );
+
+        // Counters survive a snapshot round-trip
+        let restored = ConceptFluid::from_snapshot(fluid.to_snapshot().unwrap()).unwrap();
+        assert_eq!(
+            restored.ore_stats.by_type["code"].deposited,
+            fluid.ore_stats.by_type["code"].deposited
+        );
+    }
+}
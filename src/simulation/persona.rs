@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::concept::Concept;
+use super::consensus_reactor::ConsensusOre;
+use super::fluid::ConceptFluid;
+use super::ore::OreType;
+
+/// Compact characterization of the mind for a downstream consumer to
+/// condition its behavior on. Field names and meanings are stable.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct Persona {
+    pub tick: u64,
+    /// Ore type with the most deposits on the floor (ties go to the name
+    /// first alphabetically); None while the floor is bare
+    pub dominant_ore: Option<&'static str>,
+    pub trait_count: usize,
+    /// Character trait names, oldest first
+    pub traits: Vec<String>,
+    pub is_frozen: bool,
+    pub is_turbulent: bool,
+    /// Live concept nearest the surface
+    pub highest_attention: Option<String>,
+    /// Most recently crystallized foundational truth
+    pub latest_foundational_truth: Option<String>,
+}
+
+impl ConceptFluid {
+    /// Live thoughts ordered by attention: nearest the surface first,
+    /// fastest risers breaking ties.
+    pub fn attention_ranking(&self) -> Vec<&Concept> {
+        let mut ranked: Vec<_> = self
+            .concepts
+            .values()
+            .filter(|c| c.participates_in_attention())
+            .collect();
+        ranked.sort_by(|a, b| {
+            a.layer
+                .total_cmp(&b.layer)
+                .then(a.velocity.total_cmp(&b.velocity))
+                .then(a.id.cmp(&b.id))
+        });
+        ranked
+    }
+
+    /// Ore type with the most deposits currently on the floor.
+    pub fn dominant_ore_type(&self) -> Option<OreType> {
+        let mut counts: HashMap<OreType, usize> = HashMap::new();
+        for ore in &self.ore_deposits {
+            *counts.entry(ore.ore_type).or_insert(0) += 1;
+        }
+        counts
+            .into_iter()
+            .max_by(|(a, a_count), (b, b_count)| {
+                a_count.cmp(b_count).then(b.as_str().cmp(a.as_str()))
+            })
+            .map(|(ore_type, _)| ore_type)
+    }
+
+    /// The foundational truth that crystallized last.
+    pub fn latest_foundational_truth(&self) -> Option<&ConsensusOre> {
+        self.get_foundational_truths().last().copied()
+    }
+
+    /// Assemble the persona "mood vector".
+    pub fn persona(&self) -> Persona {
+        Persona {
+            tick: self.tick_count,
+            dominant_ore: self.dominant_ore_type().map(|t| t.as_str()),
+            trait_count: self.atmosphere.len(),
+            traits: self.atmosphere.iter().map(|t| t.name.clone()).collect(),
+            is_frozen: self.is_frozen,
+            is_turbulent: self.is_turbulent,
+            highest_attention: self.attention_ranking().first().map(|c| c.name.clone()),
+            latest_foundational_truth: self.latest_foundational_truth().map(|o| o.name.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::simulation::{CharacterTrait, PreciousOre};
+
+    #[test]
+    fn test_persona_reflects_traits_ores_and_truths() {
+        let mut fluid = ConceptFluid::default();
+        let persona = fluid.persona();
+        assert_eq!(persona.dominant_ore, None);
+        assert_eq!(persona.highest_attention, None);
+        assert_eq!(persona.latest_foundational_truth, None);
+
+        let deep = fluid.add_concept("deep".to_string(), 0.8, 0.5);
+        let near = fluid.add_concept("near".to_string(), 0.3, 0.5);
+        let gone = fluid.add_concept("gone".to_string(), 0.1, 0.5);
+        fluid.get_concept_mut(deep).unwrap().layer = 0.8;
+        fluid.get_concept_mut(near).unwrap().layer = 0.2;
+        let evaporated = fluid.get_concept_mut(gone).unwrap();
+        evaporated.layer = 0.0;
+        evaporated.has_evaporated = true;
+
+        for (name, ore_type) in [
+            ("song", OreType::Art),
+            ("patch", OreType::Code),
+            ("poem", OreType::Art),
+        ] {
+            fluid.ore_deposits.push(PreciousOre {
+                name: name.to_string(),
+                ore_type,
+                density: 0.9,
+                depth: 0.9,
+                formed_from: deep,
+                vent_cycles: 3,
+                integration_value: 1.0,
+            });
+        }
+        for name in ["patience", "curiosity"] {
+            fluid
+                .atmosphere
+                .push(CharacterTrait::new(name.to_string(), 1.0, Uuid::new_v4()));
+        }
+        for certainty in [0.95, 0.85, 0.3] {
+            fluid
+                .consensus_reactor
+                .ores
+                .push(ConsensusOre::test_fixture(certainty));
+        }
+        fluid.is_turbulent = true;
+
+        let persona = fluid.persona();
+        assert_eq!(persona.dominant_ore, Some("art"));
+        assert_eq!(persona.trait_count, 2);
+        assert_eq!(persona.traits, ["patience", "curiosity"]);
+        assert!(!persona.is_frozen);
+        assert!(persona.is_turbulent);
+        assert_eq!(persona.highest_attention.as_deref(), Some("near"));
+        assert_eq!(
+            persona.latest_foundational_truth.as_deref(),
+            Some("ore_0.85")
+        );
+    }
+}
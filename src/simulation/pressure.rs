@@ -0,0 +1,136 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::concept::Concept;
+use super::fluid::{ConceptFluid, DepthForces};
+
+/// Area of the synthetic test particle (does not affect depth forces)
+const PROBE_AREA: f32 = 0.5;
+
+/// One depth sample of the pressure profile. Forces follow the fluid's sign
+/// convention: positive pushes downward (assists descent), negative lifts.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct PressureSample {
+    pub depth: f32,
+    /// Pull toward the particle's target layer
+    pub buoyancy: f32,
+    /// Salinity lift (only for particles lighter than 0.5)
+    pub salinity: f32,
+    /// Vent plume lift
+    pub thermal: f32,
+    /// Standing wave force (division experiments)
+    pub wave: f32,
+    pub net_force: f32,
+    /// Fraction of speed lost when crossing a continent here (0 in open water)
+    pub barrier: f32,
+    /// Work needed to push the particle from the surface down to this depth,
+    /// ignoring barriers (negative when the fluid helps it down)
+    pub descent_work: f32,
+}
+
+impl ConceptFluid {
+    /// Bathymetry of the fluid: the forces a test particle at rest would feel
+    /// at `steps` evenly spaced depths from surface to floor.
+    ///
+    /// Uses the same depth forces as the physics pass; motion-dependent forces
+    /// (drag, surface tension, turbulence, repulsion) are zero at rest.
+    pub fn pressure_profile(&self, steps: usize, density: f32) -> Vec<PressureSample> {
+        let steps = steps.max(2);
+        let mut probe = Concept::new(
+            Uuid::nil(),
+            "pressure_probe".to_string(),
+            density,
+            PROBE_AREA,
+        );
+
+        let mut samples = Vec::with_capacity(steps);
+        let mut descent_work = 0.0;
+        let mut previous: Option<(f32, f32)> = None;
+        for i in 0..steps {
+            let depth = i as f32 / (steps - 1) as f32;
+            probe.layer = depth;
+            let forces = DepthForces::on(
+                &probe,
+                0.0,
+                self.salinity,
+                &self.core_truths,
+                &self.standing_waves,
+                &self.persistent_waves,
+            );
+            let net_force = forces.net();
+
+            // Trapezoid rule over the previous interval
+            if let Some((previous_depth, previous_force)) = previous {
+                descent_work -= (previous_force + net_force) / 2.0 * (depth - previous_depth);
+            }
+            previous = Some((depth, net_force));
+
+            let barrier = self
+                .continents
+                .iter()
+                .filter(|continent| continent.contains_depth(depth))
+                .map(|continent| 1.0 - continent.speed_retained())
+                .fold(0.0, f32::max);
+
+            samples.push(PressureSample {
+                depth,
+                buoyancy: forces.buoyancy,
+                salinity: forces.salinity,
+                thermal: forces.thermal,
+                wave: forces.wave,
+                net_force,
+                barrier,
+                descent_work,
+            });
+        }
+        samples
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::continent::Continent;
+
+    #[test]
+    fn test_pressure_profile_continent_and_vent() {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_core_truth("warmth".to_string(), 2.0, 0.9, 0.1);
+        fluid.continents.push(Continent {
+            name: "foundation_of_logic".to_string(),
+            depth_range: (0.6, 0.7),
+            formed_from_ores: vec![],
+            total_integration: 3.0,
+            impermeability: 0.9,
+            formation_event: 1,
+        });
+
+        let profile = fluid.pressure_profile(101, 0.5);
+        assert_eq!(profile.len(), 101);
+        assert_eq!(profile[0].depth, 0.0);
+        assert_eq!(profile[100].depth, 1.0);
+        let at = |depth: f32| {
+            profile
+                .iter()
+                .min_by(|a, b| (a.depth - depth).abs().total_cmp(&(b.depth - depth).abs()))
+                .unwrap()
+        };
+
+        // Strong resistance inside the continent band, none in open water
+        assert!(at(0.65).barrier > 0.9);
+        assert_eq!(at(0.5).barrier, 0.0);
+        assert_eq!(at(0.75).barrier, 0.0);
+
+        // The hot vent lifts far harder than stratification alone
+        let vent = at(0.9);
+        assert!((vent.thermal + 2.0).abs() < 1e-4);
+        assert!(vent.net_force < -2.0);
+        assert_eq!(at(0.7).thermal, 0.0);
+
+        // A neutral particle settles at mid-depth: pushed down above it, lifted below
+        assert!(at(0.2).net_force > 0.0);
+        assert!(at(0.75).net_force < 0.0);
+        assert!(at(0.5).descent_work < 0.0, "the upper fluid helps descent");
+    }
+}
@@ -0,0 +1,299 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use super::concept::{Concept, ConceptId};
+use super::error::FluidError;
+use super::fluid::ConceptFluid;
+use crate::state::events::FluidEvent;
+
+/// Statuses a filter can name (as reported by `Concept::status`)
+const STATUSES: [&str; 5] = ["rising", "sinking", "floating", "frozen", "evaporated"];
+
+/// Which concepts to prune. Every given condition must hold.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ConceptFilter {
+    /// `rising`, `sinking`, `floating`, `frozen` or `evaporated`
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub integration_below: Option<f32>,
+    #[serde(default)]
+    pub density_above: Option<f32>,
+    #[serde(default)]
+    pub density_below: Option<f32>,
+    /// Depth range, 0.0 = surface (inclusive)
+    #[serde(default)]
+    pub layer_min: Option<f32>,
+    #[serde(default)]
+    pub layer_max: Option<f32>,
+    #[serde(default)]
+    pub name_contains: Option<String>,
+    /// Spare solutions and concepts at least halfway to evaporating
+    /// (default true)
+    #[serde(default = "default_protect")]
+    pub protect: bool,
+}
+
+fn default_protect() -> bool {
+    true
+}
+
+impl ConceptFilter {
+    /// Reject filters that would match everything or name an unknown status.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(status) = &self.status
+            && !STATUSES.contains(&status.as_str())
+        {
+            return Err(format!(
+                "Unknown status '{}' (expected one of {})",
+                status,
+                STATUSES.join(", ")
+            ));
+        }
+        let has_condition = self.status.is_some()
+            || self.integration_below.is_some()
+            || self.density_above.is_some()
+            || self.density_below.is_some()
+            || self.layer_min.is_some()
+            || self.layer_max.is_some()
+            || self.name_contains.is_some();
+        if !has_condition {
+            return Err("Prune filter needs at least one condition".to_string());
+        }
+        Ok(())
+    }
+
+    fn matches(&self, concept: &Concept) -> bool {
+        self.status.as_deref().is_none_or(|s| concept.status() == s)
+            && self
+                .integration_below
+                .is_none_or(|max| concept.integration < max)
+            && self.density_above.is_none_or(|min| concept.density > min)
+            && self.density_below.is_none_or(|max| concept.density < max)
+            && self.layer_min.is_none_or(|min| concept.layer >= min)
+            && self.layer_max.is_none_or(|max| concept.layer <= max)
+            && self
+                .name_contains
+                .as_deref()
+                .is_none_or(|part| concept.name.contains(part))
+    }
+}
+
+impl ConceptFluid {
+    /// Remove a concept as `remove_concept` does. One carrying at least
+    /// `knowledge_release_threshold` integration releases it into the fluid
+    /// as salinity (`knowledge_release_rate` per unit) and emits
+    /// `KnowledgeReleased`. None if it is unknown or in a running experiment.
+    pub fn forget_concept(
+        &mut self,
+        id: ConceptId,
+        events: &mut Vec<FluidEvent>,
+    ) -> Option<Concept> {
+        let concept = self.remove_concept(id, events).ok()?;
+        if concept.integration >= self.knowledge_release_threshold {
+            let salinity = concept.integration * self.knowledge_release_rate;
+            self.salinity += salinity;
+            events.push(FluidEvent::KnowledgeReleased {
+                concept_id: id,
+                concept_name: concept.name.clone(),
+                integration: concept.integration,
+                salinity,
+            });
+        }
+        Some(concept)
+    }
+
+    /// Take a single concept out of the fluid along with its vent pass
+    /// tracking. Removing the concept the fluid is frozen on thaws the fluid
+    /// and emits `Thaw`. Unlike `forget_concept` nothing is released as
+    /// salinity. Probes and bubbles of a running experiment can't be removed.
+    pub fn remove_concept(
+        &mut self,
+        id: ConceptId,
+        events: &mut Vec<FluidEvent>,
+    ) -> Result<Concept, FluidError> {
+        let in_division = self
+            .active_experiment
+            .as_ref()
+            .is_some_and(|exp| exp.bubble_ids.contains(&id));
+        let in_consensus = self
+            .consensus_reactor
+            .active_experiment
+            .as_ref()
+            .is_some_and(|exp| exp.probe_ids.contains(&id));
+        if in_division || in_consensus {
+            return Err(FluidError::ConceptInExperiment { id });
+        }
+
+        let concept = self
+            .concepts
+            .remove(&id)
+            .ok_or(FluidError::ConceptNotFound { id })?;
+        self.vent_encounter_count.remove(&id);
+        self.in_vent_plume
+            .retain(|(concept_id, _)| *concept_id != id);
+        if self.frozen_concept == Some(id) {
+            self.frozen_concept = None;
+            if self.is_frozen {
+                self.is_frozen = false;
+                events.push(FluidEvent::Thaw);
+            }
+        }
+        Ok(concept)
+    }
+
+    /// Remove every concept `filter` matches, returning their ids (oldest
+    /// first). Probes and bubbles of a running experiment are left alone.
+    pub fn prune_concepts(
+        &mut self,
+        filter: &ConceptFilter,
+        events: &mut Vec<FluidEvent>,
+    ) -> Vec<ConceptId> {
+        let protected_integration = self.evaporation_threshold * 0.5;
+        let mut pruned: Vec<_> = self
+            .concepts
+            .values()
+            .filter(|c| filter.matches(c))
+            .filter(|c| {
+                !filter.protect || !(c.is_solution || c.integration >= protected_integration)
+            })
+            .map(|c| (c.seq, c.id))
+            .collect();
+        pruned.sort();
+
+        pruned
+            .into_iter()
+            .filter_map(|(_, id)| self.forget_concept(id, events).map(|_| id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(json: &str) -> ConceptFilter {
+        let filter: ConceptFilter = serde_json::from_str(json).unwrap();
+        filter.validate().unwrap();
+        filter
+    }
+
+    #[test]
+    fn test_prune_removes_only_sinking_unintegrated_concepts() {
+        let mut fluid = ConceptFluid::default();
+        let mut add = |name: &str, velocity: f32, integration: f32| {
+            let id = fluid.add_concept(name.to_string(), 0.5, 0.5);
+            let concept = fluid.get_concept_mut(id).unwrap();
+            concept.velocity = velocity;
+            concept.integration = integration;
+            id
+        };
+        let dead_weight = add("dead_weight", 0.2, 0.05);
+        let old_grudge = add("old_grudge", 0.5, 0.0);
+        let digesting = add("digesting", 0.2, 0.4);
+        let hope = add("hope", -0.3, 0.0);
+        let resting = add("resting", 0.0, 0.0);
+        let fix = add("sinking_fix", 0.2, 0.0);
+        fluid.get_concept_mut(fix).unwrap().is_solution = true;
+
+        let pruned = fluid.prune_concepts(
+            &filter(r#"{"status": "sinking", "integration_below": 0.1}"#),
+            &mut Vec::new(),
+        );
+
+        assert_eq!(pruned, vec![dead_weight, old_grudge]);
+        let mut left: Vec<_> = fluid.concepts.keys().copied().collect();
+        left.sort();
+        let mut expected = vec![digesting, hope, resting, fix];
+        expected.sort();
+        assert_eq!(left, expected, "solutions are protected by default");
+
+        let pruned = fluid.prune_concepts(
+            &filter(r#"{"status": "sinking", "integration_below": 0.1, "protect": false}"#),
+            &mut Vec::new(),
+        );
+        assert_eq!(pruned, vec![fix]);
+    }
+
+    #[test]
+    fn test_prune_filter_needs_a_known_condition() {
+        let empty: ConceptFilter = serde_json::from_str("{}").unwrap();
+        assert!(empty.validate().is_err(), "would prune everything");
+        let typo: ConceptFilter = serde_json::from_str(r#"{"status": "sunk"}"#).unwrap();
+        assert!(typo.validate().is_err());
+    }
+
+    #[test]
+    fn test_forgetting_integrated_concept_salts_the_fluid() {
+        let mut fluid = ConceptFluid::default();
+        let lesson = fluid.add_concept("hard_won_lesson".to_string(), 0.5, 0.5);
+        fluid.get_concept_mut(lesson).unwrap().integration = 0.8;
+        let passing = fluid.add_concept("passing_thought".to_string(), 0.5, 0.5);
+        fluid.salinity = 1.0;
+
+        let mut events = Vec::new();
+        fluid.forget_concept(passing, &mut events).unwrap();
+        assert_eq!(fluid.salinity, 1.0, "nothing learned, nothing released");
+        assert!(events.is_empty());
+
+        fluid.forget_concept(lesson, &mut events).unwrap();
+        let expected = 0.8 * fluid.knowledge_release_rate;
+        assert!((fluid.salinity - (1.0 + expected)).abs() < 1e-6);
+        assert!(matches!(
+            events.as_slice(),
+            [FluidEvent::KnowledgeReleased { concept_id, salinity, .. }]
+                if *concept_id == lesson && (*salinity - expected).abs() < 1e-6
+        ));
+        assert!(fluid.forget_concept(lesson, &mut events).is_none());
+    }
+
+    #[test]
+    fn test_remove_concept_cleans_up_and_spares_experiment_probes() {
+        let mut fluid = ConceptFluid::default();
+        let fixation = fluid.add_concept("fixation".to_string(), 0.9, 0.5);
+        fluid.vent_encounter_count.insert(fixation, 2);
+        fluid.in_vent_plume.insert((fixation, 0));
+        fluid.frozen_concept = Some(fixation);
+        fluid.is_frozen = true;
+
+        let mut events = Vec::new();
+        let removed = fluid.remove_concept(fixation, &mut events).unwrap();
+        assert_eq!(removed.name, "fixation");
+        assert!(fluid.get_concept(fixation).is_none());
+        assert!(fluid.vent_encounter_count.is_empty());
+        assert!(fluid.in_vent_plume.is_empty());
+        assert_eq!(fluid.frozen_concept, None);
+        assert!(!fluid.is_frozen, "the fixation is gone, so is the freeze");
+        assert!(matches!(events.as_slice(), [FluidEvent::Thaw]));
+        assert_eq!(
+            fluid.remove_concept(fixation, &mut events).unwrap_err(),
+            FluidError::ConceptNotFound { id: fixation }
+        );
+
+        fluid.start_division_experiment(1.0, 2.0);
+        let bubble = fluid.active_experiment.as_ref().unwrap().bubble_ids[0];
+        assert_eq!(
+            fluid.remove_concept(bubble, &mut events).unwrap_err(),
+            FluidError::ConceptInExperiment { id: bubble }
+        );
+        assert!(fluid.get_concept(bubble).is_some());
+
+        // Forgetting and pruning go through the same removal
+        let stuck = fluid.add_concept("stuck".to_string(), 0.9, 0.5);
+        fluid.in_vent_plume.insert((stuck, 0));
+        fluid.frozen_concept = Some(stuck);
+        fluid.is_frozen = true;
+        let mut events = Vec::new();
+        let pruned = fluid.prune_concepts(
+            &filter(r#"{"layer_min": 0.0, "protect": false}"#),
+            &mut events,
+        );
+        assert_eq!(pruned, vec![stuck], "bubbles are not pruned");
+        assert!(fluid.get_concept(bubble).is_some());
+        assert!(fluid.in_vent_plume.is_empty());
+        assert_eq!(fluid.frozen_concept, None);
+        assert!(!fluid.is_frozen);
+        assert!(events.iter().any(|event| matches!(event, FluidEvent::Thaw)));
+        assert!(fluid.forget_concept(bubble, &mut Vec::new()).is_none());
+    }
+}
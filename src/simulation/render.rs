@@ -0,0 +1,210 @@
+use std::fmt::Write;
+
+use super::concept::Concept;
+use super::fluid::ConceptFluid;
+
+impl ConceptFluid {
+    /// Render a layered text view of the fluid, surface first.
+    /// Pure: builds a string and never prints.
+    pub fn render_state(&self) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(out, "🌊 Consciousness Fluid - tick {}", self.tick_count);
+        let _ = writeln!(
+            out,
+            "   salinity {:.2} | integration {:.2} | pressure {:.2}/{:.2}",
+            self.salinity,
+            self.total_integration,
+            self.ocean_floor_pressure,
+            self.pressure_threshold
+        );
+
+        // === Banners ===
+        if self.is_frozen {
+            let name = self
+                .frozen_concept
+                .and_then(|id| self.concepts.get(&id))
+                .map(|c| c.name.as_str())
+                .unwrap_or("unknown");
+            let _ = writeln!(out, "🧊 FROZEN around '{}'", name);
+        }
+        if self.is_turbulent {
+            let _ = writeln!(out, "🌪️ TURBULENT (energy {:.2})", self.turbulence_energy);
+        }
+
+        // === Core truths ===
+        if !self.core_truths.is_empty() {
+            let _ = writeln!(out, "\n🌋 Core truths:");
+            for truth in &self.core_truths {
+                let _ = writeln!(
+                    out,
+                    "   🌋 {} @ {:.2} (heat {:.2}, radius {:.2}, {} activations){}",
+                    truth.name,
+                    truth.depth,
+                    truth.heat_output,
+                    truth.radius,
+                    truth.activation_count,
+                    if truth.active { "" } else { " [inactive]" }
+                );
+            }
+        }
+
+        // === Ores ===
+        if !self.ore_deposits.is_empty() {
+            let _ = writeln!(out, "\n💎 Ore deposits:");
+            for ore in &self.ore_deposits {
+                let _ = writeln!(
+                    out,
+                    "   💎 {} [{}] @ {:.2} (value {:.2}, {} cycles)",
+                    ore.name,
+                    ore.ore_type.as_str(),
+                    ore.depth,
+                    ore.integration_value,
+                    ore.vent_cycles
+                );
+            }
+        }
+
+        // === Continents ===
+        if !self.continents.is_empty() {
+            let _ = writeln!(out, "\n🏔️ Continents:");
+            for continent in &self.continents {
+                let _ = writeln!(
+                    out,
+                    "   🏔️ {} [{:.2}-{:.2}]",
+                    continent.name, continent.depth_range.0, continent.depth_range.1
+                );
+            }
+        }
+
+        // === Atmosphere ===
+        if !self.atmosphere.is_empty() {
+            let _ = writeln!(out, "\n☁️ Atmosphere:");
+            for character_trait in &self.atmosphere {
+                let _ = writeln!(
+                    out,
+                    "   ☁️ {} ({:.2})",
+                    character_trait.name, character_trait.integration
+                );
+            }
+        }
+
+        // === Layers (surface → floor) ===
+        let num_layers = self.num_layers.max(1);
+        let mut layers: Vec<Vec<&Concept>> = vec![Vec::new(); num_layers];
+        for concept in self.concepts.values() {
+            let index = ((concept.layer * num_layers as f32) as usize).min(num_layers - 1);
+            layers[index].push(concept);
+        }
+
+        let _ = writeln!(out);
+        for (index, concepts) in layers.iter_mut().enumerate() {
+            let top = index as f32 / num_layers as f32;
+            let bottom = (index + 1) as f32 / num_layers as f32;
+            let label = if index == 0 {
+                " surface"
+            } else if index == num_layers - 1 {
+                " floor"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                out,
+                "── Layer {} [{:.2}-{:.2}]{}",
+                index, top, bottom, label
+            );
+
+            concepts.sort_by(|a, b| a.layer.total_cmp(&b.layer).then(a.name.cmp(&b.name)));
+            for concept in concepts.iter() {
+                let _ = writeln!(
+                    out,
+                    "   {} {} @ {:.2} (v {:+.2}, ρ {:.2}){}",
+                    direction_arrow(concept),
+                    concept.name,
+                    concept.layer,
+                    concept.velocity,
+                    concept.density,
+                    status_glyphs(concept)
+                );
+            }
+        }
+
+        out
+    }
+}
+
+/// Arrow for the concept's direction of travel.
+fn direction_arrow(concept: &Concept) -> &'static str {
+    match concept.status() {
+        "rising" => "↑",
+        "sinking" => "↓",
+        _ => "·",
+    }
+}
+
+/// Glyphs for notable concept states (empty when unremarkable).
+fn status_glyphs(concept: &Concept) -> String {
+    let mut glyphs = String::new();
+    if concept.is_frozen {
+        glyphs.push_str(" 🧊");
+    }
+    if concept.has_broken_surface {
+        glyphs.push_str(" ⚡");
+    }
+    if concept.has_evaporated {
+        glyphs.push_str(" ☁️");
+    }
+    if concept.is_solution {
+        glyphs.push_str(" 💡");
+    }
+    if concept.ballast > 0.0 {
+        glyphs.push_str(" ⚓");
+    }
+    glyphs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::ore::{OreType, PreciousOre};
+
+    #[test]
+    fn test_render_state_fixture() {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_core_truth("curiosity".to_string(), 1.0, 0.9, 0.3);
+        let hope = fluid.add_concept("hope".to_string(), 0.1, 0.5);
+        let dread = fluid.add_concept("dread".to_string(), 0.9, 0.5);
+        fluid.get_concept_mut(hope).unwrap().velocity = -0.4;
+        fluid.get_concept_mut(hope).unwrap().has_broken_surface = true;
+        fluid.get_concept_mut(dread).unwrap().velocity = 0.2;
+        fluid.ore_deposits.push(PreciousOre {
+            name: "dread_ore_1".to_string(),
+            ore_type: OreType::Art,
+            density: 0.9,
+            depth: 0.9,
+            formed_from: dread,
+            vent_cycles: 3,
+            integration_value: 1.5,
+        });
+        fluid.is_frozen = true;
+        fluid.frozen_concept = Some(hope);
+        fluid.is_turbulent = true;
+        fluid.turbulence_energy = 1.25;
+        fluid.tick_count = 42;
+
+        let text = fluid.render_state();
+
+        assert!(text.contains("tick 42"));
+        assert!(text.contains("🧊 FROZEN around 'hope'"));
+        assert!(text.contains("🌪️ TURBULENT (energy 1.25)"));
+        assert!(text.contains("🌋 curiosity @ 0.90"));
+        assert!(text.contains("💎 dread_ore_1 [art] @ 0.90"));
+        assert!(text.contains("── Layer 0 [0.00-0.20] surface"));
+        assert!(text.contains("── Layer 4 [0.80-1.00] floor"));
+        assert!(text.contains("↑ hope @ 0.10 (v -0.40, ρ 0.10) ⚡"));
+        assert!(text.contains("↓ dread @ 0.90 (v +0.20, ρ 0.90)"));
+
+        // Surface concepts are listed before the floor
+        assert!(text.find("hope @").unwrap() < text.find("dread @").unwrap());
+    }
+}
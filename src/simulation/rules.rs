@@ -0,0 +1,131 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tracing::warn;
+
+use crate::state::{CommandPayload, FluidEvent};
+
+/// Most rules that can be registered at once
+pub const MAX_RULES: usize = 32;
+/// Rounds of rules reacting to events caused by rules, per tick. Commands
+/// a rule would send beyond this are dropped, so rules that trigger each
+/// other cannot loop forever.
+pub const MAX_RULE_DEPTH: usize = 4;
+
+/// "When `on` happens, send `then`." A server-side reaction that lets the
+/// fluid drive closed loops without an external controller.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Rule {
+    /// Event name, as in the event's `event` field (e.g. `concept_evaporated`)
+    pub on: String,
+    /// Command template in WebSocket command form. `{field}` in a string is
+    /// replaced by that field of the triggering event; a string that is just
+    /// `"{field}"` takes the field's value as is (so numbers stay numbers).
+    pub then: Value,
+    /// Times the rule has sent its command
+    #[serde(default)]
+    pub fired: u64,
+}
+
+impl Rule {
+    pub fn new(on: String, then: Value) -> Result<Self, String> {
+        if on.trim().is_empty() {
+            return Err("Rule needs an event name in 'on'".to_string());
+        }
+        if !then.get("command").is_some_and(Value::is_string) {
+            return Err("Rule template needs a 'command' name".to_string());
+        }
+        Ok(Self { on, then, fired: 0 })
+    }
+
+    /// The command for `event`, if the rule matches and the filled-in
+    /// template is a valid command.
+    fn command_for(&self, event: &Value) -> Option<CommandPayload> {
+        let fields = event.as_object()?;
+        if fields.get("event").and_then(Value::as_str) != Some(self.on.as_str()) {
+            return None;
+        }
+        match serde_json::from_value(fill(&self.then, fields)) {
+            Ok(command) => Some(command),
+            Err(err) => {
+                warn!("Rule on '{}' produced an invalid command: {}", self.on, err);
+                None
+            }
+        }
+    }
+}
+
+/// Substitute `{field}` placeholders in every string of `template`.
+fn fill(template: &Value, fields: &Map<String, Value>) -> Value {
+    match template {
+        Value::String(text) => {
+            let whole = text
+                .strip_prefix('{')
+                .and_then(|rest| rest.strip_suffix('}'))
+                .and_then(|name| fields.get(name));
+            if let Some(value) = whole {
+                return value.clone();
+            }
+            let mut text = text.clone();
+            for (name, value) in fields {
+                let placeholder = format!("{{{}}}", name);
+                if text.contains(&placeholder) {
+                    let value = match value {
+                        Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    };
+                    text = text.replace(&placeholder, &value);
+                }
+            }
+            Value::String(text)
+        }
+        Value::Array(items) => Value::Array(items.iter().map(|v| fill(v, fields)).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), fill(value, fields)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Registered rules, saved with the fluid.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleSet {
+    pub rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    /// Register a rule, returning its index. Fails beyond `MAX_RULES`.
+    pub fn add(&mut self, rule: Rule) -> Result<usize, String> {
+        if self.rules.len() >= MAX_RULES {
+            return Err(format!("At most {} rules can be registered", MAX_RULES));
+        }
+        self.rules.push(rule);
+        Ok(self.rules.len() - 1)
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<Rule> {
+        (index < self.rules.len()).then(|| self.rules.remove(index))
+    }
+
+    /// Commands the rules send in reaction to `events`, in event order.
+    pub fn commands_for(&mut self, events: &[FluidEvent]) -> Vec<CommandPayload> {
+        if self.rules.is_empty() {
+            return Vec::new();
+        }
+        let mut commands = Vec::new();
+        for event in events {
+            let Ok(event) = serde_json::to_value(event) else {
+                continue;
+            };
+            for rule in &mut self.rules {
+                if let Some(command) = rule.command_for(&event) {
+                    rule.fired += 1;
+                    commands.push(command);
+                }
+            }
+        }
+        commands
+    }
+}
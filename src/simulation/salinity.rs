@@ -0,0 +1,122 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::fluid::ConceptFluid;
+use crate::state::events::FluidEvent;
+
+/// Share of a band's lower bound salinity must fall below it before the
+/// fluid drops back out of the band, so it can't flap at the boundary
+const SALINITY_HYSTERESIS: f32 = 0.1;
+
+/// Named salinity bands, freshest first.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SalinityBand {
+    #[default]
+    Fresh,
+    Brackish,
+    Ocean,
+    DeadSea,
+}
+
+impl SalinityBand {
+    const ALL: [Self; 4] = [Self::Fresh, Self::Brackish, Self::Ocean, Self::DeadSea];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Fresh => "fresh",
+            Self::Brackish => "brackish",
+            Self::Ocean => "ocean",
+            Self::DeadSea => "dead_sea",
+        }
+    }
+
+    /// Salinity at which the fluid enters this band from below
+    pub fn lower_bound(self) -> f32 {
+        match self {
+            Self::Fresh => 0.0,
+            Self::Brackish => 1.0,
+            Self::Ocean => 5.0,
+            Self::DeadSea => 20.0,
+        }
+    }
+
+    fn above(self) -> Option<Self> {
+        Self::ALL.get(self as usize + 1).copied()
+    }
+
+    fn below(self) -> Option<Self> {
+        (self as usize).checked_sub(1).map(|i| Self::ALL[i])
+    }
+}
+
+impl ConceptFluid {
+    /// Move `salinity_band` to match the current salinity, one boundary at a
+    /// time, with a `SalinityMilestone` for every boundary crossed.
+    pub(crate) fn update_salinity_band(&mut self) -> Vec<FluidEvent> {
+        let mut events = Vec::new();
+        loop {
+            let band = self.salinity_band;
+            let next = if let Some(up) = band.above()
+                && self.salinity >= up.lower_bound()
+            {
+                up
+            } else if let Some(down) = band.below()
+                && self.salinity < band.lower_bound() * (1.0 - SALINITY_HYSTERESIS)
+            {
+                down
+            } else {
+                return events;
+            };
+
+            self.salinity_band = next;
+            events.push(FluidEvent::SalinityMilestone {
+                level: next.name().to_string(),
+                salinity: self.salinity,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_milestones_fire_once_per_boundary_crossing() {
+        let mut fluid = ConceptFluid::default();
+        let mut levels = Vec::new();
+        // Up into brackish, then ocean, hovering around each boundary,
+        // then back down into brackish
+        for salinity in [0.5, 1.0, 0.95, 1.02, 0.92, 4.0, 5.0, 4.8, 5.1, 3.0] {
+            fluid.salinity = salinity;
+            for event in fluid.update(1.0 / 60.0) {
+                if let FluidEvent::SalinityMilestone { level, .. } = event {
+                    levels.push(level);
+                }
+            }
+        }
+
+        assert_eq!(levels, ["brackish", "ocean", "brackish"]);
+        assert_eq!(fluid.salinity_band, SalinityBand::Brackish);
+    }
+
+    #[test]
+    fn test_large_jump_crosses_every_band_between() {
+        let mut fluid = ConceptFluid {
+            salinity: 25.0,
+            ..ConceptFluid::default()
+        };
+        let levels: Vec<_> = fluid
+            .update_salinity_band()
+            .into_iter()
+            .filter_map(|event| match event {
+                FluidEvent::SalinityMilestone { level, .. } => Some(level),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(levels, ["brackish", "ocean", "dead_sea"]);
+    }
+}
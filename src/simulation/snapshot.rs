@@ -0,0 +1,278 @@
+use serde::de::Error as _;
+use serde_json::{Map, Value};
+
+use super::fluid::ConceptFluid;
+
+/// Current snapshot schema version.
+/// Bump this whenever a serialized field is renamed or reinterpreted, or is
+/// added without a usable default, and add the matching step to `upgrade`.
+///
+/// Newly-added fields should carry `#[serde(default)]` so that older snapshots
+/// still deserialize without a version bump.
+pub const SCHEMA_VERSION: u32 = 7;
+
+/// Ratio of the v5 `salinity_rate` default to the v4 one
+const SALINITY_RATE_V5_SCALE: f64 = 50.0;
+/// Seconds per tick of the 60 Hz loop that v5 re-arm counts were kept in
+const REARM_V6_TICK_SECONDS: f64 = 1.0 / 60.0;
+
+/// Snapshots written before versioning existed carry no `schema_version` field.
+pub(crate) fn legacy_schema_version() -> u32 {
+    1
+}
+
+impl ConceptFluid {
+    /// Serialize the fluid into a versioned JSON snapshot.
+    pub fn to_snapshot(&self) -> Result<Value, serde_json::Error> {
+        let mut snapshot = serde_json::to_value(self)?;
+        if let Some(obj) = snapshot.as_object_mut() {
+            obj.insert("schema_version".into(), Value::from(SCHEMA_VERSION));
+        }
+        Ok(snapshot)
+    }
+
+    /// Restore a fluid from a JSON snapshot, upgrading older schema versions.
+    /// Fields missing from older snapshots are filled with their defaults.
+    /// Snapshots whose surface zones do not nest are rejected.
+    pub fn from_snapshot(mut snapshot: Value) -> Result<Self, serde_json::Error> {
+        let obj = snapshot
+            .as_object_mut()
+            .ok_or_else(|| serde_json::Error::custom("snapshot must be a JSON object"))?;
+
+        let mut version = obj
+            .get("schema_version")
+            .and_then(Value::as_u64)
+            .map(|v| v as u32)
+            .unwrap_or_else(legacy_schema_version);
+
+        if version > SCHEMA_VERSION {
+            return Err(serde_json::Error::custom(format!(
+                "snapshot schema version {} is newer than supported version {}",
+                version, SCHEMA_VERSION
+            )));
+        }
+
+        while version < SCHEMA_VERSION {
+            upgrade(obj, version);
+            version += 1;
+        }
+        obj.insert("schema_version".into(), Value::from(SCHEMA_VERSION));
+
+        let fluid: Self = serde_json::from_value(snapshot)?;
+        fluid.validate_zones().map_err(serde_json::Error::custom)?;
+        Ok(fluid)
+    }
+}
+
+/// Upgrade a snapshot from `from` to `from + 1` in place.
+fn upgrade(snapshot: &mut Map<String, Value>, from: u32) {
+    match from {
+        // v1 → v2: introduces `schema_version` itself; no field changes
+        1 => {
+            snapshot.insert("schema_version".into(), Value::from(2));
+        }
+        // v2 → v3: number existing concepts so new ones never reuse a sequence
+        2 => {
+            let mut count = 0u64;
+            if let Some(concepts) = snapshot.get_mut("concepts").and_then(Value::as_object_mut) {
+                for concept in concepts.values_mut().filter_map(Value::as_object_mut) {
+                    concept.insert("seq".into(), Value::from(count));
+                    count += 1;
+                }
+            }
+            snapshot.insert("next_concept_seq".into(), Value::from(count));
+            snapshot.insert("schema_version".into(), Value::from(3));
+        }
+        // v3 → v4: the single `viscosity` becomes a surface-to-floor gradient,
+        // uniform at the old value
+        3 => {
+            if let Some(viscosity) = snapshot.remove("viscosity") {
+                snapshot.insert("viscosity_surface".into(), viscosity.clone());
+                snapshot.insert("viscosity_deep".into(), viscosity);
+            }
+            snapshot.insert("schema_version".into(), Value::from(4));
+        }
+        // v4 → v5: `salinity_rate` salted by the lifetime integration each
+        // second and now salts by each tick's gain; scale it so the old
+        // default (0.1) becomes the new one (5.0)
+        4 => {
+            if let Some(rate) = snapshot.get("salinity_rate").and_then(Value::as_f64) {
+                snapshot.insert(
+                    "salinity_rate".into(),
+                    Value::from(rate * SALINITY_RATE_V5_SCALE),
+                );
+            }
+            snapshot.insert("schema_version".into(), Value::from(5));
+        }
+        // v5 → v6: re-arming counts seconds instead of ticks
+        5 => {
+            if let Some(ticks) = snapshot.remove("rearm_ticks").and_then(|v| v.as_f64()) {
+                snapshot.insert(
+                    "rearm_seconds".into(),
+                    Value::from(ticks * REARM_V6_TICK_SECONDS),
+                );
+            }
+            if let Some(concepts) = snapshot.get_mut("concepts").and_then(Value::as_object_mut) {
+                for concept in concepts.values_mut().filter_map(Value::as_object_mut) {
+                    if let Some(ticks) = concept.get("rearm_progress").and_then(Value::as_f64) {
+                        concept.insert(
+                            "rearm_progress".into(),
+                            Value::from(ticks * REARM_V6_TICK_SECONDS),
+                        );
+                    }
+                }
+            }
+            snapshot.insert("schema_version".into(), Value::from(6));
+        }
+        // v6 → v7: traits record the density and area of the concept they
+        // formed from; copy them over where that concept is still around
+        6 => {
+            let concepts = snapshot.get("concepts").and_then(Value::as_object).cloned();
+            if let (Some(concepts), Some(atmosphere)) = (
+                concepts,
+                snapshot.get_mut("atmosphere").and_then(Value::as_array_mut),
+            ) {
+                for character_trait in atmosphere.iter_mut().filter_map(Value::as_object_mut) {
+                    let Some(concept) = character_trait
+                        .get("formed_from")
+                        .and_then(Value::as_str)
+                        .and_then(|id| concepts.get(id))
+                    else {
+                        continue;
+                    };
+                    for field in ["density", "area"] {
+                        if let Some(value) = concept.get(field) {
+                            character_trait.insert(field.into(), value.clone());
+                        }
+                    }
+                }
+            }
+            snapshot.insert("schema_version".into(), Value::from(7));
+        }
+        _ => unreachable!("no upgrade path from schema version {}", from),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::simulation::CharacterTrait;
+
+    #[test]
+    fn test_v1_snapshot_upgrades_with_defaults() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("curiosity".to_string(), 0.3, 0.5);
+
+        // A v1 snapshot is the current shape minus the version tag
+        let mut v1 = serde_json::to_value(&fluid).unwrap();
+        v1.as_object_mut().unwrap().remove("schema_version");
+
+        let restored = ConceptFluid::from_snapshot(v1).unwrap();
+        assert_eq!(restored.schema_version, SCHEMA_VERSION);
+        assert_eq!(restored.concepts[&id].name, "curiosity");
+        assert_eq!(restored.tick_count, fluid.tick_count);
+    }
+
+    #[test]
+    fn test_v2_snapshot_numbers_existing_concepts() {
+        let mut fluid = ConceptFluid::default();
+        for name in ["a", "b", "c"] {
+            fluid.add_concept(name.to_string(), 0.5, 0.5);
+        }
+        let mut v2 = fluid.to_snapshot().unwrap();
+        let obj = v2.as_object_mut().unwrap();
+        obj.insert("schema_version".into(), Value::from(2));
+        obj.remove("next_concept_seq");
+        for concept in obj["concepts"].as_object_mut().unwrap().values_mut() {
+            concept.as_object_mut().unwrap().remove("seq");
+        }
+
+        let mut restored = ConceptFluid::from_snapshot(v2).unwrap();
+        let mut seqs: Vec<_> = restored.concepts.values().map(|c| c.seq).collect();
+        seqs.sort();
+        assert_eq!(seqs, vec![0, 1, 2]);
+        let id = restored.add_concept("d".to_string(), 0.5, 0.5);
+        assert_eq!(restored.concepts[&id].seq, 3);
+    }
+
+    #[test]
+    fn test_v3_snapshot_keeps_its_viscosity_throughout_the_column() {
+        let fluid = ConceptFluid::default();
+        let mut v3 = fluid.to_snapshot().unwrap();
+        let obj = v3.as_object_mut().unwrap();
+        obj.insert("schema_version".into(), Value::from(3));
+        obj.remove("viscosity_surface");
+        obj.remove("viscosity_deep");
+        obj.insert("viscosity".into(), Value::from(0.8));
+
+        let restored = ConceptFluid::from_snapshot(v3).unwrap();
+        assert_eq!(restored.viscosity_surface, 0.8);
+        assert_eq!(restored.viscosity_deep, 0.8);
+    }
+
+    #[test]
+    fn test_v4_snapshot_rescales_its_salinity_rate() {
+        let fluid = ConceptFluid::default();
+        let mut v4 = fluid.to_snapshot().unwrap();
+        let obj = v4.as_object_mut().unwrap();
+        obj.insert("schema_version".into(), Value::from(4));
+        obj.insert("salinity_rate".into(), Value::from(0.1));
+
+        let restored = ConceptFluid::from_snapshot(v4).unwrap();
+        assert!((restored.salinity_rate - fluid.salinity_rate).abs() < 1e-6);
+        assert_eq!(restored.schema_version, SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_v5_snapshot_counts_rearm_in_seconds() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("recurring_urge".to_string(), 0.5, 0.5);
+        let mut v5 = fluid.to_snapshot().unwrap();
+        let obj = v5.as_object_mut().unwrap();
+        obj.insert("schema_version".into(), Value::from(5));
+        obj.remove("rearm_seconds");
+        obj.insert("rearm_ticks".into(), Value::from(30));
+        v5["concepts"][id.to_string()]["rearm_progress"] = Value::from(12);
+
+        let restored = ConceptFluid::from_snapshot(v5).unwrap();
+        assert!((restored.rearm_seconds - 0.5).abs() < 1e-6);
+        assert!((restored.concepts[&id].rearm_progress - 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_v6_snapshot_gives_traits_their_concepts_dimensions() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("patience".to_string(), 0.3, 0.5);
+        fluid.get_concept_mut(id).unwrap().has_evaporated = true;
+        fluid
+            .atmosphere
+            .push(CharacterTrait::new("patience".to_string(), 1.0, id));
+        fluid
+            .atmosphere
+            .push(CharacterTrait::new("calm".to_string(), 1.0, Uuid::new_v4()));
+        let mut v6 = fluid.to_snapshot().unwrap();
+        v6["schema_version"] = Value::from(6);
+        for character_trait in v6["atmosphere"].as_array_mut().unwrap() {
+            let character_trait = character_trait.as_object_mut().unwrap();
+            character_trait.remove("density");
+            character_trait.remove("area");
+        }
+
+        let restored = ConceptFluid::from_snapshot(v6).unwrap();
+        assert_eq!(restored.atmosphere[0].density, 0.3);
+        assert_eq!(restored.atmosphere[0].area, fluid.concepts[&id].area);
+        assert_eq!(restored.atmosphere[1].density, 0.5);
+        assert_eq!(restored.atmosphere[1].area, 1.0);
+    }
+
+    #[test]
+    fn test_future_snapshot_rejected() {
+        let fluid = ConceptFluid::default();
+        let mut snapshot = fluid.to_snapshot().unwrap();
+        snapshot["schema_version"] = Value::from(SCHEMA_VERSION + 1);
+
+        assert!(ConceptFluid::from_snapshot(snapshot).is_err());
+    }
+}
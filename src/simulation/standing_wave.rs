@@ -1,6 +1,10 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use tracing::Span;
 use uuid::Uuid;
 
+use super::trace::ExperimentTrace;
+
 /// A standing wave creates acoustic nodes at regular intervals.
 /// Bubbles naturally settle into nodes when the system is divisible.
 ///
@@ -128,12 +132,7 @@ impl StandingWave {
         self.node_positions
             .iter()
             .enumerate()
-            .min_by(|(_, a), (_, b)| {
-                (depth - *a)
-                    .abs()
-                    .partial_cmp(&(depth - *b).abs())
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            })
+            .min_by(|(_, a), (_, b)| (depth - *a).abs().total_cmp(&(depth - *b).abs()))
             .map(|(idx, _)| idx)
     }
 
@@ -143,6 +142,20 @@ impl StandingWave {
     /// - Pauli Exclusion (saturated nodes repel)
     /// - Depth-Compensated Attraction (shallow nodes boosted to overcome buoyancy)
     pub fn force_at_depth(&self, depth: f32) -> f32 {
+        self.force_on(depth, None)
+    }
+
+    /// The node a bubble at `depth` counts toward, if any (see `update_occupancy`).
+    pub fn occupied_node(&self, depth: f32) -> Option<usize> {
+        let node_idx = self.nearest_node_index(depth)?;
+        ((depth - self.node_positions[node_idx]).abs() < self.node_spacing / 2.0)
+            .then_some(node_idx)
+    }
+
+    /// `force_at_depth` for a bubble already counted in node `occupying`:
+    /// a node holding exactly its limit keeps its own bubbles and only
+    /// repels newcomers.
+    pub fn force_on(&self, depth: f32, occupying: Option<usize>) -> f32 {
         if !self.active || self.node_positions.is_empty() {
             return 0.0;
         }
@@ -154,12 +167,7 @@ impl StandingWave {
             .node_positions
             .iter()
             .enumerate()
-            .min_by(|(_, a), (_, b)| {
-                (depth - *a)
-                    .abs()
-                    .partial_cmp(&(depth - *b).abs())
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            })
+            .min_by(|(_, a), (_, b)| (depth - *a).abs().total_cmp(&(depth - *b).abs()))
             .map(|(idx, &pos)| (idx, pos))
             .unwrap_or((0, depth));
 
@@ -172,7 +180,10 @@ impl StandingWave {
         let depth_compensation = 1.0 + 0.6 * (1.0 - nearest_node).max(0.0);
 
         // Check if nearest node is saturated (Pauli Exclusion)
-        let node_occ = self.node_occupancy.get(nearest_idx).copied().unwrap_or(0);
+        let mut node_occ = self.node_occupancy.get(nearest_idx).copied().unwrap_or(0);
+        if occupying == Some(nearest_idx) {
+            node_occ = node_occ.saturating_sub(1);
+        }
 
         if node_occ >= self.saturation_limit {
             // Node is FULL - flip to repulsion!
@@ -238,6 +249,9 @@ impl DivisionProblem {
 /// Result of a division computation via fluid dynamics.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DivisionResult {
+    /// Experiment (problem) id
+    #[serde(default)]
+    pub id: Uuid,
     /// The original problem
     pub dividend: f32,
     pub divisor: f32,
@@ -264,6 +278,124 @@ pub struct DivisionResult {
     /// Peak jitter observed during settling (captures transient micro-cavitation)
     /// This is the key remainder detection metric!
     pub peak_jitter: f32,
+    /// Whether the bubbles came to rest or the experiment hit `max_ticks`
+    #[serde(default)]
+    pub settled_by: SettledBy,
+    /// Settled by meeting the thresholds for a whole stability window
+    /// (false for a timeout)
+    #[serde(default = "default_settled_naturally")]
+    pub settled_naturally: bool,
+    /// 0-1: share of the stability window held at the end, times the share
+    /// of bubbles sitting at a node
+    #[serde(default = "default_confidence")]
+    pub confidence: f32,
+}
+
+fn default_settled_naturally() -> bool {
+    true
+}
+
+fn default_confidence() -> f32 {
+    1.0
+}
+
+/// How a division experiment ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SettledBy {
+    /// All settlement thresholds were met
+    #[default]
+    Settled,
+    /// Forced to finish at `max_ticks`: the thresholds were wrong for this
+    /// experiment or it is genuinely chaotic (likely a remainder)
+    TimedOut,
+}
+
+impl SettledBy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Settled => "settled",
+            Self::TimedOut => "timed_out",
+        }
+    }
+}
+
+/// When a division experiment counts as settled: every bubble below
+/// `max_velocity`, the mean below `avg_velocity`, after at least `min_ticks`,
+/// held with unchanged node occupancy for `stability_window` consecutive ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SettlementThresholds {
+    pub min_ticks: u64,
+    pub max_velocity: f32,
+    pub avg_velocity: f32,
+    #[serde(default = "default_stability_window")]
+    pub stability_window: u64,
+}
+
+fn default_stability_window() -> u64 {
+    20 // A third of a second at 60Hz
+}
+
+impl Default for SettlementThresholds {
+    /// The fixed thresholds used before scaling (tuned for ~3 nodes).
+    fn default() -> Self {
+        Self {
+            min_ticks: 60, // 1 second at 60Hz
+            max_velocity: 0.05,
+            avg_velocity: 0.02,
+            stability_window: default_stability_window(),
+        }
+    }
+}
+
+impl SettlementThresholds {
+    /// Thresholds scaled to the experiment's size. A bubble resting at its
+    /// node only trembles with the breathing wave (about 0.05, the base
+    /// thresholds), whatever the size. What grows is the motion at the edges:
+    /// with more nodes the outermost sit near the surface and floor, and their
+    /// bubbles keep circling long after the rest have settled, peaking around
+    /// 0.05 per bubble (0.76 for 16 bubbles in 8 nodes, 1.2 for 20 in 10) and
+    /// lifting the mean to 0.015-0.02 per node. So the fastest bubble is
+    /// allowed about twice that (0.1 per extra bubble), the mean 0.02 per
+    /// node, and each extra node 10 more ticks to sort out.
+    pub fn scaled(bubble_count: usize, node_count: usize) -> Self {
+        let base = Self::default();
+        let extra_nodes = node_count.saturating_sub(1);
+        let extra_bubbles = bubble_count.saturating_sub(1);
+        Self {
+            min_ticks: base.min_ticks + 10 * extra_nodes as u64,
+            max_velocity: base.max_velocity + 0.1 * extra_bubbles as f32,
+            avg_velocity: base.avg_velocity * node_count.max(1) as f32,
+            stability_window: base.stability_window,
+        }
+    }
+
+    /// Replace any thresholds given explicitly.
+    pub fn with_overrides(
+        self,
+        min_ticks: Option<u64>,
+        max_velocity: Option<f32>,
+        avg_velocity: Option<f32>,
+    ) -> Self {
+        Self {
+            min_ticks: min_ticks.unwrap_or(self.min_ticks),
+            max_velocity: max_velocity.unwrap_or(self.max_velocity),
+            avg_velocity: avg_velocity.unwrap_or(self.avg_velocity),
+            stability_window: self.stability_window,
+        }
+    }
+
+    /// Whether this tick meets the thresholds; settlement also needs it to
+    /// hold for `stability_window` ticks (see `DivisionExperiment::observe_settlement`).
+    pub fn is_settled(&self, ticks_elapsed: u64, max_velocity: f32, avg_velocity: f32) -> bool {
+        ticks_elapsed >= self.min_ticks
+            && max_velocity < self.max_velocity
+            && avg_velocity < self.avg_velocity
+    }
+}
+
+fn default_bubble_drag() -> f32 {
+    8.0 // Settles a bubble within a few breaths of the wave
 }
 
 /// Tracks the state of an active division experiment.
@@ -281,10 +413,16 @@ pub struct DivisionExperiment {
     pub start_tick: u64,
     /// Maximum ticks before forced settlement
     pub max_ticks: u64,
+    /// Settlement criteria, scaled to the experiment when it starts
+    #[serde(default)]
+    pub thresholds: SettlementThresholds,
     /// Accumulated turbulence over the experiment
     pub accumulated_turbulence: f32,
     /// Peak Reynolds number observed
     pub peak_reynolds: f32,
+    /// Linear (Stokes) drag on the bubbles
+    #[serde(default = "default_bubble_drag")]
+    pub bubble_drag: f32,
     /// Original salinity before experiment (for restoration)
     pub original_salinity: f32,
     /// Salinity boost applied for Laminar Streamlining
@@ -300,11 +438,35 @@ pub struct DivisionExperiment {
     pub velocity_sum: f32,
     pub velocity_sum_sq: f32,
     pub velocity_samples: u32,
+    /// Consecutive ticks the thresholds have held with unchanged occupancy
+    #[serde(default)]
+    pub stable_ticks: u64,
+    /// Node occupancy seen on the previous tick
+    #[serde(default)]
+    pub last_occupancy: Vec<u32>,
+    /// Per-tick trace, only recorded when requested
+    #[serde(default)]
+    pub trace: Option<ExperimentTrace>,
+    /// Leave the bubbles in the fluid, named for their node, when it settles
+    #[serde(default)]
+    pub keep_particles: bool,
+    /// Open for the experiment's lifetime, closed when it settles
+    #[serde(skip, default = "Span::none")]
+    pub span: Span,
 }
 
 impl DivisionExperiment {
     pub fn new(problem: DivisionProblem, start_tick: u64) -> Self {
         let wave = StandingWave::new(problem.divisor, 5.0); // Strong wave amplitude
+        let span = tracing::info_span!(
+            "division_experiment",
+            experiment_id = %problem.id,
+            dividend = problem.dividend,
+            divisor = problem.divisor,
+            quotient = tracing::field::Empty,
+            remainder = tracing::field::Empty,
+            settled_by = tracing::field::Empty,
+        );
 
         Self {
             problem,
@@ -313,8 +475,10 @@ impl DivisionExperiment {
             settled: false,
             start_tick,
             max_ticks: 300, // 5 seconds at 60Hz
+            thresholds: SettlementThresholds::default(),
             accumulated_turbulence: 0.0,
             peak_reynolds: 0.0,
+            bubble_drag: default_bubble_drag(),
             original_salinity: 0.0,
             salinity_boost: 0.0,
             velocity_history: Vec::with_capacity(50),
@@ -323,9 +487,19 @@ impl DivisionExperiment {
             velocity_sum: 0.0,
             velocity_sum_sq: 0.0,
             velocity_samples: 0,
+            stable_ticks: 0,
+            last_occupancy: Vec::new(),
+            trace: None,
+            keep_particles: false,
+            span,
         }
     }
 
+    /// Start recording a per-tick trace (occupancy per node).
+    pub fn enable_trace(&mut self) {
+        self.trace = Some(ExperimentTrace::for_division(self));
+    }
+
     /// Record velocity sample for jitter analysis.
     /// Maintains a rolling window of the last `jitter_window` samples.
     /// Also tracks peak jitter for detecting transient micro-cavitation.
@@ -372,6 +546,31 @@ impl DivisionExperiment {
         (mean, sigma)
     }
 
+    /// Count towards settlement: `meets_thresholds` for this tick, with the
+    /// bubbles at `bubble_depths`. The streak restarts whenever the thresholds
+    /// lapse or a bubble changes node. Returns true once it spans the window.
+    pub fn observe_settlement(&mut self, meets_thresholds: bool, bubble_depths: &[f32]) -> bool {
+        self.wave.update_occupancy(bubble_depths);
+        let occupancy_held = self.wave.node_occupancy == self.last_occupancy;
+        self.last_occupancy.clone_from(&self.wave.node_occupancy);
+
+        self.stable_ticks = match (meets_thresholds, occupancy_held) {
+            (false, _) => 0,
+            (true, true) => self.stable_ticks + 1,
+            (true, false) => 1,
+        };
+        self.stable_ticks >= self.thresholds.stability_window
+    }
+
+    /// How much to trust a result finalized now (see `DivisionResult::confidence`).
+    pub fn confidence(&self) -> f32 {
+        let window = self.thresholds.stability_window.max(1) as f32;
+        let stability = (self.stable_ticks as f32 / window).min(1.0);
+        let at_nodes = self.wave.node_occupancy.iter().sum::<u32>() as f32
+            / self.bubble_ids.len().max(1) as f32;
+        stability * at_nodes.min(1.0)
+    }
+
     /// Check if experiment has timed out.
     pub fn is_timed_out(&self, current_tick: u64) -> bool {
         current_tick - self.start_tick >= self.max_ticks
@@ -380,8 +579,6 @@ impl DivisionExperiment {
     /// Calculate the remainder from accumulated turbulence.
     /// The key insight: turbulence energy correlates with the remainder!
     pub fn calculate_remainder(&self) -> f32 {
-        let expected_remainder = self.problem.dividend % self.problem.divisor;
-
         // Turbulence-based remainder estimation
         // When bubbles can't fit evenly into nodes, they jostle → turbulence
         // More leftover bubbles = more turbulence
@@ -421,15 +618,46 @@ mod tests {
 
         // Initially, nodes should attract
         let node = wave.node_positions[0];
-        let force_before = wave.force_at_depth(node + 0.05);
+        let force_before = wave.force_at_depth(node - 0.05);
         assert!(force_before > 0.0, "Should attract toward node");
 
         // Saturate the first node
         wave.node_occupancy[0] = 2;
 
         // Now the same position should be repelled
-        let force_after = wave.force_at_depth(node + 0.05);
+        let force_after = wave.force_at_depth(node - 0.05);
         assert!(force_after < 0.0, "Should repel from saturated node");
+
+        // ...but the two bubbles that fill it are held, not thrown out
+        let force_on_occupant = wave.force_on(node - 0.05, wave.occupied_node(node - 0.05));
+        assert!(force_on_occupant > 0.0, "Should keep its own bubbles");
+    }
+
+    #[test]
+    fn test_momentary_lull_does_not_settle_division() {
+        let mut experiment = DivisionExperiment::new(DivisionProblem::new(6.0, 3.0), 0);
+        let window = experiment.thresholds.stability_window;
+        let at_nodes = [0.17, 0.16, 0.5, 0.49, 0.83, 0.84];
+        let mut drifted = at_nodes;
+        drifted[0] = 0.4; // Slipped into the middle node
+
+        // A lull that lapses, then one broken by a bubble changing node
+        for _ in 1..window {
+            assert!(!experiment.observe_settlement(true, &at_nodes));
+        }
+        assert!(!experiment.observe_settlement(false, &at_nodes));
+        for _ in 1..window {
+            assert!(!experiment.observe_settlement(true, &at_nodes));
+        }
+        assert!(!experiment.observe_settlement(true, &drifted));
+        assert!(experiment.confidence() < 0.5);
+
+        // Held still for the whole window from there: settled
+        for _ in 2..window {
+            assert!(!experiment.observe_settlement(true, &drifted));
+        }
+        assert!(experiment.observe_settlement(true, &drifted));
+        assert!(experiment.confidence() > 0.99);
     }
 
     #[test]
@@ -0,0 +1,228 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::fluid::ConceptFluid;
+use super::heatmap::HeatmapWeight;
+use super::salinity::SalinityBand;
+
+/// Aggregate statistics over the whole fluid, cheap enough for dashboards.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct FluidStats {
+    pub tick_count: u64,
+    pub concept_count: usize,
+    /// Concept counts keyed by `Concept::status()`
+    pub concepts_by_status: BTreeMap<&'static str, usize>,
+    pub mean_velocity: f32,
+    pub max_velocity: f32,
+    /// Same Reynolds number the simulation uses for turbulence onset
+    pub reynolds_number: f32,
+    pub turbulence_energy: f32,
+    pub total_integration: f32,
+    pub salinity: f32,
+    pub salinity_band: SalinityBand,
+    pub ore_count: usize,
+    pub ocean_floor_pressure: f32,
+    pub trait_count: usize,
+    pub vent_count: usize,
+    pub continent_count: usize,
+    pub tectonic_shifts: u32,
+    pub is_frozen: bool,
+    pub is_turbulent: bool,
+    pub division_experiment: Option<DivisionExperimentSummary>,
+    pub consensus_experiment: Option<ConsensusExperimentSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DivisionExperimentSummary {
+    pub dividend: f32,
+    pub divisor: f32,
+    pub bubble_count: usize,
+    pub ticks_elapsed: u64,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ConsensusExperimentSummary {
+    pub position_a: String,
+    pub position_b: String,
+    pub probe_count: usize,
+    pub current_certainty: f32,
+    pub ticks_elapsed: u64,
+}
+
+impl ConceptFluid {
+    /// Compute aggregate statistics in a single pass over the concepts.
+    pub fn stats(&self) -> FluidStats {
+        let mut concepts_by_status = BTreeMap::new();
+        let mut velocity_sum = 0.0;
+        let mut max_velocity: f32 = 0.0;
+
+        for concept in self.concepts.values() {
+            *concepts_by_status.entry(concept.status()).or_insert(0) += 1;
+            let speed = concept.velocity.abs();
+            velocity_sum += speed;
+            max_velocity = max_velocity.max(speed);
+        }
+
+        let mean_velocity = velocity_sum / self.concepts.len().max(1) as f32;
+
+        let division_experiment =
+            self.active_experiment
+                .as_ref()
+                .map(|exp| DivisionExperimentSummary {
+                    dividend: exp.problem.dividend,
+                    divisor: exp.problem.divisor,
+                    bubble_count: exp.bubble_ids.len(),
+                    ticks_elapsed: self.tick_count.saturating_sub(exp.start_tick),
+                });
+
+        let consensus_experiment =
+            self.get_consensus_experiment()
+                .map(|exp| ConsensusExperimentSummary {
+                    position_a: exp.vent_a.position.clone(),
+                    position_b: exp.vent_b.position.clone(),
+                    probe_count: exp.probe_ids.len(),
+                    current_certainty: exp.certainty(),
+                    ticks_elapsed: self.tick_count.saturating_sub(exp.start_tick),
+                });
+
+        FluidStats {
+            tick_count: self.tick_count,
+            concept_count: self.concepts.len(),
+            concepts_by_status,
+            mean_velocity,
+            max_velocity,
+            reynolds_number: self.fluid_reynolds(),
+            turbulence_energy: self.turbulence_energy,
+            total_integration: self.total_integration,
+            salinity: self.salinity,
+            salinity_band: self.salinity_band,
+            ore_count: self.ore_deposits.len(),
+            ocean_floor_pressure: self.ocean_floor_pressure,
+            trait_count: self.atmosphere.len(),
+            vent_count: self.core_truths.len(),
+            continent_count: self.continents.len(),
+            tectonic_shifts: self.tectonic_shifts,
+            is_frozen: self.is_frozen,
+            is_turbulent: self.is_turbulent,
+            division_experiment,
+            consensus_experiment,
+        }
+    }
+
+    /// Integration held by concepts still in the fluid, summed over `steps`
+    /// equal depth buckets from surface to floor. Shows whether understanding
+    /// sits in surface thoughts being worked through or in buried material.
+    pub fn integration_profile(&self, steps: usize) -> Vec<f32> {
+        self.layer_histogram(steps, HeatmapWeight::Integration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::ore::{OreType, PreciousOre};
+    use crate::simulation::traits::CharacterTrait;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_stats_fixture() {
+        let mut fluid = ConceptFluid::default();
+        let rising = fluid.add_concept("rising".to_string(), 0.2, 0.5);
+        let sinking = fluid.add_concept("sinking".to_string(), 0.8, 0.5);
+        let floating = fluid.add_concept("floating".to_string(), 0.5, 0.5);
+        fluid.get_concept_mut(rising).unwrap().velocity = -0.4;
+        fluid.get_concept_mut(sinking).unwrap().velocity = 0.2;
+        fluid.get_concept_mut(floating).unwrap().velocity = 0.0;
+
+        let ore_id = Uuid::new_v4();
+        fluid.ore_deposits.push(PreciousOre {
+            name: "despair_ore_1".to_string(),
+            ore_type: OreType::Code,
+            density: 0.9,
+            depth: 0.9,
+            formed_from: ore_id,
+            vent_cycles: 3,
+            integration_value: 2.0,
+        });
+        fluid.ocean_floor_pressure = 1.8;
+        fluid
+            .atmosphere
+            .push(CharacterTrait::new("calm".to_string(), 1.5, ore_id));
+        fluid.tick_count = 42;
+
+        let stats = fluid.stats();
+
+        assert_eq!(stats.tick_count, 42);
+        assert_eq!(stats.concept_count, 3);
+        assert_eq!(stats.concepts_by_status["rising"], 1);
+        assert_eq!(stats.concepts_by_status["sinking"], 1);
+        assert_eq!(stats.concepts_by_status["floating"], 1);
+        // mean |v| = (0.4 + 0.2 + 0.0) / 3 = 0.2; Re = 0.2 / 0.5 viscosity = 0.4
+        assert!((stats.mean_velocity - 0.2).abs() < 1e-6);
+        assert!((stats.max_velocity - 0.4).abs() < 1e-6);
+        assert!((stats.reynolds_number - 0.4).abs() < 1e-6);
+        assert_eq!(stats.ore_count, 1);
+        assert!((stats.ocean_floor_pressure - 1.8).abs() < 1e-6);
+        assert_eq!(stats.trait_count, 1);
+        assert!(stats.division_experiment.is_none());
+        assert!(stats.consensus_experiment.is_none());
+    }
+
+    #[test]
+    fn test_stats_counts_after_mineralization() {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_core_truth("warmth".to_string(), 1.0, 0.9, 0.3);
+        let despair = fluid.add_concept("despair".to_string(), 0.9, 0.5);
+        fluid.add_concept("hope".to_string(), 0.2, 0.5);
+
+        // A dark thought mineralizes on its third pass through the plume
+        for _ in 0..3 {
+            for layer in [0.9, 0.4] {
+                fluid.get_concept_mut(despair).unwrap().layer = layer;
+                fluid.update(1.0 / 60.0);
+            }
+        }
+
+        let stats = fluid.stats();
+        assert_eq!(stats.concept_count, 2);
+        assert_eq!(stats.vent_count, 1);
+        assert_eq!(stats.ore_count, 1);
+        assert_eq!(stats.ore_count, fluid.ore_deposits.len());
+        assert_eq!(stats.continent_count, 0);
+        assert_eq!(stats.trait_count, 0);
+        assert_eq!(stats.tick_count, 6);
+        assert!(!stats.is_frozen);
+    }
+
+    #[test]
+    fn test_integration_profile_finds_deep_wisdom() {
+        let mut fluid = ConceptFluid::default();
+        for (name, layer, integration) in [
+            ("buried_grief", 0.95, 4.0),
+            ("old_lesson", 0.85, 3.0),
+            ("floor", 1.0, 1.0),
+            ("todo", 0.05, 0.2),
+            ("errand", 0.15, 0.1),
+        ] {
+            let id = fluid.add_concept(name.to_string(), 0.5, 0.5);
+            let concept = fluid.get_concept_mut(id).unwrap();
+            concept.layer = layer;
+            concept.integration = integration;
+        }
+        let gone = fluid.add_concept("gone".to_string(), 0.5, 0.5);
+        let concept = fluid.get_concept_mut(gone).unwrap();
+        concept.integration = 10.0;
+        concept.has_evaporated = true;
+
+        let profile = fluid.integration_profile(10);
+        assert_eq!(profile.len(), 10);
+        assert!((profile[9] - 5.0).abs() < 1e-6);
+        assert!((profile[8] - 3.0).abs() < 1e-6);
+        let surface: f32 = profile[..5].iter().sum();
+        let deep: f32 = profile[5..].iter().sum();
+        assert!((surface - 0.3).abs() < 1e-6);
+        assert!(deep > 20.0 * surface, "{:?}", profile);
+    }
+}
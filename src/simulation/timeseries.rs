@@ -0,0 +1,227 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::str::FromStr;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::fluid::ConceptFluid;
+
+/// Metrics that can be sampled into the in-memory time series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Metric {
+    Salinity,
+    TotalIntegration,
+    ConceptCount,
+    TurbulenceEnergy,
+    AvgVelocity,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 5] = [
+        Metric::Salinity,
+        Metric::TotalIntegration,
+        Metric::ConceptCount,
+        Metric::TurbulenceEnergy,
+        Metric::AvgVelocity,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Metric::Salinity => "salinity",
+            Metric::TotalIntegration => "total_integration",
+            Metric::ConceptCount => "concept_count",
+            Metric::TurbulenceEnergy => "turbulence_energy",
+            Metric::AvgVelocity => "avg_velocity",
+        }
+    }
+
+    /// Read this metric's current value from the fluid.
+    fn read(&self, fluid: &ConceptFluid) -> f32 {
+        match self {
+            Metric::Salinity => fluid.salinity,
+            Metric::TotalIntegration => fluid.total_integration,
+            Metric::ConceptCount => fluid.concepts.len() as f32,
+            Metric::TurbulenceEnergy => fluid.turbulence_energy,
+            Metric::AvgVelocity => {
+                fluid
+                    .concepts
+                    .values()
+                    .map(|c| c.velocity.abs())
+                    .sum::<f32>()
+                    / fluid.concepts.len().max(1) as f32
+            }
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Metric::ALL
+            .into_iter()
+            .find(|m| m.as_str() == s)
+            .ok_or_else(|| format!("Unknown metric '{}'", s))
+    }
+}
+
+/// One sampled row: the tick plus a value per recorded metric.
+#[derive(Debug, Clone)]
+struct Sample {
+    tick: u64,
+    values: Vec<f32>,
+}
+
+/// Bounded ring buffer of periodically sampled fluid metrics.
+/// Lives alongside the fluid but is never part of a snapshot.
+#[derive(Debug, Clone)]
+pub struct TimeSeries {
+    /// Sample every N ticks
+    pub sample_interval: u64,
+    /// Oldest samples are evicted beyond this many
+    pub max_samples: usize,
+    /// Which metrics are recorded (column order of each sample)
+    metrics: Vec<Metric>,
+    samples: VecDeque<Sample>,
+}
+
+impl Default for TimeSeries {
+    /// One sample per second at 60Hz, retained for an hour.
+    fn default() -> Self {
+        Self::new(60, 3600, Metric::ALL.to_vec())
+    }
+}
+
+/// Aligned arrays of sampled values, one per requested metric.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TimeSeriesQuery {
+    pub ticks: Vec<u64>,
+    pub series: BTreeMap<&'static str, Vec<f32>>,
+}
+
+impl TimeSeries {
+    pub fn new(sample_interval: u64, max_samples: usize, metrics: Vec<Metric>) -> Self {
+        Self {
+            sample_interval: sample_interval.max(1),
+            max_samples,
+            metrics,
+            samples: VecDeque::with_capacity(max_samples.min(4096)),
+        }
+    }
+
+    /// Metrics this recorder is sampling.
+    pub fn metrics(&self) -> &[Metric] {
+        &self.metrics
+    }
+
+    /// Number of samples currently retained.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Record a sample from the fluid if the tick falls on the interval.
+    fn record(&mut self, fluid: &ConceptFluid) {
+        if self.max_samples == 0 || !fluid.tick_count.is_multiple_of(self.sample_interval) {
+            return;
+        }
+
+        let values = self.metrics.iter().map(|m| m.read(fluid)).collect();
+        if self.samples.len() >= self.max_samples {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(Sample {
+            tick: fluid.tick_count,
+            values,
+        });
+    }
+
+    /// Extract aligned series for the given metrics from `since_tick` onwards.
+    /// Returns an error naming the first metric that isn't being recorded.
+    pub fn query(&self, metrics: &[Metric], since_tick: u64) -> Result<TimeSeriesQuery, Metric> {
+        let columns: Vec<(Metric, usize)> = metrics
+            .iter()
+            .map(|m| {
+                self.metrics
+                    .iter()
+                    .position(|recorded| recorded == m)
+                    .map(|idx| (*m, idx))
+                    .ok_or(*m)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let rows: Vec<&Sample> = self
+            .samples
+            .iter()
+            .filter(|s| s.tick >= since_tick)
+            .collect();
+
+        let ticks = rows.iter().map(|s| s.tick).collect();
+        let series = columns
+            .into_iter()
+            .map(|(metric, idx)| {
+                (
+                    metric.as_str(),
+                    rows.iter().map(|s| s.values[idx]).collect(),
+                )
+            })
+            .collect();
+
+        Ok(TimeSeriesQuery { ticks, series })
+    }
+}
+
+impl ConceptFluid {
+    /// Sample the configured metrics into the time series (called once per tick).
+    pub fn record_timeseries_sample(&mut self) {
+        let mut timeseries = std::mem::take(&mut self.timeseries);
+        timeseries.record(self);
+        self.timeseries = timeseries;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeseries_sampling_and_eviction() {
+        let mut fluid = ConceptFluid::default();
+        fluid.add_concept("drift".to_string(), 0.3, 0.5);
+        fluid.timeseries = TimeSeries::new(10, 50, Metric::ALL.to_vec());
+
+        for _ in 0..1000 {
+            fluid.update(1.0 / 60.0);
+            fluid.record_timeseries_sample();
+        }
+
+        // 100 samples taken, only the newest 50 retained
+        assert_eq!(fluid.timeseries.len(), 50);
+
+        let result = fluid
+            .timeseries
+            .query(&[Metric::Salinity, Metric::ConceptCount], 0)
+            .unwrap();
+        assert_eq!(result.ticks.first(), Some(&510));
+        assert_eq!(result.ticks.last(), Some(&1000));
+        assert!(result.ticks.windows(2).all(|w| w[1] - w[0] == 10));
+        assert_eq!(result.series["salinity"].len(), 50);
+        assert!(result.series["concept_count"].iter().all(|&c| c == 1.0));
+
+        let recent = fluid.timeseries.query(&[Metric::Salinity], 901).unwrap();
+        assert_eq!(recent.ticks.len(), 10);
+    }
+
+    #[test]
+    fn test_timeseries_rejects_unrecorded_metric() {
+        let series = TimeSeries::new(1, 10, vec![Metric::Salinity]);
+        assert_eq!(
+            series.query(&[Metric::AvgVelocity], 0).unwrap_err(),
+            Metric::AvgVelocity
+        );
+    }
+}
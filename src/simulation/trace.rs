@@ -0,0 +1,203 @@
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::consensus_reactor::ConsensusExperiment;
+use super::standing_wave::DivisionExperiment;
+
+/// Maximum rows kept per trace (~68s at 60Hz); older rows are dropped.
+pub const MAX_TRACE_ROWS: usize = 4096;
+/// Maximum completed traces kept; the oldest is evicted first.
+pub const MAX_STORED_TRACES: usize = 64;
+
+/// One per-tick sample of an experiment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceRow {
+    pub tick: u64,
+    pub avg_velocity: f32,
+    pub velocity_sigma: f32,
+    /// Accumulated turbulence (division) or jitter (consensus)
+    pub accumulation: f32,
+    /// Per-node occupancy (division) or per-probe depth (consensus)
+    pub values: Vec<f32>,
+}
+
+/// Bounded per-tick trace of an experiment, exportable as CSV.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentTrace {
+    /// Header for the accumulation column
+    pub accumulation_label: String,
+    /// Headers for the per-node / per-probe columns
+    pub value_labels: Vec<String>,
+    pub rows: VecDeque<TraceRow>,
+    /// Rows dropped because the buffer was full
+    pub dropped_rows: u64,
+}
+
+impl ExperimentTrace {
+    pub fn new(accumulation_label: &str, value_labels: Vec<String>) -> Self {
+        Self {
+            accumulation_label: accumulation_label.to_string(),
+            value_labels,
+            rows: VecDeque::new(),
+            dropped_rows: 0,
+        }
+    }
+
+    /// Trace columns for a division experiment: one occupancy column per node.
+    pub fn for_division(experiment: &DivisionExperiment) -> Self {
+        let labels = (0..experiment.wave.node_count())
+            .map(|i| format!("node_{}", i))
+            .collect();
+        Self::new("accumulated_turbulence", labels)
+    }
+
+    /// Trace columns for a consensus experiment: one depth column per probe.
+    pub fn for_consensus(experiment: &ConsensusExperiment) -> Self {
+        let labels = (0..experiment.probe_ids.len())
+            .map(|i| format!("probe_{}", i))
+            .collect();
+        Self::new("accumulated_jitter", labels)
+    }
+
+    pub fn push(&mut self, row: TraceRow) {
+        if self.rows.len() >= MAX_TRACE_ROWS {
+            self.rows.pop_front();
+            self.dropped_rows += 1;
+        }
+        self.rows.push_back(row);
+    }
+
+    pub fn header(&self) -> String {
+        let mut columns = vec![
+            "tick".to_string(),
+            "avg_velocity".to_string(),
+            "velocity_sigma".to_string(),
+            self.accumulation_label.clone(),
+        ];
+        columns.extend(self.value_labels.iter().cloned());
+        columns.join(",")
+    }
+
+    /// CSV lines (header first), each terminated by a newline.
+    pub fn csv_lines(&self) -> impl Iterator<Item = String> + '_ {
+        std::iter::once(format!("{}\n", self.header())).chain(self.rows.iter().map(|row| {
+            let mut line = format!(
+                "{},{},{},{}",
+                row.tick, row.avg_velocity, row.velocity_sigma, row.accumulation
+            );
+            for value in &row.values {
+                line.push(',');
+                line.push_str(&value.to_string());
+            }
+            line.push('\n');
+            line
+        }))
+    }
+
+    pub fn to_csv(&self) -> String {
+        self.csv_lines().collect()
+    }
+}
+
+/// Completed experiment traces, keyed by division experiment id or consensus ore id.
+#[derive(Debug, Clone, Default)]
+pub struct TraceStore {
+    traces: VecDeque<(Uuid, ExperimentTrace)>,
+}
+
+impl TraceStore {
+    pub fn insert(&mut self, id: Uuid, trace: ExperimentTrace) {
+        if self.traces.len() >= MAX_STORED_TRACES {
+            self.traces.pop_front();
+        }
+        self.traces.push_back((id, trace));
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<&ExperimentTrace> {
+        self.traces
+            .iter()
+            .find(|(trace_id, _)| *trace_id == id)
+            .map(|(_, trace)| trace)
+    }
+
+    pub fn len(&self) -> usize {
+        self.traces.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.traces.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulation::ConceptFluid;
+
+    #[test]
+    fn test_division_trace_covers_every_tick() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.start_division_experiment(7.0, 3.0);
+        fluid.active_experiment.as_mut().unwrap().enable_trace();
+
+        let result = loop {
+            fluid.update(1.0 / 60.0);
+            if let Some(result) = fluid.check_experiment_settlement() {
+                break result;
+            }
+        };
+
+        assert_eq!(result.id, id);
+        let trace = fluid.traces.get(id).expect("trace should be stored");
+        assert_eq!(
+            trace.header(),
+            "tick,avg_velocity,velocity_sigma,accumulated_turbulence,node_0,node_1,node_2"
+        );
+        assert_eq!(trace.rows.len() as u64, result.ticks_to_settle);
+
+        let csv = trace.to_csv();
+        assert_eq!(csv.lines().count() as u64, result.ticks_to_settle + 1);
+        assert!(csv.lines().skip(1).all(|line| line.split(',').count() == 7));
+    }
+
+    #[test]
+    fn test_consensus_trace_keyed_by_ore() {
+        let mut fluid = ConceptFluid::default();
+        fluid.start_consensus_experiment("order".to_string(), 1.0, "chaos".to_string(), 1.0);
+        let exp = fluid.consensus_reactor.active_experiment.as_mut().unwrap();
+        exp.enable_trace();
+        let start_tick = exp.start_tick;
+
+        let ore = loop {
+            fluid.update(1.0 / 60.0);
+            if let Some(ore) = fluid.check_consensus_crystallization() {
+                break ore;
+            }
+        };
+
+        let trace = fluid.traces.get(ore.id).expect("trace should be stored");
+        assert!(
+            trace
+                .header()
+                .starts_with("tick,avg_velocity,velocity_sigma,accumulated_jitter,probe_0,")
+        );
+        assert_eq!(trace.value_labels.len(), 8);
+        assert_eq!(
+            trace.rows.len() as u64,
+            fluid.tick_count - start_tick,
+            "one row per tick the experiment ran"
+        );
+
+        // Untraced experiments stay cheap
+        fluid.start_consensus_experiment("a".to_string(), 1.0, "b".to_string(), 1.0);
+        let ore = loop {
+            fluid.update(1.0 / 60.0);
+            if let Some(ore) = fluid.check_consensus_crystallization() {
+                break ore;
+            }
+        };
+        assert!(fluid.traces.get(ore.id).is_none());
+        assert_eq!(fluid.traces.len(), 1);
+    }
+}
@@ -1,6 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-use super::concept::ConceptId;
+use super::concept::{Concept, ConceptId};
+
+fn default_density() -> f32 {
+    0.5
+}
+
+fn default_area() -> f32 {
+    1.0
+}
 
 /// Evaporated concepts become permanent character traits.
 /// These exist in the "atmosphere" above the fluid and can
@@ -12,6 +20,12 @@ pub struct CharacterTrait {
     pub integration: f32,
     /// Which concept evaporated to form this
     pub formed_from: ConceptId,
+    /// Density of that concept, restored on re-immersion
+    #[serde(default = "default_density")]
+    pub density: f32,
+    /// Surface area of that concept, restored on re-immersion
+    #[serde(default = "default_area")]
+    pub area: f32,
 }
 
 impl CharacterTrait {
@@ -20,6 +34,19 @@ impl CharacterTrait {
             name,
             integration,
             formed_from,
+            density: default_density(),
+            area: default_area(),
+        }
+    }
+
+    /// The trait a concept leaves behind when it evaporates.
+    pub fn from_concept(concept: &Concept) -> Self {
+        Self {
+            name: concept.name.clone(),
+            integration: concept.integration,
+            formed_from: concept.id,
+            density: concept.density,
+            area: concept.area,
         }
     }
 }
@@ -1,10 +1,17 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use tokio::sync::{RwLock, broadcast, mpsc};
+use tracing::warn;
 
-use super::commands::Command;
+use super::commands::{CommandSender, TracedCommand};
+use super::dispatch::DispatchRules;
+use super::estimator::{DensityEstimator, PassthroughEstimator};
 use super::events::FluidEvent;
-use crate::simulation::ConceptFluid;
+use super::health::{IngestStatus, KafkaStatus, RedisStatus};
+use super::minds::Minds;
+use super::schedules::Schedules;
+use super::timing::TickTiming;
+use crate::simulation::{ConceptFluid, Heatmap};
 
 /// Shared application state containing the fluid simulation and communication channels.
 pub struct AppState {
@@ -12,36 +19,195 @@ pub struct AppState {
     pub fluid: Arc<RwLock<ConceptFluid>>,
 
     /// Channel for sending commands to the simulation loop
-    pub command_tx: mpsc::Sender<Command>,
+    pub command_tx: CommandSender,
 
     /// Channel for subscribing to real-time events
     pub event_tx: broadcast::Sender<FluidEvent>,
+
+    /// Derives density/area for injects that omit density (replaceable by embedders)
+    pub density_estimator: Arc<dyn DensityEstimator>,
+
+    /// Redis bridge status for `/health` (None when the bridge is not running)
+    pub redis: Option<Arc<RedisStatus>>,
+
+    /// Kafka producer status for `/health` (None when the producer is not running)
+    pub kafka: Option<Arc<KafkaStatus>>,
+
+    /// Status of each configured ingest source, for `/health`
+    pub ingest: Vec<Arc<IngestStatus>>,
+
+    /// Rules routing surface breakthroughs by concept name
+    pub dispatch_rules: Arc<DispatchRules>,
+
+    /// Recurring commands run by the scheduler task (empty unless configured)
+    pub schedules: Arc<Schedules>,
+
+    /// Isolated minds served under `/minds/{id}/...` (this state is the
+    /// default mind)
+    pub minds: Arc<Minds>,
+
+    /// Tick timing recorded by the simulation loop, for `/timing`
+    pub timing: Arc<TickTiming>,
+
+    /// Depth × time heatmap recorded by the simulation loop, for `/heatmap`
+    pub heatmap: Arc<Mutex<Heatmap>>,
+}
+
+/// Buffer sizes for the command and event channels.
+///
+/// A larger command buffer absorbs bursts of injects before API handlers start
+/// waiting on the simulation loop. A larger event buffer tolerates slower
+/// SSE/WebSocket clients before they lag and miss events, at the cost of
+/// memory: every slot holds a `FluidEvent` until all subscribers have seen it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelConfig {
+    /// Commands queued for the simulation loop (mpsc)
+    pub command_capacity: usize,
+    /// Events retained for subscribers (broadcast)
+    pub event_capacity: usize,
+}
+
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        Self {
+            command_capacity: 64,
+            event_capacity: 256,
+        }
+    }
+}
+
+impl ChannelConfig {
+    /// Read capacities from `COMMAND_CHANNEL_CAPACITY` and `EVENT_CHANNEL_CAPACITY`,
+    /// falling back to the defaults when unset or invalid.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            command_capacity: capacity_from_env(
+                "COMMAND_CHANNEL_CAPACITY",
+                defaults.command_capacity,
+            ),
+            event_capacity: capacity_from_env("EVENT_CHANNEL_CAPACITY", defaults.event_capacity),
+        }
+    }
+}
+
+fn capacity_from_env(var: &str, default: usize) -> usize {
+    match std::env::var(var) {
+        Ok(value) => match value.parse::<usize>() {
+            Ok(capacity) if capacity > 0 => capacity,
+            _ => {
+                warn!("Ignoring invalid {}={:?}, using {}", var, value, default);
+                default
+            }
+        },
+        Err(_) => default,
+    }
 }
 
 /// Channels passed to the simulation loop task.
 pub struct SimulationChannels {
-    pub command_rx: mpsc::Receiver<Command>,
+    pub command_rx: mpsc::Receiver<TracedCommand>,
     pub event_tx: broadcast::Sender<FluidEvent>,
+    pub timing: Arc<TickTiming>,
+    pub heatmap: Arc<Mutex<Heatmap>>,
 }
 
 impl AppState {
-    /// Create a new AppState with the given fluid.
+    /// Create a new AppState with the given fluid and default channel capacities.
     /// Returns the state and the channels needed by the simulation loop.
     pub fn new(fluid: ConceptFluid) -> (Self, SimulationChannels) {
-        let (command_tx, command_rx) = mpsc::channel(64);
-        let (event_tx, _) = broadcast::channel(256);
+        Self::with_config(fluid, ChannelConfig::default())
+    }
+
+    /// Create a new AppState with explicit channel capacities. A zero
+    /// capacity is raised to 1, the smallest the channels accept.
+    pub fn with_config(fluid: ConceptFluid, config: ChannelConfig) -> (Self, SimulationChannels) {
+        let config = ChannelConfig {
+            command_capacity: config.command_capacity.max(1),
+            event_capacity: config.event_capacity.max(1),
+        };
+        let (command_tx, command_rx) = mpsc::channel(config.command_capacity);
+        let (event_tx, _) = broadcast::channel(config.event_capacity);
+        let timing = Arc::new(TickTiming::default());
+        let heatmap = Arc::new(Mutex::new(Heatmap::default()));
 
         let state = Self {
             fluid: Arc::new(RwLock::new(fluid)),
-            command_tx,
+            command_tx: CommandSender::new(command_tx),
             event_tx: event_tx.clone(),
+            density_estimator: Arc::new(PassthroughEstimator),
+            redis: None,
+            kafka: None,
+            ingest: Vec::new(),
+            dispatch_rules: Arc::new(DispatchRules::default()),
+            schedules: Arc::new(Schedules::default()),
+            minds: Arc::new(Minds::new(config)),
+            timing: timing.clone(),
+            heatmap: heatmap.clone(),
         };
 
         let channels = SimulationChannels {
             command_rx,
             event_tx,
+            timing,
+            heatmap,
         };
 
         (state, channels)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Command;
+
+    #[test]
+    fn test_zero_capacities_become_one() {
+        let config = ChannelConfig {
+            command_capacity: 0,
+            event_capacity: 0,
+        };
+        let (state, _channels) = AppState::with_config(ConceptFluid::default(), config);
+        state.command_tx.try_send(Command::Thaw).unwrap();
+        assert!(state.command_tx.try_send(Command::Thaw).is_err());
+        assert_eq!(
+            state.minds.channel_config,
+            ChannelConfig {
+                command_capacity: 1,
+                event_capacity: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_custom_capacities_accept_full_burst() {
+        let config = ChannelConfig {
+            command_capacity: 8,
+            event_capacity: 4,
+        };
+        let (state, mut channels) = AppState::with_config(ConceptFluid::default(), config);
+
+        // A burst up to capacity is buffered without waiting on the loop
+        for _ in 0..config.command_capacity {
+            state.command_tx.try_send(Command::Thaw).unwrap();
+        }
+        assert!(state.command_tx.try_send(Command::Thaw).is_err());
+
+        let mut received = 0;
+        while channels.command_rx.try_recv().is_ok() {
+            received += 1;
+        }
+        assert_eq!(received, config.command_capacity);
+
+        // Subscribers lag once the event buffer is exceeded
+        let mut rx = state.event_tx.subscribe();
+        for _ in 0..config.event_capacity + 1 {
+            state.event_tx.send(FluidEvent::Thaw).unwrap();
+        }
+        assert!(matches!(
+            rx.try_recv(),
+            Err(broadcast::error::TryRecvError::Lagged(1))
+        ));
+    }
+}
@@ -1,23 +1,103 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{
+    self,
+    error::{SendError, TrySendError},
+};
 use tokio::sync::oneshot;
+use tracing::Span;
 use uuid::Uuid;
 
+use super::estimator::Metadata;
+use super::events::FluidEvent;
+use crate::simulation::{
+    ConceptDimensions, ConceptFilter, ConceptLink, ConceptSize, ConsensusOre, FalloffProfile,
+    FlashHealOutcome, FluidError, QualityThresholds, QualityThresholdsPatch, Rule, Spotlight,
+    StandingWave,
+};
+
 /// Commands sent from API handlers to the simulation loop.
 /// These are "Willful Acts" - deliberate interventions in the fluid.
 #[derive(Debug)]
 pub enum Command {
-    /// Inject a new concept into the fluid
+    /// Inject a new concept into the fluid; responds with its id and the
+    /// size it was given
     Inject {
         name: String,
         density: f32,
-        area: f32,
-        response_tx: oneshot::Sender<Uuid>,
+        size: ConceptSize,
+        /// Kept on the concept
+        metadata: Metadata,
+        /// Starting layer (None: the layer matching its density)
+        initial_layer: Option<f32>,
+        /// Starting velocity (None: at rest)
+        initial_velocity: Option<f32>,
+        /// Buoyancy it settles by (None: its density)
+        initial_buoyancy: Option<f32>,
+        /// Urgent interruption: thaws a frozen fluid on arrival
+        priority: bool,
+        response_tx: oneshot::Sender<(Uuid, ConceptDimensions)>,
+    },
+
+    /// Rename a concept in place; responds with the old name (None if unknown)
+    Rename {
+        concept_id: Uuid,
+        name: String,
+        response_tx: oneshot::Sender<Option<String>>,
     },
 
     /// Apply ballast to force benthic descent
-    Ballast { concept_id: Uuid, weight_delta: f32 },
+    Ballast {
+        concept_id: Uuid,
+        weight_delta: f32,
+        response_tx: oneshot::Sender<Result<(), FluidError>>,
+    },
+
+    /// Push a concept back under to stop acting on it
+    Submerge {
+        concept_id: Uuid,
+        target_layer: f32,
+        ballast: f32,
+        response_tx: oneshot::Sender<Result<(), FluidError>>,
+    },
+
+    /// Remove a single concept; responds with its name
+    RemoveConcept {
+        concept_id: Uuid,
+        response_tx: oneshot::Sender<Result<String, FluidError>>,
+    },
+
+    /// Focus attention on a depth window; responds with how many concepts
+    /// it lights up, or why no more spotlights fit
+    FocusAttention {
+        spotlight: Spotlight,
+        response_tx: oneshot::Sender<Result<usize, String>>,
+    },
+
+    /// Link two concepts; responds with the link's index
+    Link {
+        link: ConceptLink,
+        response_tx: oneshot::Sender<Result<usize, FluidError>>,
+    },
+
+    /// Remove a link by index; responds with it
+    Unlink {
+        index: usize,
+        response_tx: oneshot::Sender<Result<ConceptLink, FluidError>>,
+    },
+
+    /// Remove every concept a filter matches; responds with their ids
+    Prune {
+        filter: ConceptFilter,
+        response_tx: oneshot::Sender<Vec<Uuid>>,
+    },
 
     /// Modulate buoyancy externally
-    ModulateBuoyancy { concept_id: Uuid, delta: f32 },
+    ModulateBuoyancy {
+        concept_id: Uuid,
+        delta: f32,
+        response_tx: oneshot::Sender<Result<(), FluidError>>,
+    },
 
     /// Trigger manual tectonic shift by lowering threshold
     TriggerTectonic { pressure_threshold: f32 },
@@ -25,8 +105,14 @@ pub enum Command {
     /// Thaw frozen state
     Thaw,
 
-    /// Apply deep breath damping
-    DeepBreath { strength: f32 },
+    /// Apply deep breath damping, optionally only above a velocity threshold
+    DeepBreath {
+        strength: f32,
+        velocity_threshold: Option<f32>,
+    },
+
+    /// Enable or disable a core truth (vent) by index
+    SetVentActive { index: usize, active: bool },
 
     /// Add a core truth (vent)
     AddCoreTruth {
@@ -34,12 +120,16 @@ pub enum Command {
         heat_output: f32,
         depth: f32,
         radius: f32,
+        falloff: FalloffProfile,
     },
 
-    /// Flash heal with fresh concepts
+    /// Flash heal with fresh concepts; responds with what the heal did
+    /// (None: nobody is waiting)
     FlashHeal {
-        concepts: Vec<(String, f32, f32)>,
+        /// (name, density, size) of each fresh concept
+        concepts: Vec<(String, f32, ConceptSize)>,
         dilution_strength: f32,
+        response_tx: Option<oneshot::Sender<FlashHealOutcome>>,
     },
 
     /// Precipitate a new thought from a character trait
@@ -47,7 +137,38 @@ pub enum Command {
         trait_index: usize,
         new_concept_name: String,
         density: f32,
-        area: f32,
+        size: ConceptSize,
+    },
+
+    /// Return a character trait to the fluid as the concept it evaporated from
+    /// (None if the index is out of range)
+    Reimmerse {
+        trait_index: usize,
+        response_tx: oneshot::Sender<Option<Uuid>>,
+    },
+
+    /// Register a rule; responds with its index, or why it was refused
+    AddRule {
+        rule: Rule,
+        response_tx: oneshot::Sender<Result<usize, String>>,
+    },
+
+    /// Remove a rule by index; responds with the rule (None if unknown)
+    RemoveRule {
+        index: usize,
+        response_tx: oneshot::Sender<Option<Rule>>,
+    },
+
+    /// Add a persistent standing wave; responds once it is in effect
+    AddWave {
+        wave: StandingWave,
+        response_tx: oneshot::Sender<()>,
+    },
+
+    /// Remove a persistent standing wave; responds with it (None if unknown)
+    RemoveWave {
+        id: Uuid,
+        response_tx: oneshot::Sender<Option<StandingWave>>,
     },
 
     /// Start a division experiment (analog computing)
@@ -56,6 +177,14 @@ pub enum Command {
         dividend: f32,
         divisor: f32,
         salinity_boost: f32,
+        /// Record a per-tick trace for CSV export
+        trace: bool,
+        /// Leave the experiment's particles in the fluid when it ends
+        keep_particles: bool,
+        /// Settlement threshold overrides (scaled defaults when None)
+        min_ticks: Option<u64>,
+        max_velocity: Option<f32>,
+        avg_velocity: Option<f32>,
         response_tx: oneshot::Sender<Uuid>,
     },
 
@@ -66,6 +195,384 @@ pub enum Command {
         heat_a: f32,
         position_b: String,
         heat_b: f32,
+        /// Record a per-tick trace for CSV export
+        trace: bool,
+        /// Leave the experiment's particles in the fluid when it ends
+        keep_particles: bool,
+        /// Plume shape of both vents
+        falloff: FalloffProfile,
         response_tx: oneshot::Sender<Uuid>,
     },
+
+    /// Start a consensus experiment between two live concepts
+    /// Responds with None if either concept is missing
+    StartConsensusFromConcepts {
+        id_a: Uuid,
+        id_b: Uuid,
+        /// Record a per-tick trace for CSV export
+        trace: bool,
+        /// Leave the experiment's particles in the fluid when it ends
+        keep_particles: bool,
+        /// Plume shape of both vents
+        falloff: FalloffProfile,
+        response_tx: oneshot::Sender<Option<Uuid>>,
+    },
+
+    /// Crystallize the active consensus experiment now, with whatever
+    /// certainty its jitter so far gives; responds with the ore (None if no
+    /// experiment is running)
+    CrystallizeNow {
+        response_tx: oneshot::Sender<Option<ConsensusOre>>,
+    },
+
+    /// Change the certainty cutoffs used to classify consensus ores,
+    /// keeping the ones the patch omits
+    /// Responds with the cutoffs now in effect, or why the result is unusable
+    UpdateQualityThresholds {
+        patch: QualityThresholdsPatch,
+        response_tx: oneshot::Sender<Result<QualityThresholds, String>>,
+    },
+
+    /// Start recording the depth × time heatmap afresh
+    /// Responds once recording has started
+    StartHeatmap {
+        interval: u64,
+        bins: usize,
+        response_tx: oneshot::Sender<()>,
+    },
+
+    /// Stop recording the heatmap, keeping its rows
+    /// Responds with how many rows were recorded
+    StopHeatmap { response_tx: oneshot::Sender<usize> },
+
+    /// Set the viscosity at the surface and/or at the floor, keeping the
+    /// one left out
+    /// Responds with both values now in effect
+    SetViscosity {
+        surface: Option<f32>,
+        deep: Option<f32>,
+        response_tx: oneshot::Sender<(f32, f32)>,
+    },
+
+    /// Run N ticks synchronously (batch experiments)
+    /// Responds with the final tick count and every event emitted
+    Advance {
+        ticks: u64,
+        response_tx: oneshot::Sender<(u64, Vec<FluidEvent>)>,
+    },
+
+    /// Panic inside the simulation loop, exercising its recovery
+    #[cfg(test)]
+    Panic,
+}
+
+impl Command {
+    /// Short name used for the simulation loop's span
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Inject { .. } => "inject",
+            Self::Rename { .. } => "rename",
+            Self::RemoveConcept { .. } => "remove_concept",
+            Self::Prune { .. } => "prune",
+            Self::FocusAttention { .. } => "focus_attention",
+            Self::Link { .. } => "link",
+            Self::Unlink { .. } => "unlink",
+            Self::Ballast { .. } => "ballast",
+            Self::Submerge { .. } => "submerge",
+            Self::ModulateBuoyancy { .. } => "modulate_buoyancy",
+            Self::TriggerTectonic { .. } => "trigger_tectonic",
+            Self::Thaw => "thaw",
+            Self::DeepBreath { .. } => "deep_breath",
+            Self::SetVentActive { .. } => "set_vent_active",
+            Self::AddCoreTruth { .. } => "add_core_truth",
+            Self::FlashHeal { .. } => "flash_heal",
+            Self::Precipitate { .. } => "precipitate",
+            Self::Reimmerse { .. } => "reimmerse",
+            Self::AddRule { .. } => "add_rule",
+            Self::RemoveRule { .. } => "remove_rule",
+            Self::AddWave { .. } => "add_wave",
+            Self::RemoveWave { .. } => "remove_wave",
+            Self::StartDivisionExperiment { .. } => "start_division_experiment",
+            Self::StartConsensusExperiment { .. } => "start_consensus_experiment",
+            Self::StartConsensusFromConcepts { .. } => "start_consensus_from_concepts",
+            Self::CrystallizeNow { .. } => "crystallize_now",
+            Self::UpdateQualityThresholds { .. } => "update_quality_thresholds",
+            Self::StartHeatmap { .. } => "start_heatmap",
+            Self::StopHeatmap { .. } => "stop_heatmap",
+            Self::SetViscosity { .. } => "set_viscosity",
+            Self::Advance { .. } => "advance",
+            #[cfg(test)]
+            Self::Panic => "panic",
+        }
+    }
+}
+
+/// A fire-and-forget command as JSON, as sent over the WebSocket and stored
+/// in schedules. Tagged by `command`, e.g. `{"command": "thaw"}`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum CommandPayload {
+    Inject {
+        name: String,
+        density: f32,
+        /// Give at most one of `volume` and `area` (default: volume 0.5)
+        #[serde(default)]
+        volume: Option<f32>,
+        #[serde(default)]
+        area: Option<f32>,
+        #[serde(default)]
+        metadata: Metadata,
+        #[serde(default)]
+        initial_layer: Option<f32>,
+        #[serde(default)]
+        initial_velocity: Option<f32>,
+        #[serde(default)]
+        initial_buoyancy: Option<f32>,
+        #[serde(default)]
+        priority: bool,
+    },
+    Ballast {
+        id: Uuid,
+        weight_delta: f32,
+    },
+    Submerge {
+        id: Uuid,
+        target_layer: f32,
+        ballast: f32,
+    },
+    Rename {
+        id: Uuid,
+        name: String,
+    },
+    Thaw,
+    DeepBreath {
+        strength: f32,
+        #[serde(default)]
+        velocity_threshold: Option<f32>,
+    },
+    ModulateBuoyancy {
+        id: Uuid,
+        delta: f32,
+    },
+    AddCoreTruth {
+        name: String,
+        heat_output: f32,
+        depth: f32,
+        radius: f32,
+        #[serde(default)]
+        falloff: FalloffProfile,
+    },
+    FlashHeal {
+        concepts: Vec<FreshConcept>,
+        dilution_strength: f32,
+    },
+    Precipitate {
+        trait_index: usize,
+        new_concept_name: String,
+        density: f32,
+        #[serde(default)]
+        volume: Option<f32>,
+        #[serde(default)]
+        area: Option<f32>,
+    },
+}
+
+/// Outcome of a command that can fail, answered by the simulation loop
+pub type CommandOutcome = oneshot::Receiver<Result<(), FluidError>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FreshConcept {
+    pub name: String,
+    pub density: f32,
+    /// Give at most one of `volume` and `area` (default: volume 0.5)
+    #[serde(default)]
+    pub volume: Option<f32>,
+    #[serde(default)]
+    pub area: Option<f32>,
+}
+
+impl CommandPayload {
+    /// Short name of the command, as in the `command` tag
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Inject { .. } => "inject",
+            Self::Ballast { .. } => "ballast",
+            Self::Submerge { .. } => "submerge",
+            Self::Rename { .. } => "rename",
+            Self::Thaw => "thaw",
+            Self::DeepBreath { .. } => "deep_breath",
+            Self::ModulateBuoyancy { .. } => "modulate_buoyancy",
+            Self::AddCoreTruth { .. } => "add_core_truth",
+            Self::FlashHeal { .. } => "flash_heal",
+            Self::Precipitate { .. } => "precipitate",
+        }
+    }
+
+    /// The command for the simulation loop. Any response is discarded.
+    pub fn into_command(self) -> Command {
+        self.into_checked_command().0
+    }
+
+    /// The command for the simulation loop, with a receiver for the outcome
+    /// of commands that can fail (`ballast`, `submerge`, `modulate_buoyancy`).
+    pub fn into_checked_command(self) -> (Command, Option<CommandOutcome>) {
+        let command = match self {
+            Self::Inject {
+                name,
+                density,
+                volume,
+                area,
+                metadata,
+                initial_layer,
+                initial_velocity,
+                initial_buoyancy,
+                priority,
+            } => {
+                let (tx, _) = oneshot::channel();
+                Command::Inject {
+                    name,
+                    density,
+                    size: ConceptSize::from_fields(volume, area),
+                    metadata,
+                    initial_layer,
+                    initial_velocity,
+                    initial_buoyancy,
+                    priority,
+                    response_tx: tx,
+                }
+            }
+            Self::Ballast { id, weight_delta } => {
+                let (tx, rx) = oneshot::channel();
+                let command = Command::Ballast {
+                    concept_id: id,
+                    weight_delta,
+                    response_tx: tx,
+                };
+                return (command, Some(rx));
+            }
+            Self::Submerge {
+                id,
+                target_layer,
+                ballast,
+            } => {
+                let (tx, rx) = oneshot::channel();
+                let command = Command::Submerge {
+                    concept_id: id,
+                    target_layer,
+                    ballast,
+                    response_tx: tx,
+                };
+                return (command, Some(rx));
+            }
+            Self::Rename { id, name } => {
+                let (tx, _) = oneshot::channel();
+                Command::Rename {
+                    concept_id: id,
+                    name: name.trim().to_string(),
+                    response_tx: tx,
+                }
+            }
+            Self::Thaw => Command::Thaw,
+            Self::DeepBreath {
+                strength,
+                velocity_threshold,
+            } => Command::DeepBreath {
+                strength,
+                velocity_threshold,
+            },
+            Self::ModulateBuoyancy { id, delta } => {
+                let (tx, rx) = oneshot::channel();
+                let command = Command::ModulateBuoyancy {
+                    concept_id: id,
+                    delta,
+                    response_tx: tx,
+                };
+                return (command, Some(rx));
+            }
+            Self::AddCoreTruth {
+                name,
+                heat_output,
+                depth,
+                radius,
+                falloff,
+            } => Command::AddCoreTruth {
+                name,
+                heat_output,
+                depth,
+                radius,
+                falloff,
+            },
+            Self::FlashHeal {
+                concepts,
+                dilution_strength,
+            } => Command::FlashHeal {
+                concepts: concepts
+                    .into_iter()
+                    .map(|c| {
+                        (
+                            c.name,
+                            c.density,
+                            ConceptSize::from_fields(c.volume, c.area),
+                        )
+                    })
+                    .collect(),
+                dilution_strength,
+                response_tx: None,
+            },
+            Self::Precipitate {
+                trait_index,
+                new_concept_name,
+                density,
+                volume,
+                area,
+            } => Command::Precipitate {
+                trait_index,
+                new_concept_name,
+                density,
+                size: ConceptSize::from_fields(volume, area),
+            },
+        };
+        (command, None)
+    }
+}
+
+/// A command together with the span it was sent from, so the simulation
+/// loop's handling of it is traced under the originating request.
+#[derive(Debug)]
+pub struct TracedCommand {
+    pub command: Command,
+    pub span: Span,
+}
+
+/// Sending half of the command channel. Each command carries the caller's
+/// current span.
+#[derive(Debug, Clone)]
+pub struct CommandSender(mpsc::Sender<TracedCommand>);
+
+impl CommandSender {
+    pub fn new(tx: mpsc::Sender<TracedCommand>) -> Self {
+        Self(tx)
+    }
+
+    pub async fn send(&self, command: Command) -> Result<(), SendError<Command>> {
+        self.0
+            .send(TracedCommand {
+                command,
+                span: Span::current(),
+            })
+            .await
+            .map_err(|SendError(traced)| SendError(traced.command))
+    }
+
+    pub fn try_send(&self, command: Command) -> Result<(), TrySendError<Command>> {
+        self.0
+            .try_send(TracedCommand {
+                command,
+                span: Span::current(),
+            })
+            .map_err(|err| match err {
+                TrySendError::Full(traced) => TrySendError::Full(traced.command),
+                TrySendError::Closed(traced) => TrySendError::Closed(traced.command),
+            })
+    }
 }
@@ -0,0 +1,539 @@
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+/// Log entries kept per rule; older ones are forgotten
+const LOG_CAPACITY: usize = 50;
+
+/// How a rule's `pattern` is read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PatternKind {
+    /// `*` matches any run of characters, `?` any one (`send_*`)
+    #[default]
+    Glob,
+    Regex,
+}
+
+/// Actions handled in-process instead of by a URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BuiltinAction {
+    /// Only log the breakthrough
+    Log,
+}
+
+/// Where a matching breakthrough goes: `url = "..."` or `builtin = "log"`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DispatchTarget {
+    /// POST the action context here (like the action hook)
+    Url(String),
+    Builtin(BuiltinAction),
+}
+
+/// A dispatch rule as written in the config file's `[[dispatch_rules]]`
+/// and posted to `/dispatch-rules`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DispatchRuleConfig {
+    /// Matched against the whole concept name
+    pub pattern: String,
+    #[serde(default)]
+    pub pattern_kind: PatternKind,
+    #[serde(flatten)]
+    pub target: DispatchTarget,
+    /// Minimum seconds between dispatches by this rule; matches in between
+    /// are suppressed
+    #[serde(default)]
+    pub cooldown_secs: f32,
+}
+
+impl DispatchRuleConfig {
+    /// Check the rule and compile its pattern into an anchored regex.
+    pub fn compile(&self) -> Result<Regex, String> {
+        let source = match self.pattern_kind {
+            PatternKind::Glob => regex::escape(&self.pattern)
+                .replace(r"\*", ".*")
+                .replace(r"\?", "."),
+            PatternKind::Regex => self.pattern.clone(),
+        };
+        if !self.cooldown_secs.is_finite() || self.cooldown_secs < 0.0 {
+            return Err("cooldown_secs must be a non-negative number".to_string());
+        }
+        Regex::new(&format!("^(?:{})$", source))
+            .map_err(|err| format!("invalid pattern {:?}: {}", self.pattern, err))
+    }
+}
+
+/// What happened to one breakthrough a rule matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DispatchOutcome {
+    /// The URL answered 2xx
+    Dispatched,
+    /// The URL failed after every retry
+    Failed,
+    /// Handled by the `log` builtin
+    Logged,
+    /// Matched during the rule's cooldown, so nothing was sent
+    Suppressed,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DispatchLogEntry {
+    /// Unix time in milliseconds
+    pub at_ms: u64,
+    pub concept: String,
+    pub outcome: DispatchOutcome,
+    /// Last HTTP status from the URL (null for builtins and suppressed matches)
+    pub status: Option<u16>,
+    pub attempts: u32,
+}
+
+impl DispatchLogEntry {
+    pub fn new(
+        concept: &str,
+        outcome: DispatchOutcome,
+        status: Option<u16>,
+        attempts: u32,
+    ) -> Self {
+        Self {
+            at_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+            concept: concept.to_string(),
+            outcome,
+            status,
+            attempts,
+        }
+    }
+}
+
+/// A registered rule as reported by `/dispatch-rules`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DispatchRuleView {
+    /// Assigned at registration (config file rules in file order from 1)
+    pub id: u64,
+    #[serde(flatten)]
+    pub rule: DispatchRuleConfig,
+    /// Breakthroughs sent to the target
+    pub dispatched: u64,
+    /// Matches dropped during cooldown
+    pub suppressed: u64,
+}
+
+/// A rule's recent history, from `/dispatch-rules/:id/log`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DispatchLog {
+    pub rule: DispatchRuleView,
+    /// Seconds until the rule can dispatch again (0 when ready)
+    pub cooldown_remaining_secs: f32,
+    /// Most recent last
+    pub entries: Vec<DispatchLogEntry>,
+}
+
+/// Which way a breakthrough goes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Route {
+    /// The first matching rule fires
+    Dispatch {
+        rule_id: u64,
+        target: DispatchTarget,
+    },
+    /// The first matching rule is cooling down; later rules are not tried
+    Suppressed { rule_id: u64 },
+    /// No rule matched: the default action hook (or the log) takes it
+    Unmatched,
+}
+
+#[derive(Debug)]
+struct DispatchRule {
+    id: u64,
+    config: DispatchRuleConfig,
+    matcher: Regex,
+    last_dispatch: Option<Instant>,
+    dispatched: u64,
+    suppressed: u64,
+    log: VecDeque<DispatchLogEntry>,
+}
+
+impl DispatchRule {
+    fn view(&self) -> DispatchRuleView {
+        DispatchRuleView {
+            id: self.id,
+            rule: self.config.clone(),
+            dispatched: self.dispatched,
+            suppressed: self.suppressed,
+        }
+    }
+
+    fn cooldown_remaining(&self, now: Instant) -> Duration {
+        let cooldown = Duration::from_secs_f32(self.config.cooldown_secs);
+        self.last_dispatch
+            .map(|last| cooldown.saturating_sub(now.duration_since(last)))
+            .unwrap_or_default()
+    }
+
+    fn record(&mut self, entry: DispatchLogEntry) {
+        if self.log.len() == LOG_CAPACITY {
+            self.log.pop_front();
+        }
+        self.log.push_back(entry);
+    }
+}
+
+#[derive(Debug)]
+struct Registry {
+    rules: Vec<DispatchRule>,
+    next_id: u64,
+}
+
+impl Default for Registry {
+    /// Rule ids start at 1
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            next_id: 1,
+        }
+    }
+}
+
+/// Rules routing surface breakthroughs by concept name, evaluated in order by
+/// the action dispatch task. Changes made through the API are written back
+/// to the config file.
+#[derive(Debug, Default)]
+pub struct DispatchRules {
+    registry: RwLock<Registry>,
+    /// Config file that persists the rules (None: changes are not saved)
+    config_path: Option<PathBuf>,
+}
+
+impl DispatchRules {
+    /// Rules from the config file at `config_path`, whose patterns have
+    /// already been validated by `AppConfig`.
+    pub fn new(
+        configs: Vec<DispatchRuleConfig>,
+        config_path: Option<PathBuf>,
+    ) -> Result<Self, String> {
+        let mut registry = Registry::default();
+        for config in configs {
+            registry.push(config)?;
+        }
+        Ok(Self {
+            registry: RwLock::new(registry),
+            config_path,
+        })
+    }
+
+    pub async fn list(&self) -> Vec<DispatchRuleView> {
+        let registry = self.registry.read().await;
+        registry.rules.iter().map(DispatchRule::view).collect()
+    }
+
+    /// Append a rule (lowest precedence) and save the config file.
+    pub async fn insert(&self, config: DispatchRuleConfig) -> Result<DispatchRuleView, String> {
+        let view = self.registry.write().await.push(config)?;
+        self.save().await?;
+        Ok(view)
+    }
+
+    /// Remove a rule by id and save the config file.
+    pub async fn remove(&self, id: u64) -> Result<Option<DispatchRuleView>, String> {
+        let removed = {
+            let mut registry = self.registry.write().await;
+            let Some(index) = registry.rules.iter().position(|r| r.id == id) else {
+                return Ok(None);
+            };
+            registry.rules.remove(index).view()
+        };
+        self.save().await?;
+        Ok(Some(removed))
+    }
+
+    pub async fn log(&self, id: u64) -> Option<DispatchLog> {
+        let registry = self.registry.read().await;
+        let rule = registry.rules.iter().find(|r| r.id == id)?;
+        Some(DispatchLog {
+            rule: rule.view(),
+            cooldown_remaining_secs: rule.cooldown_remaining(Instant::now()).as_secs_f32(),
+            entries: rule.log.iter().cloned().collect(),
+        })
+    }
+
+    /// Route a breakthrough of `concept`: the first rule whose pattern
+    /// matches decides. Starts the rule's cooldown when it dispatches, and
+    /// logs suppressed matches.
+    pub async fn route(&self, concept: &str, now: Instant) -> Route {
+        let mut registry = self.registry.write().await;
+        let Some(rule) = registry
+            .rules
+            .iter_mut()
+            .find(|r| r.matcher.is_match(concept))
+        else {
+            return Route::Unmatched;
+        };
+        if !rule.cooldown_remaining(now).is_zero() {
+            rule.suppressed += 1;
+            rule.record(DispatchLogEntry::new(
+                concept,
+                DispatchOutcome::Suppressed,
+                None,
+                0,
+            ));
+            return Route::Suppressed { rule_id: rule.id };
+        }
+        rule.last_dispatch = Some(now);
+        rule.dispatched += 1;
+        Route::Dispatch {
+            rule_id: rule.id,
+            target: rule.config.target.clone(),
+        }
+    }
+
+    /// Log how a dispatch by rule `id` went.
+    pub async fn record(&self, id: u64, entry: DispatchLogEntry) {
+        let mut registry = self.registry.write().await;
+        if let Some(rule) = registry.rules.iter_mut().find(|r| r.id == id) {
+            rule.record(entry);
+        }
+    }
+
+    /// Rewrite the `[[dispatch_rules]]` of the config file, keeping the rest
+    /// of the file (comments included) as it was.
+    async fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.config_path else {
+            return Ok(());
+        };
+        let configs: Vec<_> = {
+            let registry = self.registry.read().await;
+            registry.rules.iter().map(|r| r.config.clone()).collect()
+        };
+        let existing = match tokio::fs::read_to_string(path).await {
+            Ok(source) => source,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.to_string()),
+        };
+        let updated = with_dispatch_rules(&existing, &configs)?;
+        tokio::fs::write(path, updated)
+            .await
+            .map_err(|err| format!("cannot save {}: {}", path.display(), err))
+    }
+}
+
+impl Registry {
+    fn push(&mut self, config: DispatchRuleConfig) -> Result<DispatchRuleView, String> {
+        let matcher = config.compile()?;
+        let rule = DispatchRule {
+            id: self.next_id,
+            config,
+            matcher,
+            last_dispatch: None,
+            dispatched: 0,
+            suppressed: 0,
+            log: VecDeque::new(),
+        };
+        self.next_id += 1;
+        let view = rule.view();
+        self.rules.push(rule);
+        Ok(view)
+    }
+}
+
+/// `source` with its `dispatch_rules` replaced by `rules`.
+fn with_dispatch_rules(source: &str, rules: &[DispatchRuleConfig]) -> Result<String, String> {
+    #[derive(Serialize)]
+    struct Section<'a> {
+        dispatch_rules: &'a [DispatchRuleConfig],
+    }
+
+    let mut document: toml_edit::DocumentMut = source.parse().map_err(|err| format!("{}", err))?;
+    if rules.is_empty() {
+        document.remove("dispatch_rules");
+    } else {
+        let section = toml::to_string(&Section {
+            dispatch_rules: rules,
+        })
+        .map_err(|err| err.to_string())?;
+        let mut section: toml_edit::DocumentMut =
+            section.parse().map_err(|err| format!("{}", err))?;
+        if let Some(item) = section.remove("dispatch_rules") {
+            document.insert("dispatch_rules", item);
+        }
+    }
+    Ok(document.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(
+        pattern: &str,
+        kind: PatternKind,
+        target: DispatchTarget,
+        cooldown: f32,
+    ) -> DispatchRuleConfig {
+        DispatchRuleConfig {
+            pattern: pattern.to_string(),
+            pattern_kind: kind,
+            target,
+            cooldown_secs: cooldown,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_first_match_wins_with_cooldown_and_fallthrough() {
+        let messaging = DispatchTarget::Url("http://localhost:9000/message".to_string());
+        let media = DispatchTarget::Url("http://localhost:9000/media".to_string());
+        let rules = DispatchRules::new(
+            vec![
+                rule("send_*", PatternKind::Glob, messaging.clone(), 30.0),
+                rule(
+                    "send_(hug|love)",
+                    PatternKind::Regex,
+                    DispatchTarget::Builtin(BuiltinAction::Log),
+                    0.0,
+                ),
+                rule("play_music", PatternKind::Glob, media.clone(), 0.0),
+            ],
+            None,
+        )
+        .unwrap();
+        let start = Instant::now();
+
+        // The broader glob comes first, so it takes send_hug too
+        assert_eq!(
+            rules.route("send_hug", start).await,
+            Route::Dispatch {
+                rule_id: 1,
+                target: messaging.clone()
+            }
+        );
+        // Within the cooldown later matches are suppressed, not passed down
+        assert_eq!(
+            rules
+                .route("send_letter", start + Duration::from_secs(10))
+                .await,
+            Route::Suppressed { rule_id: 1 }
+        );
+        assert_eq!(
+            rules
+                .route("send_letter", start + Duration::from_secs(31))
+                .await,
+            Route::Dispatch {
+                rule_id: 1,
+                target: messaging
+            }
+        );
+        assert_eq!(
+            rules.route("play_music", start).await,
+            Route::Dispatch {
+                rule_id: 3,
+                target: media
+            }
+        );
+        // Patterns match the whole name; the rest falls through
+        assert_eq!(
+            rules.route("play_music_loudly", start).await,
+            Route::Unmatched
+        );
+        assert_eq!(rules.route("resend_note", start).await, Route::Unmatched);
+
+        let log = rules.log(1).await.unwrap();
+        assert_eq!((log.rule.dispatched, log.rule.suppressed), (2, 1));
+        assert_eq!(
+            log.entries.len(),
+            1,
+            "dispatch outcomes are recorded by the caller"
+        );
+        assert_eq!(log.entries[0].outcome, DispatchOutcome::Suppressed);
+        assert_eq!(log.entries[0].concept, "send_letter");
+        assert_eq!(rules.log(2).await.unwrap().rule.dispatched, 0);
+
+        // Removing the glob lets the regex rule see send_* names
+        rules.remove(1).await.unwrap().unwrap();
+        assert!(matches!(
+            rules.route("send_love", start).await,
+            Route::Dispatch {
+                rule_id: 2,
+                target: DispatchTarget::Builtin(BuiltinAction::Log)
+            }
+        ));
+        assert!(
+            rules
+                .insert(rule(
+                    "(",
+                    PatternKind::Regex,
+                    DispatchTarget::Builtin(BuiltinAction::Log),
+                    0.0
+                ))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_api_changes_are_saved_to_the_config_file() {
+        let path = std::env::temp_dir().join(format!("dispatch-{}.toml", uuid::Uuid::new_v4()));
+        std::fs::write(
+            &path,
+            "# my settings\n[notify]\nwebhook_url = \"http://localhost/hook\"\n",
+        )
+        .unwrap();
+        let rules = DispatchRules::new(Vec::new(), Some(path.clone())).unwrap();
+
+        let added = rules
+            .insert(rule(
+                "send_*",
+                PatternKind::Glob,
+                DispatchTarget::Url("http://localhost/send".to_string()),
+                5.0,
+            ))
+            .await
+            .unwrap();
+        rules
+            .insert(rule(
+                "*",
+                PatternKind::Glob,
+                DispatchTarget::Builtin(BuiltinAction::Log),
+                0.0,
+            ))
+            .await
+            .unwrap();
+        rules.remove(added.id).await.unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(saved.starts_with("# my settings\n[notify]"), "{}", saved);
+        let config = crate::config::AppConfig::from_toml(&saved).unwrap();
+        assert!(config.notify.is_some());
+        assert_eq!(config.dispatch_rules.len(), 1);
+        assert_eq!(config.dispatch_rules[0].pattern, "*");
+        assert_eq!(
+            config.dispatch_rules[0].target,
+            DispatchTarget::Builtin(BuiltinAction::Log)
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rule_ids_start_at_one_however_built() {
+        let log = || {
+            rule(
+                "*",
+                PatternKind::Glob,
+                DispatchTarget::Builtin(BuiltinAction::Log),
+                0.0,
+            )
+        };
+        let built = DispatchRules::new(Vec::new(), None).unwrap();
+        let default = DispatchRules::default();
+        assert_eq!(built.insert(log()).await.unwrap().id, 1);
+        assert_eq!(default.insert(log()).await.unwrap().id, 1);
+    }
+}
@@ -0,0 +1,259 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tracing::warn;
+
+/// Free-form client metadata passed along with an injected concept.
+pub type Metadata = Map<String, Value>;
+
+/// Derives a concept's (density, area) from its text when `/inject` omits density.
+///
+/// Estimation runs on a blocking thread, so implementations may do slow or
+/// blocking work (the remote estimator makes a synchronous HTTP call).
+pub trait DensityEstimator: Send + Sync {
+    /// Identifier reported in inject responses
+    fn name(&self) -> &str;
+
+    /// Estimate (density, area). Density is clamped to 0-1 and area to 0.1-2.0 by the caller.
+    fn estimate(&self, name: &str, metadata: &Metadata) -> (f32, f32);
+}
+
+/// No-op estimator: every concept is neutral (density 0.5, the default inject area).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PassthroughEstimator;
+
+impl DensityEstimator for PassthroughEstimator {
+    fn name(&self) -> &str {
+        "passthrough"
+    }
+
+    fn estimate(&self, _name: &str, _metadata: &Metadata) -> (f32, f32) {
+        (0.5, 1.0)
+    }
+}
+
+/// Words that weigh a thought down
+const HEAVY_WORDS: &[&str] = &[
+    "afraid", "anger", "angry", "anxious", "dread", "despair", "doubt", "fail", "failure", "fear",
+    "grief", "guilt", "hurt", "lonely", "loss", "pain", "regret", "sad", "shame", "stress",
+    "worry",
+];
+/// Words that lift a thought
+const LIGHT_WORDS: &[&str] = &[
+    "calm", "curious", "excited", "fun", "grateful", "hope", "idea", "joy", "love", "play",
+    "proud", "relief", "wonder",
+];
+/// Density shift per sentiment word
+const SENTIMENT_STEP: f32 = 0.15;
+/// Area per word: longer thoughts touch more of the mind
+const AREA_PER_WORD: f32 = 0.2;
+
+/// Built-in heuristic: sentiment word lists set density, word count sets area.
+/// An optional numeric `valence` in metadata (-1 heavy .. 1 light) nudges density.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicEstimator;
+
+impl DensityEstimator for HeuristicEstimator {
+    fn name(&self) -> &str {
+        "heuristic"
+    }
+
+    fn estimate(&self, name: &str, metadata: &Metadata) -> (f32, f32) {
+        let words: Vec<String> = name
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+
+        let heavy = words
+            .iter()
+            .filter(|w| HEAVY_WORDS.contains(&w.as_str()))
+            .count() as f32;
+        let light = words
+            .iter()
+            .filter(|w| LIGHT_WORDS.contains(&w.as_str()))
+            .count() as f32;
+        let valence = metadata
+            .get("valence")
+            .and_then(Value::as_f64)
+            .map(|v| v.clamp(-1.0, 1.0) as f32)
+            .unwrap_or(0.0);
+
+        let density = (0.5 + (heavy - light) * SENTIMENT_STEP - valence * 0.3).clamp(0.05, 0.95);
+        let area = (0.1 + words.len() as f32 * AREA_PER_WORD).clamp(0.1, 2.0);
+        (density, area)
+    }
+}
+
+#[derive(Serialize)]
+struct RemoteRequest<'a> {
+    name: &'a str,
+    metadata: &'a Metadata,
+}
+
+#[derive(Deserialize)]
+struct RemoteResponse {
+    density: f32,
+    area: f32,
+}
+
+/// Calls an external scoring endpoint (`POST {url}` with `{ "name", "metadata" }`,
+/// expecting `{ "density", "area" }`). Falls back to the heuristic on any failure.
+pub struct RemoteEstimator {
+    url: String,
+    client: reqwest::blocking::Client,
+    fallback: HeuristicEstimator,
+}
+
+impl RemoteEstimator {
+    /// Build the estimator. Must not be called from inside an async task
+    /// (the blocking client owns its own runtime); use `spawn_blocking`.
+    pub fn new(url: String, timeout: Duration) -> Result<Self, reqwest::Error> {
+        Ok(Self {
+            url,
+            client: reqwest::blocking::Client::builder()
+                .timeout(timeout)
+                .build()?,
+            fallback: HeuristicEstimator,
+        })
+    }
+
+    fn request(&self, name: &str, metadata: &Metadata) -> Result<(f32, f32), String> {
+        let response: RemoteResponse = self
+            .client
+            .post(&self.url)
+            .json(&RemoteRequest { name, metadata })
+            .send()
+            .and_then(|r| r.error_for_status())
+            .and_then(|r| r.json())
+            .map_err(|err| err.to_string())?;
+        if !response.density.is_finite() || !response.area.is_finite() {
+            return Err("non-finite estimate".to_string());
+        }
+        Ok((response.density, response.area))
+    }
+}
+
+impl DensityEstimator for RemoteEstimator {
+    fn name(&self) -> &str {
+        "remote"
+    }
+
+    fn estimate(&self, name: &str, metadata: &Metadata) -> (f32, f32) {
+        self.request(name, metadata).unwrap_or_else(|err| {
+            warn!(
+                "Remote density estimate for '{}' failed ({}), using heuristic",
+                name, err
+            );
+            self.fallback.estimate(name, metadata)
+        })
+    }
+}
+
+/// Default timeout for remote estimates
+const DEFAULT_REMOTE_TIMEOUT_MS: u64 = 500;
+
+/// Select an estimator from `DENSITY_ESTIMATOR` (`passthrough`, `heuristic` or
+/// `remote`), with `DENSITY_ESTIMATOR_URL` and `DENSITY_ESTIMATOR_TIMEOUT_MS` for
+/// the remote one. Falls back to passthrough when unset or invalid.
+/// Builds a blocking HTTP client, so call it outside async tasks.
+pub fn density_estimator_from_env() -> Arc<dyn DensityEstimator> {
+    let kind = std::env::var("DENSITY_ESTIMATOR").unwrap_or_default();
+    match kind.as_str() {
+        "" | "passthrough" => Arc::new(PassthroughEstimator),
+        "heuristic" => Arc::new(HeuristicEstimator),
+        "remote" => {
+            let Ok(url) = std::env::var("DENSITY_ESTIMATOR_URL") else {
+                warn!("DENSITY_ESTIMATOR=remote needs DENSITY_ESTIMATOR_URL, using passthrough");
+                return Arc::new(PassthroughEstimator);
+            };
+            let timeout_ms = match std::env::var("DENSITY_ESTIMATOR_TIMEOUT_MS") {
+                Ok(value) => value.parse().unwrap_or_else(|_| {
+                    warn!(
+                        "Ignoring invalid DENSITY_ESTIMATOR_TIMEOUT_MS={:?}, using {}",
+                        value, DEFAULT_REMOTE_TIMEOUT_MS
+                    );
+                    DEFAULT_REMOTE_TIMEOUT_MS
+                }),
+                Err(_) => DEFAULT_REMOTE_TIMEOUT_MS,
+            };
+            match RemoteEstimator::new(url, Duration::from_millis(timeout_ms)) {
+                Ok(remote) => Arc::new(remote),
+                Err(err) => {
+                    warn!(
+                        "Remote density estimator unavailable ({}), using passthrough",
+                        err
+                    );
+                    Arc::new(PassthroughEstimator)
+                }
+            }
+        }
+        other => {
+            warn!("Unknown DENSITY_ESTIMATOR={:?}, using passthrough", other);
+            Arc::new(PassthroughEstimator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::{Json, Router, routing::post};
+    use serde_json::json;
+
+    #[test]
+    fn test_heuristic_reads_sentiment_and_length() {
+        let estimator = HeuristicEstimator;
+        let none = Metadata::new();
+
+        let (grief, grief_area) = estimator.estimate("grief_and_fear", &none);
+        let (joy, _) = estimator.estimate("Joy", &none);
+        let (neutral, neutral_area) = estimator.estimate("groceries", &none);
+        assert!(grief > neutral && neutral > joy);
+        assert_eq!(neutral, 0.5);
+        assert!(grief_area > neutral_area);
+
+        let light_hint = json!({ "valence": 1.0 }).as_object().unwrap().clone();
+        assert!(estimator.estimate("groceries", &light_hint).0 < neutral);
+    }
+
+    #[tokio::test]
+    async fn test_remote_estimate_and_fallback() {
+        let app = Router::new().route(
+            "/score",
+            post(|Json(req): Json<Value>| async move {
+                let heavy = req["metadata"]["heavy"].as_bool().unwrap_or(false);
+                Json(json!({ "density": if heavy { 0.9 } else { 0.2 }, "area": 0.7 }))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let results = tokio::task::spawn_blocking(move || {
+            let timeout = Duration::from_millis(500);
+            let remote = RemoteEstimator::new(format!("http://{}/score", addr), timeout).unwrap();
+            let heavy = json!({ "heavy": true }).as_object().unwrap().clone();
+            let unreachable =
+                RemoteEstimator::new(format!("http://{}/missing", addr), timeout).unwrap();
+            (
+                remote.estimate("anything", &heavy),
+                remote.estimate("anything", &Metadata::new()),
+                unreachable.estimate("grief", &Metadata::new()),
+            )
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(results.0, (0.9, 0.7));
+        assert_eq!(results.1, (0.2, 0.7));
+        assert_eq!(
+            results.2,
+            HeuristicEstimator.estimate("grief", &Metadata::new()),
+            "a failing endpoint falls back to the heuristic"
+        );
+    }
+}
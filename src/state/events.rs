@@ -1,10 +1,15 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use schemars::JsonSchema;
 use serde::Serialize;
 use uuid::Uuid;
 
+use crate::simulation::{PressureChangeReason, SettledBy};
+
 /// Events emitted by the fluid simulation.
 /// Only significant events are broadcast - the "Consciousness Filter"
 /// ignores microscopic position updates of every water molecule.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 #[serde(tag = "event", rename_all = "snake_case")]
 pub enum FluidEvent {
     // === Concept lifecycle (significant only) ===
@@ -13,14 +18,54 @@ pub enum FluidEvent {
         id: Uuid,
         name: String,
         density: f32,
+        area: f32,
+        volume: f32,
         layer: f32,
     },
 
+    /// A thought was reframed under a new name
+    ConceptRenamed { id: Uuid, old: String, new: String },
+
+    /// Concepts were removed in bulk by `/concept/prune`
+    ConceptsPruned { count: usize, ids: Vec<Uuid> },
+
+    /// Links to concepts that left the fluid were dropped; `indices` are the
+    /// ones they held, and later links moved down to close the gaps
+    LinksDropped { count: usize, indices: Vec<usize> },
+
+    /// A single concept was taken out of the fluid by request
+    ConceptRemoved {
+        concept_id: Uuid,
+        concept_name: String,
+    },
+
+    /// A forgotten concept's integration was released into the fluid as salinity
+    KnowledgeReleased {
+        concept_id: Uuid,
+        concept_name: String,
+        integration: f32,
+        salinity: f32,
+    },
+
     /// A thought has broken through the surface into action
     SurfaceBreakthrough {
         id: Uuid,
         name: String,
         kinetic_energy: f32,
+        /// 1 for its first breakthrough, 2 once re-armed, ...
+        #[serde(default)]
+        breakthrough_count: u32,
+    },
+
+    /// The action hook was called for a breakthrough (`status` is None when no
+    /// response arrived)
+    ActionDispatched {
+        concept_id: Uuid,
+        concept: String,
+        /// Dispatch rule whose URL was called (None: the default hook)
+        rule: Option<u64>,
+        status: Option<u16>,
+        attempts: u32,
     },
 
     /// A thought bounced off the surface (not enough energy)
@@ -31,11 +76,21 @@ pub enum FluidEvent {
         required: f32,
     },
 
+    /// A thought keeps nearly breaking through - it is "almost acting"
+    BuildingUrge {
+        id: Uuid,
+        name: String,
+        bounce_count: u32,
+        best_kinetic_energy: f32,
+    },
+
     /// A concept has evaporated into a permanent character trait
     ConceptEvaporated {
         id: Uuid,
         name: String,
         trait_formed: String,
+        /// Index of the new trait in the atmosphere
+        trait_index: usize,
         integration: f32,
     },
 
@@ -55,6 +110,41 @@ pub enum FluidEvent {
     /// Turbulence has subsided
     TurbulenceSubsided,
 
+    /// The kinetic energy books failed to balance (likely an integrator bug)
+    EnergyAnomaly {
+        tick: u64,
+        expected_delta: f32,
+        actual_delta: f32,
+        imbalance: f32,
+    },
+
+    /// Salinity crossed into a named band (`fresh`, `brackish`, `ocean` or
+    /// `dead_sea`), rising or falling
+    SalinityMilestone { level: String, salinity: f32 },
+
+    /// A tick panicked; the fluid was restored to the copy kept at
+    /// `restored_tick` and the simulation carried on from there
+    SimulationError {
+        tick: u64,
+        message: String,
+        restored_tick: u64,
+    },
+
+    /// A concept's `field` (`layer` or `velocity`) stopped being a finite
+    /// number and was put back in range before the tick ran
+    NumericAnomaly {
+        concept_id: Uuid,
+        concept: String,
+        field: String,
+    },
+
+    /// A rising thought (`a`) and a sinking one (`b`) met at the same depth
+    ConceptCollision {
+        a: Uuid,
+        b: Uuid,
+        relative_velocity: f32,
+    },
+
     // === Thermal/Mineralization events ===
     /// A dark thought has deposited ore after cycling through a vent
     Mineralization {
@@ -74,6 +164,15 @@ pub enum FluidEvent {
         threshold: f32,
     },
 
+    /// Ocean floor pressure moved by more than the debounce amount since it
+    /// was last reported, or the tectonic threshold was moved
+    PressureChanged {
+        old: f32,
+        new: f32,
+        threshold: f32,
+        reason: PressureChangeReason,
+    },
+
     /// A benthic expedition has catalyzed a solution from ore
     OreCatalysis {
         problem: String,
@@ -88,6 +187,9 @@ pub enum FluidEvent {
         continent_name: String,
         depth_range: (f32, f32),
         ores_consumed: Vec<String>,
+        /// Ores outside the continent's depth range, left on the floor
+        #[serde(default)]
+        ores_surviving: Vec<String>,
         total_integration: f32,
     },
 
@@ -113,6 +215,15 @@ pub enum FluidEvent {
         trait_name: String,
         new_concept: String,
         inherited_integration: f32,
+        /// Layer the thought fell into the fluid at
+        entry_layer: f32,
+    },
+
+    /// A character trait has returned to the fluid as its original concept
+    TraitReimmersed {
+        concept_id: Uuid,
+        name: String,
+        integration: f32,
     },
 
     /// Flash heal has diluted salinity
@@ -120,6 +231,8 @@ pub enum FluidEvent {
         concepts_added: usize,
         old_salinity: f32,
         new_salinity: f32,
+        broke_freeze: bool,
+        concept_ids: Vec<Uuid>,
     },
 
     /// Deep breath applied damping
@@ -132,6 +245,27 @@ pub enum FluidEvent {
         ballast_amount: f32,
     },
 
+    /// A concept was deliberately pushed back under to stop acting on it
+    ConceptSubmerged {
+        concept_id: Uuid,
+        concept_name: String,
+        target_layer: f32,
+        ballast: f32,
+    },
+
+    /// Attention was focused on a depth window for `duration` seconds
+    AttentionFocused {
+        id: Uuid,
+        depth: f32,
+        radius: f32,
+        strength: f32,
+        duration: f32,
+        concepts_in_focus: usize,
+    },
+
+    /// A scheduled command was sent to the simulation loop
+    ScheduledCommandFired { name: String },
+
     // === Division Experiment Events (Analog Computing) ===
     /// A division experiment has started
     DivisionExperimentStarted {
@@ -152,6 +286,8 @@ pub enum FluidEvent {
         turbulence_energy: f32,
         reynolds_number: f32,
         ticks_to_settle: u64,
+        /// A timed-out result is less trustworthy than a clean settlement
+        settled_by: SettledBy,
     },
 
     // === Consensus Reactor Events (Contradictory Vent Collision) ===
@@ -190,3 +326,77 @@ pub enum FluidEvent {
         emergent_property_count: usize,
     },
 }
+
+impl FluidEvent {
+    /// Short event name used as the SSE event type and in Redis envelopes.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::SurfaceBreakthrough { .. } => "breakthrough",
+            Self::ActionDispatched { .. } => "action_dispatched",
+            Self::SurfaceBounce { .. } => "bounce",
+            Self::BuildingUrge { .. } => "building_urge",
+            Self::ConceptInjected { .. } => "injected",
+            Self::ConceptRenamed { .. } => "renamed",
+            Self::ConceptsPruned { .. } => "pruned",
+            Self::LinksDropped { .. } => "links_dropped",
+            Self::ConceptRemoved { .. } => "removed",
+            Self::KnowledgeReleased { .. } => "knowledge_released",
+            Self::ConceptEvaporated { .. } => "evaporated",
+            Self::Freeze { .. } => "freeze",
+            Self::Thaw => "thaw",
+            Self::TurbulenceOnset { .. } => "turbulence_onset",
+            Self::TurbulenceSubsided => "turbulence_subsided",
+            Self::EnergyAnomaly { .. } => "energy_anomaly",
+            Self::SalinityMilestone { .. } => "salinity",
+            Self::SimulationError { .. } => "simulation_error",
+            Self::NumericAnomaly { .. } => "numeric_anomaly",
+            Self::ConceptCollision { .. } => "concept_collision",
+            Self::Mineralization { .. } => "mineralization",
+            Self::OreDeposited { .. } => "ore_deposited",
+            Self::PressureChanged { .. } => "pressure_changed",
+            Self::OreCatalysis { .. } => "catalysis",
+            Self::TectonicShift { .. } => "tectonic_shift",
+            Self::CoreTruthFormed { .. } => "core_truth_formed",
+            Self::CoreTruthStrengthened { .. } => "core_truth_strengthened",
+            Self::Precipitation { .. } => "precipitation",
+            Self::TraitReimmersed { .. } => "reimmersed",
+            Self::FlashHeal { .. } => "flash_heal",
+            Self::DeepBreath { .. } => "deep_breath",
+            Self::BenthicExpedition { .. } => "benthic_expedition",
+            Self::ConceptSubmerged { .. } => "submerged",
+            Self::AttentionFocused { .. } => "attention_focused",
+            Self::ScheduledCommandFired { .. } => "scheduled_command_fired",
+            Self::DivisionExperimentStarted { .. } => "division_started",
+            Self::DivisionExperimentComplete { .. } => "division_complete",
+            Self::ConsensusExperimentStarted { .. } => "consensus_started",
+            Self::ConsensusOreCrystallized { .. } => "consensus_crystallized",
+            Self::PhaseTransition { .. } => "phase_transition",
+        }
+    }
+}
+
+/// An event wrapped for external sinks (Redis, Kafka).
+#[derive(Debug, Serialize)]
+pub struct EventEnvelope<'a> {
+    /// Same name as the SSE event type
+    pub kind: &'static str,
+    /// Simulation tick when the sink picked the event up
+    pub tick: u64,
+    /// Wall-clock milliseconds since the Unix epoch
+    pub timestamp_ms: u64,
+    pub event: &'a FluidEvent,
+}
+
+impl<'a> EventEnvelope<'a> {
+    pub fn new(event: &'a FluidEvent, tick: u64) -> Self {
+        Self {
+            kind: event.kind(),
+            tick,
+            timestamp_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+            event,
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// Live counters shared between the Redis bridge task and `/health`.
+#[derive(Debug, Default)]
+pub struct RedisStatus {
+    pub channel: String,
+    pub state_key: String,
+    connected: AtomicBool,
+    published: AtomicU64,
+    dropped: AtomicU64,
+    mirrored: AtomicU64,
+}
+
+impl RedisStatus {
+    pub fn new(channel: String, state_key: String) -> Self {
+        Self {
+            channel,
+            state_key,
+            ..Self::default()
+        }
+    }
+
+    pub fn set_connected(&self, connected: bool) {
+        self.connected.store(connected, Ordering::Relaxed);
+    }
+
+    pub fn record_published(&self) {
+        self.published.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// An event or state write lost to an outage or a lagging subscription.
+    pub fn record_dropped(&self, count: u64) {
+        self.dropped.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub fn record_mirrored(&self) {
+        self.mirrored.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> RedisHealth {
+        RedisHealth {
+            connected: self.connected.load(Ordering::Relaxed),
+            channel: self.channel.clone(),
+            state_key: self.state_key.clone(),
+            published: self.published.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            mirrored: self.mirrored.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Redis integration status as reported by `/health`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct RedisHealth {
+    pub connected: bool,
+    pub channel: String,
+    pub state_key: String,
+    /// Events published since startup
+    pub published: u64,
+    /// Events and state writes dropped while Redis was unreachable
+    pub dropped: u64,
+    /// State snapshots written
+    pub mirrored: u64,
+}
+
+/// Live counters shared between the Kafka producer task and `/health`.
+#[derive(Debug, Default)]
+pub struct KafkaStatus {
+    pub topic: String,
+    sent: AtomicU64,
+    buffered: AtomicU64,
+    dropped: AtomicU64,
+    delivery_failures: AtomicU64,
+}
+
+impl KafkaStatus {
+    pub fn new(topic: String) -> Self {
+        Self {
+            topic,
+            ..Self::default()
+        }
+    }
+
+    pub fn record_sent(&self, count: u64) {
+        self.sent.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub fn set_buffered(&self, count: u64) {
+        self.buffered.store(count, Ordering::Relaxed);
+    }
+
+    /// Events discarded because the buffer was full or the subscription lagged.
+    pub fn record_dropped(&self, count: u64) {
+        self.dropped.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Failed delivery reports (`kafka_delivery_failures_total`).
+    pub fn record_delivery_failures(&self, count: u64) {
+        self.delivery_failures.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> KafkaHealth {
+        KafkaHealth {
+            topic: self.topic.clone(),
+            sent: self.sent.load(Ordering::Relaxed),
+            buffered: self.buffered.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            delivery_failures_total: self.delivery_failures.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Kafka producer status as reported by `/health`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct KafkaHealth {
+    pub topic: String,
+    /// Events delivered since startup
+    pub sent: u64,
+    /// Events waiting for the broker
+    pub buffered: u64,
+    /// Events discarded because the buffer was full
+    pub dropped: u64,
+    /// Failed delivery reports, including attempts later retried
+    pub delivery_failures_total: u64,
+}
+
+/// Live counters shared between an ingest source task and `/health`.
+#[derive(Debug, Default)]
+pub struct IngestStatus {
+    pub name: String,
+    pub kind: &'static str,
+    running: AtomicBool,
+    ingested: AtomicU64,
+    errors: AtomicU64,
+}
+
+impl IngestStatus {
+    pub fn new(name: String, kind: &'static str) -> Self {
+        Self {
+            name,
+            kind,
+            ..Self::default()
+        }
+    }
+
+    pub fn set_running(&self, running: bool) {
+        self.running.store(running, Ordering::Relaxed);
+    }
+
+    pub fn record_ingested(&self) {
+        self.ingested.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// A failed poll or read; the source keeps trying.
+    pub fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> IngestHealth {
+        IngestHealth {
+            name: self.name.clone(),
+            kind: self.kind,
+            running: self.running.load(Ordering::Relaxed),
+            ingested: self.ingested.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Ingest source status as reported by `/health`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct IngestHealth {
+    pub name: String,
+    /// `http`, `file` or `stdin`
+    pub kind: &'static str,
+    /// False once the source has ended (stdin closed)
+    pub running: bool,
+    /// Concepts injected since startup
+    pub ingested: u64,
+    /// Failed polls or reads
+    pub errors: u64,
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use super::app_state::{AppState, ChannelConfig};
+
+/// Most minds that can be created besides the default one
+pub const MAX_MINDS: usize = 64;
+
+/// An isolated mind: its own fluid, channels, simulation loop and the
+/// tasks serving it. Dropping the handle stops them all.
+pub struct MindHandle {
+    pub id: Uuid,
+    pub state: Arc<AppState>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl MindHandle {
+    /// Wrap a mind's state and the tasks running its simulation loop,
+    /// scheduler and action hook.
+    pub fn new(state: Arc<AppState>, tasks: Vec<JoinHandle<()>>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            state,
+            tasks,
+        }
+    }
+}
+
+impl Drop for MindHandle {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+/// Minds created through `/minds`. The default mind, which serves the
+/// unprefixed routes, is the `AppState` holding this registry and is not
+/// listed here.
+#[derive(Default)]
+pub struct Minds {
+    minds: RwLock<HashMap<Uuid, MindHandle>>,
+    /// Channel capacities for new minds
+    pub channel_config: ChannelConfig,
+}
+
+impl Minds {
+    pub fn new(channel_config: ChannelConfig) -> Self {
+        Self {
+            minds: RwLock::new(HashMap::new()),
+            channel_config,
+        }
+    }
+
+    pub async fn get(&self, id: Uuid) -> Option<Arc<AppState>> {
+        let minds = self.minds.read().await;
+        minds.get(&id).map(|mind| mind.state.clone())
+    }
+
+    /// Every mind with its state, in no particular order.
+    pub async fn list(&self) -> Vec<(Uuid, Arc<AppState>)> {
+        let minds = self.minds.read().await;
+        minds
+            .values()
+            .map(|mind| (mind.id, mind.state.clone()))
+            .collect()
+    }
+
+    /// Register a mind. Fails (stopping it) beyond `MAX_MINDS`.
+    pub async fn insert(&self, mind: MindHandle) -> Result<Uuid, String> {
+        let mut minds = self.minds.write().await;
+        if minds.len() >= MAX_MINDS {
+            return Err(format!("At most {} minds can exist", MAX_MINDS));
+        }
+        let id = mind.id;
+        minds.insert(id, mind);
+        Ok(id)
+    }
+
+    /// Remove a mind and stop its tasks, returning its final
+    /// state (None if unknown).
+    pub async fn remove(&self, id: Uuid) -> Option<Arc<AppState>> {
+        let mind = self.minds.write().await.remove(&id)?;
+        Some(mind.state.clone())
+    }
+}
@@ -1,7 +1,29 @@
 pub mod app_state;
 pub mod commands;
+pub mod dispatch;
+pub mod estimator;
 pub mod events;
+pub mod health;
+pub mod minds;
+pub mod schedules;
+pub mod timing;
+pub mod view;
 
-pub use app_state::{AppState, SimulationChannels};
-pub use commands::Command;
-pub use events::FluidEvent;
+pub use app_state::{AppState, ChannelConfig, SimulationChannels};
+pub use commands::{
+    Command, CommandOutcome, CommandPayload, CommandSender, FreshConcept, TracedCommand,
+};
+pub use dispatch::{
+    BuiltinAction, DispatchLog, DispatchLogEntry, DispatchOutcome, DispatchRuleConfig,
+    DispatchRuleView, DispatchRules, DispatchTarget, PatternKind, Route,
+};
+pub use estimator::{
+    DensityEstimator, HeuristicEstimator, Metadata, PassthroughEstimator, RemoteEstimator,
+    density_estimator_from_env,
+};
+pub use events::{EventEnvelope, FluidEvent};
+pub use health::{IngestHealth, IngestStatus, KafkaHealth, KafkaStatus, RedisHealth, RedisStatus};
+pub use minds::{MAX_MINDS, MindHandle, Minds};
+pub use schedules::{CronExpr, Schedule, Schedules};
+pub use timing::{TickTiming, TimingReport};
+pub use view::{ConceptDetail, ConceptSummary, FluidStateResponse};
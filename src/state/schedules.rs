@@ -0,0 +1,213 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Notify, RwLock};
+
+use super::commands::CommandPayload;
+
+/// A cron expression, parsed on deserialization so invalid ones are rejected
+/// when the config is loaded. Accepts the standard five fields
+/// (`min hour day month weekday`) or six/seven with seconds first (and an
+/// optional year), so sub-minute schedules are possible. Day of week is
+/// standard: 0 or 7 is Sunday.
+#[derive(Debug, Clone)]
+pub struct CronExpr {
+    source: String,
+    schedule: cron::Schedule,
+}
+
+impl CronExpr {
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// The first firing strictly after `time`
+    pub fn next_after(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.schedule.after(&time).next()
+    }
+}
+
+impl FromStr for CronExpr {
+    type Err = String;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let source = source.trim();
+        // The cron crate wants seconds first; read five fields as minute-aligned
+        let mut fields: Vec<String> = source.split_whitespace().map(String::from).collect();
+        if fields.len() == 5 {
+            fields.insert(0, "0".to_string());
+        }
+        if let Some(weekday) = fields.get_mut(5)
+            && let Some(names) = weekday_names(weekday)
+        {
+            *weekday = names;
+        }
+        let schedule = cron::Schedule::from_str(&fields.join(" "))
+            .map_err(|err| format!("invalid cron expression {:?}: {}", source, err))?;
+        Ok(Self {
+            source: source.to_string(),
+            schedule,
+        })
+    }
+}
+
+const WEEKDAYS: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// A standard day-of-week field (0 or 7 is Sunday) spelled as day names, since
+/// the cron crate numbers Sunday 1. None leaves the field to the cron crate:
+/// `*`, `?` or anything not made of days, ranges, lists and steps.
+fn weekday_names(field: &str) -> Option<String> {
+    if field == "*" || field == "?" {
+        return None;
+    }
+    let day = |value: &str| -> Option<usize> {
+        match value.parse::<usize>() {
+            Ok(day) if day <= 7 => Some(day),
+            Ok(_) => None,
+            Err(_) => WEEKDAYS
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(value)),
+        }
+    };
+    let mut days = [false; 7];
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<usize>().ok().filter(|&s| s > 0)?),
+            None => (part, 1),
+        };
+        let (first, last) = match range.split_once('-') {
+            _ if range == "*" => (0, 6),
+            Some((first, last)) => (day(first)?, day(last)?),
+            None if step > 1 => (day(range)?, 6),
+            None => (day(range)?, day(range)?),
+        };
+        if first > last {
+            return None;
+        }
+        for day in (first..=last).step_by(step) {
+            days[day % 7] = true;
+        }
+    }
+    let names: Vec<&str> = (0..7).filter(|&d| days[d]).map(|d| WEEKDAYS[d]).collect();
+    Some(names.join(","))
+}
+
+impl Serialize for CronExpr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for CronExpr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// A command the server sends itself on a cron schedule.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Schedule {
+    /// Unique name, reported in `scheduled_command_fired` events
+    pub name: String,
+    /// e.g. `"0 * * * *"` (hourly) or `"*/10 * * * * *"` (every 10 seconds)
+    #[schemars(with = "String")]
+    pub cron: CronExpr,
+    /// The command to send, in WebSocket command form
+    pub command: CommandPayload,
+}
+
+/// The live schedule list, shared by the scheduler task and `/schedules`.
+#[derive(Debug, Default)]
+pub struct Schedules {
+    entries: RwLock<Vec<Schedule>>,
+    changed: Notify,
+}
+
+impl Schedules {
+    /// Schedules from the config file (names already checked unique)
+    pub fn new(entries: Vec<Schedule>) -> Self {
+        Self {
+            entries: RwLock::new(entries),
+            changed: Notify::new(),
+        }
+    }
+
+    pub async fn list(&self) -> Vec<Schedule> {
+        self.entries.read().await.clone()
+    }
+
+    /// Add a schedule; fails if the name is taken.
+    pub async fn insert(&self, schedule: Schedule) -> Result<(), String> {
+        let mut entries = self.entries.write().await;
+        if entries.iter().any(|s| s.name == schedule.name) {
+            return Err(format!("Schedule '{}' already exists", schedule.name));
+        }
+        entries.push(schedule);
+        self.changed.notify_one();
+        Ok(())
+    }
+
+    /// Remove a schedule by name, returning it.
+    pub async fn remove(&self, name: &str) -> Option<Schedule> {
+        let mut entries = self.entries.write().await;
+        let index = entries.iter().position(|s| s.name == name)?;
+        let removed = entries.remove(index);
+        self.changed.notify_one();
+        Some(removed)
+    }
+
+    /// The earliest firing strictly after `time`, with every schedule due then.
+    pub async fn next_due(&self, time: DateTime<Utc>) -> Option<(DateTime<Utc>, Vec<Schedule>)> {
+        let entries = self.entries.read().await;
+        let at = entries
+            .iter()
+            .filter_map(|s| s.cron.next_after(time))
+            .min()?;
+        let due = entries
+            .iter()
+            .filter(|s| s.cron.next_after(time) == Some(at))
+            .cloned()
+            .collect();
+        Some((at, due))
+    }
+
+    /// Resolves after the next insert or remove (or at once if one happened
+    /// since the last call).
+    pub async fn changed(&self) {
+        self.changed.notified().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, TimeZone, Weekday};
+
+    fn next_weekday(cron: &str) -> Weekday {
+        // A Sunday
+        let start = Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap();
+        cron.parse::<CronExpr>()
+            .unwrap()
+            .next_after(start)
+            .unwrap()
+            .weekday()
+    }
+
+    #[test]
+    fn test_weekdays_count_from_sunday_zero() {
+        assert_eq!(next_weekday("0 9 * * 1"), Weekday::Mon);
+        assert_eq!(next_weekday("0 9 * * 0"), Weekday::Sun);
+        assert_eq!(next_weekday("0 9 * * 7"), Weekday::Sun);
+        assert_eq!(next_weekday("0 9 * * 5-7"), Weekday::Fri);
+        assert_eq!(next_weekday("0 9 * * 2,4"), Weekday::Tue);
+        assert_eq!(next_weekday("0 9 * * sat"), Weekday::Sat);
+        assert_eq!(next_weekday("0 0 9 * * 3"), Weekday::Wed);
+        assert_eq!(weekday_names("1-5").unwrap(), "MON,TUE,WED,THU,FRI");
+        assert_eq!(weekday_names("*/2").unwrap(), "SUN,TUE,THU,SAT");
+        assert!("0 9 * * 8".parse::<CronExpr>().is_err());
+    }
+}
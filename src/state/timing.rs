@@ -0,0 +1,115 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// Weight of the newest tick in the average tick duration
+const TICK_DURATION_SMOOTHING: f64 = 0.1;
+
+/// Loop timing shared between the simulation loop and `/timing`.
+///
+/// Instants are passed in rather than read here, so the loop measures real
+/// time and tests can run a clock of their own.
+#[derive(Debug, Default)]
+pub struct TickTiming {
+    inner: Mutex<TimingState>,
+}
+
+#[derive(Debug, Default)]
+struct TimingState {
+    started: Option<Instant>,
+    tick_period: Duration,
+    ticks: u64,
+    average_tick_secs: f64,
+}
+
+impl TickTiming {
+    /// The loop started at `now`, aiming for one tick every `tick_period`.
+    pub fn start(&self, now: Instant, tick_period: Duration) {
+        *self.inner.lock().unwrap() = TimingState {
+            started: Some(now),
+            tick_period,
+            ..TimingState::default()
+        };
+    }
+
+    /// One tick ran, taking `duration` of work.
+    pub fn record_tick(&self, duration: Duration) {
+        let mut timing = self.inner.lock().unwrap();
+        let secs = duration.as_secs_f64();
+        timing.average_tick_secs = if timing.ticks == 0 {
+            secs
+        } else {
+            timing.average_tick_secs + TICK_DURATION_SMOOTHING * (secs - timing.average_tick_secs)
+        };
+        timing.ticks += 1;
+    }
+
+    pub fn snapshot(&self, now: Instant) -> TimingReport {
+        let timing = self.inner.lock().unwrap();
+        let sim_time = timing.ticks as f64 * timing.tick_period.as_secs_f64();
+        let wall_time = timing
+            .started
+            .map_or(0.0, |started| now.duration_since(started).as_secs_f64());
+        TimingReport {
+            running: timing.started.is_some(),
+            tick_count: timing.ticks,
+            sim_time,
+            wall_time,
+            drift: wall_time - sim_time,
+            target_tick_ms: timing.tick_period.as_secs_f64() * 1000.0,
+            average_tick_ms: timing.average_tick_secs * 1000.0,
+        }
+    }
+}
+
+/// How closely the simulation loop is keeping up with real time.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TimingReport {
+    /// False until the simulation loop has started
+    pub running: bool,
+    /// Ticks run by the loop since it started (`/advance` ticks excluded)
+    pub tick_count: u64,
+    /// Simulated seconds: ticks × the tick period
+    pub sim_time: f64,
+    /// Real seconds since the loop started
+    pub wall_time: f64,
+    /// Seconds the simulation has fallen behind real time (skipped ticks)
+    pub drift: f64,
+    pub target_tick_ms: f64,
+    /// Recent average of the work done per tick
+    pub average_tick_ms: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drift_grows_when_ticks_are_delayed() {
+        let period = Duration::from_millis(20);
+        let start = Instant::now();
+        let timing = TickTiming::default();
+        timing.start(start, period);
+
+        // Keeping up: every tick lands on time
+        for _ in 0..50 {
+            timing.record_tick(Duration::from_millis(2));
+        }
+        let on_time = timing.snapshot(start + period * 50);
+        assert_eq!(on_time.tick_count, 50);
+        assert!((on_time.sim_time - 1.0).abs() < 1e-9);
+        assert!(on_time.drift.abs() < 1e-9);
+        assert!((on_time.average_tick_ms - 2.0).abs() < 1e-9);
+
+        // Slow ticks: each takes 60ms, so two of every three are skipped
+        for _ in 0..10 {
+            timing.record_tick(Duration::from_millis(60));
+        }
+        let behind = timing.snapshot(start + period * 50 + Duration::from_millis(600));
+        assert!((behind.drift - 0.4).abs() < 1e-9);
+        assert!(behind.drift > on_time.drift);
+        assert!(behind.average_tick_ms > 30.0);
+    }
+}
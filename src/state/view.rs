@@ -0,0 +1,254 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::estimator::Metadata;
+use crate::simulation::{Concept, ConceptFluid, ConceptLink, ConceptOrigin, SalinityBand};
+
+#[derive(Serialize, JsonSchema)]
+pub struct ConceptSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub layer: f32,
+    pub velocity: f32,
+    pub density: f32,
+    pub buoyancy: f32,
+    pub integration: f32,
+    pub status: String,
+    /// Kinetic energy over surface tension while rising; >= 1.0 means it
+    /// would break through on reaching the surface now
+    pub breakthrough_margin: f32,
+    /// Size of its breaking eddies (0.0 when not turbulent)
+    pub eddy_scale: f32,
+    /// Integration per second it is gaining from those eddies
+    pub integration_rate: f32,
+    /// Space it occupies in consciousness (`density * area`)
+    pub volume: f32,
+    /// Density including any ballast it is carrying
+    pub effective_density: f32,
+    pub is_frozen: bool,
+    pub has_broken_surface: bool,
+    /// Times it has broken through (it re-arms after sinking back)
+    pub breakthrough_count: u32,
+    /// How it came into the fluid
+    pub origin: ConceptOrigin,
+    /// Client metadata given at injection
+    pub metadata: Metadata,
+}
+
+impl ConceptSummary {
+    /// How `/state` and `/concepts` report a concept.
+    pub fn of(fluid: &ConceptFluid, c: &Concept) -> Self {
+        Self {
+            id: c.id,
+            name: c.name.clone(),
+            layer: c.layer,
+            velocity: c.velocity,
+            density: c.density,
+            buoyancy: c.buoyancy,
+            integration: c.integration,
+            status: c.status().to_string(),
+            breakthrough_margin: fluid.breakthrough_margin(c),
+            eddy_scale: c.eddy_scale,
+            integration_rate: fluid.integration_rate(c),
+            volume: c.volume(),
+            effective_density: c.effective_density(),
+            is_frozen: c.is_frozen,
+            has_broken_surface: c.has_broken_surface,
+            breakthrough_count: c.breakthrough_count,
+            origin: c.origin,
+            metadata: c.metadata.clone(),
+        }
+    }
+}
+
+/// A single concept as `/concept/{id}` reports it: the `/state` summary plus
+/// the details a poller watching one thought wants.
+#[derive(Serialize, JsonSchema)]
+pub struct ConceptDetail {
+    #[serde(flatten)]
+    pub summary: ConceptSummary,
+    /// Extra density it is carrying (0.0 when none)
+    pub ballast: f32,
+    /// Seconds it has spent in the freeze zone
+    pub time_at_surface: f32,
+    /// Catalyzed from ore rather than injected
+    pub is_solution: bool,
+}
+
+impl ConceptDetail {
+    pub fn of(fluid: &ConceptFluid, c: &Concept) -> Self {
+        Self {
+            summary: ConceptSummary::of(fluid, c),
+            ballast: c.ballast,
+            time_at_surface: c.time_at_surface,
+            is_solution: c.is_solution,
+        }
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct CoreTruthSummary {
+    pub name: String,
+    pub heat_output: f32,
+    pub depth: f32,
+    pub radius: f32,
+    pub activation_count: u32,
+    pub active: bool,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct OreSummary {
+    pub name: String,
+    pub ore_type: String,
+    pub depth: f32,
+    pub integration_value: f32,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct ContinentSummary {
+    pub name: String,
+    pub depth_range: (f32, f32),
+    pub total_integration: f32,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct TraitSummary {
+    pub name: String,
+    pub integration: f32,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct FluidStateResponse {
+    // Entities
+    pub concepts: Vec<ConceptSummary>,
+    pub core_truths: Vec<CoreTruthSummary>,
+    pub ore_deposits: Vec<OreSummary>,
+    pub continents: Vec<ContinentSummary>,
+    pub atmosphere: Vec<TraitSummary>,
+    pub links: Vec<ConceptLink>,
+
+    // Global state
+    pub is_frozen: bool,
+    pub is_turbulent: bool,
+    pub turbulence_energy: f32,
+    pub total_integration: f32,
+    pub salinity: f32,
+    pub salinity_band: SalinityBand,
+    pub ocean_floor_pressure: f32,
+    pub pressure_threshold: f32,
+    pub tectonic_shifts: u32,
+
+    // Physics
+    /// Viscosity at the surface, rising or falling linearly to
+    /// `viscosity_deep` at the floor
+    pub viscosity_surface: f32,
+    pub viscosity_deep: f32,
+}
+
+impl FluidStateResponse {
+    /// Compact snapshot of the fluid (also mirrored to Redis when enabled).
+    pub fn from_fluid(fluid: &ConceptFluid) -> Self {
+        let concepts: Vec<_> = fluid
+            .concepts
+            .values()
+            .map(|c| ConceptSummary::of(fluid, c))
+            .collect();
+
+        let core_truths: Vec<_> = fluid
+            .core_truths
+            .iter()
+            .map(|v| CoreTruthSummary {
+                name: v.name.clone(),
+                heat_output: v.heat_output,
+                depth: v.depth,
+                radius: v.radius,
+                activation_count: v.activation_count,
+                active: v.active,
+            })
+            .collect();
+
+        let ore_deposits: Vec<_> = fluid
+            .ore_deposits
+            .iter()
+            .map(|o| OreSummary {
+                name: o.name.clone(),
+                ore_type: o.ore_type.as_str().to_string(),
+                depth: o.depth,
+                integration_value: o.integration_value,
+            })
+            .collect();
+
+        let continents: Vec<_> = fluid
+            .continents
+            .iter()
+            .map(|c| ContinentSummary {
+                name: c.name.clone(),
+                depth_range: c.depth_range,
+                total_integration: c.total_integration,
+            })
+            .collect();
+
+        let atmosphere: Vec<_> = fluid
+            .atmosphere
+            .iter()
+            .map(|t| TraitSummary {
+                name: t.name.clone(),
+                integration: t.integration,
+            })
+            .collect();
+
+        Self {
+            concepts,
+            core_truths,
+            ore_deposits,
+            continents,
+            atmosphere,
+            links: fluid.links.clone(),
+            is_frozen: fluid.is_frozen,
+            is_turbulent: fluid.is_turbulent,
+            turbulence_energy: fluid.turbulence_energy,
+            total_integration: fluid.total_integration,
+            salinity: fluid.salinity,
+            salinity_band: fluid.salinity_band,
+            ocean_floor_pressure: fluid.ocean_floor_pressure,
+            pressure_threshold: fluid.pressure_threshold,
+            tectonic_shifts: fluid.tectonic_shifts,
+            viscosity_surface: fluid.viscosity_surface,
+            viscosity_deep: fluid.viscosity_deep,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_velocity_spike_shows_breaking_eddies() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("jolt".to_string(), 0.5, 0.5);
+        let calm = FluidStateResponse::from_fluid(&fluid);
+        assert_eq!(calm.concepts[0].eddy_scale, 0.0);
+        assert_eq!(calm.concepts[0].integration_rate, 0.0);
+
+        fluid.get_concept_mut(id).unwrap().velocity = 2.0;
+        fluid.update(1.0 / 60.0);
+        let snapshot = FluidStateResponse::from_fluid(&fluid);
+        let concept = &snapshot.concepts[0];
+        assert!(concept.eddy_scale > 0.0);
+        assert!(concept.integration_rate > 0.0);
+    }
+
+    #[test]
+    fn test_reported_volume_is_density_times_area() {
+        let mut fluid = ConceptFluid::default();
+        let id = fluid.add_concept("weighty".to_string(), 0.6, 0.8);
+        fluid.get_concept_mut(id).unwrap().ballast = 0.3;
+
+        let snapshot = FluidStateResponse::from_fluid(&fluid);
+        let concept = &snapshot.concepts[0];
+        assert!((concept.volume - 0.6 * 0.8).abs() < 1e-6);
+        assert!((concept.effective_density - 0.9).abs() < 1e-6);
+    }
+}
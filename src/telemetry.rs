@@ -0,0 +1,99 @@
+use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, util::SubscriberInitExt};
+
+/// Spans exported over OTLP. Request spans from tower-http are emitted at
+/// debug level and parent the handler spans, so they are kept.
+#[cfg(feature = "otel")]
+const OTEL_SPAN_FILTER: &str = "info,tower_http=debug";
+
+/// Keeps the span exporter alive; dropping it flushes any buffered spans.
+#[must_use = "dropping Telemetry shuts down span export"]
+pub struct Telemetry {
+    #[cfg(feature = "otel")]
+    provider: Option<opentelemetry_sdk::trace::SdkTracerProvider>,
+}
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        #[cfg(feature = "otel")]
+        if let Some(provider) = self.provider.take()
+            && let Err(err) = provider.shutdown()
+        {
+            eprintln!("Failed to flush spans: {}", err);
+        }
+    }
+}
+
+/// Install the global subscriber: compact console logs filtered by `RUST_LOG`
+/// (default `info`), plus OTLP span export when built with the `otel` feature
+/// and `OTEL_EXPORTER_OTLP_ENDPOINT` is set.
+pub fn init() -> Telemetry {
+    let console = tracing_subscriber::fmt::layer()
+        .with_target(false)
+        .compact()
+        .with_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")));
+    let registry = tracing_subscriber::registry().with(console);
+
+    #[cfg(feature = "otel")]
+    {
+        let provider = otlp_provider_from_env();
+        let otel = provider
+            .as_ref()
+            .ok()
+            .and_then(Option::as_ref)
+            .map(|provider| otel_layer(provider).with_filter(EnvFilter::new(OTEL_SPAN_FILTER)));
+        registry.with(otel).init();
+
+        let provider = provider.unwrap_or_else(|err| {
+            tracing::warn!("OTLP export disabled: {}", err);
+            None
+        });
+        if provider.is_some() {
+            tracing::info!("Exporting spans over OTLP");
+        }
+        Telemetry { provider }
+    }
+
+    #[cfg(not(feature = "otel"))]
+    {
+        registry.init();
+        Telemetry {}
+    }
+}
+
+/// A tracing layer that records spans into `provider`.
+#[cfg(feature = "otel")]
+pub fn otel_layer<S>(provider: &opentelemetry_sdk::trace::SdkTracerProvider) -> impl Layer<S>
+where
+    S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+{
+    use opentelemetry::trace::TracerProvider as _;
+
+    tracing_opentelemetry::layer().with_tracer(provider.tracer(env!("CARGO_PKG_NAME")))
+}
+
+/// A batching OTLP (gRPC) tracer provider, or None when
+/// `OTEL_EXPORTER_OTLP_ENDPOINT` is unset. The exporter reads the endpoint and
+/// the other standard `OTEL_EXPORTER_OTLP_*` variables itself.
+#[cfg(feature = "otel")]
+fn otlp_provider_from_env() -> Result<Option<opentelemetry_sdk::trace::SdkTracerProvider>, String> {
+    use opentelemetry_sdk::{Resource, trace::SdkTracerProvider};
+
+    if std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").is_ok_and(|v| !v.trim().is_empty()) {
+        let exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_tonic()
+            .build()
+            .map_err(|err| err.to_string())?;
+        let mut resource = Resource::builder();
+        if std::env::var("OTEL_SERVICE_NAME").is_err() {
+            resource = resource.with_service_name(env!("CARGO_PKG_NAME"));
+        }
+        Ok(Some(
+            SdkTracerProvider::builder()
+                .with_batch_exporter(exporter)
+                .with_resource(resource.build())
+                .build(),
+        ))
+    } else {
+        Ok(None)
+    }
+}